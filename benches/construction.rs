@@ -0,0 +1,42 @@
+//! Cost of building the builtin interpreters: once, on the first use of their shared tables,
+//! and then on each construction.
+//!
+//! Run with `cargo bench --bench construction`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use text2num::{get_interpreter_for, replace_numbers_in_text};
+
+const SAMPLES: [(&str, &str); 4] = [
+    ("de", "zweiundzwanzig"),
+    ("it", "ventidue"),
+    ("nl", "tweeëntwintig"),
+    ("en", "twenty-two"),
+];
+
+fn time<R>(rounds: u32, mut f: impl FnMut() -> R) -> Duration {
+    let start = Instant::now();
+    for _ in 0..rounds {
+        black_box(f());
+    }
+    start.elapsed() / rounds
+}
+
+fn main() {
+    let rounds = 100_000;
+    for (code, sample) in SAMPLES {
+        // the compound splitters of German, Italian and Dutch are built on the first use
+        let first = time(1, || {
+            replace_numbers_in_text(sample, &get_interpreter_for(code).unwrap(), 0.0)
+        });
+        let construction = time(rounds, || get_interpreter_for(black_box(code)));
+        let language = get_interpreter_for(code).unwrap();
+        let reuse = time(rounds, || {
+            replace_numbers_in_text(black_box(sample), &language, 0.0)
+        });
+        println!(
+            "{code}: first use {first:?}, construction {construction:?}, replacement {reuse:?}"
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Compare [`extract_values`] with replacing the numbers, then scanning the output for digits.
+//!
+//! Run with `cargo bench --bench extract_values`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use text2num::{Language, Options, extract_values, replace_numbers_in_text_with};
+
+const SAMPLE: &str = "so we sold twenty-one thousand three hundred units in the first quarter, \
+    then about two point five million in the second one, and minus seven percent in the third, \
+    which leaves forty-two customers for the one hundred and fifth time. ";
+
+/// The runs of digits, decimal points and signs of `text`, parsed as numbers.
+fn scan_digits(text: &str) -> Vec<f64> {
+    let mut values = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        let in_number = c.is_ascii_digit() || (start.is_some() && c == '.') || c == '-';
+        match (start, in_number) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                if let Ok(value) = text[s..i].trim_end_matches('.').parse() {
+                    values.push(value);
+                }
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    values
+}
+
+fn time<R>(name: &str, rounds: u32, mut f: impl FnMut() -> R) -> Duration {
+    let start = Instant::now();
+    for _ in 0..rounds {
+        black_box(f());
+    }
+    let elapsed = start.elapsed() / rounds;
+    println!("{name:<24} {elapsed:?}");
+    elapsed
+}
+
+fn main() {
+    let en = Language::english();
+    let options = Options::new(10.0);
+    let text = SAMPLE.repeat(500);
+    let rounds = 20;
+    let extract = time("extract_values", rounds, || {
+        extract_values(black_box(&text), &en, &options)
+    });
+    let replace = time("replace then scan", rounds, || {
+        scan_digits(&replace_numbers_in_text_with(
+            black_box(&text),
+            &en,
+            &options,
+        ))
+    });
+    println!(
+        "speedup: {:.2}x",
+        replace.as_secs_f64() / extract.as_secs_f64()
+    );
+}
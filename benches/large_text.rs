@@ -0,0 +1,60 @@
+//! Time and peak memory of [`replace_numbers_in_text`] on a text of about 10 MB.
+//!
+//! Run with `cargo bench --bench large_text`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use text2num::{Language, replace_numbers_in_text};
+
+struct Counting;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+// SAFETY: all the calls are forwarded to the system allocator.
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const SAMPLE: &str = "So we sold Twenty-One thousand three hundred units in the First Quarter, \
+    then about two point five million in the second one, and nobody counted the rest. ";
+
+fn main() {
+    let en = Language::english();
+    let text = SAMPLE.repeat(10_000_000 / SAMPLE.len());
+    let tokens = text2num::pipeline::tokenize(SAMPLE).len() * (text.len() / SAMPLE.len());
+    let rounds = 5;
+
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..rounds {
+        black_box(replace_numbers_in_text(black_box(&text), &en, 10.0));
+    }
+    let elapsed = start.elapsed() / rounds;
+    let peak = PEAK.load(Ordering::Relaxed) - base;
+    println!(
+        "{} bytes, {tokens} tokens: {elapsed:?} ({:.1} MB/s)",
+        text.len(),
+        text.len() as f64 / elapsed.as_secs_f64() / 1e6
+    );
+    println!(
+        "peak allocation: {peak} bytes ({:.2} per token beyond the output)",
+        peak.saturating_sub(text.len()) as f64 / tokens as f64
+    );
+}
@@ -0,0 +1,58 @@
+//! Count the bytes allocated by [`replace_numbers_in_text_cow`] and [`replace_numbers_in_text_with`],
+//! on a text without numbers to replace and on a text with some.
+//! Both allocate a window of tokens of a fixed size; only the first allocates the output of a text without numbers.
+//!
+//! Run with `cargo bench --bench replaced`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use text2num::{Language, Options, replace_numbers_in_text_cow, replace_numbers_in_text_with};
+
+struct Counting;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+// SAFETY: all the calls are forwarded to the system allocator.
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const PLAIN: &str =
+    "The quick brown fox jumps over the lazy dog, and nobody counts anything here. ";
+const NUMBERS: &str = "So we sold twenty-one thousand units, then forty-two more. ";
+
+fn allocated(name: &str, text: &str, f: impl Fn(&str)) {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    f(black_box(text));
+    let bytes = ALLOCATED.load(Ordering::Relaxed) - before;
+    println!(
+        "{name:<24} {bytes:>9} bytes allocated ({:.2} per input byte)",
+        bytes as f64 / text.len() as f64
+    );
+}
+
+fn main() {
+    let en = Language::english();
+    let options = Options::new(10.0);
+    for (kind, sample) in [("plain", PLAIN), ("numbers", NUMBERS)] {
+        let text = sample.repeat(500);
+        allocated(&format!("{kind}, String"), &text, |text| {
+            black_box(replace_numbers_in_text_with(text, &en, &options));
+        });
+        allocated(&format!("{kind}, Cow"), &text, |text| {
+            black_box(replace_numbers_in_text_cow(text, &en, &options));
+        });
+    }
+}
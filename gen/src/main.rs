@@ -0,0 +1,107 @@
+/*!
+Generate the word table of a text2num data language from the spellout rules of a CLDR RBNF file.
+
+```text
+text2num-gen [--ruleset NAME]... [--decimal-symbol C] [--crate PATH] [--output FILE] RBNF_FILE
+```
+
+The table reads the public `spellout-cardinal*` rule sets of the file, or the ones given with `--ruleset`,
+and the rule sets they refer to. The decimal separator word of the `x.x` rules is only kept with
+`--decimal-symbol`, as the rules don't say which symbol it stands for. The source is written on the standard
+output, or in the `--output` file; `--crate crate` writes a table for the crate itself.
+
+The builtin tables are regenerated with the command written in their header, from the repository root.
+*/
+
+use std::fmt;
+use std::process::ExitCode;
+
+mod rbnf;
+mod table;
+
+use table::Settings;
+
+/// Why a file couldn't be turned into a table.
+#[derive(Debug)]
+pub enum GenError {
+    /// The file is not the RBNF file we expect
+    Xml(String),
+    /// A rule is not understood
+    Rule {
+        ruleset: String,
+        value: String,
+        message: String,
+    },
+}
+
+impl fmt::Display for GenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenError::Xml(message) => f.write_str(message),
+            GenError::Rule {
+                ruleset,
+                value,
+                message,
+            } => write!(f, "{ruleset}, rule {value}: {message}"),
+        }
+    }
+}
+
+const USAGE: &str = "usage: text2num-gen [--ruleset NAME]... [--decimal-symbol C] [--crate PATH] [--output FILE] RBNF_FILE";
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut settings = Settings::default();
+    let mut krate = "text2num".to_string();
+    let mut output = None;
+    let mut source = None;
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        let mut value = || args_iter.next().cloned().ok_or_else(|| USAGE.to_string());
+        match arg.as_str() {
+            "--ruleset" => settings
+                .rulesets
+                .push(value()?.trim_start_matches('%').to_string()),
+            "--decimal-symbol" => {
+                let symbol = value()?;
+                let mut chars = symbol.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => settings.decimal_symbol = Some(c),
+                    _ => {
+                        return Err(format!(
+                            "the decimal symbol \"{symbol}\" is not one character"
+                        ));
+                    }
+                }
+            }
+            "--crate" => krate = value()?,
+            "--output" => output = Some(value()?),
+            "-h" | "--help" => return Err(USAGE.into()),
+            _ if source.is_none() && !arg.starts_with("--") => source = Some(arg.clone()),
+            _ => return Err(USAGE.into()),
+        }
+    }
+    let source = source.ok_or_else(|| USAGE.to_string())?;
+    let xml = std::fs::read_to_string(&source).map_err(|e| format!("{source}: {e}"))?;
+    let document = rbnf::parse(&xml).map_err(|e| format!("{source}: {e}"))?;
+    let table = table::generate(&document, &settings).map_err(|e| format!("{source}: {e}"))?;
+    let command = format!("cargo run -p text2num-gen -- {}", args.join(" "));
+    let code = table::emit(&table, &source, &command, &krate);
+    match output {
+        Some(path) => std::fs::write(&path, code).map_err(|e| format!("{path}: {e}")),
+        None => {
+            print!("{code}");
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("text2num-gen: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
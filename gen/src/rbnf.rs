@@ -0,0 +1,338 @@
+//! Reading of the rule sets of a CLDR RBNF file.
+//!
+//! Both layouts of the CLDR files are read: the one rule per `<rbnfrule>` element of the older releases,
+//! and the ICU rule syntax of the `<rbnfRules>` element of the newer ones.
+
+use crate::GenError;
+
+/// A rule of a rule set: its value, like "*20*" or "*x.x*", its radix and its body, without the final ';'.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub value: String,
+    pub radix: Option<u64>,
+    pub body: String,
+}
+
+/// A rule set, like `%spellout-cardinal-masculine`, without its '%'.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    pub name: String,
+    /// The private rule sets (`%%name`) are only used by the other rule sets
+    pub private: bool,
+    pub rules: Vec<Rule>,
+}
+
+/// The spellout rule sets of a file, and the language of the file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+    pub language: Option<String>,
+    pub rulesets: Vec<RuleSet>,
+}
+
+impl Document {
+    pub fn ruleset(&self, name: &str) -> Option<&RuleSet> {
+        self.rulesets.iter().find(|ruleset| ruleset.name == name)
+    }
+}
+
+/// An XML tag: its name, its attributes, and whether it closes an element or is empty.
+struct Tag<'a> {
+    name: &'a str,
+    attributes: &'a str,
+    closing: bool,
+    empty: bool,
+}
+
+impl<'a> Tag<'a> {
+    fn attribute(&self, name: &str) -> Option<String> {
+        let mut rest = self.attributes;
+        while let Some(eq) = rest.find('=') {
+            let key = rest[..eq].trim();
+            let after = rest[eq + 1..].trim_start();
+            let quote = after.chars().next()?;
+            let value = &after[1..];
+            let end = value.find(quote)?;
+            if key == name {
+                return Some(decode_entities(&value[..end]));
+            }
+            rest = &value[end + 1..];
+        }
+        None
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The tags and the text between them, comments and declarations skipped.
+enum Node<'a> {
+    Tag(Tag<'a>),
+    Text(String),
+}
+
+fn nodes(xml: &str) -> Result<Vec<Node<'_>>, GenError> {
+    let mut nodes = Vec::new();
+    let mut rest = xml;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            nodes.push(Node::Text(decode_entities(rest)));
+            break;
+        };
+        if start > 0 {
+            nodes.push(Node::Text(decode_entities(&rest[..start])));
+        }
+        rest = &rest[start..];
+        let (skip, end) = if rest.starts_with("<!--") {
+            (true, "-->")
+        } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata
+                .find("]]>")
+                .ok_or_else(|| GenError::Xml("unterminated CDATA section".into()))?;
+            nodes.push(Node::Text(cdata[..end].into()));
+            rest = &cdata[end + 3..];
+            continue;
+        } else if rest.starts_with("<?") {
+            (true, "?>")
+        } else if rest.starts_with("<!") {
+            (true, ">")
+        } else {
+            (false, ">")
+        };
+        let end = rest
+            .find(end)
+            .ok_or_else(|| GenError::Xml("unterminated tag".into()))?
+            + end.len();
+        if !skip {
+            let inner = &rest[1..end - 1];
+            let closing = inner.starts_with('/');
+            let empty = inner.ends_with('/');
+            let inner = inner.trim_start_matches('/').trim_end_matches('/');
+            let (name, attributes) = inner.split_once(char::is_whitespace).unwrap_or((inner, ""));
+            nodes.push(Node::Tag(Tag {
+                name,
+                attributes,
+                closing,
+                empty,
+            }));
+        }
+        rest = &rest[end..];
+    }
+    Ok(nodes)
+}
+
+/// Read the language and the spellout rule sets of the RBNF file `xml`.
+pub fn parse(xml: &str) -> Result<Document, GenError> {
+    let mut document = Document::default();
+    let mut grouping: Option<bool> = None;
+    let mut ruleset: Option<RuleSet> = None;
+    let mut rule: Option<Rule> = None;
+    let mut syntax: Option<String> = None;
+    for node in nodes(xml)? {
+        match node {
+            Node::Tag(tag) if tag.closing => match tag.name {
+                "rulesetGrouping" => grouping = None,
+                "ruleset" => document.rulesets.extend(ruleset.take()),
+                "rbnfrule" => {
+                    if let (Some(mut rule), Some(ruleset)) = (rule.take(), ruleset.as_mut()) {
+                        rule.body = rule.body.trim().trim_end_matches(';').to_string();
+                        ruleset.rules.push(rule);
+                    }
+                }
+                "rbnfRules" => {
+                    if let Some(text) = syntax.take() {
+                        document.rulesets.extend(parse_icu_syntax(&text)?);
+                    }
+                }
+                _ => {}
+            },
+            Node::Tag(tag) => match tag.name {
+                "language" if document.language.is_none() => {
+                    document.language = tag.attribute("type")
+                }
+                "rulesetGrouping" => {
+                    grouping = Some(tag.attribute("type").as_deref() == Some("SpelloutRules"))
+                }
+                "ruleset" if grouping == Some(true) && !tag.empty => {
+                    ruleset = Some(RuleSet {
+                        name: tag.attribute("type").unwrap_or_default(),
+                        private: tag.attribute("access").as_deref() == Some("private"),
+                        rules: Vec::new(),
+                    })
+                }
+                "rbnfrule" if ruleset.is_some() && !tag.empty => {
+                    let radix =
+                        match tag.attribute("radix") {
+                            Some(radix) => Some(radix.parse().map_err(|_| {
+                                GenError::Xml(format!("invalid radix \"{radix}\""))
+                            })?),
+                            None => None,
+                        };
+                    rule = Some(Rule {
+                        value: tag.attribute("value").unwrap_or_default(),
+                        radix,
+                        body: String::new(),
+                    })
+                }
+                "rbnfRules" if grouping == Some(true) && !tag.empty => syntax = Some(String::new()),
+                _ => {}
+            },
+            Node::Text(text) => {
+                if let Some(rule) = rule.as_mut() {
+                    rule.body.push_str(&text);
+                } else if let Some(syntax) = syntax.as_mut() {
+                    syntax.push_str(&text);
+                }
+            }
+        }
+    }
+    Ok(document)
+}
+
+/// Read the rule sets written in the ICU syntax: `%name:` then `value: body;` rules.
+fn parse_icu_syntax(text: &str) -> Result<Vec<RuleSet>, GenError> {
+    let mut rulesets: Vec<RuleSet> = Vec::new();
+    for statement in text.split(';') {
+        let mut statement = statement.trim();
+        if statement.is_empty() {
+            continue;
+        }
+        if statement.starts_with('%') {
+            let (name, rest) = statement.split_once(':').ok_or_else(|| {
+                GenError::Xml(format!("rule set without a colon: \"{statement}\""))
+            })?;
+            let private = name.starts_with("%%");
+            rulesets.push(RuleSet {
+                name: name.trim_start_matches('%').to_string(),
+                private,
+                rules: Vec::new(),
+            });
+            statement = rest.trim();
+        }
+        let ruleset = rulesets
+            .last_mut()
+            .ok_or_else(|| GenError::Xml(format!("rule outside of a rule set: \"{statement}\"")))?;
+        let (descriptor, body) = statement
+            .split_once(": ")
+            .ok_or_else(|| GenError::Xml(format!("rule without a value: \"{statement}\"")))?;
+        let (value, radix) = match descriptor.split_once('/') {
+            Some((value, radix)) => (
+                value,
+                Some(
+                    radix
+                        .parse()
+                        .map_err(|_| GenError::Xml(format!("invalid radix \"{radix}\"")))?,
+                ),
+            ),
+            None => (descriptor, None),
+        };
+        ruleset.rules.push(Rule {
+            value: value.replace(',', ""),
+            radix,
+            // a leading apostrophe keeps the spaces after it
+            body: body.trim().trim_start_matches('\'').to_string(),
+        });
+    }
+    Ok(rulesets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(value: &str, body: &str) -> Rule {
+        Rule {
+            value: value.into(),
+            radix: None,
+            body: body.into(),
+        }
+    }
+
+    #[test]
+    fn test_rbnfrule_elements() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<!DOCTYPE ldml SYSTEM "ldml.dtd">
+<!-- a comment with <tags> -->
+<ldml>
+    <identity><language type="xx"/></identity>
+    <rbnf>
+        <rulesetGrouping type="OrdinalRules">
+            <ruleset type="digits-ordinal"><rbnfrule value="0">=#,##0=.;</rbnfrule></ruleset>
+        </rulesetGrouping>
+        <rulesetGrouping type="SpelloutRules">
+            <ruleset type="spellout-cardinal">
+                <rbnfrule value="1">one;</rbnfrule>
+                <rbnfrule value="20">twenty[-→→];</rbnfrule>
+                <rbnfrule value="100" radix="1000">←← hundred &amp; →→;</rbnfrule>
+            </ruleset>
+            <ruleset type="lenient" access="private">
+                <rbnfrule value="0">=%spellout-cardinal=;</rbnfrule>
+            </ruleset>
+        </rulesetGrouping>
+    </rbnf>
+</ldml>"#;
+        let document = parse(xml).unwrap();
+        assert_eq!(document.language.as_deref(), Some("xx"));
+        assert_eq!(document.rulesets.len(), 2);
+        let cardinal = document.ruleset("spellout-cardinal").unwrap();
+        assert!(!cardinal.private);
+        assert_eq!(
+            cardinal.rules,
+            [
+                rule("1", "one"),
+                rule("20", "twenty[-→→]"),
+                Rule {
+                    radix: Some(1000),
+                    ..rule("100", "←← hundred & →→")
+                }
+            ]
+        );
+        assert!(document.ruleset("lenient").unwrap().private);
+        assert!(document.ruleset("digits-ordinal").is_none());
+    }
+
+    #[test]
+    fn test_icu_syntax() {
+        let xml = r#"<ldml><rbnf><rulesetGrouping type="SpelloutRules"><rbnfRules><![CDATA[
+%spellout-numbering:
+    0: =%spellout-cardinal=;
+%%private:
+    0: ' and;
+%spellout-cardinal:
+    1: one;
+    1,000/1000: ←← thousand[ →→];
+]]></rbnfRules></rulesetGrouping></rbnf></ldml>"#;
+        let document = parse(xml).unwrap();
+        let names: Vec<_> = document
+            .rulesets
+            .iter()
+            .map(|ruleset| (ruleset.name.as_str(), ruleset.private))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("spellout-numbering", false),
+                ("private", true),
+                ("spellout-cardinal", false)
+            ]
+        );
+        assert_eq!(
+            document.ruleset("spellout-cardinal").unwrap().rules,
+            [
+                rule("1", "one"),
+                Rule {
+                    radix: Some(1000),
+                    ..rule("1000", "←← thousand[ →→]")
+                }
+            ]
+        );
+        assert_eq!(
+            document.ruleset("private").unwrap().rules,
+            [rule("0", " and")]
+        );
+    }
+}
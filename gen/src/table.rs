@@ -0,0 +1,571 @@
+//! Interpretation of the spellout rules as a table of words, and its Rust source.
+//!
+//! Only the rules of the languages that spell their numbers additively, from left to right, are understood:
+//! a word for each number under a thousand, or a round number followed by the rest ("*vinte[ e →→]*"),
+//! and a multiplier before a scale word ("*←← mil[ →→]*"). Everything else is an error, so that a file
+//! that can't be read faithfully is never turned into a wrong table.
+
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use crate::GenError;
+use crate::rbnf::{Document, Rule};
+
+/// The words of a language, like `text2num::lang::LanguageTable`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Table {
+    pub code: String,
+    pub cardinals: Vec<(String, u64)>,
+    pub scales: Vec<(String, u32)>,
+    pub links: Vec<(String, u32)>,
+    pub decimal_separators: Vec<(String, char)>,
+}
+
+/// How to generate a table.
+#[derive(Debug, Default)]
+pub struct Settings {
+    /// The rule sets to read; the public `spellout-cardinal*` ones if empty
+    pub rulesets: Vec<String>,
+    /// The symbol of the decimal separator word of the `x.x` rules; they are skipped without it
+    pub decimal_symbol: Option<char>,
+}
+
+/// A piece of the body of a rule.
+#[derive(Debug)]
+enum Part {
+    Text(String),
+    /// `←←`, with the rule set it names, if any
+    Quotient(Option<String>),
+    /// `→→`, with the rule set it names, if any
+    Remainder(Option<String>),
+    /// `=%name=`
+    Same(String),
+    Open,
+    Close,
+}
+
+impl Part {
+    fn is_substitution(&self) -> bool {
+        matches!(self, Part::Quotient(_) | Part::Remainder(_) | Part::Same(_))
+    }
+}
+
+/// Split the body of a rule in its pieces.
+fn parts(body: &str) -> Result<Vec<Part>, String> {
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '←' | '<' | '→' | '>' | '=' => {
+                let mut inner = String::new();
+                loop {
+                    match chars.next() {
+                        Some(next) if next == c => break,
+                        Some(next) => inner.push(next),
+                        None => return Err(format!("unterminated substitution \"{c}{inner}\"")),
+                    }
+                }
+                if chars.peek() == Some(&c) {
+                    return Err(format!("the substitution \"{c}{c}{c}\" is not supported"));
+                }
+                let ruleset = match inner.strip_prefix('%') {
+                    Some(name) => Some(name.trim_start_matches('%').to_string()),
+                    None if inner.is_empty() => None,
+                    None => {
+                        return Err(format!(
+                            "the number format \"{c}{inner}{c}\" is not supported"
+                        ));
+                    }
+                };
+                match (c, ruleset) {
+                    ('←' | '<', ruleset) => Part::Quotient(ruleset),
+                    ('→' | '>', ruleset) => Part::Remainder(ruleset),
+                    (_, Some(ruleset)) => Part::Same(ruleset),
+                    (_, None) => return Err("an empty \"==\" substitution".into()),
+                }
+            }
+            '[' => Part::Open,
+            ']' => Part::Close,
+            '$' if chars.peek() == Some(&'(') => {
+                return Err("the plural patterns \"$(…)$\" are not supported".into());
+            }
+            c => {
+                text.push(c);
+                continue;
+            }
+        };
+        if !text.is_empty() {
+            parts.push(Part::Text(std::mem::take(&mut text)));
+        }
+        parts.push(token);
+    }
+    if !text.is_empty() {
+        parts.push(Part::Text(text));
+    }
+    // a word glued to a substitution, like "←←cientos", is not a word of its own
+    let pieces: Vec<_> = parts
+        .iter()
+        .filter(|part| !matches!(part, Part::Open | Part::Close))
+        .collect();
+    for pair in pieces.windows(2) {
+        let fused = match pair {
+            [Part::Text(text), next] if next.is_substitution() => {
+                !text.ends_with(char::is_whitespace)
+            }
+            [previous, Part::Text(text)] if previous.is_substitution() => {
+                !text.starts_with(char::is_whitespace)
+            }
+            [previous, next] => previous.is_substitution() && next.is_substitution(),
+            _ => false,
+        };
+        if fused {
+            return Err("a substitution fused with a word is not supported".into());
+        }
+    }
+    Ok(parts)
+}
+
+/// A word of a rule, and whether it is inside the brackets.
+struct Word {
+    text: String,
+    optional: bool,
+}
+
+/// The shape of a rule: its words before the remainder, and its substitutions.
+struct Shape {
+    words: Vec<Word>,
+    quotient: bool,
+    remainder: bool,
+    /// The rule sets named by the substitutions
+    references: Vec<String>,
+}
+
+fn shape(parts: Vec<Part>) -> Result<Shape, String> {
+    let mut shape = Shape {
+        words: Vec::new(),
+        quotient: false,
+        remainder: false,
+        references: Vec::new(),
+    };
+    let mut optional = false;
+    for part in parts {
+        match part {
+            Part::Open if optional => return Err("nested brackets".into()),
+            Part::Open => optional = true,
+            Part::Close => optional = false,
+            Part::Text(text) => {
+                for word in text.split_whitespace() {
+                    if shape.remainder {
+                        return Err(format!("the word \"{word}\" after the remainder"));
+                    }
+                    shape.words.push(Word {
+                        text: word.to_lowercase(),
+                        optional,
+                    });
+                }
+            }
+            Part::Quotient(ruleset) => {
+                if !shape.words.is_empty() {
+                    return Err("words before the multiplier".into());
+                }
+                shape.quotient = true;
+                shape.references.extend(ruleset);
+            }
+            Part::Remainder(ruleset) => {
+                shape.remainder = true;
+                shape.references.extend(ruleset);
+            }
+            Part::Same(ruleset) => shape.references.push(ruleset),
+        }
+    }
+    Ok(shape)
+}
+
+struct Builder {
+    table: Table,
+    decimal_symbol: Option<char>,
+}
+
+impl Builder {
+    fn cardinal(&mut self, word: &str, value: u64) -> Result<(), String> {
+        if self.table.scales.iter().any(|(known, _)| known == word) {
+            return Err(format!("\"{word}\" is both a number and a scale word"));
+        }
+        match self.table.cardinals.iter().find(|(known, _)| known == word) {
+            Some((_, known)) if *known != value => {
+                Err(format!("\"{word}\" is both {known} and {value}"))
+            }
+            Some(_) => Ok(()),
+            None => {
+                self.table.cardinals.push((word.to_string(), value));
+                Ok(())
+            }
+        }
+    }
+
+    fn scale(&mut self, word: &str, power: u32) -> Result<(), String> {
+        if self.table.cardinals.iter().any(|(known, _)| known == word) {
+            return Err(format!("\"{word}\" is both a number and a scale word"));
+        }
+        match self.table.scales.iter().find(|(known, _)| known == word) {
+            Some((_, known)) if *known != power => Err(format!(
+                "the scale word \"{word}\" is both 10^{known} and 10^{power}"
+            )),
+            Some(_) => Ok(()),
+            None => {
+                self.table.scales.push((word.to_string(), power));
+                Ok(())
+            }
+        }
+    }
+
+    fn link(&mut self, word: &str, zeroes: u32) {
+        if !self
+            .table
+            .links
+            .iter()
+            .any(|(known, z)| known == word && *z == zeroes)
+        {
+            self.table.links.push((word.to_string(), zeroes));
+        }
+    }
+
+    /// Read a rule, and return the rule sets it refers to.
+    fn rule(&mut self, rule: &Rule) -> Result<Vec<String>, String> {
+        let shape = shape(parts(&rule.body)?)?;
+        let value = match rule.value.as_str() {
+            "-x" | "Inf" | "NaN" => return Ok(Vec::new()),
+            "x.x" => {
+                if let Some(symbol) = self.decimal_symbol {
+                    match shape.words.as_slice() {
+                        [word] if shape.quotient && shape.remainder => {
+                            let separator = (word.text.clone(), symbol);
+                            if !self.table.decimal_separators.contains(&separator) {
+                                self.table.decimal_separators.push(separator);
+                            }
+                        }
+                        _ => return Err("a decimal rule that is not \"←← word →→\"".into()),
+                    }
+                }
+                return Ok(shape.references);
+            }
+            value => value
+                .parse::<u64>()
+                .map_err(|_| format!("unsupported rule value \"{value}\""))?,
+        };
+        if shape.words.is_empty() {
+            if shape.quotient || shape.remainder {
+                return Err("a rule without words".into());
+            }
+            // "=%spellout-cardinal-masculine="
+            return Ok(shape.references);
+        }
+        let radix = rule.radix.unwrap_or(10);
+        let mut divisor = 1;
+        while divisor <= value / radix {
+            divisor *= radix;
+        }
+        let zeroes = match power_of_ten(divisor) {
+            Some(zeroes) => zeroes,
+            None if !shape.quotient && !shape.remainder => 0,
+            None => return Err(format!("the divisor {divisor} is not a power of ten")),
+        };
+        let main: Vec<_> = shape
+            .words
+            .iter()
+            .filter(|word| !word.optional)
+            .map(|word| word.text.as_str())
+            .collect();
+        let optional = shape.words.iter().filter(|word| word.optional);
+        if shape.quotient {
+            // "←← mil[ →→]", "←← mil millóns[ →→]"
+            if zeroes < 3 {
+                return Err("a multiplier before a number under a thousand".into());
+            }
+            match main.as_slice() {
+                [] => return Err("a multiplier without a scale word".into()),
+                [word] => self.scale(word, zeroes)?,
+                words => {
+                    let mut total = 0;
+                    for word in words {
+                        match self.table.scales.iter().find(|(known, _)| known == word) {
+                            Some((_, power)) => total += power,
+                            None => {
+                                return Err(format!(
+                                    "\"{}\" is not a stack of known scale words",
+                                    words.join(" ")
+                                ));
+                            }
+                        }
+                    }
+                    if total != zeroes {
+                        return Err(format!("\"{}\" is not 10^{zeroes}", words.join(" ")));
+                    }
+                }
+            }
+        } else if value < 1000 {
+            // "dous", "vinte[ e →→]", "cento →→"
+            let [word, links @ ..] = main.as_slice() else {
+                return Err("a number without words".into());
+            };
+            if !links.is_empty() && !shape.remainder {
+                return Err(format!("several words for {value}: \"{}\"", main.join(" ")));
+            }
+            let round = if shape.remainder {
+                value - value % divisor
+            } else {
+                value
+            };
+            self.cardinal(word, round)?;
+            for link in links {
+                self.link(link, zeroes);
+            }
+        } else {
+            // "mil[ →→]", "un millón[ →→]"
+            if power_of_ten(value).is_none() {
+                return Err(format!("{value} is not a power of ten"));
+            }
+            let word = match main.as_slice() {
+                [word] => word,
+                [one, word]
+                    if self
+                        .table
+                        .cardinals
+                        .iter()
+                        .any(|(known, v)| known == one && *v == 1) =>
+                {
+                    word
+                }
+                _ => return Err(format!("unsupported words \"{}\"", main.join(" "))),
+            };
+            self.scale(word, zeroes)?;
+        }
+        for word in optional {
+            self.link(&word.text, zeroes);
+        }
+        Ok(shape.references)
+    }
+}
+
+fn power_of_ten(mut value: u64) -> Option<u32> {
+    let mut power = 0;
+    while value >= 10 && value.is_multiple_of(10) {
+        value /= 10;
+        power += 1;
+    }
+    (value == 1).then_some(power)
+}
+
+/// Read the rule sets of `document` into a table.
+pub fn generate(document: &Document, settings: &Settings) -> Result<Table, GenError> {
+    let code = document
+        .language
+        .clone()
+        .ok_or_else(|| GenError::Xml("no <language> element".into()))?;
+    let mut queue: VecDeque<String> = if settings.rulesets.is_empty() {
+        document
+            .rulesets
+            .iter()
+            .filter(|ruleset| !ruleset.private && ruleset.name.starts_with("spellout-cardinal"))
+            .map(|ruleset| ruleset.name.clone())
+            .collect()
+    } else {
+        settings.rulesets.iter().cloned().collect()
+    };
+    if queue.is_empty() {
+        return Err(GenError::Xml("no spellout-cardinal rule set".into()));
+    }
+    let mut builder = Builder {
+        table: Table {
+            code,
+            ..Table::default()
+        },
+        decimal_symbol: settings.decimal_symbol,
+    };
+    let mut done = Vec::new();
+    while let Some(name) = queue.pop_front() {
+        if done.contains(&name) {
+            continue;
+        }
+        let ruleset = document
+            .ruleset(&name)
+            .ok_or_else(|| GenError::Xml(format!("unknown rule set \"{name}\"")))?;
+        for rule in &ruleset.rules {
+            let references = builder.rule(rule).map_err(|message| GenError::Rule {
+                ruleset: name.clone(),
+                value: rule.value.clone(),
+                message,
+            })?;
+            queue.extend(references);
+        }
+        done.push(name);
+    }
+    Ok(builder.table)
+}
+
+/// The Rust source of `table`.
+///
+/// `source` and `command` are written in the header, `krate` is the path of the text2num crate:
+/// `text2num`, or `crate` for the tables of the crate itself.
+pub fn emit(table: &Table, source: &str, command: &str, krate: &str) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "// Generated by text2num-gen from {source}, do not edit."
+    );
+    let _ = writeln!(out, "// To regenerate: {command}");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "use {krate}::lang::LanguageTable;");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "pub static TABLE: LanguageTable = LanguageTable {{");
+    let _ = writeln!(out, "    code: {:?},", table.code);
+    let _ = writeln!(out, "    cardinals: &[");
+    for (word, value) in &table.cardinals {
+        let _ = writeln!(out, "        ({word:?}, \"{value}\"),");
+    }
+    let _ = writeln!(out, "    ],");
+    let _ = writeln!(out, "    scales: &[");
+    for (word, power) in &table.scales {
+        let _ = writeln!(out, "        ({word:?}, {power}),");
+    }
+    let _ = writeln!(out, "    ],");
+    let _ = writeln!(out, "    links: &[");
+    for (word, zeroes) in &table.links {
+        let _ = writeln!(out, "        ({word:?}, {zeroes}),");
+    }
+    let _ = writeln!(out, "    ],");
+    let _ = writeln!(out, "    decimal_separators: &[");
+    for (word, symbol) in &table.decimal_separators {
+        let _ = writeln!(out, "        ({word:?}, {symbol:?}),");
+    }
+    let _ = writeln!(out, "    ],");
+    let _ = writeln!(out, "}};");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rbnf::parse;
+
+    const GL_COMMAND: &str = "cargo run -p text2num-gen -- --crate crate --decimal-symbol , --output src/lang/gl/table.rs gen/rbnf/gl.xml";
+
+    fn spellout(rules: &[(&str, &str)]) -> String {
+        let rules: String = rules
+            .iter()
+            .map(|(value, body)| format!("<rbnfrule value=\"{value}\">{body};</rbnfrule>"))
+            .collect();
+        format!(
+            "<ldml><identity><language type=\"xx\"/></identity><rbnf>\
+             <rulesetGrouping type=\"SpelloutRules\">\
+             <ruleset type=\"spellout-cardinal\">{rules}</ruleset>\
+             </rulesetGrouping></rbnf></ldml>"
+        )
+    }
+
+    fn error(rules: &[(&str, &str)]) -> String {
+        let document = parse(&spellout(rules)).unwrap();
+        generate(&document, &Settings::default())
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn test_galician_is_up_to_date() {
+        let document = parse(include_str!("../rbnf/gl.xml")).unwrap();
+        let settings = Settings {
+            decimal_symbol: Some(','),
+            ..Settings::default()
+        };
+        let table = generate(&document, &settings).unwrap();
+        assert_eq!(
+            emit(&table, "gen/rbnf/gl.xml", GL_COMMAND, "crate"),
+            include_str!("../../src/lang/gl/table.rs"),
+            "src/lang/gl/table.rs is out of date, regenerate it with: {GL_COMMAND}"
+        );
+    }
+
+    #[test]
+    fn test_rules() {
+        let document = parse(&spellout(&[
+            ("-x", "minus →→"),
+            ("x.x", "←← point →→"),
+            ("0", "zero"),
+            ("1", "one"),
+            ("2", "two"),
+            ("20", "twenty[ →→]"),
+            ("100", "hundred[ and →→]"),
+            ("1000", "←← thousand[ →→]"),
+            ("1000000", "one million[ and →→]"),
+            ("1000000000", "←← thousand million[ →→]"),
+        ]))
+        .unwrap();
+        let table = generate(
+            &document,
+            &Settings {
+                decimal_symbol: Some('.'),
+                ..Settings::default()
+            },
+        )
+        .unwrap();
+        let owned = |words: &[(&str, u32)]| -> Vec<(String, u32)> {
+            words.iter().map(|(w, n)| (w.to_string(), *n)).collect()
+        };
+        assert_eq!(table.code, "xx");
+        assert_eq!(
+            table.cardinals,
+            [
+                ("zero", 0),
+                ("one", 1),
+                ("two", 2),
+                ("twenty", 20),
+                ("hundred", 100)
+            ]
+            .map(|(w, n)| (w.to_string(), n))
+        );
+        assert_eq!(table.scales, owned(&[("thousand", 3), ("million", 6)]));
+        assert_eq!(table.links, owned(&[("and", 2), ("and", 6)]));
+        assert_eq!(table.decimal_separators, [("point".to_string(), '.')]);
+    }
+
+    #[test]
+    fn test_unsupported_rules() {
+        assert_eq!(
+            error(&[("2", "$(cardinal,one{one}other{two})$")]),
+            "spellout-cardinal, rule 2: the plural patterns \"$(…)$\" are not supported"
+        );
+        assert_eq!(
+            error(&[("100", "←←cientos[ →→]")]),
+            "spellout-cardinal, rule 100: a substitution fused with a word is not supported"
+        );
+        assert_eq!(
+            error(&[("20", "twenty[-→→]")]),
+            "spellout-cardinal, rule 20: a substitution fused with a word is not supported"
+        );
+        assert_eq!(
+            error(&[("1", "one"), ("100", "hundred[ →→→]")]),
+            "spellout-cardinal, rule 100: the substitution \"→→→\" is not supported"
+        );
+        assert_eq!(
+            error(&[("0", "=#,##0=")]),
+            "spellout-cardinal, rule 0: the number format \"=#,##0=\" is not supported"
+        );
+        assert_eq!(
+            error(&[("1", "one"), ("2", "one")]),
+            "spellout-cardinal, rule 2: \"one\" is both 1 and 2"
+        );
+        assert_eq!(
+            error(&[("0.x", "point →→")]),
+            "spellout-cardinal, rule 0.x: unsupported rule value \"0.x\""
+        );
+        assert_eq!(
+            error(&[("1500", "fifteen hundred")]),
+            "spellout-cardinal, rule 1500: 1500 is not a power of ten"
+        );
+        assert_eq!(
+            error(&[("100", "←← hundred[ →→]")]),
+            "spellout-cardinal, rule 100: a multiplier before a number under a thousand"
+        );
+    }
+}
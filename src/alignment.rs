@@ -0,0 +1,198 @@
+/*!
+Correspondence between the positions of a text and those of its replacement, to carry offsets across.
+
+[`replace_numbers_in_text_aligned`](crate::replace_numbers_in_text_aligned) returns the replaced text
+with its [`Alignment`]: the list of the segments of the input, in order, each with the segment of the output
+it became.
+
+```rust
+use text2num::{Language, Options, replace_numbers_in_text_aligned};
+
+let en = Language::english();
+let (text, alignment) = replace_numbers_in_text_aligned("at twenty-one o'clock", &en, &Options::new(10.0));
+
+assert_eq!(text, "at 21 o'clock");
+// "o'clock" starts at byte 14 of the input and byte 6 of the output
+assert_eq!(alignment.map_input_to_output(14), 6);
+assert_eq!(alignment.map_output_to_input(6), 14);
+```
+*/
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// A segment of the input and the segment of the output it became.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    /// The byte offsets of the segment in the input
+    pub input: Range<usize>,
+    /// The byte offsets of the segment in the output
+    pub output: Range<usize>,
+    /// The segment is a number replaced by its digits. Otherwise, it is copied as is,
+    /// and `input` and `output` have the same length.
+    pub replaced: bool,
+}
+
+/// The segments of a replacement, which cover the whole input and the whole output.
+///
+/// Neither the input nor the output segments are empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Alignment {
+    segments: Vec<Segment>,
+}
+
+impl Alignment {
+    /// The segments of the input and the output, in order.
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// Add the next segment of the input, written as `output_len` bytes of output.
+    pub(crate) fn push(&mut self, input: Range<usize>, output_len: usize, replaced: bool) {
+        if input.is_empty() && output_len == 0 {
+            return;
+        }
+        let start = self.segments.last().map_or(0, |segment| segment.output.end);
+        self.segments.push(Segment {
+            input,
+            output: start..start + output_len,
+            replaced,
+        });
+    }
+
+    /// The position in the output of the byte offset `pos` of the input.
+    ///
+    /// The positions in the copied segments map exactly. A position inside a replaced number, after its start,
+    /// maps to the start of its digits: the number is replaced as a whole. The end of the input, or any position
+    /// past it, maps to the end of the output.
+    pub fn map_input_to_output(&self, pos: usize) -> usize {
+        map(&self.segments, pos, |segment| {
+            (&segment.input, &segment.output)
+        })
+    }
+
+    /// The position in the input of the byte offset `pos` of the output.
+    ///
+    /// This is the reverse of [`map_input_to_output`](Self::map_input_to_output), with the same rounding:
+    /// a position inside the digits of a number, after their start, maps to the start of the number.
+    pub fn map_output_to_input(&self, pos: usize) -> usize {
+        map(&self.segments, pos, |segment| {
+            (&segment.output, &segment.input)
+        })
+    }
+}
+
+/// Map `pos` from the `from` side of the segments to their `to` side.
+fn map(
+    segments: &[Segment],
+    pos: usize,
+    sides: impl Fn(&Segment) -> (&Range<usize>, &Range<usize>),
+) -> usize {
+    let index = segments.partition_point(|segment| sides(segment).0.end <= pos);
+    let Some(segment) = segments.get(index) else {
+        return segments.last().map_or(0, |segment| sides(segment).1.end);
+    };
+    let (from, to) = sides(segment);
+    if segment.replaced {
+        to.start
+    } else {
+        to.start + (pos - from.start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg_attr(not(feature = "en"), allow(unused_imports))]
+    use crate::{Options, lang::Language, word_to_digit::replace_numbers_in_text_aligned};
+
+    fn check_cover(alignment: &Alignment, input: &str, output: &str) {
+        let (mut input_end, mut output_end) = (0, 0);
+        for segment in alignment.segments() {
+            assert_eq!(segment.input.start, input_end);
+            assert_eq!(segment.output.start, output_end);
+            if !segment.replaced {
+                assert_eq!(
+                    &input[segment.input.clone()],
+                    &output[segment.output.clone()]
+                );
+            }
+            (input_end, output_end) = (segment.input.end, segment.output.end);
+        }
+        assert_eq!((input_end, output_end), (input.len(), output.len()));
+    }
+
+    #[test]
+    fn test_map() {
+        // "ab" + "cdef" -> "X" + "g"
+        let mut alignment = Alignment::default();
+        alignment.push(0..2, 2, false);
+        alignment.push(2..6, 1, true);
+        alignment.push(6..7, 1, false);
+        alignment.push(7..7, 0, false);
+        assert_eq!(alignment.segments().len(), 3);
+        check_cover(&alignment, "abcdefg", "abXg");
+        let forward: Vec<_> = (0..9).map(|i| alignment.map_input_to_output(i)).collect();
+        assert_eq!(forward, [0, 1, 2, 2, 2, 2, 3, 4, 4]);
+        // the replacement may also be longer than the number
+        let mut alignment = Alignment::default();
+        alignment.push(0..1, 3, true);
+        alignment.push(1..2, 1, false);
+        check_cover(&alignment, "ab", "XYZb");
+        let backward: Vec<_> = (0..6).map(|i| alignment.map_output_to_input(i)).collect();
+        assert_eq!(backward, [0, 0, 0, 1, 2, 2]);
+        assert_eq!(Alignment::default().map_input_to_output(3), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_replacement_alignment() {
+        let en = Language::english();
+        let input = "naïve: twenty-one cafés, one hundred and five élèves, and eleven";
+        let (output, alignment) = replace_numbers_in_text_aligned(input, &en, &Options::new(10.0));
+        assert_eq!(output, "naïve: 21 cafés, 105 élèves, and 11");
+        check_cover(&alignment, input, &output);
+        let replaced: Vec<_> = alignment
+            .segments()
+            .iter()
+            .filter(|segment| segment.replaced)
+            .map(|segment| {
+                (
+                    &input[segment.input.clone()],
+                    &output[segment.output.clone()],
+                )
+            })
+            .collect();
+        assert_eq!(
+            replaced,
+            [
+                ("twenty-one", "21"),
+                ("one hundred and five", "105"),
+                ("eleven", "11")
+            ]
+        );
+        // the offsets of the words after the numbers carry over, multi-byte characters included
+        for word in ["cafés", "élèves", "and"] {
+            let from = input.rfind(word).unwrap();
+            let to = output.rfind(word).unwrap();
+            assert_eq!(alignment.map_input_to_output(from), to);
+            assert_eq!(alignment.map_output_to_input(to), from);
+            assert_eq!(
+                alignment.map_input_to_output(from + word.len()),
+                to + word.len()
+            );
+        }
+        // inside a number
+        let hundred = input.find("hundred").unwrap();
+        assert_eq!(
+            alignment.map_input_to_output(hundred),
+            output.find("105").unwrap()
+        );
+        assert_eq!(
+            alignment.map_output_to_input(output.find("05").unwrap()),
+            input.find("one hundred").unwrap()
+        );
+        assert_eq!(alignment.map_input_to_output(input.len()), output.len());
+        assert_eq!(alignment.map_output_to_input(output.len()), input.len());
+    }
+}
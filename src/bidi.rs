@@ -0,0 +1,121 @@
+/*!
+The direction of the paragraphs of a text, to isolate the digits spliced into the right-to-left ones
+(see [`Options::isolate_digits_in_rtl`](crate::Options::isolate_digits_in_rtl)).
+
+The direction is guessed with the first strong character rule of the Unicode bidirectional algorithm,
+on a rough classification of the characters: the letters of the right-to-left scripts (Hebrew, Arabic, Syriac,
+Thaana, N'Ko…) and the RLM are right-to-left, the other letters and the LRM are left-to-right,
+and everything else is neutral. The text inside isolates is skipped.
+*/
+
+use core::ops::Range;
+
+/// First Strong Isolate, that opens the digits.
+pub(crate) const FSI: char = '\u{2068}';
+/// Pop Directional Isolate, that closes them.
+pub(crate) const PDI: char = '\u{2069}';
+
+const LRM: char = '\u{200E}';
+const RLM: char = '\u{200F}';
+
+/// The paragraph separators of the bidirectional algorithm.
+fn is_paragraph_separator(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\u{1C}'..='\u{1E}' | '\u{85}' | '\u{2029}')
+}
+
+fn is_rtl_letter(c: char) -> bool {
+    c == RLM
+        || (c.is_alphabetic()
+            && matches!(
+                c,
+                '\u{0590}'..='\u{08FF}'
+                    | '\u{FB1D}'..='\u{FDFF}'
+                    | '\u{FE70}'..='\u{FEFF}'
+                    | '\u{10800}'..='\u{10FFF}'
+                    | '\u{1E800}'..='\u{1EFFF}'
+            ))
+}
+
+/// Whether the first strong character of `paragraph` is right-to-left.
+///
+/// A paragraph without any strong character is left-to-right.
+fn is_rtl(paragraph: &str) -> bool {
+    let mut isolates = 0usize;
+    for c in paragraph.chars() {
+        match c {
+            '\u{2066}'..='\u{2068}' => isolates += 1,
+            PDI => isolates = isolates.saturating_sub(1),
+            _ if isolates > 0 => {}
+            LRM => return false,
+            c if is_rtl_letter(c) => return true,
+            c if c.is_alphabetic() => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// The directions of the paragraphs of a text, looked up in order.
+pub(crate) struct Paragraphs<'t> {
+    text: &'t str,
+    /// The byte offsets of the last paragraph looked up
+    current: Range<usize>,
+    rtl: bool,
+}
+
+impl<'t> Paragraphs<'t> {
+    pub(crate) fn new(text: &'t str) -> Self {
+        Self {
+            text,
+            current: 0..0,
+            rtl: false,
+        }
+    }
+
+    /// Whether the paragraph at byte offset `pos` is right-to-left.
+    pub(crate) fn is_rtl_at(&mut self, pos: usize) -> bool {
+        if !self.current.contains(&pos) {
+            let start = self.text[..pos]
+                .rfind(is_paragraph_separator)
+                .map_or(0, |i| {
+                    i + self.text[i..].chars().next().map_or(0, char::len_utf8)
+                });
+            let end = self.text[pos..]
+                .find(is_paragraph_separator)
+                .map_or(self.text.len(), |i| pos + i);
+            self.rtl = is_rtl(&self.text[start..end]);
+            self.current = start..end;
+        }
+        self.rtl
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_first_strong() {
+        assert!(is_rtl("שלום world"));
+        assert!(is_rtl("42, مرحبا hello"));
+        assert!(!is_rtl("hello שלום"));
+        assert!(!is_rtl("12 - 3"));
+        assert!(!is_rtl(""));
+        // the isolated text doesn't count
+        assert!(is_rtl("\u{2068}hello\u{2069} שלום"));
+        assert!(is_rtl("\u{200F}hello"));
+        assert!(!is_rtl("\u{200E}שלום"));
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let text = "one שתיים\nשלוש four\r\nfive";
+        let mut paragraphs = Paragraphs::new(text);
+        let directions: Vec<_> = ["one", "שתיים", "שלוש", "four", "five"]
+            .iter()
+            .map(|word| paragraphs.is_rtl_at(text.find(word).unwrap()))
+            .collect();
+        assert_eq!(directions, [false, false, true, true, false]);
+    }
+}
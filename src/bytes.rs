@@ -0,0 +1,165 @@
+/*!
+Find and replace the spelled numbers of a byte buffer that may not be entirely valid UTF-8.
+
+The buffer is read in its runs of valid UTF-8, as they come, without converting it to a
+string first. The invalid byte sequences in between are written back as they are, and
+no number spans them: they separate the words like the end of a text.
+
+```rust
+use text2num::{Language, Options, find_numbers_bytes, replace_numbers_bytes};
+
+let en = Language::english();
+let input = b"forty-two \xff\xfe cats and twenty\xc3 dogs";
+
+assert_eq!(
+    replace_numbers_bytes(input, &en, &Options::new(10.0)),
+    b"42 \xff\xfe cats and 20\xc3 dogs"
+);
+let numbers = find_numbers_bytes(input, &en, &Options::new(10.0));
+assert_eq!(numbers[1].range, 22..28);
+```
+*/
+
+use alloc::vec::Vec;
+
+use crate::lang::LangInterpreter;
+use crate::options::Options;
+use crate::word_to_digit::{FindNumbers, Occurence, replace_in_text, text_tokens, token_stream};
+
+/// An output appended to a byte buffer.
+struct ByteWriter<'o>(&'o mut Vec<u8>);
+
+impl core::fmt::Write for ByteWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+/// Same as [`replace_numbers_in_text_with`](crate::replace_numbers_in_text_with), but on bytes:
+/// the valid UTF-8 runs of `input` are replaced independently, and its invalid byte sequences copied untouched.
+pub fn replace_numbers_bytes<L: LangInterpreter>(
+    input: &[u8],
+    lang: &L,
+    options: &Options,
+) -> Vec<u8> {
+    let mut out = Vec::with_capacity(input.len());
+    for chunk in input.utf8_chunks() {
+        let text = chunk.valid();
+        if !text.is_empty() {
+            let start = out.len();
+            let replaced = replace_in_text(
+                text,
+                token_stream(text, lang, options),
+                lang,
+                options,
+                None,
+                None,
+                &mut ByteWriter(&mut out),
+            );
+            // the run is left unchanged if the splice goes wrong
+            if replaced.is_err() {
+                out.truncate(start);
+                out.extend_from_slice(text.as_bytes());
+            }
+        }
+        out.extend_from_slice(chunk.invalid());
+    }
+    out
+}
+
+/// Same as [`find_numbers_with`](crate::find_numbers_with), but in the valid UTF-8 runs of `input`.
+///
+/// The [`Occurence::range`] and [`Occurence::linking_words`] offsets are the byte offsets in `input`,
+/// and [`Occurence::start`] and [`Occurence::end`] count the tokens from its start, the invalid
+/// sequences being no tokens.
+pub fn find_numbers_bytes<L: LangInterpreter>(
+    input: &[u8],
+    lang: &L,
+    options: &Options,
+) -> Vec<Occurence> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+    let mut token_offset = 0;
+    for chunk in input.utf8_chunks() {
+        let text = chunk.valid();
+        if !text.is_empty() {
+            let tokens = text_tokens(text, lang, options);
+            found.extend(
+                FindNumbers::new(tokens.iter().enumerate(), lang, options).map(|mut occurence| {
+                    occurence.start += token_offset;
+                    occurence.end += token_offset;
+                    occurence.range = occurence.range.start + offset..occurence.range.end + offset;
+                    for (range, _) in &mut occurence.linking_words {
+                        *range = range.start + offset..range.end + offset;
+                    }
+                    occurence
+                }),
+            );
+            token_offset += tokens.len();
+        }
+        offset += text.len() + chunk.invalid().len();
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg_attr(not(feature = "en"), allow(unused_imports))]
+    use super::*;
+    #[cfg_attr(not(feature = "en"), allow(unused_imports))]
+    use crate::{lang::Language, word_to_digit::replace_numbers_in_text_with};
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_invalid_bytes() {
+        let en = Language::english();
+        let options = Options::new(0.0);
+        // the garbage is copied byte for byte, and right against the numbers
+        assert_eq!(
+            replace_numbers_bytes(b"\xfftwenty-one\x80 cats", &en, &options),
+            b"\xff21\x80 cats"
+        );
+        assert_eq!(
+            replace_numbers_bytes(b"two hundred\xe2\x82 and five", &en, &options),
+            b"200\xe2\x82 and 5"
+        );
+        assert_eq!(
+            replace_numbers_bytes(b"one\xc0\xc1two three", &en, &options),
+            b"1\xc0\xc12 3"
+        );
+        assert_eq!(
+            replace_numbers_bytes(b"\xf0\x9f\x98", &en, &options),
+            b"\xf0\x9f\x98"
+        );
+        assert_eq!(replace_numbers_bytes(b"", &en, &options), b"");
+        // valid text is replaced as a string
+        let text = "Twenty-one cats, the third one, one hundred and five point five";
+        assert_eq!(
+            replace_numbers_bytes(text.as_bytes(), &en, &Options::new(10.0)),
+            replace_numbers_in_text_with(text, &en, &Options::new(10.0)).as_bytes()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_find_invalid_bytes() {
+        let en = Language::english();
+        let input = b"one hundred \xffand five\xfe two hundred and three";
+        let numbers = find_numbers_bytes(input, &en, &Options::new(0.0));
+        let found: Vec<_> = numbers
+            .iter()
+            .map(|occurence| (occurence.text.as_str(), &input[occurence.range.clone()]))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("100", &b"one hundred"[..]),
+                ("5", b"five"),
+                ("203", b"two hundred and three")
+            ]
+        );
+        assert_eq!(numbers[2].linking_words[0].0, 35..38);
+        assert_eq!((numbers[2].start, numbers[2].end), (8, 15));
+    }
+}
@@ -0,0 +1,590 @@
+/*!
+A conformance harness, to check a [`LangInterpreter`] against the behavior the crate expects from it.
+
+A [`ConformanceSpec`] lists number cases, the words and the number [`parse_number`] must read from them,
+and replacement cases, the text [`replace_numbers_in_text_with`] must make of a sentence. [`check`] runs them,
+together with structural assertions that hold for any language:
+
+- a word that [`apply`](LangInterpreter::apply) rejects leaves the digits as they were before it;
+- the interpreter keeps no state between calls: reading the same words again gives the same result.
+
+The builtin languages come with their own specs (see [`ConformanceSpec::builtin`]), made of the replacement
+cases of their corpus in `tests/corpus` (see [`ConformanceSpec::corpus`]).
+An external crate that implements a language writes its spec in its tests:
+
+```rust
+use text2num::conformance::{ConformanceSpec, check};
+use text2num::{Language, NumberKind};
+
+let spec = ConformanceSpec::new()
+    .number("twenty-one", "21", 21.0, NumberKind::Cardinal)
+    .number("three point five", "3.5", 3.5, NumberKind::Decimal)
+    .not_a_number("fifty zero three")
+    .replacement("two cats and twenty-one dogs", 10.0, "two cats and 21 dogs")
+    .corpus("# in the format of tests/corpus\nsix and five\t6 and 5\tthreshold=0\n")
+    .unwrap();
+
+assert_eq!(check(&Language::english(), &spec), Ok(()));
+```
+
+This module is only available with the `test-utils` feature.
+*/
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::digit_string::DigitString;
+use crate::lang::LangInterpreter;
+use crate::options::{AdjacentNumberPolicy, Options};
+use crate::parse::{NumberKind, ParseError, ParsedNumber, parse_number};
+use crate::word_to_digit::replace_numbers_in_text_with;
+
+/// The expectations a language must meet, built case by case.
+#[derive(Debug, Clone, Default)]
+pub struct ConformanceSpec {
+    /// The words and the number they make, if any
+    numbers: Vec<(String, Option<ParsedNumber>)>,
+    /// The text, the options and the text once its numbers are replaced
+    replacements: Vec<(String, Options, String)>,
+}
+
+impl ConformanceSpec {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The words make the number `text`, of `value` and `kind`.
+    pub fn number(mut self, words: &str, text: &str, value: f64, kind: NumberKind) -> Self {
+        self.numbers.push((
+            words.into(),
+            Some(ParsedNumber {
+                kind,
+                text: text.into(),
+                value,
+            }),
+        ));
+        self
+    }
+
+    /// The words do not make a number.
+    pub fn not_a_number(mut self, words: &str) -> Self {
+        self.numbers.push((words.into(), None));
+        self
+    }
+
+    /// The numbers of `text` worth `threshold` or more are replaced by their digits, to give `expected`.
+    pub fn replacement(self, text: &str, threshold: f64, expected: &str) -> Self {
+        self.replacement_with(text, &Options::new(threshold), expected)
+    }
+
+    /// Same as [`replacement`](Self::replacement), with the numbers replaced according to `options`.
+    pub fn replacement_with(mut self, text: &str, options: &Options, expected: &str) -> Self {
+        self.replacements
+            .push((text.into(), options.clone(), expected.into()));
+        self
+    }
+
+    /**
+    The replacement cases of a corpus in the format of `tests/corpus`: one case per line,
+    with three tab separated columns:
+
+    1. the text;
+    2. the text once its numbers are replaced;
+    3. optional comma separated flags:
+       - `threshold=<value>`: the lone number threshold (10 by default);
+       - `stutters`: set [`Options::collapse_stutters`];
+       - `concatenate` or `auto`: the [`AdjacentNumberPolicy`];
+       - `xfail`: a known gap, the line is left out.
+
+    Empty lines and lines starting with `#` are ignored.
+    */
+    pub fn corpus(mut self, tsv: &str) -> Result<Self, CorpusError> {
+        for (index, line) in tsv.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut columns = line.split('\t');
+            let (Some(text), Some(expected)) = (columns.next(), columns.next()) else {
+                return Err(CorpusError::Columns { line: index + 1 });
+            };
+            let mut options = Options::new(10.0);
+            let mut xfail = false;
+            for flag in columns
+                .next()
+                .unwrap_or("")
+                .split(',')
+                .map(str::trim)
+                .filter(|flag| !flag.is_empty())
+            {
+                let known = match flag.split_once('=') {
+                    Some(("threshold", value)) => value
+                        .parse()
+                        .map(|threshold| options.threshold = threshold)
+                        .is_ok(),
+                    None if flag == "stutters" => {
+                        options.collapse_stutters = true;
+                        true
+                    }
+                    None if flag == "concatenate" => {
+                        options.adjacent_number_policy = AdjacentNumberPolicy::Concatenate;
+                        true
+                    }
+                    None if flag == "auto" => {
+                        options.adjacent_number_policy = AdjacentNumberPolicy::Auto;
+                        true
+                    }
+                    None if flag == "xfail" => {
+                        xfail = true;
+                        true
+                    }
+                    _ => false,
+                };
+                if !known {
+                    return Err(CorpusError::Flag {
+                        line: index + 1,
+                        flag: flag.into(),
+                    });
+                }
+            }
+            if !xfail {
+                self = self.replacement_with(text, &options, expected);
+            }
+        }
+        Ok(self)
+    }
+
+    /// The spec of the builtin language of `code`, if its feature is enabled: a few number cases,
+    /// and the replacement cases of its corpus in `tests/corpus`, but its known gaps.
+    pub fn builtin(code: &str) -> Option<Self> {
+        use NumberKind::{Cardinal, Decimal, Ordinal};
+        let spec = Self::new()
+            .corpus(crate::corpus::tsv(code))
+            .expect("the corpora of tests/corpus are well formed");
+        Some(match code {
+            #[cfg(feature = "bg")]
+            "bg" => spec
+                .number("двадесет и три", "23", 23.0, Cardinal)
+                .number("сто и три", "103", 103.0, Cardinal)
+                .number("две хиляди и петстотин", "2500", 2500.0, Cardinal)
+                .number("пети", "5-ти", 5.0, Ordinal)
+                .number("сто и двадесет цяло нула пет", "120,05", 120.05, Decimal)
+                .not_a_number("двадесет и"),
+            #[cfg(feature = "de")]
+            "de" => spec
+                .number("zwei und vierzig", "42", 42.0, Cardinal)
+                .number("einhundert fünfzehn", "115", 115.0, Cardinal)
+                .number("neunzehnhundertdreiundsiebzig", "1973", 1973.0, Cardinal)
+                .number("fünfte", "5.", 5.0, Ordinal)
+                .number("drei komma zwei fünf", "3,25", 3.25, Decimal)
+                .number("zweieinhalb", "2,5", 2.5, Decimal)
+                .not_a_number("fünf null"),
+            #[cfg(feature = "en")]
+            "en" => spec
+                .number("twenty-one", "21", 21.0, Cardinal)
+                .number("two thousand twelve", "2012", 2012.0, Cardinal)
+                .number("one hundred and five", "105", 105.0, Cardinal)
+                .number("twenty-first", "21st", 21.0, Ordinal)
+                .number("three point two five", "3.25", 3.25, Decimal)
+                .not_a_number("fifty zero three"),
+            #[cfg(feature = "eo")]
+            "eo" => spec
+                .number("dudek tri", "23", 23.0, Cardinal)
+                .number("ducent tridek kvin", "235", 235.0, Cardinal)
+                .number("mil naŭcent okdek kvar", "1984", 1984.0, Cardinal)
+                .number("kvina", "5-a", 5.0, Ordinal)
+                .number("tri komo kvin", "3,5", 3.5, Decimal)
+                .not_a_number("kvin nul"),
+            #[cfg(feature = "es")]
+            "es" => spec
+                .number("treinta y uno", "31", 31.0, Cardinal)
+                .number("ciento veinte", "120", 120.0, Cardinal)
+                .number("dos mil quinientos", "2500", 2500.0, Cardinal)
+                .number("quinto", "5.º", 5.0, Ordinal)
+                .number("uno coma uno", "1,1", 1.1, Decimal)
+                .not_a_number("cinco cero"),
+            #[cfg(feature = "fa")]
+            "fa" => spec
+                .number("بیست و سه", "23", 23.0, Cardinal)
+                .number("صد و بیست و سه", "123", 123.0, Cardinal)
+                .number("سه هزار و پانصد", "3500", 3500.0, Cardinal)
+                .number("پنجم", "5م", 5.0, Ordinal)
+                .number("سه ممیز پنج", "3.5", 3.5, Decimal)
+                .not_a_number("پنج صفر"),
+            #[cfg(feature = "fr")]
+            "fr" => spec
+                .number("vingt et un", "21", 21.0, Cardinal)
+                .number("quatre-vingt-dix-sept", "97", 97.0, Cardinal)
+                .number(
+                    "mille neuf cent quatre-vingt-quatre",
+                    "1984",
+                    1984.0,
+                    Cardinal,
+                )
+                .number("vingt et unième", "21e", 21.0, Ordinal)
+                .number("trois virgule deux cinq", "3,25", 3.25, Decimal)
+                .not_a_number("cinq zéro"),
+            #[cfg(feature = "gl")]
+            "gl" => spec
+                .number("vinte e un", "21", 21.0, Cardinal)
+                .number("cento corenta e cinco", "145", 145.0, Cardinal)
+                .number("dous mil trescentos vinte e un", "2321", 2321.0, Cardinal)
+                .number("tres coma cinco", "3,5", 3.5, Decimal)
+                .not_a_number("vinte e"),
+            #[cfg(feature = "hr")]
+            "hr" => spec
+                .number("dvadeset tri", "23", 23.0, Cardinal)
+                .number("sto dvadeset pet", "125", 125.0, Cardinal)
+                .number("dvije tisuće petsto", "2500", 2500.0, Cardinal)
+                .number("peti", "5.", 5.0, Ordinal)
+                .number("tri zarez pet", "3,5", 3.5, Decimal)
+                .not_a_number("dvadeset i"),
+            #[cfg(feature = "it")]
+            "it" => spec
+                .number("ventuno", "21", 21.0, Cardinal)
+                .number("trecentoquarantadue", "342", 342.0, Cardinal)
+                .number("duemila", "2000", 2000.0, Cardinal)
+                .number("quinto", "5º", 5.0, Ordinal)
+                .number("tre virgola cinque", "3,5", 3.5, Decimal)
+                .not_a_number("duemille"),
+            #[cfg(feature = "lt")]
+            "lt" => spec
+                .number("dvidešimt trys", "23", 23.0, Cardinal)
+                .number("šimtas vienas", "101", 101.0, Cardinal)
+                .number("du šimtai", "200", 200.0, Cardinal)
+                .number("penktas", "5-as", 5.0, Ordinal)
+                .number("trys kablelis penki", "3,5", 3.5, Decimal)
+                .not_a_number("du tūkstantis"),
+            #[cfg(feature = "nl")]
+            "nl" => spec
+                .number("tweeëndertig", "32", 32.0, Cardinal)
+                .number("negentienhonderd negentig", "1990", 1990.0, Cardinal)
+                .number("tweeduizend", "2000", 2000.0, Cardinal)
+                .number("vijfde", "5e", 5.0, Ordinal)
+                .number("drie komma vijf", "3,5", 3.5, Decimal)
+                .not_a_number("vijf nul"),
+            #[cfg(feature = "pt")]
+            "pt" => spec
+                .number("vinte e um", "21", 21.0, Cardinal)
+                .number("cento e vinte", "120", 120.0, Cardinal)
+                .number("dois mil", "2000", 2000.0, Cardinal)
+                .number("quinto", "5.º", 5.0, Ordinal)
+                .number("um vírgula um", "1,1", 1.1, Decimal)
+                .not_a_number("sessenta quinze"),
+            #[cfg(feature = "sk")]
+            "sk" => spec
+                .number("dvadsať jeden", "21", 21.0, Cardinal)
+                .number("sto dvadsaťtri", "123", 123.0, Cardinal)
+                .number("dvetisíc", "2000", 2000.0, Cardinal)
+                .number("piaty", "5.", 5.0, Ordinal)
+                .number("tri celé päť", "3,5", 3.5, Decimal)
+                .not_a_number("päť nula"),
+            #[cfg(feature = "sw")]
+            "sw" => spec
+                .number("ishirini na tatu", "23", 23.0, Cardinal)
+                .number("mia moja ishirini na tatu", "123", 123.0, Cardinal)
+                .number("elfu", "1000", 1000.0, Cardinal)
+                .number("tatu nukta mbili tano", "3.25", 3.25, Decimal)
+                .not_a_number("ishirini na"),
+            _ => return None,
+        })
+    }
+}
+
+/// An expectation of the spec that the language does not meet.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Failure {
+    /// The words do not make the expected number (`None` if they must not make one)
+    Number {
+        words: String,
+        expected: Option<ParsedNumber>,
+        found: Result<ParsedNumber, ParseError>,
+    },
+    /// The numbers of the text are not replaced as expected
+    Replacement {
+        text: String,
+        expected: String,
+        found: String,
+    },
+    /// `word`, rejected after `words`, changed their digits
+    DirtyRollback { words: String, word: String },
+    /// The words gave another result when they were read again
+    Unstable { words: String },
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number {
+                words,
+                expected: Some(expected),
+                found,
+            } => write!(
+                f,
+                "\"{words}\" gives {found:?} instead of \"{}\"",
+                expected.text
+            ),
+            Self::Number { words, found, .. } => {
+                write!(f, "\"{words}\" gives {found:?} instead of no number")
+            }
+            Self::Replacement {
+                text,
+                expected,
+                found,
+            } => write!(f, "\"{text}\" gives \"{found}\" instead of \"{expected}\""),
+            Self::DirtyRollback { words, word } => {
+                write!(
+                    f,
+                    "\"{word}\" is rejected after \"{words}\" but changes its digits"
+                )
+            }
+            Self::Unstable { words } => {
+                write!(f, "\"{words}\" gives another result when read again")
+            }
+        }
+    }
+}
+
+/// A line of a corpus that is not in the format of [`ConformanceSpec::corpus`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum CorpusError {
+    /// The line has less than two tab separated columns
+    Columns { line: usize },
+    /// The flag is unknown, or its value is not valid
+    Flag { line: usize, flag: String },
+}
+
+impl fmt::Display for CorpusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Columns { line } => {
+                write!(
+                    f,
+                    "line {line}: expected at least two tab separated columns"
+                )
+            }
+            Self::Flag { line, flag } => write!(f, "line {line}: invalid flag \"{flag}\""),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CorpusError {}
+
+/// Check `lang` against `spec`, and return all the expectations it does not meet.
+pub fn check<L: LangInterpreter>(lang: &L, spec: &ConformanceSpec) -> Result<(), Vec<Failure>> {
+    let mut failures = Vec::new();
+    let first: Vec<_> = spec
+        .numbers
+        .iter()
+        .map(|(words, _)| parse_number(words, lang))
+        .collect();
+    for ((words, expected), found) in spec.numbers.iter().zip(&first) {
+        let meets = match (expected, found) {
+            (Some(expected), Ok(found)) => expected == found,
+            (None, found) => found.is_err(),
+            _ => false,
+        };
+        if !meets {
+            failures.push(Failure::Number {
+                words: words.clone(),
+                expected: expected.clone(),
+                found: found.clone(),
+            });
+        }
+        // at once, then after all the others
+        if parse_number(words, lang) != *found {
+            failures.push(Failure::Unstable {
+                words: words.clone(),
+            });
+        }
+    }
+    for ((words, _), found) in spec.numbers.iter().zip(&first).rev() {
+        if parse_number(words, lang) != *found
+            && !failures.contains(&Failure::Unstable {
+                words: words.clone(),
+            })
+        {
+            failures.push(Failure::Unstable {
+                words: words.clone(),
+            });
+        }
+    }
+    for (text, options, expected) in &spec.replacements {
+        let found = replace_numbers_in_text_with(text, lang, options);
+        if found != *expected {
+            failures.push(Failure::Replacement {
+                text: text.clone(),
+                expected: expected.clone(),
+                found,
+            });
+        }
+    }
+    check_rollbacks(lang, spec, &mut failures);
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures)
+    }
+}
+
+/// Try all the words of the spec and of the vocabulary after each start of the number cases.
+fn check_rollbacks<L: LangInterpreter>(
+    lang: &L,
+    spec: &ConformanceSpec,
+    failures: &mut Vec<Failure>,
+) {
+    let lowercase: Vec<_> = spec
+        .numbers
+        .iter()
+        .map(|(words, _)| words.to_lowercase())
+        .collect();
+    let mut candidates: Vec<&str> = lowercase
+        .iter()
+        .flat_map(|words| words.split_whitespace())
+        .collect();
+    let vocabulary = lang.vocabulary();
+    candidates.extend(vocabulary.iter().map(String::as_str));
+    candidates.sort_unstable();
+    candidates.dedup();
+    for words in &lowercase {
+        let mut b = DigitString::new();
+        let mut start = String::new();
+        for word in words.split_whitespace() {
+            if lang.apply(word, &mut b).is_err() {
+                break;
+            }
+            if !start.is_empty() {
+                start.push(' ');
+            }
+            start.push_str(word);
+            for candidate in &candidates {
+                let mut after = b.clone();
+                if lang.apply(candidate, &mut after).is_err()
+                    && (after.to_string() != b.to_string()
+                        || after.marker != b.marker
+                        || after.is_frozen() != b.is_frozen())
+                {
+                    failures.push(Failure::DirtyRollback {
+                        words: start.clone(),
+                        word: candidate.to_string(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::lang::{MorphologicalMarker, get_interpreter_for};
+    use core::cell::Cell;
+
+    /// Toy language counting up to three, that spoils the digits when it rejects a word,
+    /// and forgets "three" after its first use.
+    #[derive(Default)]
+    struct Sloppy {
+        threes: Cell<usize>,
+    }
+
+    impl LangInterpreter for Sloppy {
+        fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+            match num_func {
+                "one" => b.put(b"1"),
+                "two" => b.put(b"2"),
+                "three" if self.threes.replace(self.threes.get() + 1) == 0 => b.put(b"3"),
+                "ten" => {
+                    b.shift(1)?;
+                    Err(Error::Overlap)
+                }
+                _ => Err(Error::NaN),
+            }
+        }
+        fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+            self.apply(decimal_func, b)
+        }
+        fn get_morph_marker(&self, _word: &str) -> MorphologicalMarker {
+            MorphologicalMarker::None
+        }
+        fn check_decimal_separator(&self, _word: &str) -> Option<char> {
+            None
+        }
+        fn is_linking(&self, _word: &str) -> bool {
+            false
+        }
+        fn vocabulary(&self) -> Vec<String> {
+            ["one", "two", "three", "ten"].map(String::from).to_vec()
+        }
+    }
+
+    #[test]
+    fn test_builtin_specs() {
+        for code in crate::corpus::CODES {
+            let Some(spec) = ConformanceSpec::builtin(code) else {
+                continue;
+            };
+            let lang = get_interpreter_for(code).unwrap();
+            assert_eq!(check(&lang, &spec), Ok(()), "{code}");
+        }
+        assert!(ConformanceSpec::builtin("xx").is_none());
+    }
+
+    #[test]
+    fn test_corpus() {
+        let spec = ConformanceSpec::new()
+            .corpus(
+                "# comment\n\none and two\t1 and 2\tthreshold=0\none and ten\t1 and 10\txfail\n",
+            )
+            .unwrap();
+        assert_eq!(spec.replacements.len(), 1);
+        assert_eq!(spec.replacements[0].1.threshold, 0.0);
+        assert_eq!(
+            ConformanceSpec::new().corpus("one\n").unwrap_err(),
+            CorpusError::Columns { line: 1 }
+        );
+        assert_eq!(
+            ConformanceSpec::new()
+                .corpus("one\t1\nten\t10\tthreshold=ten")
+                .unwrap_err()
+                .to_string(),
+            "line 2: invalid flag \"threshold=ten\""
+        );
+    }
+
+    #[test]
+    fn test_failures() {
+        let spec = ConformanceSpec::new()
+            .number("two", "2", 2.0, NumberKind::Cardinal)
+            .number("three", "3", 3.0, NumberKind::Cardinal)
+            .not_a_number("one two")
+            .replacement("one and ten", 0.0, "1 and 10");
+        let failures = check(&Sloppy::default(), &spec).unwrap_err();
+        assert_eq!(
+            failures,
+            [
+                Failure::Unstable {
+                    words: "three".into()
+                },
+                Failure::Replacement {
+                    text: "one and ten".into(),
+                    expected: "1 and 10".into(),
+                    found: "1 and ten".into()
+                },
+                Failure::DirtyRollback {
+                    words: "two".into(),
+                    word: "ten".into()
+                },
+                Failure::DirtyRollback {
+                    words: "one".into(),
+                    word: "ten".into()
+                },
+            ]
+        );
+        assert_eq!(
+            failures[2].to_string(),
+            "\"ten\" is rejected after \"two\" but changes its digits"
+        );
+    }
+}
@@ -0,0 +1,44 @@
+//! The corpora of `tests/corpus`, for the tests and the builtin conformance specs.
+
+/// The codes of the languages that have a corpus.
+#[cfg(all(test, any(feature = "test-utils", feature = "unicode-segmentation")))]
+pub(crate) const CODES: [&str; 15] = [
+    "bg", "de", "en", "eo", "es", "fa", "fr", "gl", "hr", "it", "lt", "nl", "pt", "sk", "sw",
+];
+
+/// The corpus of `code`, empty if there is none or if its language is disabled.
+pub(crate) fn tsv(code: &str) -> &'static str {
+    match code {
+        #[cfg(feature = "bg")]
+        "bg" => include_str!("../tests/corpus/bg.tsv"),
+        #[cfg(feature = "de")]
+        "de" => include_str!("../tests/corpus/de.tsv"),
+        #[cfg(feature = "en")]
+        "en" => include_str!("../tests/corpus/en.tsv"),
+        #[cfg(feature = "eo")]
+        "eo" => include_str!("../tests/corpus/eo.tsv"),
+        #[cfg(feature = "es")]
+        "es" => include_str!("../tests/corpus/es.tsv"),
+        #[cfg(feature = "fa")]
+        "fa" => include_str!("../tests/corpus/fa.tsv"),
+        #[cfg(feature = "fr")]
+        "fr" => include_str!("../tests/corpus/fr.tsv"),
+        #[cfg(feature = "gl")]
+        "gl" => include_str!("../tests/corpus/gl.tsv"),
+        #[cfg(feature = "hr")]
+        "hr" => include_str!("../tests/corpus/hr.tsv"),
+        #[cfg(feature = "it")]
+        "it" => include_str!("../tests/corpus/it.tsv"),
+        #[cfg(feature = "lt")]
+        "lt" => include_str!("../tests/corpus/lt.tsv"),
+        #[cfg(feature = "nl")]
+        "nl" => include_str!("../tests/corpus/nl.tsv"),
+        #[cfg(feature = "pt")]
+        "pt" => include_str!("../tests/corpus/pt.tsv"),
+        #[cfg(feature = "sk")]
+        "sk" => include_str!("../tests/corpus/sk.tsv"),
+        #[cfg(feature = "sw")]
+        "sw" => include_str!("../tests/corpus/sw.tsv"),
+        _ => "",
+    }
+}
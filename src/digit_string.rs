@@ -12,11 +12,20 @@ use core::fmt::Write as _;
 use super::error::Error;
 use super::lang::MorphologicalMarker;
 
-#[derive(Debug)]
+/// The default maximum number of digits of a [`DigitString`].
+pub const DEFAULT_MAX_DIGITS: usize = 60;
+
+/// A number being built, digit by digit.
+///
+/// Cloning it to try an operation is O(len): it copies the digits, and the text of its marker if any.
+/// The `can_*` predicates answer without the copy.
+#[derive(Debug, Clone)]
 pub struct DigitString {
     buffer: Vec<u8>,
     leading_zeroes: usize,
     frozen: bool,
+    year: bool,
+    max_len: usize,
     /// Decoder state if any
     pub flags: u64,
     pub marker: MorphologicalMarker,
@@ -32,15 +41,34 @@ impl DigitString {
             buffer: Vec::with_capacity(4),
             leading_zeroes: 0,
             frozen: false,
+            year: false,
+            max_len: DEFAULT_MAX_DIGITS,
             flags: 0,
             marker: MorphologicalMarker::None,
         }
     }
 
+    /// Set the maximum number of digits, leading zeroes included, beyond which operations fail with [`Error::TooLong`].
+    ///
+    /// The limit guards against pathological inputs; it is kept by [`reset`](Self::reset).
+    pub fn set_max_len(&mut self, max_len: usize) {
+        self.max_len = max_len;
+    }
+
+    /// Check that the buffer can grow to `buffer_len` digits.
+    fn check_len(&self, buffer_len: usize) -> Result<(), Error> {
+        if buffer_len + self.leading_zeroes > self.max_len {
+            Err(Error::TooLong)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Clear DigitString as if it was brand new.
     pub fn reset(&mut self) {
         self.leading_zeroes = 0;
         self.frozen = false;
+        self.year = false;
         self.marker = MorphologicalMarker::None;
         self.buffer.clear();
         self.flags = 0;
@@ -54,6 +82,11 @@ impl DigitString {
         self.frozen = true;
     }
 
+    /// The number was marked as complete with [`freeze`](Self::freeze).
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
     /// Put the given digit string in the buffer, right aligned.
     ///
     /// Return an error if slots are not free or not 0 or digit string is frozen.
@@ -61,27 +94,56 @@ impl DigitString {
     /// * only valid in leading position (that is, the buffer still evaluates to 0)
     /// * any number of leading zeroes are accepted and kept.
     pub fn put(&mut self, digits: &[u8]) -> Result<(), Error> {
+        self.check_put(digits)?;
+        let l = self.buffer.len();
+        if l == 0 && digits == b"0" {
+            self.leading_zeroes += 1;
+        } else if l == 0 {
+            self.buffer.extend_from_slice(digits);
+        } else {
+            self.buffer[(l - digits.len())..].copy_from_slice(digits);
+        }
+        Ok(())
+    }
+
+    /// [`put`](Self::put) would succeed, without changing anything.
+    ///
+    /// Like [`can_shift`](Self::can_shift), it allows the languages to look ahead, as in
+    /// "*would the word after this one still fit?*", in O(`digits.len()`).
+    ///
+    /// ```rust
+    /// # use text2num::digit_string::DigitString;
+    /// # fn main() -> Result<(), text2num::error::Error> {
+    /// let mut b = DigitString::new();
+    /// b.put(b"200")?;
+    /// assert!(b.can_put(b"10"));
+    /// b.put(b"20")?;
+    /// assert!(!b.can_put(b"10"));
+    /// assert!(b.can_put(b"5"));
+    /// assert_eq!(b.to_string(), "220");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn can_put(&self, digits: &[u8]) -> bool {
+        self.check_put(digits).is_ok()
+    }
+
+    /// The outcome of [`put`](Self::put).
+    fn check_put(&self, digits: &[u8]) -> Result<(), Error> {
         if self.frozen {
             return Err(Error::Frozen);
         }
         if self.buffer.is_empty() && digits == b"0" {
-            self.leading_zeroes += 1;
-            return Ok(());
+            return self.check_len(1);
         }
         if all_zeros(digits) {
             return Err(Error::Overlap);
         }
         let positions = digits.len();
         match self.buffer.len() {
-            0 => {
-                self.buffer.extend_from_slice(digits);
-                Ok(())
-            }
+            0 => self.check_len(positions),
             l if l < positions => Err(Error::Overlap),
-            l if all_zeros(&self.buffer[(l - positions)..]) => {
-                self.buffer[(l - positions)..].copy_from_slice(digits);
-                Ok(())
-            }
+            l if all_zeros(&self.buffer[(l - positions)..]) => Ok(()),
             _ => Err(Error::Overlap),
         }
     }
@@ -98,6 +160,7 @@ impl DigitString {
         }
         let len = self.buffer.len();
         if position >= len {
+            self.check_len(position + 1)?;
             let mut new_buffer = Vec::with_capacity(position + 3);
             new_buffer.resize(position + 1, b'0');
             new_buffer[0] = digit;
@@ -112,8 +175,30 @@ impl DigitString {
         }
     }
 
+    /// Free the digit at a given position from the right, dropping the leading zeros it may leave.
+    ///
+    /// This undoes a provisional digit, like the implicit "one" of a scale word that comes before its multiplier.
+    pub fn clear_digit_at(&mut self, position: usize) -> Result<(), Error> {
+        if self.frozen {
+            return Err(Error::Frozen);
+        }
+        let len = self.buffer.len();
+        if position < len {
+            self.buffer[len - 1 - position] = b'0';
+            let zeroes = self.buffer.iter().take_while(|&&c| c == b'0').count();
+            self.buffer.drain(..zeroes);
+        }
+        Ok(())
+    }
+
     /// push the given digit string at the right, appending it to the digits already in the buffer.
+    ///
+    /// Return an error if digit string is frozen.
     pub fn push(&mut self, digits: &[u8]) -> Result<(), Error> {
+        if self.frozen {
+            return Err(Error::Frozen);
+        }
+        self.check_len(self.buffer.len() + digits.len())?;
         self.buffer.extend_from_slice(digits);
         Ok(())
     }
@@ -124,6 +209,7 @@ impl DigitString {
             return Err(Error::Frozen);
         }
         let positions = digits.len();
+        self.check_len(self.buffer.len().max(positions))?;
         match self.buffer.len() {
             0 => {
                 self.buffer.extend_from_slice(digits);
@@ -190,45 +276,221 @@ impl DigitString {
     /// Return an error if destination slots are  not free or not 0 or string is frozen.
     /// If there is  nothing on the starting position, first puts 1.
     pub fn shift(&mut self, positions: usize) -> Result<(), Error> {
+        let Some((padding_zeroes, implicit_one)) = self.check_shift(positions)? else {
+            if positions > 0 {
+                if self.buffer.is_empty() {
+                    self.buffer.push(b'1');
+                }
+                self.buffer.resize(self.buffer.len() + positions, b'0');
+            }
+            return Ok(());
+        };
+        let l = self.buffer.len();
+        let span = 2 * positions - padding_zeroes;
+        if implicit_one {
+            self.buffer[l - 1] = b'1';
+        }
+        let (left, right) = self.buffer.split_at_mut(l - positions);
+        left[(l - span)..].swap_with_slice(&mut right[padding_zeroes..]);
+        Ok(())
+    }
+
+    /// [`shift`](Self::shift) would succeed, without changing anything, in O(`positions`).
+    pub fn can_shift(&self, positions: usize) -> bool {
+        self.check_shift(positions).is_ok()
+    }
+
+    /// The outcome of [`shift`](Self::shift): the padding zeroes of the shifted digits and whether they are
+    /// an implicit one, or nothing if the digits are only padded.
+    fn check_shift(&self, positions: usize) -> Result<Option<(usize, bool)>, Error> {
         if self.frozen {
             return Err(Error::Frozen);
         }
+        let l = self.buffer.len();
         if positions == 0 {
-            return Ok(());
-        }
-        if self.buffer.is_empty() {
-            self.buffer.push(b'1');
+            return Ok(None);
         }
-        let l = self.buffer.len();
         if l <= positions {
-            return {
-                self.buffer.resize(l + positions, b'0');
-                Ok(())
-            };
+            self.check_len(l.max(1) + positions)?;
+            return Ok(None);
         }
         // maybe subpart of a bigger number
         let mut padding_zeroes = self.buffer[(l - positions)..]
             .iter()
             .take_while(|&c| *c == b'0')
             .count();
-        if padding_zeroes == positions {
-            self.buffer[l - 1] = b'1';
+        let implicit_one = padding_zeroes == positions;
+        if implicit_one {
             padding_zeroes -= 1;
         }
         let span = 2 * positions - padding_zeroes;
         if l >= span && all_zeros(&self.buffer[(l - span)..(l - positions)]) {
-            let (left, right) = self.buffer.split_at_mut(l - positions);
-            left[(l - span)..].swap_with_slice(&mut right[padding_zeroes..]);
-            Ok(())
+            Ok(Some((padding_zeroes, implicit_one)))
         } else {
             Err(Error::Overlap)
         }
     }
 
+    /// The digit at `position`, 0 beyond the buffer.
+    fn digit_at(&self, position: usize) -> u32 {
+        let len = self.buffer.len();
+        if position < len {
+            (self.buffer[len - 1 - position] - b'0') as u32
+        } else {
+            0
+        }
+    }
+
+    /// Replace the buffer with `digits`, given from the units up, if it fits in the maximum length.
+    fn set_reversed(&mut self, mut digits: Vec<u8>) -> Result<(), Error> {
+        while digits.last() == Some(&b'0') {
+            digits.pop();
+        }
+        self.check_len(digits.len())?;
+        digits.reverse();
+        self.buffer = digits;
+        Ok(())
+    }
+
+    /// Multiply the number by `factor`, for the scale words that multiply what comes before them.
+    ///
+    /// It is real arithmetic, with carries, so the multiplied number needs not end with free positions:
+    /// the myriads of Japanese compose "*go sen man*" (五千万) as 5 × 1000 × 10000.
+    ///
+    /// ```rust
+    /// # use text2num::digit_string::DigitString;
+    /// # fn main() -> Result<(), text2num::error::Error> {
+    /// let mut b = DigitString::new();
+    /// b.put(b"5")?;
+    /// b.mul_assign_small(1000)?;
+    /// b.mul_assign_small(10000)?;
+    /// assert_eq!(b.to_string(), "50000000");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Return an error if the digit string is frozen or the product is too long.
+    pub fn mul_assign_small(&mut self, factor: u16) -> Result<(), Error> {
+        if self.frozen {
+            return Err(Error::Frozen);
+        }
+        let mut product = Vec::with_capacity(self.buffer.len() + 5);
+        let mut carry = 0;
+        for position in 0..self.buffer.len() {
+            let digit = self.digit_at(position) * factor as u32 + carry;
+            product.push(b'0' + (digit % 10) as u8);
+            carry = digit / 10;
+        }
+        while carry > 0 {
+            product.push(b'0' + (carry % 10) as u8);
+            carry /= 10;
+        }
+        self.set_reversed(product)
+    }
+
+    /// Add the completed sub-number `other`, multiplied by 10^`positions`.
+    ///
+    /// It suits the scales that close a group of words, like the lakhs (10^5) and crores (10^7) of Indian English:
+    /// "*two crore fifty lakh*" is 2 × 10^7 + 50 × 10^5.
+    ///
+    /// ```rust
+    /// # use text2num::digit_string::DigitString;
+    /// # fn main() -> Result<(), text2num::error::Error> {
+    /// let (mut two, mut fifty) = (DigitString::new(), DigitString::new());
+    /// two.put(b"2")?;
+    /// fifty.put(b"50")?;
+    /// let mut b = DigitString::new();
+    /// b.add_shifted(&two, 7)?;
+    /// b.add_shifted(&fifty, 5)?;
+    /// assert_eq!(b.to_string(), "25000000");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Unlike [`put`](Self::put), the positions need not be free: the digits are added, with carries.
+    /// Return an error if the digit string is frozen or the sum is too long.
+    pub fn add_shifted(&mut self, other: &DigitString, positions: usize) -> Result<(), Error> {
+        if self.frozen {
+            return Err(Error::Frozen);
+        }
+        if other.is_null() {
+            return Ok(());
+        }
+        let width = self.buffer.len().max(other.buffer.len() + positions);
+        let mut sum = Vec::with_capacity(width + 1);
+        let mut carry = 0;
+        for position in 0..width {
+            let theirs = position
+                .checked_sub(positions)
+                .map_or(0, |position| other.digit_at(position));
+            let digit = self.digit_at(position) + theirs + carry;
+            sum.push(b'0' + (digit % 10) as u8);
+            carry = digit / 10;
+        }
+        if carry > 0 {
+            sum.push(b'1');
+        }
+        self.set_reversed(sum)
+    }
+
+    /// Append the group of digits `group` at the right, padded with zeroes to `width` digits.
+    ///
+    /// It suits the languages that read large numbers as fixed-width groups, like the myriads (groups of four digits)
+    /// of Chinese and Japanese: "*san oku go sen man*" (三億五千万) is 3, 5000 and 0000.
+    ///
+    /// ```rust
+    /// # use text2num::digit_string::DigitString;
+    /// # fn main() -> Result<(), text2num::error::Error> {
+    /// let (mut man, none) = (DigitString::new(), DigitString::new());
+    /// man.put(b"5000")?;
+    /// let mut b = DigitString::new();
+    /// b.put(b"3")?;
+    /// b.push_group(&man, 4)?;
+    /// b.push_group(&none, 4)?;
+    /// assert_eq!(b.to_string(), "350000000");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// If the number is still null, the group is appended without padding.
+    /// Return an error if the digit string is frozen, if `group` is wider than `width` or if the result is too long.
+    pub fn push_group(&mut self, group: &DigitString, width: usize) -> Result<(), Error> {
+        if self.frozen {
+            return Err(Error::Frozen);
+        }
+        if group.buffer.len() > width {
+            return Err(Error::Overlap);
+        }
+        if self.is_null() {
+            self.check_len(group.buffer.len())?;
+            self.buffer.extend_from_slice(&group.buffer);
+            return Ok(());
+        }
+        self.check_len(self.buffer.len() + width)?;
+        self.buffer
+            .resize(self.buffer.len() + width - group.buffer.len(), b'0');
+        self.buffer.extend_from_slice(&group.buffer);
+        Ok(())
+    }
+
     pub fn is_ordinal(&self) -> bool {
         self.marker.is_ordinal()
     }
 
+    /// Signal that the number was read as a year, like "*nineteen oh five*".
+    pub fn mark_year(&mut self) {
+        self.year = true;
+    }
+
+    pub fn is_year(&self) -> bool {
+        self.year
+    }
+
+    /// The number starts with zeroes, like a digit sequence ("*zero six*").
+    pub fn has_leading_zeroes(&self) -> bool {
+        self.leading_zeroes > 0 && self.len() > 1
+    }
+
     /// Parse digits as an integer.
     pub fn parse(&self) -> u64 {
         let mut result = 0;
@@ -243,7 +505,8 @@ impl DigitString {
     pub fn parse_decimal(&self) -> f64 {
         let digits = self.leading_zeroes + self.buffer.len();
         let value = self.parse();
-        value as f64 / 10f64.powi(digits as i32)
+        // without `f64::powi`, that needs std
+        value as f64 / (0..digits).fold(1.0, |power, _| power * 10.0)
     }
 }
 
@@ -273,7 +536,7 @@ impl core::fmt::Display for DigitString {
 
 #[cfg(test)]
 mod tests {
-    use alloc::string::ToString as _;
+    use alloc::{string::ToString as _, vec};
 
     use super::*;
 
@@ -363,6 +626,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_shift_overlap_unchanged() -> Result<(), Error> {
+        let mut builder = DigitString::new();
+        builder.fput(b"100")?;
+        assert!(builder.shift(2).is_err());
+        assert_eq!(builder.peek(4), b"100");
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_len() -> Result<(), Error> {
+        let mut builder = DigitString::new();
+        builder.set_max_len(6);
+        builder.put(b"0")?;
+        builder.put(b"12")?;
+        builder.shift(3)?;
+        assert!(matches!(builder.shift(5), Err(Error::TooLong)));
+        assert!(matches!(builder.put_digit_at(b'9', 5), Err(Error::TooLong)));
+        assert!(matches!(builder.push(b"34"), Err(Error::TooLong)));
+        assert_eq!(builder.to_string(), "012000");
+        builder.reset();
+        assert!(matches!(builder.fput(b"1234567"), Err(Error::TooLong)));
+        Ok(())
+    }
+
     #[test]
     fn test_shift_shorter() -> Result<(), Error> {
         let mut builder = DigitString::new();
@@ -419,6 +707,38 @@ mod tests {
         assert_eq!(builder.to_string(), "1100")
     }
 
+    #[test]
+    fn test_can_put_and_shift() {
+        let mut frozen = DigitString::new();
+        frozen.put(b"2").unwrap();
+        frozen.freeze();
+        let mut states = vec![DigitString::new(), frozen];
+        for digits in [&b"0"[..], b"7", b"20", b"200", b"1000", b"1010"] {
+            let mut builder = DigitString::new();
+            builder.put(digits).unwrap();
+            states.push(builder);
+        }
+        // the predicates tell the outcome of the operation, and leave the string as it is
+        for builder in &states {
+            for digits in [&b"0"[..], b"00", b"1", b"10", b"35", b"100"] {
+                let mut applied = builder.clone();
+                assert_eq!(builder.can_put(digits), applied.put(digits).is_ok());
+            }
+            for positions in 0..5 {
+                let mut applied = builder.clone();
+                assert_eq!(
+                    builder.can_shift(positions),
+                    applied.shift(positions).is_ok()
+                );
+            }
+        }
+        let mut long = DigitString::new();
+        long.set_max_len(3);
+        long.put(b"5").unwrap();
+        assert!(long.can_shift(2));
+        assert!(!long.can_shift(3));
+    }
+
     #[test]
     fn complete_example() -> Result<(), Error> {
         // 2792
@@ -460,6 +780,18 @@ mod tests {
         assert_eq!(builder.to_string(), "1020");
     }
 
+    #[test]
+    fn test_clear_digit_at() {
+        let mut builder = DigitString::new();
+        builder.put(b"2010").unwrap();
+        builder.clear_digit_at(1).unwrap();
+        assert_eq!(builder.to_string(), "2000");
+        builder.clear_digit_at(3).unwrap();
+        assert!(builder.is_empty());
+        builder.put(b"25").unwrap();
+        assert_eq!(builder.to_string(), "25");
+    }
+
     #[test]
     fn test_is_range_free() {
         let mut dstring = DigitString::new();
@@ -482,6 +814,68 @@ mod tests {
         assert!(!dstring.is_position_free(5));
     }
 
+    #[test]
+    fn test_mul_assign_small() -> Result<(), Error> {
+        let mut builder = DigitString::new();
+        builder.put(b"75")?;
+        builder.mul_assign_small(20)?;
+        assert_eq!(builder.to_string(), "1500");
+        builder.mul_assign_small(u16::MAX)?;
+        assert_eq!(builder.parse(), 1500 * u16::MAX as u64);
+        builder.mul_assign_small(0)?;
+        assert!(builder.is_null());
+        let mut builder = DigitString::new();
+        builder.set_max_len(3);
+        builder.put(b"75")?;
+        assert_eq!(builder.mul_assign_small(20), Err(Error::TooLong));
+        assert_eq!(builder.to_string(), "75");
+        builder.freeze();
+        assert_eq!(builder.mul_assign_small(2), Err(Error::Frozen));
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_shifted() -> Result<(), Error> {
+        let mut other = DigitString::new();
+        other.put(b"75")?;
+        let mut builder = DigitString::new();
+        builder.add_shifted(&other, 0)?;
+        assert_eq!(builder.to_string(), "75");
+        // with a carry beyond the buffer
+        builder.add_shifted(&other, 1)?;
+        assert_eq!(builder.to_string(), "825");
+        builder.add_shifted(&other, 5)?;
+        assert_eq!(builder.to_string(), "7500825");
+        builder.add_shifted(&DigitString::new(), 3)?;
+        assert_eq!(builder.to_string(), "7500825");
+        builder.set_max_len(7);
+        assert_eq!(builder.add_shifted(&other, 6), Err(Error::TooLong));
+        assert_eq!(builder.to_string(), "7500825");
+        builder.freeze();
+        assert_eq!(builder.add_shifted(&other, 0), Err(Error::Frozen));
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_group() -> Result<(), Error> {
+        let mut group = DigitString::new();
+        group.put(b"45")?;
+        let mut builder = DigitString::new();
+        builder.push_group(&group, 3)?;
+        assert_eq!(builder.to_string(), "45");
+        builder.push_group(&group, 3)?;
+        assert_eq!(builder.to_string(), "45045");
+        builder.push_group(&DigitString::new(), 2)?;
+        assert_eq!(builder.to_string(), "4504500");
+        assert_eq!(builder.push_group(&group, 1), Err(Error::Overlap));
+        builder.set_max_len(8);
+        assert_eq!(builder.push_group(&group, 2), Err(Error::TooLong));
+        assert_eq!(builder.to_string(), "4504500");
+        builder.freeze();
+        assert_eq!(builder.push_group(&group, 2), Err(Error::Frozen));
+        Ok(())
+    }
+
     #[test]
     fn test_int_parse() {
         let mut dstring = DigitString::new();
@@ -0,0 +1,292 @@
+/*!
+Replacements proposed for review, to apply once an editor has accepted them.
+
+[`propose_edits`] finds the numbers of a text like [`replace_numbers_in_text_with`](crate::replace_numbers_in_text_with),
+but returns the replacements instead of applying them.
+[`apply_edits`] then applies all of them, or only the ones that were accepted.
+
+```rust
+use text2num::{Language, Options, apply_edits, propose_edits};
+
+let en = Language::english();
+let text = "one apple and twenty-five pears";
+let edits = propose_edits(text, &en, &Options::new(0.0));
+
+assert_eq!(edits.len(), 2);
+// a lone "one" is less certain
+assert!(edits[0].confidence < edits[1].confidence);
+let accepted: Vec<_> = edits.into_iter().filter(|edit| edit.confidence == 1.0).collect();
+assert_eq!(apply_edits(text, &accepted).unwrap(), "one apple and 25 pears");
+```
+*/
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::bidi::{FSI, PDI, Paragraphs};
+use crate::lang::LangInterpreter;
+use crate::options::Options;
+use crate::parse::NumberKind;
+use crate::word_to_digit::{FindNumbers, text_tokens};
+
+/// A replacement of a number of the text by its digits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edit {
+    /// The byte offsets of the number in the text
+    pub range: Range<usize>,
+    /// The digits that replace the number, as [`replace_numbers_in_text_with`](crate::replace_numbers_in_text_with)
+    /// writes them
+    pub replacement: String,
+    pub kind: NumberKind,
+    /// The value of the number, or the rank it represents for ordinals
+    pub value: f64,
+    /// How sure the replacement is, from 0 to 1: halved for a lone word under ten, like "*one*" that may be
+    /// a pronoun or "*second*" a unit of time, and halved for a number recovered from a disfluency
+    /// (see [`Options::collapse_stutters`]) or salvaged from a word (see [`Options::salvage_partial_groups`]).
+    pub confidence: f64,
+}
+
+/// The reason why [`apply_edits`] rejected the edits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditError {
+    /// The range of the edit at `index` ends after the text
+    OutOfBounds { index: usize },
+    /// The range of the edit at `index` starts after its end, or cuts a character of the text
+    InvalidRange { index: usize },
+    /// The range of the edit at `index` overlaps that of the previous edit
+    Overlap { index: usize },
+    /// The edit at `index` comes before the previous edit in the text
+    Unsorted { index: usize },
+    /// With the edit at `index`, the output would be longer than the limit
+    /// (see [`Options::max_output_factor`])
+    TooLong { index: usize },
+    /// The output is not the length that the edits make: the text was spliced wrong
+    LengthMismatch { expected: usize, written: usize },
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { index } => write!(f, "edit {index} ends after the text"),
+            Self::InvalidRange { index } => write!(f, "edit {index} has an invalid range"),
+            Self::Overlap { index } => write!(f, "edit {index} overlaps the previous edit"),
+            Self::Unsorted { index } => write!(f, "edit {index} comes before the previous edit"),
+            Self::TooLong { index } => write!(f, "edit {index} makes the output too long"),
+            Self::LengthMismatch { expected, written } => {
+                write!(f, "{written} bytes written instead of {expected}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EditError {}
+
+/// Find the spelled numbers of `text` and return their replacements, in order, without applying them.
+pub fn propose_edits<L: LangInterpreter>(text: &str, lang: &L, options: &Options) -> Vec<Edit> {
+    let tokens = text_tokens(text, lang, options);
+    let mut paragraphs = options.isolate_digits_in_rtl.then(|| Paragraphs::new(text));
+    FindNumbers::new(tokens.iter().enumerate(), lang, options)
+        .map(|mut occurence| {
+            let mut confidence = 1.0;
+            if occurence.words == 1 && occurence.value.abs() < 10.0 {
+                confidence /= 2.0;
+            }
+            if occurence.repaired || occurence.partial {
+                confidence /= 2.0;
+            }
+            if let Some(paragraphs) = paragraphs.as_mut()
+                && paragraphs.is_rtl_at(occurence.range.start)
+            {
+                occurence.text = format!("{FSI}{}{PDI}", occurence.text);
+            }
+            Edit {
+                range: occurence.range,
+                replacement: occurence.text,
+                kind: occurence.kind,
+                value: occurence.value,
+                confidence,
+            }
+        })
+        .collect()
+}
+
+/// Apply `edits` to `text`: all the edits of [`propose_edits`], or any subset of them.
+///
+/// The edits must be sorted by position, not overlap, and their ranges must fall on the characters of `text`,
+/// or nothing is applied.
+pub fn apply_edits(text: &str, edits: &[Edit]) -> Result<String, EditError> {
+    let mut guard = SpliceGuard::new(text, None);
+    for edit in edits {
+        guard.replace(&edit.range, edit.replacement.len())?;
+    }
+    let mut out = String::with_capacity(guard.len);
+    let mut cursor = 0;
+    for edit in edits {
+        out.push_str(&text[cursor..edit.range.start]);
+        out.push_str(&edit.replacement);
+        cursor = edit.range.end;
+    }
+    out.push_str(&text[cursor..]);
+    guard.finish(out.len())?;
+    Ok(out)
+}
+
+/// The invariants of the replacements spliced into a text, checked as they come, before they are written:
+/// their ranges fall on the characters of the text, in order and without overlapping, and the output
+/// stays under its limit. Once written, the output must be exactly the length they make.
+pub(crate) struct SpliceGuard<'t> {
+    text: &'t str,
+    previous: Option<Range<usize>>,
+    index: usize,
+    /// The length of the output, with the replacements checked so far
+    len: usize,
+    max_len: Option<usize>,
+}
+
+impl<'t> SpliceGuard<'t> {
+    pub(crate) fn new(text: &'t str, max_len: Option<usize>) -> Self {
+        Self {
+            text,
+            previous: None,
+            index: 0,
+            len: text.len(),
+            max_len,
+        }
+    }
+
+    /// Check the next replacement: `range` of the text is replaced by `replacement_len` bytes.
+    pub(crate) fn replace(
+        &mut self,
+        range: &Range<usize>,
+        replacement_len: usize,
+    ) -> Result<(), EditError> {
+        let index = self.index;
+        if range.end > self.text.len() {
+            return Err(EditError::OutOfBounds { index });
+        }
+        if range.start > range.end
+            || !self.text.is_char_boundary(range.start)
+            || !self.text.is_char_boundary(range.end)
+        {
+            return Err(EditError::InvalidRange { index });
+        }
+        if let Some(previous) = &self.previous
+            && range.start < previous.end
+        {
+            return Err(if range.end <= previous.start {
+                EditError::Unsorted { index }
+            } else {
+                EditError::Overlap { index }
+            });
+        }
+        // the ranges checked so far are disjoint: this one is still in the output
+        self.len = self.len - range.len() + replacement_len;
+        if self.max_len.is_some_and(|max_len| self.len > max_len) {
+            return Err(EditError::TooLong { index });
+        }
+        self.previous = Some(range.clone());
+        self.index += 1;
+        Ok(())
+    }
+
+    /// Check the length of the output once written.
+    pub(crate) fn finish(&self, written: usize) -> Result<(), EditError> {
+        if written == self.len {
+            Ok(())
+        } else {
+            Err(EditError::LengthMismatch {
+                expected: self.len,
+                written,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg_attr(not(feature = "en"), allow(unused_imports))]
+    use crate::lang::Language;
+
+    fn edit(range: Range<usize>, replacement: &str) -> Edit {
+        Edit {
+            range,
+            replacement: replacement.into(),
+            kind: NumberKind::Cardinal,
+            value: 0.0,
+            confidence: 1.0,
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_propose_and_apply() {
+        let en = Language::english();
+        let text = "twenty-one cats, one dog and three point five fish";
+        let edits = propose_edits(text, &en, &Options::new(0.0));
+        assert_eq!(
+            edits
+                .iter()
+                .map(|edit| (&text[edit.range.clone()], edit.replacement.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                ("twenty-one", "21"),
+                ("one", "1"),
+                ("three point five", "3.5")
+            ]
+        );
+        assert_eq!(edits[2].kind, NumberKind::Decimal);
+        assert_eq!(edits[2].value, 3.5);
+        assert_eq!(edits[1].confidence, 0.5);
+        assert_eq!(
+            apply_edits(text, &edits).unwrap(),
+            crate::replace_numbers_in_text(text, &en, 0.0)
+        );
+        // a subset of the edits, as an editor may accept them
+        assert_eq!(
+            apply_edits(text, &[edits[0].clone(), edits[2].clone()]).unwrap(),
+            "21 cats, one dog and 3.5 fish"
+        );
+        assert_eq!(apply_edits(text, &[]).unwrap(), text);
+    }
+
+    #[test]
+    fn test_rejected_edits() {
+        let text = "one two three";
+        assert_eq!(
+            apply_edits(text, &[edit(4..7, "2"), edit(0..3, "1")]),
+            Err(EditError::Unsorted { index: 1 })
+        );
+        assert_eq!(
+            apply_edits(text, &[edit(0..7, "12"), edit(4..13, "23")]),
+            Err(EditError::Overlap { index: 1 })
+        );
+        assert_eq!(
+            apply_edits(text, &[edit(0..3, "1"), edit(0..3, "1")]),
+            Err(EditError::Overlap { index: 1 })
+        );
+        assert_eq!(
+            apply_edits(text, &[edit(8..14, "3")]),
+            Err(EditError::OutOfBounds { index: 0 })
+        );
+        assert_eq!(
+            apply_edits("née deux", &[edit(0..2, "x")]),
+            Err(EditError::InvalidRange { index: 0 })
+        );
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = edit(3..0, "1");
+        assert_eq!(
+            apply_edits(text, &[reversed]),
+            Err(EditError::InvalidRange { index: 0 })
+        );
+        // adjacent edits are fine
+        assert_eq!(
+            apply_edits(text, &[edit(0..3, "1"), edit(3..4, "-")]).unwrap(),
+            "1-two three"
+        );
+    }
+}
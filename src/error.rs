@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 /// Number recognition errors.
 /// They are mostly used internally by the high level API.
 pub enum Error {
@@ -11,4 +11,7 @@ pub enum Error {
     Incomplete,
     /// The currently decoded number is complete and we don't accept new data
     Frozen,
+    /// The number is longer than the configured limit (see [`Options::max_digits`](crate::Options::max_digits)
+    /// and [`Options::max_tokens`](crate::Options::max_tokens))
+    TooLong,
 }
@@ -0,0 +1,401 @@
+/*!
+Custom rendering of the digit forms.
+
+The languages render the numbers they decode with their own conventions ("*20th*", "*3,5*"…).
+A [`NumberFormatter`], set with [`Options::with_formatter`](crate::Options::with_formatter),
+can take over that rendering, for every number or only for some of them.
+
+```rust
+use text2num::{Language, Options, replace_numbers_in_text_with};
+use text2num::formatter::Grouped;
+
+let en = Language::english();
+let options = Options::new(0.0).with_formatter(Box::new(Grouped::new(',')));
+
+assert_eq!(
+    replace_numbers_in_text_with("one million two hundred thousand people in one thousand nine hundred ninety", &en, &options),
+    "1,200,000 people in 1990"
+);
+```
+*/
+
+use alloc::string::String;
+
+use crate::digit_string::DigitString;
+use crate::lang::MorphologicalMarker;
+use crate::parse::NumberKind;
+
+/// A decoded number, as handed to a [`NumberFormatter`].
+#[derive(Debug, Clone, Copy)]
+pub struct Digits<'a> {
+    /// The integer part of the number (the whole number if it is not a decimal number)
+    pub int: &'a DigitString,
+    /// The decimal part and the decimal separator symbol, for decimal numbers
+    pub decimal: Option<(&'a DigitString, char)>,
+    /// The ordinal or fraction marker of the number
+    pub marker: MorphologicalMarker,
+    /// What the number is, as far as the interpretation of its words tells
+    pub kind: NumberKind,
+    /// The code of the language the number was decoded from (see [`LangInterpreter::language_code`](crate::LangInterpreter::language_code))
+    pub language: &'static str,
+}
+
+/// A rendering of the digit forms that replaces the one of the language.
+pub trait NumberFormatter: Send + Sync {
+    /// Return the digit form of `number`, or `None` to keep the rendering of the language.
+    ///
+    /// The default keeps the rendering of the language for all numbers.
+    fn format(&self, _number: &Digits<'_>) -> Option<String> {
+        None
+    }
+}
+
+impl core::fmt::Debug for dyn NumberFormatter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("NumberFormatter")
+    }
+}
+
+/// The same rendering for all languages: the digits decorated with the affix of their marker,
+/// and the decimal numbers with their separator, as in "*20th*" or "*3.5*".
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Plain;
+
+impl NumberFormatter for Plain {
+    fn format(&self, number: &Digits<'_>) -> Option<String> {
+        Some(plain(number.int, number))
+    }
+}
+
+/// Cardinals and decimal numbers with their integer digits grouped by thousands, as in "*1,200,000*".
+///
+/// Only the numbers of at least [`min_digits`](Self::min_digits) integer digits are grouped;
+/// ordinals and fractions keep the rendering of the language, and so do years and digit sequences
+/// ([`NumberKind::Year`] and [`NumberKind::Sequence`]), with their leading zeroes.
+#[derive(Debug, Clone, Copy)]
+pub struct Grouped {
+    pub separator: char,
+    pub min_digits: usize,
+}
+
+impl Grouped {
+    /// Group with `separator` the numbers of 5 digits or more, so that years stay as they are.
+    pub fn new(separator: char) -> Self {
+        Self {
+            separator,
+            min_digits: 5,
+        }
+    }
+}
+
+impl NumberFormatter for Grouped {
+    fn format(&self, number: &Digits<'_>) -> Option<String> {
+        if !number.marker.is_none()
+            || matches!(number.kind, NumberKind::Year | NumberKind::Sequence)
+            || number.int.len() < self.min_digits
+        {
+            return None;
+        }
+        let digits = alloc::string::ToString::to_string(number.int);
+        let mut int = String::with_capacity(digits.len() * 4 / 3);
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                int.push(self.separator);
+            }
+            int.push(c);
+        }
+        Some(plain(int, number))
+    }
+}
+
+/// The rendering of [`Plain`], with the Persian digits "*۰۱۲۳۴۵۶۷۸۹*" and the Persian decimal separator "*٫*",
+/// as in "*۲۳*" or "*۳٫۵*".
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PersianDigits;
+
+impl NumberFormatter for PersianDigits {
+    fn format(&self, number: &Digits<'_>) -> Option<String> {
+        let number = Digits {
+            decimal: number.decimal.map(|(dec, _)| (dec, '٫')),
+            ..*number
+        };
+        Some(
+            plain(number.int, &number)
+                .chars()
+                .map(|c| match c.to_digit(10) {
+                    Some(d) if c.is_ascii_digit() => char::from_u32(0x06f0 + d).unwrap_or(c),
+                    _ => c,
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The digits the replacements are written with (see [`Options::digit_script`](crate::Options::digit_script)).
+///
+/// The ASCII digits of the rendering, whether it is the one of the language or of a [`NumberFormatter`],
+/// are transliterated last, and so is the decimal separator of the scripts that have their own.
+/// The Latin letters of the ordinal affixes, like "*st*" in "*21st*", are dropped, as they don't go
+/// with the digits of another script: "*٢١*". The affixes of the other scripts, like Persian "*م*", are kept,
+/// and so are the grouping separators and the other characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DigitScript {
+    /// "*0123456789*"
+    #[default]
+    Ascii,
+    /// "*٠١٢٣٤٥٦٧٨٩*", of Arabic, with the decimal separator "*٫*"
+    ArabicIndic,
+    /// "*۰۱۲۳۴۵۶۷۸۹*", of Persian and Urdu, with the decimal separator "*٫*"
+    ExtendedArabicIndic,
+    /// "*०१२३४५६७८९*", of Hindi and Marathi
+    Devanagari,
+}
+
+impl DigitScript {
+    /// The zero of the script and its decimal separator, if it has its own.
+    fn zero_and_separator(self) -> Option<(u32, Option<char>)> {
+        match self {
+            Self::Ascii => None,
+            Self::ArabicIndic => Some((0x0660, Some('٫'))),
+            Self::ExtendedArabicIndic => Some((0x06f0, Some('٫'))),
+            Self::Devanagari => Some((0x0966, None)),
+        }
+    }
+
+    /// Write the digit form `text` with the digits of the script, and its `decimal_separator` with the one of the script.
+    ///
+    /// The decimal separator is the last one of the text, so that a grouping separator that is the same symbol
+    /// is kept. The Latin letters of an `ordinal` are dropped.
+    pub(crate) fn transliterate(
+        self,
+        text: &str,
+        decimal_separator: Option<char>,
+        ordinal: bool,
+    ) -> Option<String> {
+        let (zero, script_separator) = self.zero_and_separator()?;
+        let decimal = decimal_separator
+            .zip(script_separator)
+            .and_then(|(sep, script_sep)| Some((text.rfind(sep)?, script_sep)));
+        Some(
+            text.char_indices()
+                .filter(|&(_, c)| !(ordinal && is_latin_letter(c)))
+                .map(|(i, c)| match (c.to_digit(10), decimal) {
+                    (Some(d), _) if c.is_ascii_digit() => char::from_u32(zero + d).unwrap_or(c),
+                    (_, Some((pos, script_sep))) if pos == i => script_sep,
+                    _ => c,
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Return true if `c` is a Latin letter, or one of its superscript forms, like "*ˢ*" and "*º*" of the ordinals.
+fn is_latin_letter(c: char) -> bool {
+    c.is_alphabetic()
+        && matches!(c, 'a'..='z' | 'A'..='Z' | '\u{aa}'..='\u{24f}' | '\u{2b0}'..='\u{2ff}'
+            | '\u{1d00}'..='\u{1dbf}' | '\u{1e00}'..='\u{1eff}' | '\u{2070}'..='\u{209f}')
+}
+
+fn plain<D: core::fmt::Display>(int: D, number: &Digits<'_>) -> String {
+    match (number.decimal, number.marker) {
+        (Some((dec, sep)), _) => alloc::format!("{int}{sep}{dec}"),
+        (None, MorphologicalMarker::Ordinal(affix) | MorphologicalMarker::Fraction(affix)) => {
+            affix.wrap(int)
+        }
+        (None, MorphologicalMarker::None) => alloc::format!("{int}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg_attr(not(any(feature = "en", feature = "fr")), allow(unused_imports))]
+    use super::*;
+    #[cfg_attr(not(any(feature = "en", feature = "fr")), allow(unused_imports))]
+    use crate::{Options, OrdinalStyle, word_to_digit::replace_numbers_in_text_with};
+    #[cfg_attr(not(any(feature = "en", feature = "fr")), allow(unused_imports))]
+    use alloc::boxed::Box;
+
+    /// Fixed width codes
+    #[cfg(feature = "en")]
+    struct ZeroPadded(usize);
+
+    #[cfg(feature = "en")]
+    impl NumberFormatter for ZeroPadded {
+        fn format(&self, number: &Digits<'_>) -> Option<String> {
+            (number.marker.is_none() && number.decimal.is_none()).then(|| {
+                let digits = alloc::string::ToString::to_string(number.int);
+                alloc::format!("{digits:0>width$}", width = self.0)
+            })
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_custom_formatter() {
+        let en = crate::Language::english();
+        let options = Options {
+            adjacent_number_policy: crate::AdjacentNumberPolicy::Concatenate,
+            ..Options::new(0.0)
+        }
+        .with_formatter(Box::new(ZeroPadded(3)));
+        assert_eq!(
+            replace_numbers_in_text_with(
+                "agent seven, room twenty-one, the fifth, two point five",
+                &en,
+                &options
+            ),
+            "agent 007, room 021, the 5th, 2.5"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_persian_digits() {
+        let en = crate::Language::english();
+        let options = Options::new(0.0).with_formatter(Box::new(PersianDigits));
+        assert_eq!(
+            replace_numbers_in_text_with(
+                "twenty-three, the fifth, three point five",
+                &en,
+                &options
+            ),
+            "۲۳, the ۵th, ۳٫۵"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_grouped_kinds() {
+        let en = crate::Language::english();
+        let options = Options {
+            adjacent_number_policy: crate::AdjacentNumberPolicy::Concatenate,
+            ..Options::new(0.0)
+        }
+        .with_formatter(Box::new(Grouped {
+            separator: ',',
+            min_digits: 4,
+        }));
+        assert_eq!(
+            replace_numbers_in_text_with(
+                "one thousand nine hundred five, in nineteen oh five, call zero one two three four five",
+                &en,
+                &options
+            ),
+            "1,905, in 1905, call 012345"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fr")]
+    fn test_builtin_formatters() {
+        let fr = crate::Language::french();
+        let text = "deux millions trois cent mille habitants, la première, douze virgule cinq, en mille neuf cent quatre-vingt-dix";
+        let options = Options::new(0.0).with_formatter(Box::new(Grouped::new('\u{202f}')));
+        assert_eq!(
+            replace_numbers_in_text_with(text, &fr, &options),
+            "2\u{202f}300\u{202f}000 habitants, la 1re, 12,5, en 1990"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("deux virgule cinq millions", &fr, &options),
+            "2\u{202f}500\u{202f}000"
+        );
+        let options = Options::new(0.0).with_formatter(Box::new(Plain));
+        assert_eq!(
+            replace_numbers_in_text_with(text, &fr, &options),
+            "2300000 habitants, la 1re, 12,5, en 1990"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_digit_scripts() {
+        let en = crate::Language::english();
+        let text = "room 12: twenty-three, the fifth, three point five";
+        let grouped = "one million two hundred thousand point five, 42";
+        let expected = [
+            (
+                DigitScript::Ascii,
+                "room 12: 23, the 5th, 3.5",
+                "1,200,000.5, 42",
+            ),
+            (
+                DigitScript::ArabicIndic,
+                "room 12: ٢٣, the ٥, ٣٫٥",
+                "١,٢٠٠,٠٠٠٫٥, 42",
+            ),
+            (
+                DigitScript::ExtendedArabicIndic,
+                "room 12: ۲۳, the ۵, ۳٫۵",
+                "۱,۲۰۰,۰۰۰٫۵, 42",
+            ),
+            (
+                DigitScript::Devanagari,
+                "room 12: २३, the ५, ३.५",
+                "१,२००,०००.५, 42",
+            ),
+        ];
+        for (digit_script, replaced, replaced_grouped) in expected {
+            let options = Options {
+                digit_script,
+                ..Options::new(0.0)
+            };
+            assert_eq!(replace_numbers_in_text_with(text, &en, &options), replaced);
+            let options = options.with_formatter(Box::new(Grouped::new(',')));
+            assert_eq!(
+                replace_numbers_in_text_with(grouped, &en, &options),
+                replaced_grouped
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "en", feature = "fa"))]
+    fn test_digit_script_ordinals() {
+        let options = |digit_script, ordinal_style| Options {
+            digit_script,
+            ordinal_style,
+            ..Options::new(0.0)
+        };
+        let en = crate::Language::english();
+        for style in [OrdinalStyle::Plain, OrdinalStyle::Superscript] {
+            assert_eq!(
+                replace_numbers_in_text_with(
+                    "the twenty-first of two",
+                    &en,
+                    &options(DigitScript::ArabicIndic, style)
+                ),
+                "the ٢١ of ٢"
+            );
+        }
+        // the affix of the script is kept
+        let fa = crate::Language::persian();
+        assert_eq!(
+            replace_numbers_in_text_with(
+                "روز پنجم",
+                &fa,
+                &options(DigitScript::ExtendedArabicIndic, OrdinalStyle::Plain)
+            ),
+            "روز ۵م"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fr")]
+    fn test_digit_script_decimal_comma() {
+        let fr = crate::Language::french();
+        // the grouping separator is the decimal separator of French
+        let options = Options {
+            digit_script: DigitScript::ArabicIndic,
+            ..Options::new(0.0)
+        }
+        .with_formatter(Box::new(Grouped::new(',')));
+        assert_eq!(
+            replace_numbers_in_text_with(
+                "deux millions trois cent mille virgule cinq",
+                &fr,
+                &options
+            ),
+            "٢,٣٠٠,٠٠٠٫٥"
+        );
+    }
+}
@@ -0,0 +1,456 @@
+//! Bulgarian number interpreter
+//!
+//! Bulgarian links the last element of a number with "и": "сто двадесет и три", "две хиляди и петстотин".
+//! Ordinals are adjectives, written with the last letters of their masculine, feminine or neuter form:
+//! "двадесет и първи" is "21-ви", "двадесета" is "20-та", "стотно" is "100-тно".
+//! The definite forms ("първият", "първата") are written like the indefinite ones.
+
+use alloc::{format, string::String, vec::Vec};
+
+use bitflags::bitflags;
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker, sorted_vocabulary};
+use vocabulary::{INSIGNIFICANT, NUMBER_WORDS};
+
+bitflags! {
+    /// word chaining restrictions
+    struct Restriction: u64 {
+        const CONJUNCTION = 1;
+    }
+}
+
+/// Stems of the ordinals declined like "първи", "първа", "първо".
+const ORDINAL_STEMS: [&str; 39] = [
+    "първ",
+    "втор",
+    "трет",
+    "четвърт",
+    "пет",
+    "шест",
+    "седм",
+    "осм",
+    "девет",
+    "десет",
+    "единадесет",
+    "единайсет",
+    "дванадесет",
+    "дванайсет",
+    "тринадесет",
+    "тринайсет",
+    "четиринадесет",
+    "четиринайсет",
+    "петнадесет",
+    "петнайсет",
+    "шестнадесет",
+    "шестнайсет",
+    "седемнадесет",
+    "седемнайсет",
+    "осемнадесет",
+    "осемнайсет",
+    "деветнадесет",
+    "деветнайсет",
+    "двадесет",
+    "тридесет",
+    "четиридесет",
+    "петдесет",
+    "шестдесет",
+    "седемдесет",
+    "осемдесет",
+    "деветдесет",
+    "двайсет",
+    "трийсет",
+    "четирийсет",
+];
+
+/// Endings of the ordinals declined like "първи", with the form they are written with:
+/// masculine (and plural), feminine or neuter.
+const ORDINAL_ENDINGS: [(&str, usize); 8] = [
+    ("и", 0),
+    ("ия", 0),
+    ("ият", 0),
+    ("ите", 0),
+    ("а", 1),
+    ("ата", 1),
+    ("о", 2),
+    ("ото", 2),
+];
+
+/// Stems of the ordinals declined like "стотен", "стотна", "стотно".
+const EN_ORDINAL_STEMS: [&str; 12] = [
+    "стот",
+    "двестот",
+    "тристот",
+    "четиристот",
+    "петстот",
+    "шестстот",
+    "седемстот",
+    "осемстот",
+    "деветстот",
+    "хиляд",
+    "милион",
+    "милиард",
+];
+
+/// Endings of the ordinals declined like "стотен", with the form they are written with:
+/// masculine, feminine, neuter or plural.
+const EN_ORDINAL_ENDINGS: [(&str, usize); 9] = [
+    ("ен", 0),
+    ("ния", 0),
+    ("ният", 0),
+    ("на", 1),
+    ("ната", 1),
+    ("но", 2),
+    ("ното", 2),
+    ("ни", 3),
+    ("ните", 3),
+];
+
+/// The suffixes of the digit forms, by last letter of the stem.
+const SUFFIXES: [(char, [&str; 3]); 4] = [
+    ('в', ["-ви", "-ва", "-во"]),
+    ('р', ["-ри", "-ра", "-ро"]),
+    ('т', ["-ти", "-та", "-то"]),
+    ('м', ["-ми", "-ма", "-мо"]),
+];
+
+const EN_SUFFIXES: [(char, [&str; 4]); 3] = [
+    ('т', ["-тен", "-тна", "-тно", "-тни"]),
+    ('д', ["-ден", "-дна", "-дно", "-дни"]),
+    ('н', ["-нен", "-нна", "-нно", "-нни"]),
+];
+
+fn find_ordinal<'a>(word: &'a str, stems: &[&str], endings: &[(&str, usize)]) -> Option<(&'a str, usize)> {
+    stems.iter().find_map(|stem| {
+        let ending = word.strip_prefix(stem)?;
+        endings
+            .iter()
+            .find(|(e, _)| *e == ending)
+            .map(|(_, form)| (&word[..stem.len()], *form))
+    })
+}
+
+fn suffix_for<const N: usize>(stem: &str, form: usize, suffixes: &[(char, [&'static str; N])]) -> &'static str {
+    let last = stem.chars().next_back();
+    suffixes
+        .iter()
+        .find(|(letter, _)| Some(*letter) == last)
+        .map_or("-и", |(_, forms)| forms[form])
+}
+
+/// Return the stem of `word` and the suffix of its digit form if it is an ordinal, or `word` itself.
+fn lemmatize(word: &str) -> (&str, Option<&'static str>) {
+    if let Some((stem, form)) = find_ordinal(word, &ORDINAL_STEMS, &ORDINAL_ENDINGS) {
+        (stem, Some(suffix_for(stem, form, &SUFFIXES)))
+    } else if let Some((stem, form)) = find_ordinal(word, &EN_ORDINAL_STEMS, &EN_ORDINAL_ENDINGS) {
+        (stem, Some(suffix_for(stem, form, &EN_SUFFIXES)))
+    } else {
+        (word, None)
+    }
+}
+
+/// Return true if `word` is one of the number words, inflected.
+fn is_number_word(word: &str) -> bool {
+    let lemma = lemmatize(word).0;
+    NUMBER_WORDS.iter().any(|known| lemmatize(known).0 == lemma)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Bulgarian {}
+
+impl Bulgarian {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl LangInterpreter for Bulgarian {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        // bare plural scale words are vague quantities: "хиляди хора"
+        if b.is_empty() && matches!(num_func, "хиляди" | "милиони" | "милиарди") {
+            return Err(Error::NaN);
+        }
+        let (lemma, ordinal) = lemmatize(num_func);
+        let status = match lemma {
+            "нула" => b.put(b"0"),
+            "един" | "една" | "едно" | "първ" if b.peek(2) != b"10" => b.put(b"1"),
+            "два" | "две" | "двама" | "втор" if b.peek(2) != b"10" => b.put(b"2"),
+            "три" | "трима" | "трет" if b.peek(2) != b"10" => b.put(b"3"),
+            "четири" | "четирима" | "четвърт" if b.peek(2) != b"10" => b.put(b"4"),
+            "пет" if b.peek(2) != b"10" => b.put(b"5"),
+            "шест" if b.peek(2) != b"10" => b.put(b"6"),
+            "седем" | "седм" if b.peek(2) != b"10" => b.put(b"7"),
+            "осем" | "осм" if b.peek(2) != b"10" => b.put(b"8"),
+            "девет" if b.peek(2) != b"10" => b.put(b"9"),
+            "десет" => b.put(b"10"),
+            "единадесет" | "единайсет" => b.put(b"11"),
+            "дванадесет" | "дванайсет" => b.put(b"12"),
+            "тринадесет" | "тринайсет" => b.put(b"13"),
+            "четиринадесет" | "четиринайсет" => b.put(b"14"),
+            "петнадесет" | "петнайсет" => b.put(b"15"),
+            "шестнадесет" | "шестнайсет" => b.put(b"16"),
+            "седемнадесет" | "седемнайсет" => b.put(b"17"),
+            "осемнадесет" | "осемнайсет" => b.put(b"18"),
+            "деветнадесет" | "деветнайсет" => b.put(b"19"),
+            "двадесет" | "двайсет" => b.put(b"20"),
+            "тридесет" | "трийсет" => b.put(b"30"),
+            "четиридесет" | "четирийсет" => b.put(b"40"),
+            "петдесет" => b.put(b"50"),
+            "шестдесет" => b.put(b"60"),
+            "седемдесет" => b.put(b"70"),
+            "осемдесет" => b.put(b"80"),
+            "деветдесет" => b.put(b"90"),
+            "сто" | "стот" => b.put(b"100"),
+            "двеста" | "двестот" => b.put(b"200"),
+            "триста" | "тристот" => b.put(b"300"),
+            "четиристотин" | "четиристот" => b.put(b"400"),
+            "петстотин" | "петстот" => b.put(b"500"),
+            "шестстотин" | "шестстот" => b.put(b"600"),
+            "седемстотин" | "седемстот" => b.put(b"700"),
+            "осемстотин" | "осемстот" => b.put(b"800"),
+            "деветстотин" | "деветстот" => b.put(b"900"),
+            "хиляда" | "хиляди" | "хиляд" if b.is_range_free(3, 5) => b.shift(3),
+            "милион" | "милиона" | "милиони" if b.is_range_free(6, 8) => b.shift(6),
+            "милиард" | "милиарда" | "милиарди" if b.is_range_free(9, 11) => b.shift(9),
+            // "сто и пет", "двадесет и три", but not "две и три"
+            "и" if b.len() >= 2
+                && b.marker.is_none()
+                && !Restriction::from_bits_truncate(b.flags).contains(Restriction::CONJUNCTION) =>
+            {
+                Err(Error::Incomplete)
+            }
+            _ => Err(Error::NaN),
+        };
+        match status {
+            Ok(()) => {
+                b.flags = 0;
+                if let Some(suffix) = ordinal {
+                    b.marker = MorphologicalMarker::Ordinal(suffix.into());
+                    b.freeze();
+                }
+            }
+            Err(Error::Incomplete) => b.flags = Restriction::CONJUNCTION.bits(),
+            _ => b.flags = 0,
+        }
+        status
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        // "три цяло и пет"
+        if decimal_func == "и" && b.is_empty() && b.flags == 0 {
+            b.flags = Restriction::CONJUNCTION.bits();
+            Err(Error::Incomplete)
+        } else {
+            self.apply(decimal_func, b)
+        }
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        match lemmatize(word).1 {
+            Some(suffix) => MorphologicalMarker::Ordinal(suffix.into()),
+            None => MorphologicalMarker::None,
+        }
+    }
+
+    fn language_code(&self) -> &'static str {
+        "bg"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        let ordinals = ORDINAL_STEMS
+            .iter()
+            .flat_map(|stem| ORDINAL_ENDINGS.iter().map(move |(ending, _)| format!("{stem}{ending}")));
+        let en_ordinals = EN_ORDINAL_STEMS
+            .iter()
+            .flat_map(|stem| EN_ORDINAL_ENDINGS.iter().map(move |(ending, _)| format!("{stem}{ending}")));
+        sorted_vocabulary(NUMBER_WORDS.iter().map(|word| String::from(*word)).chain(ordinals).chain(en_ordinals))
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        match word {
+            "запетая" | "цяло" | "цели" => Some(','),
+            _ => None,
+        }
+    }
+
+    fn decimal_symbol(&self) -> char {
+        ','
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "три на две"
+        &["на"]
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        // the words of the vocabulary, in context
+        is_number_word(word) && super::dry_run_start(self, word)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        (word == "и" || is_number_word(word)) && super::dry_run_continue(self, word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Bulgarian::new();
+            let res = text2digits($text, &f);
+            crate::tests::dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Bulgarian::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Bulgarian::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Bulgarian::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("един", "1");
+        assert_text2digits!("двадесет и три", "23");
+        assert_text2digits!("сто двадесет и три", "123");
+        assert_text2digits!("сто и три", "103");
+        assert_text2digits!("сто и двадесет", "120");
+        assert_text2digits!("двеста и единадесет", "211");
+        assert_text2digits!("деветстотин деветдесет и девет", "999");
+        assert_text2digits!("хиляда", "1000");
+        assert_text2digits!("хиляда и сто", "1100");
+        assert_text2digits!("две хиляди и петстотин", "2500");
+        assert_text2digits!("двадесет и пет хиляди", "25000");
+        assert_text2digits!("три хиляди четиристотин петдесет и шест", "3456");
+        assert_text2digits!("сто хиляди", "100000");
+        assert_text2digits!("един милион", "1000000");
+        assert_text2digits!("два милиона и триста хиляди", "2300000");
+        assert_text2digits!("пет милиарда", "5000000000");
+    }
+
+    #[test]
+    fn test_short_forms() {
+        assert_text2digits!("единайсет", "11");
+        assert_text2digits!("двайсет и две", "22");
+        assert_text2digits!("трийсет и пет", "35");
+    }
+
+    #[test]
+    fn test_genders() {
+        assert_text2digits!("една", "1");
+        assert_text2digits!("едно", "1");
+        assert_text2digits!("две", "2");
+        assert_text2digits!("двама", "2");
+        assert_text2digits!("двадесет и една", "21");
+        assert_text2digits!("тридесет и две", "32");
+    }
+
+    #[test]
+    fn test_conjunction() {
+        assert_invalid!("двадесет и");
+        assert_invalid!("двадесет и и три");
+        assert_replace_all_numbers!("две и три ябълки", "2 и 3 ябълки");
+        assert_replace_all_numbers!("двадесет и сто", "20 и 100");
+        assert_replace_numbers!(
+            "Купих двадесет и три ябълки и дванадесет круши.",
+            "Купих 23 ябълки и 12 круши."
+        );
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("първи", "1-ви");
+        assert_text2digits!("втора", "2-ра");
+        assert_text2digits!("трето", "3-то");
+        assert_text2digits!("седми", "7-ми");
+        assert_text2digits!("двадесети", "20-ти");
+        assert_text2digits!("двадесета", "20-та");
+        assert_text2digits!("двадесет и първи", "21-ви");
+        assert_text2digits!("сто двадесет и трета", "123-та");
+        assert_text2digits!("първият", "1-ви");
+        assert_text2digits!("стотен", "100-тен");
+        assert_text2digits!("стотна", "100-тна");
+        assert_text2digits!("хиляден", "1000-ден");
+        assert_invalid!("първи двадесет");
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("нула", "0");
+        assert_text2digits!("нула осем", "08");
+        assert_invalid!("пет нула");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("десет пет");
+        assert_invalid!("пет сто");
+        assert_invalid!("хиляда хиляда");
+        assert_invalid!("шестдесет петнадесет");
+        assert_invalid!("хиляди");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "Имаме двадесет и пет крави, дванадесет пилета и сто двадесет и пет килограма картофи.",
+            "Имаме 25 крави, 12 пилета и 125 килограма картофи."
+        );
+        assert_replace_numbers!("Хиляда двеста шестдесет и шест евро", "1266 евро");
+        assert_replace_numbers!("един два три четири двадесет петнадесет", "1 2 3 4 20 15");
+        assert_replace_numbers!("хиляди хора и милиони звезди", "хиляди хора и милиони звезди");
+    }
+
+    #[test]
+    fn test_replace_decimals() {
+        assert_replace_numbers!(
+            "дванадесет запетая деветдесет и девет, сто и двадесет цяло нула пет",
+            "12,99, 120,05"
+        );
+        assert_replace_all_numbers!("три цяло и пет", "3,5");
+    }
+
+    #[test]
+    fn test_replace_ordinals() {
+        assert_replace_numbers!(
+            "Той беше двадесет и трети в състезанието, а тя двадесет и първа.",
+            "Той беше 23-ти в състезанието, а тя 21-ва."
+        );
+        assert_replace_all_numbers!("пети ден, първи клас", "5-ти ден, 1-ви клас");
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("ДВАДЕСЕТ И ТРИ", "23");
+        assert_replace_all_numbers!("Сто И Пет", "105");
+    }
+}
@@ -0,0 +1,25 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "и", "а", "плюс", "минус", "по", "е", "са", "ъъ", "ами", "после", "още", "тоест"
+};
+
+/// The number words, inflected by [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "нула", "един", "една", "едно", "два", "две", "двама", "три", "трима", "четири", "четирима",
+    "пет", "шест", "седем", "осем", "девет", "десет", "единадесет", "единайсет", "дванадесет",
+    "дванайсет", "тринадесет", "тринайсет", "четиринадесет", "четиринайсет", "петнадесет",
+    "петнайсет", "шестнадесет", "шестнайсет", "седемнадесет", "седемнайсет", "осемнадесет",
+    "осемнайсет", "деветнадесет", "деветнайсет", "двадесет", "двайсет", "тридесет", "трийсет",
+    "четиридесет", "четирийсет", "петдесет", "шестдесет", "седемдесет", "осемдесет", "деветдесет",
+    "сто", "двеста", "триста", "четиристотин", "петстотин", "шестстотин", "седемстотин",
+    "осемстотин", "деветстотин", "хиляда", "милион", "милиона", "милиард", "милиарда", "милиарди",
+    "първи", "втори", "трети", "четвърти", "пети", "шести", "седми", "осми", "девети", "десети",
+    "единадесети", "единайсети", "дванадесети", "дванайсети", "тринадесети", "тринайсети",
+    "четиринадесети", "четиринайсети", "петнадесети", "петнайсети", "шестнадесети", "шестнайсети",
+    "седемнадесети", "седемнайсети", "осемнадесети", "осемнайсети", "деветнадесети", "деветнайсети",
+    "двадесети", "тридесети", "четиридесети", "петдесети", "шестдесети", "седемдесети",
+    "осемдесети", "деветдесети", "двайсети", "трийсети", "четирийсети", "стотен", "двестотен",
+    "тристотен", "четиристотен", "петстотен", "шестстотен", "седемстотен", "осемстотен",
+    "деветстотен", "хиляден", "милионен", "милиарден", "хиляди", "милиони",
+];
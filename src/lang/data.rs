@@ -0,0 +1,215 @@
+/*!
+A language interpreter driven by a table of words, like the ones generated from the CLDR spellout rules.
+
+A [`LanguageTable`] lists the words of the numbers under a thousand with their digits, the scale words
+with their power of ten, and the words that link a round number to the next one. [`DataLanguage`] reads
+the numbers of the languages that spell them additively from left to right, like Galician or Spanish:
+"*dous mil trescentos vinte e un*" is "*2321*".
+
+The tables of the builtin data languages are generated by the `text2num-gen` tool of the repository,
+from the RBNF files of CLDR. It can also generate the table of another language, to be registered
+at run time with the `registry` feature:
+
+```rust
+use text2num::lang::{DataLanguage, LanguageTable};
+use text2num::text2digits;
+
+static TOY: LanguageTable = LanguageTable {
+    code: "toy",
+    cardinals: &[("one", "1"), ("two", "2"), ("twenty", "20"), ("hundred", "100")],
+    scales: &[("thousand", 3)],
+    links: &[("and", 1)],
+    decimal_separators: &[],
+};
+
+let toy = DataLanguage::new(&TOY);
+assert_eq!(text2digits("two thousand hundred twenty and one", &toy).unwrap(), "2121");
+```
+
+The ordinals, the fractions and the constructions that depend on more than one word are out of reach
+of a table: a language that needs them is written by hand.
+*/
+
+use alloc::{string::String, vec::Vec};
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+use super::{LangInterpreter, MorphologicalMarker};
+
+/// The words of a language, for [`DataLanguage`].
+#[derive(Debug)]
+pub struct LanguageTable {
+    /// The code of the language (see [`LangInterpreter::language_code`])
+    pub code: &'static str,
+    /// The words of the numbers under a thousand, zero included, with their digits, like `("vinte", "20")`
+    pub cardinals: &'static [(&'static str, &'static str)],
+    /// The scale words, with their power of ten, like `("mil", 3)`
+    pub scales: &'static [(&'static str, usize)],
+    /// The words that link a round number to the next one, with the number of zeroes the round number ends with,
+    /// like `("e", 1)` for "*vinte e un*"
+    pub links: &'static [(&'static str, usize)],
+    /// The decimal separator words, with their symbol, like `("coma", ',')`
+    pub decimal_separators: &'static [(&'static str, char)],
+}
+
+impl LanguageTable {
+    fn cardinal(&self, word: &str) -> Option<&'static str> {
+        self.cardinals
+            .iter()
+            .find_map(|&(known, digits)| (known == word).then_some(digits))
+    }
+
+    fn scale(&self, word: &str) -> Option<usize> {
+        self.scales
+            .iter()
+            .find_map(|&(known, power)| (known == word).then_some(power))
+    }
+
+    /// The numbers of zeroes after which `word` is a link word, if it is one.
+    fn links(&self, word: &str) -> impl Iterator<Item = usize> {
+        self.links
+            .iter()
+            .filter_map(move |&(known, zeroes)| (known == word).then_some(zeroes))
+    }
+}
+
+/// An interpreter reading the numbers with the words of a [`LanguageTable`].
+#[derive(Debug, Clone, Copy)]
+pub struct DataLanguage {
+    table: &'static LanguageTable,
+}
+
+impl DataLanguage {
+    pub const fn new(table: &'static LanguageTable) -> Self {
+        Self { table }
+    }
+}
+
+impl LangInterpreter for DataLanguage {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        // after a link word, the number of digits the next word may put: "vinte e" waits for a unit
+        let pending = core::mem::take(&mut b.flags) as usize;
+        if let Some(digits) = self.table.cardinal(num_func) {
+            if pending > 0 && (digits.len() > pending || digits == "0") {
+                return Err(Error::NaN);
+            }
+            return b.put(digits.as_bytes());
+        }
+        if pending > 0 {
+            return Err(Error::NaN);
+        }
+        if let Some(power) = self.table.scale(num_func) {
+            return if b.is_range_free(power, power + 2) {
+                b.shift(power)
+            } else {
+                Err(Error::Overlap)
+            };
+        }
+        // "vinte e", but not "vinte e un e"
+        let round = self.table.links(num_func).find(|&zeroes| {
+            let tail = b.peek(zeroes + 1);
+            tail.len() == zeroes + 1 && tail[0] != b'0' && tail[1..].iter().all(|&d| d == b'0')
+        });
+        match round {
+            Some(zeroes) => {
+                b.flags = zeroes as u64;
+                Err(Error::Incomplete)
+            }
+            None => Err(Error::NaN),
+        }
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn get_morph_marker(&self, _word: &str) -> MorphologicalMarker {
+        MorphologicalMarker::None
+    }
+
+    fn language_code(&self) -> &'static str {
+        self.table.code
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        let cardinals = self.table.cardinals.iter().map(|(word, _)| *word);
+        let scales = self.table.scales.iter().map(|(word, _)| *word);
+        cardinals.chain(scales).map(String::from).collect()
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        self.table.cardinal(word).is_some() || self.table.scale(word).is_some()
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        self.table
+            .cardinal(word)
+            .is_some_and(|digits| digits != "0")
+            || self.table.scale(word).is_some()
+            || self.table.links(word).next().is_some()
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        self.table
+            .decimal_separators
+            .iter()
+            .find_map(|&(known, symbol)| (known == word).then_some(symbol))
+    }
+
+    fn decimal_symbol(&self) -> char {
+        self.table
+            .decimal_separators
+            .first()
+            .map_or('.', |&(_, symbol)| symbol)
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        self.table.links(word).next().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    static TOY: LanguageTable = LanguageTable {
+        code: "toy",
+        cardinals: &[
+            ("zero", "0"),
+            ("one", "1"),
+            ("two", "2"),
+            ("twelve", "12"),
+            ("twenty", "20"),
+            ("hundred", "100"),
+            ("twohundred", "200"),
+        ],
+        scales: &[("thousand", 3), ("million", 6)],
+        links: &[("and", 1), ("and", 2)],
+        decimal_separators: &[("point", '.')],
+    };
+
+    #[test]
+    fn test_table() {
+        let toy = DataLanguage::new(&TOY);
+        assert_eq!(text2digits("twenty and one", &toy).unwrap(), "21");
+        assert_eq!(text2digits("twenty one", &toy).unwrap(), "21");
+        assert_eq!(
+            text2digits("two million twohundred thousand twelve", &toy).unwrap(),
+            "2200012"
+        );
+        assert_eq!(text2digits("thousand hundred", &toy).unwrap(), "1100");
+        assert_eq!(text2digits("hundred and twelve", &toy).unwrap(), "112");
+        assert!(text2digits("twenty and twelve", &toy).is_err());
+        assert!(text2digits("twelve and one", &toy).is_err());
+        assert!(text2digits("twenty and zero", &toy).is_err());
+        assert!(text2digits("thousand thousand", &toy).is_err());
+        assert_eq!(
+            replace_numbers_in_text("one cat and twenty and two point one dogs", &toy, 0.0),
+            "1 cat and 22.1 dogs"
+        );
+        assert_eq!(toy.language_code(), "toy");
+        assert!(toy.vocabulary().iter().any(|word| word == "million"));
+    }
+}
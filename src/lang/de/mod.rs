@@ -2,17 +2,29 @@
 //!
 //! This interpreter is tolerant and accepts splitted words, that is "ein und zwanzig" is treated like "einundzwanzig", as
 //! the main application, Speech-to-text recognition, may introduce spurious spaces.
+//!
+//! The fraction nouns give fractions ("drei Viertel" is "3/4") and the halves decimal numbers
+//! ("zweieinhalb" and "anderthalb" are "2,5" and "1,5").
+
+use alloc::{format, string::String, vec::Vec};
 
 use bitflags::bitflags;
 
 use crate::digit_string::DigitString;
 use crate::error::Error;
-use crate::tokenizer::WordSplitter;
+use crate::options::Options;
+use crate::tokenizer::LazySplitter;
 
 mod vocabulary;
 
-use super::{LangInterpreter, MorphologicalMarker};
-use vocabulary::INSIGNIFICANT;
+use super::{
+    ANNOTATION_CONTEXT, BasicAnnotate, Denominator, LangInterpreter, MorphologicalMarker,
+    sorted_vocabulary,
+};
+use vocabulary::{
+    FEMININE_MEASURE_NOUNS, INSIGNIFICANT, MASCULINE_MEASURE_NOUNS, MONTHS, NEUTER_MEASURE_NOUNS,
+    NUMBER_WORDS, VAGUE_QUANTIFIERS,
+};
 
 fn lemmatize(word: &str) -> &str {
     // remove declination for ordinals
@@ -32,36 +44,43 @@ bitflags! {
     ///(logical, numerical feature inconsistencies are already taken care of by DigitString)
     struct Excludable: u64 {
         const TENS = 1;
+        const CONJUNCTION = 2;
     }
 }
 
-pub struct German {
-    word_splitter: WordSplitter,
-}
-
-impl Default for German {
-    fn default() -> Self {
-        Self {
-            word_splitter: WordSplitter::new([
-                "billion",
-                "billionste",
-                "milliarden",
-                "milliarde",
-                "milliardste",
-                "millionen",
-                "million",
-                "millionste",
-                "tausend",
-                "tausendste",
-                "hundert",
-                "hundertste",
-                "und",
-            ])
-            .unwrap(),
-        }
+/// The splitter of the compound words, built on first use.
+static WORD_SPLITTER: LazySplitter = LazySplitter::new(&[
+    "billion",
+    "billionste",
+    "milliarden",
+    "milliarde",
+    "milliardste",
+    "millionen",
+    "million",
+    "millionste",
+    "tausend",
+    "tausendste",
+    "hundert",
+    "hundertste",
+    "und",
+]);
+
+/// Return true if `word` is one of the number words, declined or compounded.
+fn is_number_word(word: &str) -> bool {
+    let lemma = lemmatize(word);
+    if WORD_SPLITTER.get().is_splittable(lemma) {
+        return WORD_SPLITTER
+            .get()
+            .split(lemma)
+            .all(|piece| piece == "und" || is_number_word(piece));
     }
+    NUMBER_WORDS.contains(&lemma)
 }
 
+#[derive(Debug, Default, Clone, Copy)]
+pub struct German {}
+
+
 impl German {
     pub fn new() -> Self {
         Default::default()
@@ -72,8 +91,8 @@ impl LangInterpreter for German {
     fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
         // In German, numbers are compounded to form a group
         let lemma = lemmatize(num_func);
-        if self.word_splitter.is_splittable(lemma) {
-            return match self.exec_group(self.word_splitter.split(lemma)) {
+        if WORD_SPLITTER.get().is_splittable(lemma) {
+            return match self.exec_group(WORD_SPLITTER.get().split(lemma)) {
                 Ok(ds) => {
                     if ds.len() > 3 && ds.len() <= 6 && !b.is_range_free(3, 5) {
                         return Err(Error::Overlap);
@@ -88,6 +107,10 @@ impl LangInterpreter for German {
                 Err(err) => Err(err),
             };
         }
+        // bare plural scale words are vague quantities: "Millionen von Menschen"
+        if b.is_empty() && matches!(lemma, "millionen" | "milliarden") {
+            return Err(Error::NaN);
+        }
         let blocked = Excludable::from_bits_truncate(b.flags);
         let mut to_block = Excludable::empty();
 
@@ -97,6 +120,11 @@ impl LangInterpreter for German {
                 to_block = Excludable::TENS;
                 b.put(b"1")
             }
+            // the declined articles, as in "eine Million", start no compound: not "eine und zwanzig"
+            "eine" | "einen" | "einem" | "einer" if b.is_empty() => {
+                to_block = Excludable::TENS | Excludable::CONJUNCTION;
+                b.put(b"1")
+            }
             "zwei" | "zwo" | "zweite" if b.is_free(2) => {
                 to_block = Excludable::TENS;
                 b.put(b"2")
@@ -117,7 +145,7 @@ impl LangInterpreter for German {
                 to_block = Excludable::TENS;
                 b.put(b"6")
             }
-            "sieben" | "siebte" if b.is_free(2) => {
+            "sieben" | "siebte" | "siebente" if b.is_free(2) => {
                 to_block = Excludable::TENS;
                 b.put(b"7")
             }
@@ -167,7 +195,8 @@ impl LangInterpreter for German {
             }
             "hundert" | "hundertste" => {
                 let peek = b.peek(2);
-                if peek.len() == 1 || peek < b"20" {
+                if peek.len() == 1 || peek < b"10" || peek < b"20" && b.len() == 2 {
+                    // "fünfzehnhundert" only when the teen starts the group
                     b.shift(2)
                 } else {
                     Err(Error::Overlap)
@@ -177,7 +206,9 @@ impl LangInterpreter for German {
             "million" | "millionen" | "millionste" if b.is_range_free(6, 8) => b.shift(6),
             "milliarde" | "milliarden" | "milliardste" => b.shift(9),
             "billion" | "billionste" => b.shift(12),
-            "und" => Err(Error::Incomplete),
+            "und" if !b.is_empty() && !blocked.contains(Excludable::CONJUNCTION) => {
+                Err(Error::Incomplete)
+            }
 
             _ => Err(Error::NaN),
         };
@@ -212,6 +243,19 @@ impl LangInterpreter for German {
         }
     }
 
+    fn language_code(&self) -> &'static str {
+        "de"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        // the declined ordinals: "*zweiten*", "*zweiter*", "*zweites*", "*zweitem*"
+        let ordinals = NUMBER_WORDS
+            .iter()
+            .filter(|word| word.ends_with("te"))
+            .flat_map(|word| ["n", "r", "s", "m"].map(|ending| format!("{word}{ending}")));
+        sorted_vocabulary(NUMBER_WORDS.iter().map(|word| String::from(*word)).chain(ordinals))
+    }
+
     fn check_decimal_separator(&self, word: &str) -> Option<char> {
         match word {
             "komma" => Some(','),
@@ -219,10 +263,47 @@ impl LangInterpreter for German {
             _ => None,
         }
     }
+
+    fn decimal_symbol(&self) -> char {
+        ','
+    }
+
+    fn recommended_options(&self) -> Options {
+        Options {
+            // "am dritten" gives "am 3.", but a lone "ein" is an article, unless
+            // a unit follows (see `lone_number_context`)
+            threshold: 2.0,
+            // "erstens" gives "1."
+            adverbial_ordinals: true,
+            ..Options::default()
+        }
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "drei zu zwei"
+        &["zu"]
+    }
+
+    fn range_connectors(&self) -> &'static [&'static str] {
+        // "dritte bis fünfte", "vom dritten bis zum fünften"
+        &["bis", "bis zum", "bis zur"]
+    }
     
+    fn split_decimal_word<'a>(&self, word: &'a str) -> Option<(&'a str, char, &'static str)> {
+        // "anderthalb" and "eineinhalb" are 1,5, "zweieinhalb" is 2,5, and "zwei einhalb" too
+        match word {
+            "anderthalb" => Some(("ein", ',', "5")),
+            "einhalb" => Some(("", ',', "5")),
+            _ => word
+                .strip_suffix("einhalb")
+                .filter(|stem| !stem.is_empty())
+                .map(|stem| (stem, ',', "5")),
+        }
+    }
+
     fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
         if word.ends_with("te") {
-            MorphologicalMarker::Ordinal(".")
+            MorphologicalMarker::Ordinal(".".into())
         } else {
             MorphologicalMarker::None
         }
@@ -231,12 +312,100 @@ impl LangInterpreter for German {
     fn is_linking(&self, word: &str) -> bool {
         INSIGNIFICANT.contains(word)
     }
+
+    fn is_vague_quantifier(&self, word: &str) -> bool {
+        VAGUE_QUANTIFIERS.contains(word)
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        // the words of the vocabulary, in context
+        is_number_word(word) && super::dry_run_start(self, word)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        (word == "und" || is_number_word(word)) && super::dry_run_continue(self, word)
+    }
+
+    fn lone_number_context(&self, number: &str, next: &str) -> Option<bool> {
+        // "ein" is also the article, that agrees with the noun: "ein Uhr", "einen Tag", "nach einer Stunde",
+        // but "ein bisschen", "ein paar", "ein Buch", "eine Weile", and "eine Uhr" (a watch)
+        let masculine = MASCULINE_MEASURE_NOUNS.contains(next);
+        let neuter = NEUTER_MEASURE_NOUNS.contains(next);
+        match number {
+            "ein" => Some(next == "uhr" || masculine || neuter),
+            "einen" => Some(masculine),
+            "einem" => Some(masculine || neuter),
+            "eine" | "einer" => Some(FEMININE_MEASURE_NOUNS.contains(next)),
+            _ => None,
+        }
+    }
+
+    fn basic_annotate<T: BasicAnnotate>(&self, tokens: &mut Vec<T>) {
+        // the declined articles are only numbers before a scale word, as in "eine Million",
+        // or before a measure noun they agree with (see `lone_number_context`),
+        // and no article of a vague quantifier is a number, whatever the threshold: "ein paar hundert Leute"
+        for i in 0..tokens.len() {
+            let article = tokens[i].text_lowercase();
+            if !matches!(article, "ein" | "eine" | "einen" | "einem" | "einer") {
+                continue;
+            }
+            let next = tokens[i + 1..]
+                .iter()
+                .take(ANNOTATION_CONTEXT)
+                .map(BasicAnnotate::text_lowercase)
+                .find(|word| !word.chars().all(char::is_whitespace));
+            let quantifier = next.is_some_and(|next| VAGUE_QUANTIFIERS.contains(next));
+            if article == "ein" && !quantifier {
+                continue;
+            }
+            let number = !quantifier
+                && next.is_some_and(|next| {
+                    let mut b = DigitString::new();
+                    self.lone_number_context(article, next) == Some(true)
+                        || self.apply(article, &mut b).is_ok() && self.apply(next, &mut b).is_ok()
+                });
+            if !number {
+                tokens[i].set_nan(true);
+            }
+        }
+    }
+
+    fn adverbial_ordinal(&self, word: &str) -> Option<u64> {
+        // "erstens", "zweitens", "zwanzigstens"…
+        let stem = word.strip_suffix("tens")?;
+        self.exec_group(core::iter::once(alloc::format!("{stem}te").as_str()))
+            .ok()
+            .filter(DigitString::is_ordinal)
+            .map(|ds| ds.parse())
+    }
+
+    fn is_month_name(&self, word: &str) -> bool {
+        // "am 3. Mai": the ordinals keep their dot before the month
+        MONTHS.contains(&*crate::tokenizer::lowercase(word))
+    }
+
+    fn fraction_denominator(&self, word: &str) -> Option<Denominator> {
+        // "Drittel", "Viertel", "Fünftel", "Zwanzigstel"… are never ordinals.
+        let stem = word.strip_suffix("tel")?;
+        let rank: u64 = self
+            .exec_group(core::iter::once(alloc::format!("{stem}te").as_str()))
+            .ok()
+            .filter(DigitString::is_ordinal)?
+            .parse();
+        (rank >= 3).then_some(Denominator {
+            value: rank,
+            needs_context: false,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::German;
-    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+    use alloc::vec::Vec;
+    use crate::Options;
+    use crate::options::FractionOutput;
+    use crate::word_to_digit::{replace_numbers_in_text, replace_numbers_in_text_with, text2digits};
 
     macro_rules! assert_text2digits {
         ($text:expr, $res:expr) => {
@@ -283,6 +452,9 @@ mod tests {
         assert_text2digits!("vierzehntausend", "14000");
         assert_text2digits!("eintausendneunhundertzwanzig", "1920");
         assert_text2digits!("neunzehnhundertdreiundsiebzig", "1973");
+        assert_text2digits!("fünfzehnhundert", "1500");
+        assert_text2digits!("zwölfhundert", "1200");
+        assert_text2digits!("zwölf hundert achtzig", "1280");
         assert_text2digits!(
             "dreiundfünfzig Milliarden zweihundertdreiundvierzigtausendsiebenhundertvierundzwanzig",
             "53000243724"
@@ -301,6 +473,16 @@ mod tests {
         assert_text2digits!("neunundvierzigster", "49.");
     }
 
+    #[test]
+    fn test_scale_ordinals() {
+        assert_text2digits!("zweihundertste", "200.");
+        assert_text2digits!("dreitausendste", "3000.");
+        assert_text2digits!("zweihunderttausendste", "200000.");
+        assert_text2digits!("sechsmillionste", "6000000.");
+        assert_text2digits!("sechs millionste", "6000000.");
+        assert_replace_numbers!("der sechsmillionste Besucher", "der 6000000. Besucher");
+    }
+
     #[test]
     fn test_zeroes() {
         assert_text2digits!("null", "0");
@@ -316,6 +498,7 @@ mod tests {
         assert_invalid!("tausendtausendzweihundert");
         assert_invalid!("sechzigfünfzehn");
         assert_invalid!("sechzighundert");
+        assert_invalid!("hundertzwölfhundert");
         assert_invalid!("zwei und vierzig und");
         assert_invalid!("dreißig und elf");
         assert_invalid!("ein und zehn");
@@ -434,6 +617,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_date_ordinals() {
+        let units = [
+            "ers", "zwei", "drit", "vier", "fünf", "sechs", "sieb", "ach", "neun",
+        ];
+        let teens = [
+            "zehn", "elf", "zwölf", "dreizehn", "vierzehn", "fünfzehn", "sechzehn", "siebzehn",
+            "achtzehn", "neunzehn",
+        ];
+        let cardinals = ["ein", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"];
+        let mut stems: Vec<(alloc::string::String, usize)> = Vec::new();
+        stems.extend(units.iter().enumerate().map(|(i, w)| ((*w).into(), i + 1)));
+        stems.extend(teens.iter().enumerate().map(|(i, w)| ((*w).into(), i + 10)));
+        stems.push(("zwanzigs".into(), 20));
+        stems.extend(cardinals.iter().enumerate().map(|(i, w)| (alloc::format!("{w}undzwanzigs"), i + 21)));
+        stems.push(("dreißigs".into(), 30));
+        stems.push(("einunddreißigs".into(), 31));
+        let f = German::new();
+        for (stem, day) in stems {
+            for ending in ["ter", "ten"] {
+                let text = alloc::format!("am {stem}{ending} Dezember");
+                assert_eq!(replace_numbers_in_text(&text, &f, 0.0), alloc::format!("am {day}. Dezember"), "{text}");
+            }
+        }
+        assert_replace_numbers!("der dreiundzwanzigste Dezember", "der 23. Dezember");
+        assert_replace_numbers!("am einunddreißigsten Mai", "am 31. Mai");
+        assert_replace_all_numbers!("am siebenten Mai, am siebenundzwanzigsten", "am 7. Mai, am 27.");
+        assert_replace_all_numbers!("am siebtem Tag, sein einunddreißigstes Jahr", "am 7. Tag, sein 31. Jahr");
+    }
+
     #[test]
     fn test_replace_decimals() {
         assert_replace_numbers!(
@@ -455,6 +668,13 @@ mod tests {
         assert_replace_all_numbers!("eins komma erste", "1 komma 1.");
     }
 
+    #[test]
+    fn test_replace_decimal_scales() {
+        assert_replace_numbers!("zwei komma fünf Millionen Einwohner", "2500000 Einwohner");
+        assert_replace_numbers!("eins komma fünf Milliarden", "1500000000");
+        assert_replace_numbers!("null komma zwei fünf Millionen", "250000");
+    }
+
     #[test]
     fn test_replace_signed() {
         assert_replace_numbers!(
@@ -489,9 +709,267 @@ mod tests {
         // assert_replace_numbers!("Ein Millionen Deal", "Ein 1000000 Deal");
     }
 
+    #[test]
+    fn test_measure_context() {
+        // "ein" before a measure or time noun, and before anything else
+        assert_replace_numbers!("Wir treffen uns um ein Uhr.", "Wir treffen uns um 1 Uhr.");
+        assert_replace_numbers!("Warte ein bisschen.", "Warte ein bisschen.");
+        assert_replace_numbers!("Ein Kilo Mehl", "1 Kilo Mehl");
+        assert_replace_numbers!("Ein paar Leute", "Ein paar Leute");
+        assert_replace_numbers!("Noch ein Meter", "Noch 1 Meter");
+        assert_replace_numbers!("Noch ein Stück", "Noch ein Stück");
+        assert_replace_numbers!("Die Zinsen steigen um ein Prozent", "Die Zinsen steigen um 1 Prozent");
+        assert_replace_numbers!("Das dauert eine Stunde", "Das dauert 1 Stunde");
+        assert_replace_numbers!("Das dauert eine Weile", "Das dauert eine Weile");
+        assert_replace_numbers!("Ein Jahr später", "1 Jahr später");
+        assert_replace_numbers!("Ein Buch später", "Ein Buch später");
+        assert_replace_numbers!("Er blieb einen Tag", "Er blieb 1 Tag");
+        assert_replace_numbers!("Er blieb einen Moment", "Er blieb einen Moment");
+        assert_replace_numbers!("nach einem Monat", "nach 1 Monat");
+        assert_replace_numbers!("nach einem Streit", "nach einem Streit");
+        assert_replace_numbers!("nach einer Woche", "nach 1 Woche");
+        assert_replace_numbers!("nach einer Pause", "nach einer Pause");
+        // the article agrees with the noun: "ein Uhr" is the time, "eine Uhr" a watch
+        assert_replace_numbers!("eine Uhr kaufen", "eine Uhr kaufen");
+        assert_replace_numbers!("einen Meter, eine Minute", "1 Meter, 1 Minute");
+        // even in a sequence of numbers
+        assert_replace_numbers!("eins, zwei, ein paar", "1, 2, ein paar");
+        assert_replace_numbers!("zwei, drei oder ein Uhr", "2, 3 oder 1 Uhr");
+        // everything else converts without threshold
+        assert_replace_all_numbers!("ein Buch", "1 Buch");
+    }
+
     // #[test]
     // fn test_isolates_with_noise() {
     //     //TODO!
     //     unimplemented!();
     // }
+
+    #[test]
+    fn test_stutters() {
+        let f = German::new();
+        let options = Options {
+            collapse_stutters: true,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("zwei zwei hundert fünfzig", &f, &options),
+            "250"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("zwei zwei", &f, &options),
+            "2 2"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("drei drei tausend Euro", &f, &options),
+            "3000 Euro"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("eins zwei zwei drei", &f, &options),
+            "1 2 2 3"
+        );
+        assert_replace_numbers!("zwei zwei hundert fünfzig", "2 250");
+    }
+
+    #[test]
+    fn test_adverbial_ordinals() {
+        assert_replace_all_numbers!("Erstens stimmen wir zu, zweitens unterschreiben wir, drittens ruhen wir.", "Erstens stimmen wir zu, zweitens unterschreiben wir, drittens ruhen wir.");
+        let f = German::default();
+        let options = Options {
+            adverbial_ordinals: true,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("Erstens stimmen wir zu, zweitens unterschreiben wir, drittens ruhen wir.", &f, &options),
+            "1. stimmen wir zu, 2. unterschreiben wir, 3. ruhen wir."
+        );
+    }
+
+    #[test]
+    fn test_digits_passthrough() {
+        assert_replace_all_numbers!("Ich zahle 1.234,56 Euro und fünfundzwanzig Cent.", "Ich zahle 1.234,56 Euro und 25 Cent.");
+        assert_replace_all_numbers!("zwanzig 1.234,5 einunddreißig", "20 1.234,5 31");
+        assert_replace_all_numbers!("hundert 250", "100 250");
+    }
+
+    #[test]
+    fn test_digits_with_scales() {
+        assert_replace_all_numbers!("5 Tausend", "5 1000");
+        let f = German::default();
+        let options = Options {
+            digits_with_scales: true,
+            ..Options::new(0.0)
+        };
+        for (text, expected) in [
+            ("5 Tausend", "5000"),
+            ("2 hundert", "200"),
+            ("3 Millionen Euro", "3000000 Euro"),
+        ] {
+            assert_eq!(replace_numbers_in_text_with(text, &f, &options), expected);
+        }
+    }
+
+    #[test]
+    fn test_vague_quantities() {
+        assert_replace_all_numbers!("Millionen von Menschen", "Millionen von Menschen");
+        assert_replace_all_numbers!("tausende von Menschen, Milliarden, Hunderte", "tausende von Menschen, Milliarden, Hunderte");
+        assert_replace_all_numbers!("zwei Millionen Menschen", "2000000 Menschen");
+    }
+
+    #[test]
+    fn test_signs() {
+        assert_replace_all_numbers!("Es ist -zwanzig Grad", "Es ist -20 Grad");
+        assert_replace_all_numbers!("Es ist \u{2212}fünf Grad", "Es ist -5 Grad");
+        assert_replace_all_numbers!("von \u{2013}drei bis minus -zwei", "von -3 bis minus -2");
+        assert_replace_all_numbers!("zwanzig - dreißig", "20 - 30");
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_replace_all_numbers!("drei Viertel der Nutzer", "3/4 der Nutzer");
+        assert_replace_all_numbers!("ein Fünftel, zwei Zwanzigstel", "1/5, 2/20");
+        assert_replace_all_numbers!("das Viertel", "das Viertel");
+        assert_replace_all_numbers!("der fünfte Tag", "der 5. Tag");
+        assert_replace_numbers!(
+            "zwei Drittel Tasse Zucker und ein Viertel Liter Milch",
+            "2/3 Tasse Zucker und 1/4 Liter Milch"
+        );
+        assert_replace_numbers!("ein Einundzwanzigstel", "1/21");
+        assert_replace_numbers!("drei Hundertstel Sekunden", "3/100 Sekunden");
+        // "Hälfte" is a plain noun
+        assert_replace_all_numbers!("die Hälfte der Leute", "die Hälfte der Leute");
+        assert_replace_all_numbers!("eine Hälfte", "eine Hälfte");
+        let f = German::new();
+        let options = Options {
+            fraction_output: FractionOutput::Decimal,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("drei Viertel Liter Milch", &f, &options),
+            "0,75 Liter Milch"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("zwei Drittel Tasse Zucker", &f, &options),
+            "0,666667 Tasse Zucker"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("ein Achtel Butter", &f, &options),
+            "0,125 Butter"
+        );
+    }
+
+    #[test]
+    fn test_halves() {
+        assert_replace_numbers!("anderthalb Stunden backen", "1,5 Stunden backen");
+        assert_replace_numbers!("eineinhalb Tassen Mehl", "1,5 Tassen Mehl");
+        assert_replace_numbers!("zweieinhalb Kilo Kartoffeln", "2,5 Kilo Kartoffeln");
+        assert_replace_numbers!("zwei einhalb Kilo", "2,5 Kilo");
+        assert_replace_numbers!("bei dreiundzwanzigeinhalb Grad", "bei 23,5 Grad");
+        assert_replace_numbers!("hundertzweieinhalb Meter", "102,5 Meter");
+        assert_replace_numbers!("zweieinhalb drei", "2,5 3");
+        assert_replace_all_numbers!("einhalb Kilo", "einhalb Kilo");
+        assert_replace_all_numbers!("um halb zehn", "um halb 10");
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("DREISSIG", "30");
+        assert_replace_all_numbers!("DREIßIG", "30");
+        assert_replace_all_numbers!("ZWEIHUNDERTDREIUNDDREISSIG", "233");
+        assert_replace_all_numbers!("Der Fünfte", "Der 5.");
+    }
+
+    #[test]
+    fn test_vague_quantifiers() {
+        assert_replace_numbers!("ein paar hundert Leute", "ein paar hundert Leute");
+        assert_replace_numbers!("einige tausend Euro", "einige tausend Euro");
+        assert_replace_numbers!("dreihundert Leute", "300 Leute");
+        // the article of the quantifier is no number either, whatever the threshold
+        assert_replace_all_numbers!("ein paar hundert Leute", "ein paar hundert Leute");
+        assert_replace_all_numbers!("ein paar Leute", "ein paar Leute");
+        assert_replace_all_numbers!("ein bisschen Zeit", "ein bisschen Zeit");
+        assert_replace_all_numbers!("ein wenig später", "ein wenig später");
+        assert_replace_all_numbers!("ein hundert Leute", "100 Leute");
+    }
+
+    #[test]
+    fn test_ordinal_markers() {
+        let lang = German::default();
+        let options = |keep_ordinal_markers| Options {
+            keep_ordinal_markers,
+            ..Options::new(0.0)
+        };
+        let text = "der erste und der einundzwanzigste";
+        assert_eq!(
+            replace_numbers_in_text_with(text, &lang, &options(true)),
+            "der 1. und der 21."
+        );
+        assert_eq!(
+            replace_numbers_in_text_with(text, &lang, &options(false)),
+            "der 1 und der 21"
+        );
+    }
+
+    #[test]
+    fn test_ordinal_ranges() {
+        assert_replace_numbers!("dritte bis fünfte", "3. bis 5.");
+        assert_replace_numbers!("vom dritten bis zum fünften", "vom 3. bis zum 5.");
+        assert_replace_numbers!("von der dritten bis zur fünften", "von der 3. bis zur 5.");
+        // no range
+        assert_replace_numbers!("der dritte bis heute", "der dritte bis heute");
+    }
+
+    #[test]
+    fn test_dates() {
+        // the ordinals keep their dot, the first of the month as the others
+        assert_replace_numbers!("am ersten Mai", "am 1. Mai");
+        assert_replace_numbers!("am dritten März 2020", "am 3. März 2020");
+        assert_replace_numbers!("der einundzwanzigste Juni", "der 21. Juni");
+        assert_replace_numbers!("vom ersten bis zum dritten Jänner", "vom 1. bis zum 3. Jänner");
+        assert_replace_numbers!("der erste Tag", "der erste Tag");
+        assert_replace_all_numbers!("am drei Mai", "am 3 Mai");
+    }
+
+
+    #[test]
+    fn test_approximations() {
+        use crate::options::AdjacentNumberPolicy;
+        let f = German::new();
+        for adjacent_number_policy in [
+            AdjacentNumberPolicy::Separate,
+            AdjacentNumberPolicy::Concatenate,
+            AdjacentNumberPolicy::Auto,
+        ] {
+            let options = Options {
+                adjacent_number_policy,
+                ..Options::new(0.0)
+            };
+            let replace = |text| replace_numbers_in_text_with(text, &f, &options);
+            // "two or three hours"
+            assert_eq!(replace("zwei, drei Stunden"), "2, 3 Stunden");
+            assert_eq!(replace("drei, vier, fünf Tage"), "3, 4, 5 Tage");
+            assert_eq!(replace("zwei bis drei Stunden"), "2 bis 3 Stunden");
+            assert_eq!(replace("zwei oder drei Stunden"), "2 oder 3 Stunden");
+            // the decade alone
+            assert_eq!(replace("Mitte zwanzig"), "Mitte 20");
+            assert_eq!(replace("Anfang Dreißig"), "Anfang 30");
+            assert_eq!(replace("Ende vierzig Jahre"), "Ende 40 Jahre");
+            // "about a hundred"
+            assert_eq!(replace("an die hundert Leute"), "an die 100 Leute");
+            assert_eq!(replace("an die zweihundert Leute"), "an die 200 Leute");
+            // the plain forms
+            assert_eq!(replace("hundert Leute"), "100 Leute");
+            assert_eq!(replace("zweiundzwanzig, dreiundzwanzig"), "22, 23");
+        }
+        let options = Options {
+            adjacent_number_policy: AdjacentNumberPolicy::Concatenate,
+            ..Options::new(0.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("eins zwei drei vier", &f, &options),
+            "1234"
+        );
+        assert_replace_numbers!("zwei, drei Stunden", "2, 3 Stunden");
+        assert_replace_numbers!("zwei oder drei Stunden", "zwei oder drei Stunden");
+    }
 }
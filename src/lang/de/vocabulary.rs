@@ -3,3 +3,46 @@ use phf::{Set, phf_set};
 pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
     "aber", "ah", "äh", "ähm", "also", "gut", "auch", "denn", "doch", "dort", "eben", "eh", "halt", "ja", "mal", "sehen", "naja", "nun", "ok", "schon", "so", "genau", "und", "noch"
 };
+
+/// Vague quantifiers, like "*ein paar*", "*ein bisschen*", "*einige*"
+pub static VAGUE_QUANTIFIERS: Set<&'static str> = phf_set! {
+    "paar", "bisschen", "wenig", "wenige", "wenigen", "einige", "einigen", "mehrere", "mehreren",
+    "etliche", "etlichen"
+};
+
+/// The month names, with the Austrian "*Jänner*"
+pub static MONTHS: Set<&'static str> = phf_set! {
+    "januar", "jänner", "februar", "märz", "april", "mai", "juni", "juli", "august", "september",
+    "oktober", "november", "dezember"
+};
+
+/// The masculine measure and time nouns before which "*ein*", "*einen*" or "*einem*" is a number,
+/// like "*ein Meter*", "*einen Tag*"
+pub static MASCULINE_MEASURE_NOUNS: Set<&'static str> = phf_set! {
+    "tag", "monat", "meter", "kilometer", "zentimeter", "millimeter", "liter", "milliliter", "grad",
+    "euro", "dollar", "cent"
+};
+
+/// The neuter measure and time nouns before which "*ein*" or "*einem*" is a number, like "*ein Jahr*", "*ein Kilo*"
+pub static NEUTER_MEASURE_NOUNS: Set<&'static str> = phf_set! {
+    "jahr", "jahrzehnt", "jahrhundert", "kilo", "kilogramm", "gramm", "pfund", "prozent"
+};
+
+/// The feminine measure and time nouns before which "*eine*" or "*einer*" is a number,
+/// like "*eine Stunde*", "*nach einer Woche*"
+pub static FEMININE_MEASURE_NOUNS: Set<&'static str> = phf_set! {
+    "sekunde", "minute", "stunde", "woche", "tonne", "meile"
+};
+
+/// The number words, inflected by [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "null", "ein", "eine", "einen", "einem", "einer", "eins", "erste", "zwei", "zwo", "zweite", "drei", "dritte", "vier", "vierte",
+    "fünf", "fünfte", "sechs", "sechste", "sieben", "siebte", "siebente", "acht", "achte", "neun",
+    "neunte", "zehn", "zehnte", "elf", "elfte", "zwölf", "zwölfte", "dreizehn", "dreizehnte",
+    "vierzehn", "vierzehnte", "fünfzehn", "fünfzehnte", "sechzehn", "sechzehnte", "siebzehn",
+    "siebzehnte", "achtzehn", "achtzehnte", "neunzehn", "neunzehnte", "zwanzig", "zwanzigste",
+    "dreißig", "dreissig", "dreißigste", "dreissigste", "vierzig", "vierzigste", "fünfzig",
+    "fünfzigste", "sechzig", "sechzigste", "siebzig", "siebzigste", "achtzig", "achtzigste",
+    "neunzig", "neunzigste", "hundert", "hundertste", "tausend", "tausendste", "million",
+    "millionste", "milliarde", "milliarden", "milliardste", "billion", "billionste", "millionen",
+];
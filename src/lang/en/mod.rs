@@ -2,13 +2,22 @@
 
 use alloc::{format, string::String, vec::Vec};
 
+use bitflags::bitflags;
+
 use crate::digit_string::DigitString;
 use crate::error::Error;
+use crate::options::{Options, OrdinalStyle};
 
 mod vocabulary;
 
-use super::{BasicAnnotate, LangInterpreter, MorphologicalMarker};
-use vocabulary::INSIGNIFICANT;
+use super::{
+    Affix, BasicAnnotate, Denominator, LangInterpreter, MorphologicalMarker, Scale, TokenKind,
+    sorted_vocabulary,
+};
+use vocabulary::{
+    INSIGNIFICANT, MEASURE_CONTEXTS, MONTHS, NOT_PLURAL, NUMBER_WORDS, PRONOUN_DETERMINERS,
+    QUANTITY_NOUNS, RELATIVE_MARKERS, VAGUE_QUANTIFIERS,
+};
 
 fn lemmatize(word: &str) -> &str {
     // brute, blind removal of 's' ending is enough here
@@ -19,13 +28,95 @@ fn lemmatize(word: &str) -> &str {
     }
 }
 
-#[derive(Default)]
-pub struct English {}
+bitflags! {
+    /// Pending constructions that depend on the next word.
+    struct Pending: u64 {
+        /// "*nineteen oh*" or "*four oh*" waits for the unit of "*nineteen oh five*" or "*four oh nine*"
+        const OH_UNIT = 1;
+        /// "*four score*" may be followed by the units of "*four score and seven*" (archaic forms only)
+        const SCORE_UNITS = 2;
+    }
+}
+
+/// The word is an ordinal in digits, like "*3rd*", "*21ˢᵗ*" or "*3.*", as the replacement writes them.
+fn is_digit_ordinal(word: &str) -> bool {
+    let suffix = word.trim_start_matches(|c: char| c.is_ascii_digit());
+    suffix.len() < word.len()
+        && matches!(
+            suffix,
+            "." | "st" | "nd" | "rd" | "th" | "rds" | "ths" | "ˢᵗ" | "ⁿᵈ" | "ʳᵈ" | "ᵗʰ" | "ʳᵈˢ" | "ᵗʰˢ"
+        )
+}
+
+/// The value of a unit word, for constructions that only accept units.
+fn unit_digit(word: &str) -> Option<&'static [u8]> {
+    match word {
+        "one" => Some(b"1"),
+        "two" => Some(b"2"),
+        "three" => Some(b"3"),
+        "four" => Some(b"4"),
+        "five" => Some(b"5"),
+        "six" => Some(b"6"),
+        "seven" => Some(b"7"),
+        "eight" => Some(b"8"),
+        "nine" => Some(b"9"),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct English {
+    scale: Scale,
+    archaic: bool,
+    math_talk: bool,
+}
+
+impl Default for English {
+    fn default() -> Self {
+        English::with_scale(Scale::Short)
+    }
+}
 
 impl English {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// English with "*billion*" and "*trillion*" on the given scale: short by default,
+    /// long for older British texts, where a "*billion*" is a million millions.
+    pub fn with_scale(scale: Scale) -> Self {
+        English {
+            scale,
+            archaic: false,
+            math_talk: false,
+        }
+    }
+
+    /// Also read the archaic forms of historical texts: "*score*" as twenty times the cardinal before it
+    /// ("*four score and seven*" is 87, "*threescore*" is 60) and "*twain*" as two.
+    ///
+    /// A "*score*" that doesn't follow a cardinal, as in "*the score was three two*", is left alone.
+    pub fn with_archaic(self, archaic: bool) -> Self {
+        English { archaic, ..self }
+    }
+
+    /// Also read the "*tens*" of math talk as a multiple of ten: "*three tens*" is 30.
+    ///
+    /// A "*tens*" that doesn't follow a unit, as in "*tens of thousands*", stays a vague quantity.
+    pub fn with_math_talk(self, math_talk: bool) -> Self {
+        English { math_talk, ..self }
+    }
+
+    /// Multiply the cardinal of `b`, under twenty, by a score.
+    fn score(&self, b: &mut DigitString) -> Result<(), Error> {
+        let value = b.parse();
+        if b.is_empty() || b.len() > 2 || value >= 20 || !b.marker.is_none() {
+            return Err(Error::NaN);
+        }
+        b.mul_assign_small(20)?;
+        b.flags = Pending::SCORE_UNITS.bits();
+        Ok(())
+    }
 }
 
 impl LangInterpreter for English {
@@ -50,20 +141,86 @@ impl LangInterpreter for English {
             };
         }
         let lemma = lemmatize(num_func);
+        if Pending::from_bits_truncate(b.flags).contains(Pending::OH_UNIT) {
+            b.flags = 0;
+            return match unit_digit(num_func) {
+                Some(digit) => {
+                    let year = b.len() == 2;
+                    b.shift(2)?;
+                    if year {
+                        b.mark_year();
+                    }
+                    b.put(digit)
+                }
+                None => Err(Error::NaN),
+            };
+        }
+        if self.archaic
+            && let Some(stem) = lemma.strip_suffix("score")
+        {
+            // "four score" or "threescore"
+            if !stem.is_empty() {
+                if !b.is_empty() {
+                    return Err(Error::Overlap);
+                }
+                b.put(&self.exec_group(core::iter::once(stem))?)?;
+            }
+            return self.score(b);
+        }
+        if Pending::from_bits_truncate(b.flags).contains(Pending::SCORE_UNITS) && lemma != "and" {
+            // the units after a score, like "seven" in "four score and seven"
+            b.flags = 0;
+            let units = self
+                .exec_group(core::iter::once(num_func))
+                .ok()
+                .filter(|units| units.marker.is_none() && units.parse() < 20)
+                .ok_or(Error::NaN)?;
+            return b.add_shifted(&units, 0);
+        }
+        // bare plural scale words are vague quantities: "millions of people"
+        if b.is_empty() && matches!(
+                num_func,
+                "hundreds" | "thousands" | "millions" | "billions" | "trillions"
+            ) {
+            return Err(Error::NaN);
+        }
+        // "tens" is no cardinal, but the multiple of ten of math talk, as in "three tens"
+        if num_func == "tens" {
+            return if self.math_talk && b.len() == 1 && !b.is_null() && b.marker.is_none() {
+                b.shift(1)
+            } else {
+                Err(Error::NaN)
+            };
+        }
         let status = match lemma {
-            "zero" | "o" | "nought" => b.put(b"0"),
+            "zero" | "nought" => b.put(b"0"),
+            // "oh" is only a zero as a leading zero of a digit sequence or…
+            "o" | "oh" if b.is_null() => b.put(b"0"),
+            // …in a year like "nineteen oh five", or a number read digit by digit like "four oh nine"
+            "o" | "oh"
+                if b.marker.is_none()
+                    && (b.len() == 1 && !b.is_null()
+                        || b.len() == 2
+                            && b.peek(2).len() == 2
+                            && (b"10".as_slice()..=b"20").contains(&b.peek(2))) =>
+            {
+                b.flags = Pending::OH_UNIT.bits();
+                Err(Error::Incomplete)
+            }
             "one" | "first" | "oneth" if b.peek(2) != b"10" => b.put(b"1"),
             "two" | "second" if b.peek(2) != b"10" => b.put(b"2"),
+            "twain" if self.archaic && b.peek(2) != b"10" => b.put(b"2"),
             "three" | "third" if b.peek(2) != b"10" => b.put(b"3"),
             "four" | "fourth" if b.peek(2) != b"10" => b.put(b"4"),
             "five" | "fifth" if b.peek(2) != b"10" => b.put(b"5"),
             "six" | "sixth" if b.peek(2) != b"10" => b.put(b"6"),
             "seven" | "seventh" if b.peek(2) != b"10" => b.put(b"7"),
             "eight" | "eighth" if b.peek(2) != b"10" => b.put(b"8"),
-            "nine" | "ninth" if b.peek(2) != b"10" => b.put(b"9"),
+            // "nineth" and "twelvth" are common misspellings
+            "nine" | "ninth" | "nineth" if b.peek(2) != b"10" => b.put(b"9"),
             "ten" | "tenth" => b.put(b"10"),
             "eleven" | "eleventh" => b.put(b"11"),
-            "twelve" | "twelfth" => b.put(b"12"),
+            "twelve" | "twelfth" | "twelvth" | "twelveth" => b.put(b"12"),
             "thirteen" | "thirteenth" => b.put(b"13"),
             "fourteen" | "fourteenth" => b.put(b"14"),
             "fifteen" | "fifteenth" => b.put(b"15"),
@@ -75,13 +232,16 @@ impl LangInterpreter for English {
             "thirty" | "thirtieth" => b.put(b"30"),
             "fourty" | "forty" | "fortieth" | "fourtieth" => b.put(b"40"),
             "fifty" | "fiftieth" => b.put(b"50"),
-            "sixty" | "sixteeth" => b.put(b"60"),
+            "sixty" | "sixtieth" => b.put(b"60"),
             "seventy" | "seventieth" => b.put(b"70"),
             "eighty" | "eightieth" => b.put(b"80"),
             "ninety" | "ninetieth" => b.put(b"90"),
             "hundred" | "hundredth" => {
                 let peek = b.peek(2);
-                if peek.len() == 1 || peek != b"00" {
+                if peek.len() < 2 || peek[0] == b'0' && peek != b"00" {
+                    b.shift(2)
+                } else if b.len() == 2 && peek >= b"10" {
+                    // "twelve hundred": only when starting the group, never in "one hundred twelve hundred"
                     b.shift(2)
                 } else {
                     Err(Error::Overlap)
@@ -89,8 +249,10 @@ impl LangInterpreter for English {
             }
             "thousand" | "thousandth" if b.is_range_free(3, 5) => b.shift(3),
             "million" | "millionth" if b.is_range_free(6, 8) => b.shift(6),
-            "billion" | "billionth" => b.shift(9),
-            "and" if b.len() >= 2 => Err(Error::Incomplete),
+            "billion" | "billionth" => b.shift(self.scale.illion(2)),
+            "trillion" | "trillionth" => b.shift(self.scale.illion(3)),
+            // a linking word if a unit still fits: "forty and two", but "forty-two and two" are two numbers
+            "and" if b.len() >= 2 && b.can_put(b"1") => Err(Error::Incomplete),
 
             _ => Err(Error::NaN),
         };
@@ -108,7 +270,7 @@ impl LangInterpreter for English {
 
     fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
         match decimal_func {
-            "zero" | "o" | "nought" => b.push(b"0"),
+            "zero" | "o" | "oh" | "nought" => b.push(b"0"),
             "one" => b.push(b"1"),
             "two" => b.push(b"2"),
             "three" => b.push(b"3"),
@@ -122,74 +284,233 @@ impl LangInterpreter for English {
         }
     }
 
-    fn check_decimal_separator(&self, word: &str) -> Option<char> {
-        if word == "point" { Some('.') } else { None }
+    fn language_code(&self) -> &'static str {
+        "en"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        // the ordinals in plural, as denominators: "*two thirds*", "*three fifths*"
+        let plurals = NUMBER_WORDS
+            .iter()
+            .filter(|word| !self.get_morph_marker(word).is_none())
+            .filter_map(|word| {
+                let plural = format!("{word}s");
+                (lemmatize(&plural) == *word && !self.get_morph_marker(&plural).is_none()).then_some(plural)
+            });
+        sorted_vocabulary(NUMBER_WORDS.iter().map(|word| String::from(*word)).chain(plurals))
+    }
+
+    fn clitics(&self) -> &'static [&'static str] {
+        // the possessive: "the twenty's"
+        &["'s"]
     }
 
-    fn format_decimal_and_value(
-        &self,
-        int: &DigitString,
-        dec: &DigitString,
-        _sep: char,
-    ) -> (String, f64) {
-        let val = int.parse() as f64 + dec.parse_decimal();
-        (format!("{int}.{dec}"), val)
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        if word == "point" { Some('.') } else { None }
     }
 
     fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
         if word.ends_with("th") {
-            MorphologicalMarker::Ordinal("th")
+            MorphologicalMarker::Ordinal("th".into())
         } else if word.ends_with("ths") {
-            MorphologicalMarker::Ordinal("ths")
+            MorphologicalMarker::Ordinal("ths".into())
         } else {
             match word {
-                "first" => MorphologicalMarker::Ordinal("st"),
-                "second" => MorphologicalMarker::Ordinal("nd"),
-                "third" => MorphologicalMarker::Ordinal("rd"),
-                "thirds" => MorphologicalMarker::Ordinal("rds"),
+                "first" => MorphologicalMarker::Ordinal("st".into()),
+                "second" => MorphologicalMarker::Ordinal("nd".into()),
+                "third" => MorphologicalMarker::Ordinal("rd".into()),
+                "thirds" => MorphologicalMarker::Ordinal("rds".into()),
                 _ => MorphologicalMarker::None,
             }
         }
     }
 
+    fn styled_ordinal(&self, _b: &DigitString, affix: Affix, style: OrdinalStyle) -> Option<Affix> {
+        match style {
+            OrdinalStyle::Plain | OrdinalStyle::Long => None,
+            OrdinalStyle::Superscript => Some(
+                match affix.suffix {
+                    "st" => "ˢᵗ",
+                    "nd" => "ⁿᵈ",
+                    "rd" => "ʳᵈ",
+                    "rds" => "ʳᵈˢ",
+                    "ths" => "ᵗʰˢ",
+                    _ => "ᵗʰ",
+                }
+                .into(),
+            ),
+            OrdinalStyle::PeriodOnly => Some(".".into()),
+        }
+    }
+
     fn is_linking(&self, word: &str) -> bool {
         INSIGNIFICANT.contains(word)
     }
 
-    fn basic_annotate<T: BasicAnnotate>(&self, tokens: &mut Vec<T>) {
-        let mut b = DigitString::new();
-        let significant_tokens_indices: Vec<usize> = tokens
-            .iter()
-            .enumerate()
-            .filter_map(|(i, t)| {
-                if !t.text_lowercase().chars().all(|c| c.is_ascii_whitespace()) {
-                    Some(i)
-                } else {
-                    None
-                }
-            })
-            .collect();
-        for (j, &i) in significant_tokens_indices.iter().enumerate() {
-            if tokens[i].text_lowercase() == "o" {
-                if j > 0
-                    && self
-                        .apply(
-                            tokens[significant_tokens_indices[j - 1]].text_lowercase(),
-                            &mut b,
-                        )
-                        .is_ok()
-                    || j + 1 < significant_tokens_indices.len()
-                        && self
-                            .apply(
-                                tokens[significant_tokens_indices[j + 1]].text_lowercase(),
-                                &mut b,
-                            )
-                            .is_ok()
-                {
-                    b.reset()
-                } else {
-                    tokens[i].set_nan(true);
+    fn is_vague_quantifier(&self, word: &str) -> bool {
+        VAGUE_QUANTIFIERS.contains(word)
+    }
+
+    fn is_pronoun(&self, previous: Option<&str>, number: &str, next: Option<&str>) -> bool {
+        if number != "one" || next.is_some_and(|next| MEASURE_CONTEXTS.contains(next)) {
+            return false;
+        }
+        // "the one I like", "the third one", "one who knows", "one's own", and "the 3rd one" once replaced
+        next == Some("'s")
+            || previous.is_some_and(|previous| {
+            PRONOUN_DETERMINERS.contains(previous)
+                || self.token_kind(previous) == TokenKind::OrdinalWord
+                || is_digit_ordinal(previous)
+        }) || next.is_some_and(|next| RELATIVE_MARKERS.contains(next))
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        if word.contains('-') {
+            return super::dry_run_start(self, word);
+        }
+        // bare plural scale words are vague quantities
+        !matches!(
+            word,
+            "tens" | "hundreds" | "thousands" | "millions" | "billions" | "trillions"
+        )
+            && NUMBER_WORDS.contains(lemmatize(word))
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        if word.contains('-') {
+            return super::dry_run_continue(self, word);
+        }
+        // "zero" never follows another number word, and "tens" only the units of math talk
+        match word {
+            "and" => true,
+            "tens" => self.math_talk,
+            _ => {
+                let lemma = lemmatize(word);
+                !matches!(lemma, "zero" | "nought") && NUMBER_WORDS.contains(lemma)
+            }
+        }
+    }
+
+    fn adverbial_ordinal(&self, word: &str) -> Option<u64> {
+        // "firstly", "secondly", "twenty-thirdly"…
+        let ordinal = word.strip_suffix("ly")?;
+        self.exec_group(core::iter::once(ordinal))
+            .ok()
+            .filter(DigitString::is_ordinal)
+            .map(|ds| ds.parse())
+    }
+
+    fn informal_quantity(&self, determiner: &str, noun: &str) -> Option<u64> {
+        // "a pair", "a dozen"… but not "the pair" nor "both"
+        if determiner == "a" {
+            QUANTITY_NOUNS.get(noun).copied()
+        } else {
+            None
+        }
+    }
+
+    fn completes_informal_quantity(&self, linker: &str, word: &str) -> bool {
+        linker == "of"
+            && word.len() > 2
+            && word.ends_with('s')
+            && !word.ends_with("ss")
+            && word.chars().all(|c| c.is_alphabetic())
+            && !NOT_PLURAL.contains(word)
+    }
+
+    fn fraction_connectors(&self) -> &'static [&'static str] {
+        // "three over four", "nine out of ten", "seven divided by two"
+        &["over", "out of", "divided by"]
+    }
+
+    fn fraction_tails(&self) -> &'static [(&'static str, char, &'static str)] {
+        // "two and a half", "three and a half thousand"; "and a half" alone is no number
+        &[("and a half", '.', "5"), ("and a quarter", '.', "25")]
+    }
+
+    fn unit_fraction_tails(&self) -> &'static [(&'static str, u64)] {
+        &[("and a third", 3)]
+    }
+
+    fn recommended_options(&self) -> Options {
+        Options {
+            // "one" is as often a pronoun as a number
+            threshold: 10.0,
+            // "the twenty's" gives "the 20's"
+            strip_clitics: true,
+            ..Options::default()
+        }
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "three to two"
+        &["to"]
+    }
+
+    fn range_connectors(&self) -> &'static [&'static str] {
+        // "first to third", "from the first to the third"
+        &["to", "to the", "through", "through the"]
+    }
+
+    fn is_month_name(&self, word: &str) -> bool {
+        // "may" is also the verb: "you may second the motion"
+        word == "May" || MONTHS.iter().any(|month| month.eq_ignore_ascii_case(word))
+    }
+
+    fn is_date_connector(&self, word: &str) -> bool {
+        word == "of"
+    }
+
+    fn fraction_denominator(&self, word: &str) -> Option<Denominator> {
+        // "half" and "quarters" are no ordinals, "quarter" might be the one of a town or of an hour.
+        let (value, needs_context) = match word {
+            "half" | "halves" => (2, false),
+            "quarters" => (4, false),
+            "quarter" => (4, true),
+            _ => {
+                let singular = word.strip_suffix('s');
+                let rank: u64 = self
+                    .exec_group(core::iter::once(singular.unwrap_or(word)))
+                    .ok()
+                    .filter(DigitString::is_ordinal)?
+                    .parse();
+                // "seconds" are a unit, "thirds" and above are fractions
+                if rank < 3 {
+                    return None;
                 }
+                (rank, singular.is_none())
+            }
+        };
+        Some(Denominator {
+            value,
+            needs_context,
+        })
+    }
+
+    fn is_fraction_context(&self, word: &str) -> bool {
+        word == "of"
+    }
+
+    fn basic_annotate<T: BasicAnnotate>(&self, tokens: &mut Vec<T>) {
+        let significant = |t: &&T| !t.text_lowercase().chars().all(|c| c.is_ascii_whitespace());
+        for i in 0..tokens.len() {
+            let word = tokens[i].text_lowercase();
+            if word != "o" && word != "oh" {
+                continue;
+            }
+            let mut b = DigitString::new();
+            let applies = |t: Option<&T>, b: &mut DigitString| {
+                t.is_some_and(|t| self.apply(t.text_lowercase(), b).is_ok())
+            };
+            let next = tokens[i + 1..].iter().find(significant);
+            // "oh" is much more often an interjection: it must be followed by a number word
+            let number = if word == "o" {
+                applies(tokens[..i].iter().rev().find(significant), &mut b) || applies(next, &mut b)
+            } else {
+                applies(next, &mut b)
+            };
+            if !number {
+                tokens[i].set_nan(true);
             }
         }
     }
@@ -198,11 +519,18 @@ impl LangInterpreter for English {
 #[cfg(test)]
 mod tests {
     use super::English;
-    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+    use crate::options::OrdinalStyle;
+    use crate::lang::{LangInterpreter, Scale};
+    use crate::Options;
+    use crate::word_to_digit::{
+        find_numbers_with, replace_numbers_in_text, replace_numbers_in_text_with, text2digits,
+    };
+    use crate::tokenizer::tokenize;
+    use alloc::{format, vec};
 
     macro_rules! assert_text2digits {
         ($text:expr, $res:expr) => {
-            let f = English {};
+            let f = English::new();
             let res = text2digits($text, &f);
             crate::tests::dbg!(&res);
             assert!(res.is_ok());
@@ -212,21 +540,21 @@ mod tests {
 
     macro_rules! assert_replace_numbers {
         ($text:expr, $res:expr) => {
-            let f = English {};
+            let f = English::new();
             assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
         };
     }
 
     macro_rules! assert_replace_all_numbers {
         ($text:expr, $res:expr) => {
-            let f = English {};
+            let f = English::new();
             assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
         };
     }
 
     macro_rules! assert_invalid {
         ($text:expr) => {
-            let f = English {};
+            let f = English::new();
             let res = text2digits($text, &f);
             assert!(res.is_err());
         };
@@ -264,6 +592,13 @@ mod tests {
         assert_text2digits!("nineteen hundred seventy-three", "1973");
         assert_text2digits!("forty five hundred thirty eight", "4538");
         assert_text2digits!("sixty hundreds", "6000");
+        assert_text2digits!("twelve hundred", "1200");
+        assert_text2digits!("nineteen hundred eighty", "1980");
+        assert_text2digits!("fifteen hundred fifty", "1550");
+        assert_text2digits!("ninety-nine hundred ninety-nine", "9999");
+        assert_invalid!("one hundred twelve hundred");
+        assert_invalid!("two thousand twelve hundred");
+        assert_text2digits!("ten hundred", "1000");
         // assert_text2digits!("nineteen seventy-three", "1973");
     }
 
@@ -274,6 +609,73 @@ mod tests {
         assert_text2digits!("fiftieth", "50th");
         assert_text2digits!("seventy fourth", "74th");
         assert_text2digits!("twenty-eighth", "28th");
+        assert_text2digits!("one hundred and first", "101st");
+        assert_text2digits!("two hundred twelfth", "212th");
+        // common misspellings
+        assert_text2digits!("twelvth", "12th");
+        assert_text2digits!("twenty-nineth", "29th");
+    }
+
+    #[test]
+    fn test_scale_ordinals() {
+        // the ordinal scale word makes an ordinal of the whole number before it
+        assert_text2digits!("two hundredth", "200th");
+        assert_text2digits!("twenty-one hundredth", "2100th");
+        assert_text2digits!("three thousandth", "3000th");
+        assert_text2digits!("two hundred thousandth", "200000th");
+        assert_text2digits!("six millionth", "6000000th");
+        assert_text2digits!("one hundred and five millionth", "105000000th");
+        assert_replace_numbers!("the six millionth visitor", "the 6000000th visitor");
+        assert_replace_numbers!("the millionth visitor", "the 1000000th visitor");
+    }
+
+    #[test]
+    fn test_ordinal_table() {
+        let units = [
+            "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth",
+        ];
+        let teens = [
+            "tenth",
+            "eleventh",
+            "twelfth",
+            "thirteenth",
+            "fourteenth",
+            "fifteenth",
+            "sixteenth",
+            "seventeenth",
+            "eighteenth",
+            "nineteenth",
+        ];
+        let suffix = |n: usize| match (n % 10, n % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        };
+        let mut dates: Vec<(alloc::string::String, usize)> = Vec::new();
+        dates.extend(units.iter().enumerate().map(|(i, w)| ((*w).into(), i + 1)));
+        dates.extend(teens.iter().enumerate().map(|(i, w)| ((*w).into(), i + 10)));
+        dates.push(("twentieth".into(), 20));
+        dates.extend(units.iter().enumerate().map(|(i, w)| (format!("twenty-{w}"), i + 21)));
+        dates.push(("thirtieth".into(), 30));
+        dates.push(("thirty-first".into(), 31));
+        for (word, n) in &dates {
+            assert_text2digits!(word.as_str(), format!("{n}{}", suffix(*n)));
+        }
+        let tens = [
+            "fortieth",
+            "fiftieth",
+            "sixtieth",
+            "seventieth",
+            "eightieth",
+            "ninetieth",
+            "hundredth",
+        ];
+        for (i, word) in tens.iter().enumerate() {
+            assert_text2digits!(*word, format!("{}th", 40 + 10 * i));
+        }
+        assert_text2digits!("one hundredth", "100th");
     }
 
     #[test]
@@ -300,6 +702,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_oh() {
+        assert_text2digits!("oh eight", "08");
+        assert_text2digits!("nineteen oh five", "1905");
+        assert_text2digits!("twenty o one", "2001");
+        assert_invalid!("nineteen oh");
+        assert_invalid!("five oh");
+        assert_invalid!("nineteen oh fifteen");
+        assert_invalid!("one hundred oh five");
+        assert_invalid!("sixty oh five");
+        assert_replace_numbers!("three point oh five", "3.05");
+        assert_text2digits!("four oh nine", "409");
+        assert_text2digits!("one oh one", "101");
+        assert_replace_numbers!("room four oh nine", "room 409");
+        assert_replace_numbers!("from room four oh nine to one oh one", "from room 409 to 101");
+        assert_replace_numbers!(
+            "my number is oh two oh seven nine four six",
+            "my number is 02 07 9 4 6"
+        );
+        assert_replace_numbers!("It was built in nineteen oh five.", "It was built in 1905.");
+        assert_replace_numbers!("nineteen oh no", "19 oh no");
+        assert_replace_all_numbers!("oh no", "oh no");
+        assert_replace_all_numbers!("Oh, five of them", "Oh, 5 of them");
+        assert_replace_all_numbers!("five oh my", "5 oh my");
+    }
+
     #[test]
     fn test_invalid() {
         assert_invalid!("thousand thousand two hundreds");
@@ -341,6 +769,18 @@ mod tests {
             "I want 566 rupees"
         );
         assert_replace_numbers!("fifty sixty thirty and eleven", "50 60 30 and 11");
+        // "and" only links the number to a word that can still fit in it
+        let f = English::new();
+        let options = Options {
+            adjacent_number_policy: crate::options::AdjacentNumberPolicy::Concatenate,
+            ..Options::new(10.0)
+        };
+        let replace = |text| replace_numbers_in_text_with(text, &f, &options);
+        assert_eq!(replace("twenty-one and two cats"), "21 and 2 cats");
+        assert_eq!(replace("ninety-nine and one hundred"), "99 and 100");
+        assert_eq!(replace("forty and two"), "42");
+        assert_eq!(replace("two hundred and five"), "205");
+        assert_eq!(replace("twenty-two and a half"), "22.5");
     }
 
     #[test]
@@ -373,6 +813,22 @@ mod tests {
         assert_replace_numbers!("five hundred first", "501st");
     }
 
+    #[test]
+    fn test_month_ordinals() {
+        assert_replace_numbers!("We met on June fifth.", "We met on June 5th.");
+        assert_replace_numbers!("fifth of June", "5th of June");
+        assert_replace_numbers!("Remember the fifth of November", "Remember the 5th of November");
+        assert_replace_numbers!("by the first of May", "by the 1st of May");
+        assert_replace_numbers!("May second", "May 2nd");
+        assert_replace_numbers!("by the twenty-third", "by the 23rd");
+        // no month
+        assert_replace_numbers!("the fifth element", "the fifth element");
+        assert_replace_numbers!("on the fifth", "on the fifth");
+        assert_replace_numbers!("the fifth of them", "the fifth of them");
+        assert_replace_numbers!("you may second the motion", "you may second the motion");
+        assert_replace_all_numbers!("on the fifth", "on the 5th");
+    }
+
     #[test]
     fn test_replace_decimals() {
         assert_replace_numbers!(
@@ -383,6 +839,18 @@ mod tests {
         assert_replace_numbers!("I say point three", "I say point three");
     }
 
+    #[test]
+    fn test_replace_decimal_scales() {
+        assert_replace_numbers!(
+            "three point five million people, two point five five million users",
+            "3500000 people, 2550000 users"
+        );
+        assert_replace_numbers!("two point zero five thousand", "2050");
+        assert_replace_numbers!("one point two three four five thousand", "1234.5");
+        assert_replace_numbers!("zero point five billion", "500000000");
+        assert_replace_numbers!("three point five million two", "3500000 2");
+    }
+
     #[test]
     fn test_uppercase() {
         assert_replace_numbers!("FIFTEEN ONE TEN ONE", "15 1 10 1");
@@ -394,9 +862,10 @@ mod tests {
             "This is the one I was waiting for",
             "This is the one I was waiting for"
         );
+        // a pronoun
         assert_replace_all_numbers!(
             "This is the one I was waiting for",
-            "This is the 1 I was waiting for"
+            "This is the one I was waiting for"
         );
 
         assert_replace_numbers!("First, let's think twice!", "First, let's think twice!");
@@ -404,6 +873,36 @@ mod tests {
         assert_replace_numbers!("One may count: one two three", "One may count: 1 2 3");
     }
 
+    #[test]
+    fn test_pronoun_one() {
+        let pronouns = [
+            "the one I like",
+            "Which one is yours?",
+            "No one came.",
+            "Is there any one left?",
+            "I want this one, not that one.",
+            "She is the one who called.",
+            "I need one that works.",
+            "the one and only",
+        ];
+        for sentence in pronouns {
+            assert_replace_numbers!(sentence, sentence);
+            assert_replace_all_numbers!(sentence, sentence);
+        }
+        assert_replace_all_numbers!("the third one from the left", "the 3rd one from the left");
+        // once replaced
+        assert_replace_all_numbers!("the 3rd one from the left", "the 3rd one from the left");
+        assert_replace_all_numbers!("the 21ˢᵗ one and the 2. one", "the 21ˢᵗ one and the 2. one");
+        // the measures and the partitive
+        assert_replace_all_numbers!("the one meter rule", "the 1 meter rule");
+        assert_replace_all_numbers!("which one of the three", "which 1 of the 3");
+        assert_replace_all_numbers!("I have one cat", "I have 1 cat");
+        assert_replace_numbers!("I have one cat", "I have one cat");
+        // in a sequence
+        assert_replace_numbers!("which one: one two three", "which one: 1 2 3");
+        assert_replace_all_numbers!("the one hundred", "the 100");
+    }
+
     #[test]
     fn test_isolates_with_noise() {
         assert_replace_numbers!(
@@ -411,4 +910,569 @@ mod tests {
             "4 plus 5 so 11 then 3 uh 6 uh well 7"
         );
     }
+
+    #[test]
+    fn test_stutters() {
+        let f = English::new();
+        let options = Options {
+            collapse_stutters: true,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("two two hundred and fifty", &f, &options),
+            "250"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("I paid twenty twenty-five dollars", &f, &options),
+            "I paid 25 dollars"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("one one thousand three three hundred", &f, &options),
+            "1300"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("one one two two", &f, &options),
+            "1 1 2 2"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("three thousand thousand", &f, &options),
+            "3000 1000"
+        );
+        assert_replace_numbers!("two two hundred and fifty", "2 250");
+    }
+
+    #[test]
+    fn test_adverbial_ordinals() {
+        assert_replace_all_numbers!("Firstly we agree, secondly we sign, twenty-thirdly we rest.", "Firstly we agree, secondly we sign, twenty-thirdly we rest.");
+        let f = English::default();
+        let options = Options {
+            adverbial_ordinals: true,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("Firstly we agree, secondly we sign, twenty-thirdly we rest.", &f, &options),
+            "1. we agree, 2. we sign, 23. we rest."
+        );
+    }
+
+    #[test]
+    fn test_digits_passthrough() {
+        assert_replace_all_numbers!("I paid 1,234.56 dollars and twenty-five cents.", "I paid 1,234.56 dollars and 25 cents.");
+        assert_replace_all_numbers!("1,234 and five more", "1,234 and 5 more");
+        assert_replace_all_numbers!("twenty 1,234.5 thirty-one", "20 1,234.5 31");
+        assert_replace_all_numbers!("one hundred 250", "100 250");
+    }
+
+    #[test]
+    fn test_digits_with_scales() {
+        assert_replace_all_numbers!("3 thousand people", "3 1000 people");
+        let f = English::default();
+        let options = Options {
+            digits_with_scales: true,
+            ..Options::new(0.0)
+        };
+        for (text, expected) in [
+            ("3 thousand people and 1,234 more", "3000 people and 1,234 more"),
+            ("12 hundred", "1200"),
+            ("3 thousand five hundred", "3500"),
+            ("2 million 3 thousand", "2000000 3000"),
+            ("30 five", "30 5"),
+            ("1.5 million", "1.5 1000000"),
+            ("the 3 thousandth", "the 3 1000th"),
+        ] {
+            assert_eq!(replace_numbers_in_text_with(text, &f, &options), expected);
+        }
+    }
+
+    #[test]
+    fn test_vague_quantities() {
+        assert_replace_all_numbers!("millions of people", "millions of people");
+        assert_replace_all_numbers!("thousands of times, hundreds of cars, billions", "thousands of times, hundreds of cars, billions");
+        assert_replace_all_numbers!("two millions people", "2000000 people");
+        assert_replace_all_numbers!("a million people", "a 1000000 people");
+    }
+
+    #[test]
+    fn test_plural_scale_nouns() {
+        for text in [
+            "Hundreds of people gathered outside.",
+            "Tens of thousands of fans filled the stadium.",
+            "Hundreds of thousands of voters stayed home.",
+            "The losses run in the tens of millions.",
+            "Prices are in the thousands; costs in the hundreds.",
+            "They came by the hundreds, thousands upon thousands.",
+            "Tens of people, then tens, then hundreds and hundreds.",
+        ] {
+            assert_replace_all_numbers!(text, text);
+        }
+        assert_replace_all_numbers!("ten thousands of them", "10000 of them");
+        // math talk is only read on request
+        assert_replace_all_numbers!("three tens of marbles", "3 tens of marbles");
+        let math = English::new().with_math_talk(true);
+        assert_eq!(text2digits("three tens", &math), Ok("30".into()));
+        assert_eq!(
+            replace_numbers_in_text("tens of thousands, seven tens", &math, 0.0),
+            "tens of thousands, 70"
+        );
+        assert!(text2digits("twenty tens", &math).is_err());
+    }
+
+    #[test]
+    fn test_informal_quantities() {
+        let text = "I bought a pair of shoes and a gross of pencils, a score of years ago.";
+        assert_replace_all_numbers!(text, text);
+        let f = English::default();
+        let options = Options {
+            informal_quantities: true,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with(text, &f, &options),
+            "I bought 2 shoes and 144 pencils, 20 years ago."
+        );
+        // strict frame
+        for text in [
+            "both of them",
+            "the pair of shoes",
+            "a pair of his shoes",
+            "a pair of",
+            "a score of 6-2",
+            "what a pair!",
+            "a pair of glass",
+            "a dozen eggs",
+        ] {
+            assert_eq!(replace_numbers_in_text_with(text, &f, &options), text);
+        }
+        assert_eq!(
+            replace_numbers_in_text_with("twenty-five and a pair of socks, a pair", &f, &options),
+            "25 and 2 socks, a pair"
+        );
+    }
+
+    #[test]
+    fn test_spoken_fractions() {
+        let text = "three over four, nine out of ten people, seven divided by two";
+        assert_replace_all_numbers!(text, "3 over 4, 9 out of 10 people, 7 divided by 2");
+        let f = English::default();
+        let options = Options {
+            spoken_fractions: true,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with(text, &f, &options),
+            "3/4, 9/10 people, 7/2"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("twenty one over forty two and a half", &f, &options),
+            "21/42 and a half"
+        );
+        let occurences = find_numbers_with(tokenize("x is three over four."), &f, &options);
+        assert_eq!(occurences.len(), 1);
+        assert_eq!(occurences[0].value, 0.75);
+        assert_eq!(occurences[0].kind, crate::NumberKind::Fraction);
+        assert_eq!(occurences[0].words, 3);
+        // no cardinal on both sides
+        for text in [
+            "three over there",
+            "nine out there",
+            "seven divided",
+            "one over zero",
+            "three over fourth",
+            "three over, four",
+            "nine out of",
+            "over four",
+        ] {
+            assert_eq!(replace_numbers_in_text_with(text, &f, &options), text);
+        }
+        assert_eq!(
+            replace_numbers_in_text_with("three over four point five", &f, &options),
+            "three over 4.5"
+        );
+    }
+
+    #[test]
+    fn test_signs() {
+        assert_replace_all_numbers!("It is -twenty degrees", "It is -20 degrees");
+        assert_replace_all_numbers!("It is \u{2212}twenty degrees", "It is -20 degrees");
+        assert_replace_all_numbers!("from \u{2013}five to (-three point five)", "from -5 to (-3.5)");
+        assert_replace_all_numbers!("minus -five", "minus -5");
+        // not a sign, or not signed
+        assert_replace_all_numbers!("twenty-five - thirty", "25 - 30");
+        assert_replace_all_numbers!("forty-twenty", "forty-twenty");
+        assert_replace_all_numbers!("the -fifth", "the -5th");
+    }
+
+    #[test]
+    fn test_fractions_in_text() {
+        assert_replace_all_numbers!("one fifth of users", "1/5 of users");
+        assert_replace_all_numbers!("the fifth of May", "the 5th of May");
+        assert_replace_all_numbers!("two fifths agreed", "2/5 agreed");
+        assert_replace_all_numbers!("three quarters, a quarter past", "3/4, a quarter past");
+        assert_replace_all_numbers!("one quarter of an hour", "1/4 of an hour");
+        assert_replace_all_numbers!("seven tenths", "7/10");
+        // seconds are a unit
+        assert_replace_all_numbers!("two seconds", "2 seconds");
+        let f = English::default();
+        let options = Options {
+            fraction_output: crate::FractionOutput::Decimal,
+            ..Options::new(0.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("one fifth of users, three halves", &f, &options),
+            "0.2 of users, 1.5"
+        );
+    }
+
+    #[test]
+    fn test_and_a_half() {
+        assert_replace_numbers!("two and a half", "2.5");
+        assert_replace_numbers!("twenty one and a quarter miles", "21.25 miles");
+        assert_replace_numbers!("three and a half thousand", "3500");
+        assert_replace_numbers!("three and a half million people", "3500000 people");
+        assert_replace_numbers!("two and a third", "2 1/3");
+        // the scale multiplies the fraction too
+        assert_replace_numbers!("two and a third thousand", "2333 1/3");
+        assert_replace_numbers!("two and a third million people", "2333333 1/3 people");
+        // the tail must follow the cardinal
+        assert_replace_all_numbers!("a mile and a quarter", "a mile and a quarter");
+        assert_replace_all_numbers!("an hour and a quarter", "an hour and a quarter");
+        assert_replace_all_numbers!("two and the half", "2 and the half");
+        assert_replace_all_numbers!("and a half-baked idea", "and a half-baked idea");
+        assert_replace_all_numbers!("two and a half-baked ideas", "2 and a half-baked ideas");
+        assert_replace_all_numbers!("half of it", "half of it");
+        let f = English::default();
+        let options = Options {
+            fraction_output: crate::FractionOutput::Decimal,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("two and a third cups", &f, &options),
+            "2.333333 cups"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("two and a third thousand cups", &f, &options),
+            "2333.333333 cups"
+        );
+    }
+
+    #[test]
+    fn test_custom_decimal_separators() {
+        let f = English::default();
+        // aviation
+        let options = Options {
+            decimal_separators: vec![("decimal".into(), '.')],
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("contact tower on one hundred eighteen decimal seven five", &f, &options),
+            "contact tower on 118.75"
+        );
+        assert_eq!(replace_numbers_in_text("three decimal five", &f, 10.0), "three decimal five");
+        // maritime digit-by-digit, with the builtin separator
+        assert_eq!(
+            replace_numbers_in_text_with("steer two seven zero, speed twelve point five knots", &f, &options),
+            "steer 2 7 0, speed 12.5 knots"
+        );
+        // borrowed word, and a builtin word figured differently
+        let options = Options {
+            decimal_separators: vec![("komma".into(), ','), ("point".into(), ',')],
+            ..Options::new(10.0)
+        };
+        assert_eq!(replace_numbers_in_text_with("three komma five", &f, &options), "3,5");
+        assert_eq!(replace_numbers_in_text_with("three point five", &f, &options), "3,5");
+        let occurences = find_numbers_with(
+            tokenize("three komma five or twenty"),
+            &f,
+            &options,
+        );
+        assert_eq!(occurences.len(), 2);
+        assert_eq!(occurences[0].decimal_separator, Some(','));
+        assert_eq!(occurences[1].decimal_separator, None);
+    }
+
+    #[test]
+    fn test_point_homograph() {
+        let f = English::default();
+        for options in [
+            Options::new(0.0),
+            Options {
+                leading_decimal_separator: true,
+                ..Options::new(0.0)
+            },
+        ] {
+            let replace = |text| replace_numbers_in_text_with(text, &f, &options);
+            assert_eq!(replace("my point is three"), "my point is 3");
+            assert_eq!(replace("three point"), "3 point");
+            assert_eq!(replace("three point, four"), "3 point, 4");
+            assert_eq!(replace("a good point twenty times"), "a good point 20 times");
+            assert_eq!(replace("three point five"), "3.5");
+        }
+        assert_replace_all_numbers!("point five percent", "point 5 percent");
+        let options = Options {
+            leading_decimal_separator: true,
+            ..Options::new(0.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("point five percent", &f, &options),
+            "0.5 percent"
+        );
+        let occurences = find_numbers_with(tokenize("the point of point five"), &f, &options);
+        assert_eq!(occurences.len(), 1);
+        assert_eq!(occurences[0].start, 6);
+        assert_eq!(occurences[0].text, "0.5");
+    }
+
+    #[test]
+    fn test_too_long() {
+        let f = English::default();
+        let zeros = "zero ".repeat(1_000_000);
+        let text = format!("call {zeros}now, or twenty-one");
+        assert_eq!(replace_numbers_in_text(&text, &f, 0.0), format!("call {zeros}now, or 21"));
+        let digits = format!("three point {}", "one four ".repeat(50));
+        assert_eq!(replace_numbers_in_text(&digits, &f, 0.0), digits);
+        // the adjacent numbers that would merge into a number too long are all left
+        for policy in [crate::AdjacentNumberPolicy::Concatenate, crate::AdjacentNumberPolicy::Auto] {
+            let options = Options {
+                adjacent_number_policy: policy,
+                ..Options::new(0.0)
+            };
+            let ones = "one ".repeat(100_000);
+            let text = format!("call {ones}now, or twenty-one");
+            assert_eq!(
+                replace_numbers_in_text_with(&text, &f, &options),
+                format!("call {ones}now, or 21"),
+                "{policy:?}"
+            );
+            let ones = "one ".repeat(300);
+            assert_eq!(replace_numbers_in_text_with(&ones, &f, &options), ones, "{policy:?}");
+        }
+        let options = Options {
+            adjacent_number_policy: crate::AdjacentNumberPolicy::Concatenate,
+            ..Options::new(0.0)
+        };
+        let twos = format!("{}million people", "two ".repeat(150));
+        assert_eq!(replace_numbers_in_text_with(&twos, &f, &options), twos);
+        assert_eq!(
+            replace_numbers_in_text_with("two two million", &f, &options),
+            "22000000"
+        );
+        let options = Options {
+            max_tokens: 4,
+            ..Options::new(0.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("one thousand two hundred five, one thousand two hundred", &f, &options),
+            "one thousand two hundred five, 1200"
+        );
+        let options = Options {
+            max_digits: 4,
+            ..Options::new(0.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("twelve thousand six hundred, one thousand", &f, &options),
+            "twelve thousand six hundred, 1000"
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("TWENTY-THREE", "23");
+        assert_replace_all_numbers!("Twenty-Three Cats", "23 Cats");
+        assert_replace_all_numbers!("THE TWENTY-FIRST", "THE 21st");
+    }
+
+    #[test]
+    fn test_hyphenated_compounds() {
+        assert_replace_all_numbers!("three-dimensional", "three-dimensional");
+        assert_replace_all_numbers!("a twenty-two-year-old", "a twenty-two-year-old");
+        assert_replace_all_numbers!("two-faced", "two-faced");
+        assert_replace_all_numbers!("carbon-14, MP3 and B2B", "carbon-14, MP3 and B2B");
+        let f = English::default();
+        let options = Options {
+            convert_in_hyphenated_compounds: true,
+            ..Options::new(0.0)
+        };
+        assert_eq!(replace_numbers_in_text_with("Three-Dimensional", &f, &options), "3-Dimensional");
+        assert_eq!(replace_numbers_in_text_with("a twenty-two-year-old", &f, &options), "a 22-year-old");
+        assert_eq!(replace_numbers_in_text_with("two-faced", &f, &options), "2-faced");
+        assert_eq!(replace_numbers_in_text_with("one hundred-fold", &f, &options), "100-fold");
+        assert_eq!(replace_numbers_in_text_with("the twenty-first-century", &f, &options), "the 21st-century");
+        assert_eq!(replace_numbers_in_text_with("twenty-two, carbon-14 MP3", &f, &options), "22, carbon-14 MP3");
+    }
+
+    #[test]
+    fn test_salvaged_groups() {
+        let f = English::default();
+        let options = Options {
+            salvage_partial_groups: true,
+            ..Options::new(0.0)
+        };
+        // the prefix
+        assert_eq!(replace_numbers_in_text_with("twenty-one-porridge", &f, &options), "21-porridge");
+        assert_eq!(replace_numbers_in_text_with("one hundred twenty-porridge", &f, &options), "120-porridge");
+        // the suffix
+        assert_eq!(replace_numbers_in_text_with("Porridge-Twenty-One", &f, &options), "Porridge-21");
+        // the longer one
+        assert_eq!(replace_numbers_in_text_with("one-and-twenty-one", &f, &options), "one-and-21");
+        assert_eq!(replace_numbers_in_text_with("one-to-one", &f, &options), "1-to-one");
+        // neither
+        assert_eq!(replace_numbers_in_text_with("porridge-and-oats", &f, &options), "porridge-and-oats");
+        assert_eq!(replace_numbers_in_text_with("twenty-one", &f, &options), "21");
+        // all or nothing otherwise
+        assert_replace_all_numbers!("twenty-one-porridge or porridge-twenty", "twenty-one-porridge or porridge-twenty");
+    }
+
+    #[test]
+    fn test_word_class_queries() {
+        // the answers of the dry runs are checked in all the languages by lang::tests
+        let en = English::new();
+        assert!(en.can_start_number("hundred"));
+        assert!(!en.can_start_number("hundreds"));
+        assert!(en.can_continue("hundreds"));
+        assert!(!en.can_start_number("and"));
+        assert!(en.can_continue("and"));
+        assert!(!en.can_continue("point"));
+        assert_eq!(en.check_decimal_separator("point"), Some('.'));
+    }
+
+    #[test]
+    fn test_clitics() {
+        let f = English::new();
+        let options = Options {
+            strip_clitics: true,
+            ..Options::new(0.0)
+        };
+        assert_eq!(replace_numbers_in_text_with("in the twenty's", &f, &options), "in the 20's");
+        assert_eq!(replace_numbers_in_text_with("one hundred's worth", &f, &options), "100's worth");
+        assert_eq!(replace_numbers_in_text_with("TWENTY'S", &f, &options), "20'S");
+        assert_eq!(replace_numbers_in_text_with("two twenty's three", &f, &options), "2 20's 3");
+        assert_eq!(replace_numbers_in_text_with("John's cat", &f, &options), "John's cat");
+        // the possessive pronoun
+        assert_eq!(replace_numbers_in_text_with("one's own", &f, &options), "one's own");
+        assert_eq!(replace_numbers_in_text_with("the third one's", &f, &options), "the 3rd one's");
+        assert_eq!(replace_numbers_in_text_with("twenty one's", &f, &options), "21's");
+        // off by default
+        assert_replace_all_numbers!("in the twenty's", "in the twenty's");
+        let occurences = find_numbers_with(tokenize("forty-two's and ten"), &f, &options);
+        assert_eq!(occurences.len(), 2);
+        assert_eq!(occurences[0].text, "42's");
+        assert_eq!(occurences[0].value, 42.0);
+        assert_eq!(occurences[0].clitic.as_deref(), Some("'s"));
+        assert_eq!(occurences[1].clitic, None);
+    }
+
+    #[test]
+    fn test_vague_quantifiers() {
+        assert_replace_numbers!("a few hundred people", "a few hundred people");
+        assert_replace_numbers!("several thousand", "several thousand");
+        assert_replace_numbers!("a couple hundred dollars", "a couple hundred dollars");
+        assert_replace_numbers!("a few hundred thousand people", "a few hundred thousand people");
+        assert_replace_numbers!("three hundred people", "300 people");
+        assert_replace_numbers!("a few people and three hundred dollars", "a few people and 300 dollars");
+    }
+
+    #[test]
+    fn test_archaic() {
+        let archaic = English::new().with_archaic(true);
+        assert_eq!(
+            replace_numbers_in_text(
+                "Four score and seven years ago our fathers brought forth",
+                &archaic,
+                10.0
+            ),
+            "87 years ago our fathers brought forth"
+        );
+        assert_eq!(text2digits("threescore and ten", &archaic), Ok("70".into()));
+        assert_eq!(text2digits("twelve score", &archaic), Ok("240".into()));
+        assert_eq!(text2digits("twain", &archaic), Ok("2".into()));
+        assert_eq!(text2digits("nought", &archaic), Ok("0".into()));
+        assert!(text2digits("score", &archaic).is_err());
+        assert!(text2digits("one hundred score", &archaic).is_err());
+        assert_eq!(
+            replace_numbers_in_text("the final score, and a score of years", &archaic, 0.0),
+            "the final score, and a score of years"
+        );
+        assert_eq!(
+            replace_numbers_in_text("four score and twenty", &archaic, 0.0),
+            "80 and 20"
+        );
+        // modern English
+        let modern = English::new();
+        assert!(text2digits("four score", &modern).is_err());
+        assert!(text2digits("twain", &modern).is_err());
+        assert_eq!(
+            replace_numbers_in_text("Four score and seven years ago", &modern, 10.0),
+            "Four score and seven years ago"
+        );
+    }
+
+    #[test]
+    fn test_scales() {
+        let short = English::new();
+        let long = English::with_scale(Scale::Long);
+        assert_eq!(text2digits("one billion", &short), Ok("1000000000".into()));
+        assert_eq!(text2digits("one billion", &long), Ok("1000000000000".into()));
+        assert_eq!(text2digits("two trillion", &short), Ok("2000000000000".into()));
+        assert_eq!(text2digits("two trillion", &long), Ok("2000000000000000000".into()));
+        assert_eq!(text2digits("one thousand million", &short), Ok("1000000000".into()));
+        assert_eq!(text2digits("one thousand million", &long), Ok("1000000000".into()));
+        assert_eq!(
+            text2digits("two billion five hundred thousand million", &long),
+            Ok("2500000000000".into())
+        );
+        assert_eq!(
+            text2digits("three thousand two hundred million four hundred thousand", &long),
+            Ok("3200400000".into())
+        );
+        assert_eq!(text2digits("one billionth", &long), Ok("1000000000000th".into()));
+        assert!(text2digits("one billion two thousand billion", &long).is_err());
+        assert_eq!(
+            replace_numbers_in_text("a debt of two billion pounds", &long, 10.0),
+            "a debt of 2000000000000 pounds"
+        );
+    }
+
+    #[test]
+    fn test_ordinal_markers() {
+        let lang = English::new();
+        let text = "firstly, the twentieth and the twenty-second";
+        let stripped = |ordinal_style| Options {
+            keep_ordinal_markers: false,
+            adverbial_ordinals: true,
+            ordinal_style,
+            ..Options::new(0.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with(text, &lang, &stripped(OrdinalStyle::Plain)),
+            "1, the 20 and the 22"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with(text, &lang, &stripped(OrdinalStyle::Superscript)),
+            "1, the 20 and the 22"
+        );
+        let options = Options {
+            keep_ordinal_markers: true,
+            ..stripped(OrdinalStyle::Superscript)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with(text, &lang, &options),
+            "1., the 20ᵗʰ and the 22ⁿᵈ"
+        );
+        // still ordinals
+        let occurences = find_numbers_with(tokenize(text), &lang, &stripped(OrdinalStyle::Plain));
+        assert!(occurences.iter().all(|occurence| occurence.is_ordinal));
+        assert_eq!(occurences[2].value, 22.0);
+    }
+
+    #[test]
+    fn test_ordinal_ranges() {
+        assert_replace_numbers!("chapters first to third", "chapters 1st to 3rd");
+        assert_replace_numbers!("from the first to the third", "from the 1st to the 3rd");
+        assert_replace_numbers!("second through fourth", "2nd through 4th");
+        assert_replace_numbers!("the first to twentieth", "the 1st to 20th");
+        // no range
+        assert_replace_numbers!("the first to arrive", "the first to arrive");
+        assert_replace_numbers!("the first to finish the third", "the first to finish the third");
+        assert_replace_numbers!("the first of them to three", "the first of them to three");
+    }
 }
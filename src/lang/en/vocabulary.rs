@@ -1,5 +1,58 @@
-use phf::{Set, phf_set};
+use phf::{Map, Set, phf_map, phf_set};
 
 pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
     "and", "ha", "ah", "hu", "hum", "minus", "more", "ok", "plus", "so", "that's", "then", "uh", "well", "yeah", "yes", "is"
 };
+
+/// Numeral nouns of the "*a pair of*" frame
+pub static QUANTITY_NOUNS: Map<&'static str, u64> = phf_map! {
+    "pair" => 2, "trio" => 3, "dozen" => 12, "score" => 20, "gross" => 144
+};
+
+/// Words ending in 's' that are not plural nouns
+pub static NOT_PLURAL: Set<&'static str> = phf_set! {
+    "his", "hers", "its", "ours", "yours", "theirs", "this", "was", "is", "us", "yes", "thus", "less", "various"
+};
+
+/// The lemmas of the number words, but "*and*", and the scale words in plural
+pub static NUMBER_WORDS: Set<&'static str> = phf_set! {
+    "zero", "nought", "o", "oh",
+    "one", "first", "oneth", "two", "second", "three", "third", "four", "fourth", "five", "fifth",
+    "six", "sixth", "seven", "seventh", "eight", "eighth", "nine", "ninth", "nineth",
+    "ten", "tenth", "eleven", "eleventh", "twelve", "twelfth", "twelvth", "twelveth",
+    "thirteen", "thirteenth", "fourteen", "fourteenth", "fifteen", "fifteenth", "sixteen", "sixteenth",
+    "seventeen", "seventeenth", "eighteen", "eighteenth", "nineteen", "nineteenth",
+    "twenty", "twentieth", "thirty", "thirtieth", "fourty", "forty", "fortieth", "fourtieth",
+    "fifty", "fiftieth", "sixty", "sixtieth", "seventy", "seventieth", "eighty", "eightieth",
+    "ninety", "ninetieth", "hundred", "hundredth", "thousand", "thousandth",
+    "million", "millionth", "billion", "billionth", "trillion", "trillionth",
+    "hundreds", "thousands", "millions", "billions", "trillions"
+};
+
+/// Vague quantifiers, like "*a few*", "*several*"
+pub static VAGUE_QUANTIFIERS: Set<&'static str> = phf_set! {
+    "few", "several", "many", "couple"
+};
+
+/// The month names, but "*may*", which is also a verb
+pub static MONTHS: Set<&'static str> = phf_set! {
+    "january", "february", "march", "april", "june", "july", "august", "september", "october",
+    "november", "december"
+};
+
+/// The determiners after which "*one*" is a pronoun, like "*the one*", "*which one*"
+pub static PRONOUN_DETERMINERS: Set<&'static str> = phf_set! {
+    "the", "this", "that", "which", "no", "any", "each", "every", "another"
+};
+
+/// The relative markers before which "*one*" is a pronoun, like "*one who*"
+pub static RELATIVE_MARKERS: Set<&'static str> = phf_set! {
+    "who", "whom", "whose", "that", "which", "where"
+};
+
+/// The measure nouns and the partitive "*of*", before which "*one*" is a number, like "*the one meter rule*"
+pub static MEASURE_CONTEXTS: Set<&'static str> = phf_set! {
+    "of", "meter", "metre", "kilometer", "kilometre", "centimeter", "centimetre", "millimeter", "millimetre",
+    "mile", "inch", "foot", "yard", "gram", "kilogram", "kilo", "pound", "ounce", "liter", "litre", "gallon",
+    "percent", "degree", "dollar", "euro", "cent", "hour", "minute", "second", "day", "week", "month", "year"
+};
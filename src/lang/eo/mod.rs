@@ -0,0 +1,368 @@
+//! Esperanto number interpreter
+//!
+//! Tens and hundreds are fused words ("dudek", "ducent"), the teens are not ("dek unu").
+//! Ordinals add the adjective ending to the last word ("dudek kvina", "la 25-an de majo")
+//! and keep it on the digit form: "dudeka" is "20-a".
+//! The words may be written in the x-system, with "ux" for "ŭ": "naux".
+
+use alloc::{format, string::String, vec::Vec};
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker, sorted_vocabulary};
+use vocabulary::{INSIGNIFICANT, NUMBER_WORDS};
+
+/// The adjective endings of the ordinals, with their digit form: singular, plural, and their accusatives.
+const ORDINAL_ENDINGS: [(&str, &str); 4] =
+    [("ajn", "-ajn"), ("aj", "-aj"), ("an", "-an"), ("a", "-a")];
+
+/// Return the cardinal form of `word` and the suffix of its digit form if it is an ordinal, or `word` itself.
+fn lemmatize(word: &str) -> (&str, Option<&'static str>) {
+    ORDINAL_ENDINGS
+        .iter()
+        .find_map(|(ending, suffix)| {
+            let stem = word.strip_suffix(ending)?;
+            // "miliona" and "miliarda" are built on "miliono" and "miliardo"
+            (matches!(stem, "mil" | "milion" | "miliard") || cardinal(stem).is_some())
+                .then_some((stem, Some(*suffix)))
+        })
+        .unwrap_or((word, None))
+}
+
+/// The digits of the units, tens and hundreds.
+fn cardinal(word: &str) -> Option<&'static [u8]> {
+    Some(match word {
+        "unu" => b"1",
+        "du" => b"2",
+        "tri" => b"3",
+        "kvar" => b"4",
+        "kvin" => b"5",
+        "ses" => b"6",
+        "sep" => b"7",
+        "ok" => b"8",
+        "naŭ" | "naux" => b"9",
+        "dek" => b"10",
+        "dudek" => b"20",
+        "tridek" => b"30",
+        "kvardek" => b"40",
+        "kvindek" => b"50",
+        "sesdek" => b"60",
+        "sepdek" => b"70",
+        "okdek" => b"80",
+        "naŭdek" | "nauxdek" => b"90",
+        "cent" => b"100",
+        "ducent" => b"200",
+        "tricent" => b"300",
+        "kvarcent" => b"400",
+        "kvincent" => b"500",
+        "sescent" => b"600",
+        "sepcent" => b"700",
+        "okcent" => b"800",
+        "naŭcent" | "nauxcent" => b"900",
+        _ => return None,
+    })
+}
+
+/// Return true if `lemma` is a number word, ordinal endings removed.
+fn is_number_word(lemma: &str) -> bool {
+    matches!(
+        lemma,
+        "nul"
+            | "nulo"
+            | "mil"
+            | "miliono"
+            | "milionoj"
+            | "milion"
+            | "miliardo"
+            | "miliardoj"
+            | "miliard"
+    ) || cardinal(lemma).is_some()
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Esperanto {}
+
+impl Esperanto {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl LangInterpreter for Esperanto {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        // bare plural scale words are vague quantities: "milionoj da homoj"
+        if b.is_empty() && matches!(num_func, "milionoj" | "miliardoj") {
+            return Err(Error::NaN);
+        }
+        let (lemma, ordinal) = lemmatize(num_func);
+        let status = match lemma {
+            "nul" | "nulo" => b.put(b"0"),
+            "mil" if b.is_range_free(3, 5) => b.shift(3),
+            "miliono" | "milionoj" | "milion" if b.is_range_free(6, 8) => b.shift(6),
+            "miliardo" | "miliardoj" | "miliard" if b.is_range_free(9, 11) => b.shift(9),
+            word => match cardinal(word) {
+                Some(digits) => b.put(digits),
+                None => Err(Error::NaN),
+            },
+        };
+        if status.is_ok()
+            && let Some(suffix) = ordinal
+        {
+            b.marker = MorphologicalMarker::Ordinal(suffix.into());
+            b.freeze();
+        }
+        status
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        match lemmatize(word).1 {
+            Some(suffix) => MorphologicalMarker::Ordinal(suffix.into()),
+            None => MorphologicalMarker::None,
+        }
+    }
+
+    fn language_code(&self) -> &'static str {
+        "eo"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        // the ordinals are the cardinals with an adjective ending
+        let ordinals = NUMBER_WORDS.iter().flat_map(|word| {
+            ORDINAL_ENDINGS
+                .iter()
+                .map(move |(ending, _)| format!("{word}{ending}"))
+                .filter(|ordinal| lemmatize(ordinal).1.is_some())
+        });
+        sorted_vocabulary(NUMBER_WORDS.iter().map(|word| String::from(*word)).chain(ordinals))
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        match word {
+            "komo" => Some(','),
+            _ => None,
+        }
+    }
+
+    fn decimal_symbol(&self) -> char {
+        ','
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "tri kontraŭ du"
+        &["kontraŭ"]
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        // bare plural scale words are vague quantities
+        !matches!(word, "milionoj" | "miliardoj") && is_number_word(lemmatize(word).0)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        !matches!(word, "nul" | "nulo") && is_number_word(lemmatize(word).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Esperanto::new();
+            let res = text2digits($text, &f);
+            crate::tests::dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Esperanto::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Esperanto::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Esperanto::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    /// The Esperanto words of `n`, below one billion.
+    fn spell(n: u64) -> String {
+        const UNITS: [&str; 10] = [
+            "", "unu", "du", "tri", "kvar", "kvin", "ses", "sep", "ok", "naŭ",
+        ];
+        fn below_thousand(n: u64, words: &mut Vec<String>) {
+            let (hundreds, tens, units) = (n / 100, n / 10 % 10, n % 10);
+            if hundreds > 0 {
+                words.push(alloc::format!(
+                    "{}cent",
+                    if hundreds > 1 {
+                        UNITS[hundreds as usize]
+                    } else {
+                        ""
+                    }
+                ));
+            }
+            if tens > 0 {
+                words.push(alloc::format!(
+                    "{}dek",
+                    if tens > 1 { UNITS[tens as usize] } else { "" }
+                ));
+            }
+            if units > 0 {
+                words.push(UNITS[units as usize].to_string());
+            }
+        }
+        if n == 0 {
+            return "nul".to_string();
+        }
+        let mut words = Vec::new();
+        let (millions, thousands, rest) = (n / 1_000_000, n / 1000 % 1000, n % 1000);
+        if millions > 0 {
+            below_thousand(millions, &mut words);
+            words.push(if millions > 1 { "milionoj" } else { "miliono" }.to_string());
+        }
+        if thousands > 1 {
+            below_thousand(thousands, &mut words);
+        }
+        if thousands > 0 {
+            words.push("mil".to_string());
+        }
+        below_thousand(rest, &mut words);
+        words.join(" ")
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("unu", "1");
+        assert_text2digits!("dek", "10");
+        assert_text2digits!("dek unu", "11");
+        assert_text2digits!("dudek", "20");
+        assert_text2digits!("dudek tri", "23");
+        assert_text2digits!("cent", "100");
+        assert_text2digits!("ducent tridek kvin", "235");
+        assert_text2digits!("naŭcent naŭdek naŭ", "999");
+        assert_text2digits!("mil", "1000");
+        assert_text2digits!("mil naŭcent okdek kvar", "1984");
+        assert_text2digits!("du mil", "2000");
+        assert_text2digits!("dudek kvin mil", "25000");
+        assert_text2digits!("unu miliono", "1000000");
+        assert_text2digits!("tri milionoj ducent mil", "3200000");
+        assert_text2digits!("kvin miliardoj", "5000000000");
+    }
+
+    #[test]
+    fn test_x_system() {
+        assert_text2digits!("naux", "9");
+        assert_text2digits!("nauxcent nauxdek naux", "999");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let eo = Esperanto::new();
+        for n in (0..=20_000).chain((20_000..1_000_000_000).step_by(7_654_321)) {
+            let words = spell(n);
+            assert_eq!(text2digits(&words, &eo), Ok(n.to_string()), "{words}");
+        }
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("unua", "1-a");
+        assert_text2digits!("dua", "2-a");
+        assert_text2digits!("dudeka", "20-a");
+        assert_text2digits!("dudek kvina", "25-a");
+        assert_text2digits!("centa", "100-a");
+        assert_text2digits!("mila", "1000-a");
+        assert_text2digits!("miliona", "1000000-a");
+        assert_text2digits!("dudek kvinan", "25-an");
+        assert_text2digits!("unuaj", "1-aj");
+        assert_invalid!("unua du");
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("nul", "0");
+        assert_text2digits!("nul ok", "08");
+        assert_invalid!("kvin nul");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("dek dudek");
+        assert_invalid!("kvin cent");
+        assert_invalid!("mil mil");
+        assert_invalid!("sesdek dek kvin");
+        assert_invalid!("milionoj");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "Ni havas dudek kvin bovinojn, dek du kokidojn kaj cent dudek kvin kilogramojn da terpomoj.",
+            "Ni havas 25 bovinojn, 12 kokidojn kaj 125 kilogramojn da terpomoj."
+        );
+        assert_replace_numbers!("Mil ducent sesdek ses eŭroj", "1266 eŭroj");
+        assert_replace_numbers!("unu du tri kvar dudek dek kvin", "1 2 3 4 20 15");
+        assert_replace_numbers!("milionoj da steloj", "milionoj da steloj");
+    }
+
+    #[test]
+    fn test_replace_decimals() {
+        assert_replace_numbers!("dek du komo naŭdek naŭ", "12,99");
+        assert_replace_all_numbers!("tri komo kvin", "3,5");
+    }
+
+    #[test]
+    fn test_replace_ordinals() {
+        assert_replace_numbers!("la dudek kvinan de majo", "la 25-an de majo");
+        assert_replace_all_numbers!("la kvina tago, la unua klaso", "la 5-a tago, la 1-a klaso");
+    }
+
+    #[test]
+    fn test_word_class_queries() {
+        let eo = Esperanto::new();
+        assert!(eo.can_start_number("dudek"));
+        assert!(eo.can_start_number("kvina"));
+        assert!(eo.can_start_number("mil"));
+        assert!(!eo.can_start_number("milionoj"));
+        assert!(!eo.can_start_number("kaj"));
+        assert!(eo.can_continue("milionoj"));
+        assert!(eo.can_continue("kvinan"));
+        assert!(!eo.can_continue("nul"));
+        assert!(!eo.can_continue("komo"));
+        assert!(!eo.can_continue("bovinojn"));
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("DUCENT TRIDEK KVIN", "235");
+        assert_replace_all_numbers!("Dudek Tri", "23");
+    }
+}
@@ -0,0 +1,17 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "kaj", "plus", "minus", "oble", "estas", "nu", "ehm", "do", "poste", "ankoraŭ"
+};
+
+/// The number words, inflected by [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "nul", "nulo", "unu", "du", "tri", "kvar", "kvin", "ses", "sep", "ok", "naŭ", "naux", "dek",
+    "dudek", "tridek", "kvardek", "kvindek", "sesdek", "sepdek", "okdek", "naŭdek", "nauxdek",
+    "cent", "ducent", "tricent", "kvarcent", "kvincent", "sescent", "sepcent", "okcent", "naŭcent",
+    "nauxcent", "mil", "miliono", "milionoj", "miliardo", "miliardoj", "unua", "dua", "tria",
+    "kvara", "kvina", "sesa", "sepa", "oka", "naŭa", "nauxa", "deka", "dudeka", "trideka",
+    "kvardeka", "kvindeka", "sesdeka", "sepdeka", "okdeka", "naŭdeka", "nauxdeka", "centa",
+    "ducenta", "tricenta", "kvarcenta", "kvincenta", "sescenta", "sepcenta", "okcenta", "naŭcenta",
+    "nauxcenta", "mila", "miliona", "miliarda",
+];
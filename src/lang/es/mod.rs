@@ -1,14 +1,28 @@
 //! Spanish number interpreter
 
-use alloc::{format, string::String};
+use alloc::{format, string::String, vec::Vec};
+
+use bitflags::bitflags;
 
 use crate::digit_string::DigitString;
 use crate::error::Error;
+use crate::options::OrdinalStyle;
 
 mod vocabulary;
 
-use super::{LangInterpreter, MorphologicalMarker};
-use vocabulary::INSIGNIFICANT;
+use super::{
+    ANNOTATION_CONTEXT, Affix, BasicAnnotate, Denominator, LangInterpreter, MorphologicalMarker,
+    Scale, sorted_vocabulary,
+};
+use vocabulary::{INSIGNIFICANT, NUMBER_WORDS, VAGUE_QUANTIFIERS};
+
+bitflags! {
+    /// Pending constructions that depend on the next word.
+    struct Pending: u64 {
+        /// "*treinta y*" waits for the unit of "*treinta y dos*"
+        const UNIT = 1;
+    }
+}
 
 fn lemmatize(word: &str) -> &str {
     // brute, blind removal of 's' ending is enough here
@@ -21,21 +35,140 @@ fn lemmatize(word: &str) -> &str {
     }
 }
 
-#[derive(Default)]
-pub struct Spanish {}
+/// Return true if `word` is one of the number words, inflected or fused.
+fn is_number_word(word: &str) -> bool {
+    let lemma = lemmatize(word);
+    NUMBER_WORDS.iter().any(|known| lemmatize(known) == lemma) || split_fused_ordinal(word).is_some()
+}
+
+/// The ordinal scale words of a thousand or more, that make an ordinal of the whole cardinal before them:
+/// "*seis millonésimo*" is the 6 000 000th.
+fn is_ordinal_scale(word: &str) -> bool {
+    matches!(
+        lemmatize(word),
+        "milésimo"
+            | "milésima"
+            | "millonésimo"
+            | "millonésima"
+            | "billonésimo"
+            | "billonésima"
+            | "trillonésimo"
+            | "trillonésima"
+    )
+}
+
+/// The stems of the tens ordinals, as written in the fused ordinals like "*vigesimoprimero*",
+/// with their stand-alone spelling.
+const FUSED_TENS: [(&str, &str); 9] = [
+    ("decim", "décim"),
+    ("vigesim", "vigésim"),
+    ("trigesim", "trigésim"),
+    ("cuadragesim", "cuadragésim"),
+    ("quincuagesim", "quincuagésim"),
+    ("sexagesim", "sexagésim"),
+    ("septuagesim", "septuagésim"),
+    ("octogesim", "octogésim"),
+    ("nonagesim", "nonagésim"),
+];
+
+/// Split a fused ordinal like "*vigesimoprimeras*" into its tens ("*vigésimas*") and unit ("*primeras*") ordinals.
+///
+/// The tens of a fused ordinal are usually invariable ("*vigesimoprimera*") but agree with the unit once split.
+fn split_fused_ordinal(word: &str) -> Option<(String, &str)> {
+    FUSED_TENS.iter().find_map(|(fused, stem)| {
+        let unit = word
+            .strip_prefix(fused)?
+            .strip_prefix(['o', 'a'])
+            .filter(|unit| is_unit_ordinal(lemmatize(unit)))?;
+        let gender = if lemmatize(unit).ends_with('a') { 'a' } else { 'o' };
+        let plural = if word.ends_with('s') { "s" } else { "" };
+        Some((format!("{stem}{gender}{plural}"), unit))
+    })
+}
+
+fn is_unit_ordinal(word: &str) -> bool {
+    matches!(
+        word,
+        "primero"
+            | "primera"
+            | "segundo"
+            | "segunda"
+            | "tercero"
+            | "tercera"
+            | "cuarto"
+            | "cuarta"
+            | "quinto"
+            | "quinta"
+            | "sexto"
+            | "sexta"
+            | "séptimo"
+            | "séptima"
+            | "septimo"
+            | "septima"
+            | "octavo"
+            | "octava"
+            | "noveno"
+            | "novena"
+    )
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Spanish {
+    scale: Scale,
+    cents: bool,
+}
+
+impl Default for Spanish {
+    fn default() -> Self {
+        Spanish::with_scale(Scale::Long)
+    }
+}
 
 impl Spanish {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Spanish with "*billón*" and "*trillón*" on the given scale: long by default, as in the norm,
+    /// where a "*billón*" is a million millions and 10⁹ is "*mil millones*".
+    pub fn with_scale(scale: Scale) -> Self {
+        Spanish {
+            scale,
+            cents: false,
+        }
+    }
+
+    /// Also read the "*con*" of prices as a decimal comma before the cents, that are two digits:
+    /// "*tres con cincuenta*" is 3,50 and "*tres con cinco*" is 3,05.
+    ///
+    /// Otherwise "*con*" is a linking word, as in "*dos con tres*".
+    pub fn with_cents(self, cents: bool) -> Self {
+        Spanish { cents, ..self }
+    }
 }
 
 impl LangInterpreter for Spanish {
     fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
         let num_marker = self.get_morph_marker(num_func);
-        if !b.is_empty() && num_marker != b.marker && !num_marker.is_fraction() {
+        // "seis millonésimo": the ordinal scale word makes an ordinal of the cardinal
+        let promoted = b.marker.is_none() && is_ordinal_scale(num_func);
+        if !b.is_empty() && num_marker != b.marker && !num_marker.is_fraction() && !promoted {
             return Err(Error::Overlap);
         }
+        if Pending::from_bits_truncate(b.flags).contains(Pending::UNIT) {
+            b.flags = 0;
+            let mut unit = DigitString::new();
+            if self.apply(num_func, &mut unit).is_err() || unit.len() != 1 || unit.is_null() {
+                return Err(Error::NaN);
+            }
+        }
+        // bare plural scale words are vague quantities: "millones de personas"
+        if b.is_empty() && matches!(
+                num_func,
+                "cientos" | "miles" | "millones" | "billones" | "trillones"
+            ) {
+            return Err(Error::NaN);
+        }
         let status = match lemmatize(num_func) {
             "cero" => b.put(b"0"),
             "un" | "uno" | "una" if b.peek(2) != b"10" && b.peek(2) != b"20" => b.put(b"1"),
@@ -52,12 +185,12 @@ impl LangInterpreter for Spanish {
             "seis" if b.peek(2) != b"10" && b.peek(2) != b"20" => b.put(b"6"),
             "sexto" | "sexta" => b.put(b"6"),
             "siete" if b.peek(2) != b"10" && b.peek(2) != b"20" => b.put(b"7"),
-            "séptimo" | "séptima" => b.put(b"7"),
+            "séptimo" | "séptima" | "septimo" | "septima" => b.put(b"7"),
             "ocho" if b.peek(2) != b"10" && b.peek(2) != b"20" => b.put(b"8"),
             "octavo" | "octava" => b.put(b"8"),
             "nueve" if b.peek(2) != b"10" && b.peek(2) != b"20" => b.put(b"9"),
             "noveno" | "novena" => b.put(b"9"),
-            "diez" | "décimo" | "décima" => b.put(b"10"),
+            "diez" | "décimo" | "décima" | "decimo" | "decima" => b.put(b"10"),
             "once" | "undécimo" | "undécima" | "decimoprimero" | "decimoprimera" | "onceavo" => {
                 b.put(b"11")
             }
@@ -73,7 +206,7 @@ impl LangInterpreter for Spanish {
             "diecisiete" | "decimoséptimo" | "decimoséptima" | "diecisieteavo" => b.put(b"17"),
             "dieciocho" | "decimoctavo" | "decimoctava" | "dieciochoavo" => b.put(b"18"),
             "diecinueve" | "decimonoveno" | "decimonovena" | "decinueveavo" => b.put(b"19"),
-            "veinte" | "vigésimo" | "vigésima" | "veintavo" | "veinteavo" => b.put(b"20"),
+            "veinte" | "vigésimo" | "vigésima" | "vigesimo" | "vigesima" | "veintavo" | "veinteavo" => b.put(b"20"),
             "veintiuno" | "veintiuna" | "veintiunoavo" => b.put(b"21"),
             "veintidós" | "veintidos" | "veintidosavo" => b.put(b"22"),
             "veintitrés" | "veintitres" | "veintitresavo" => b.put(b"23"),
@@ -83,14 +216,14 @@ impl LangInterpreter for Spanish {
             "veintisiete" | "veintisieteavo" => b.put(b"27"),
             "veintiocho" | "veintiochoavo" => b.put(b"28"),
             "veintinueve" | "veintinueveavo" => b.put(b"29"),
-            "treinta" | "trigésimo" | "trigésima" | "treintavo" => b.put(b"30"),
-            "cuarenta" | "cuadragésimo" | "cuadragésima" | "cuarentavo" => b.put(b"40"),
-            "cincuenta" | "quincuagésimo" | "quincuagésima" | "cincuentavo" => b.put(b"50"),
-            "sesenta" | "sexagésimo" | "sexagésima" | "sesentavo" => b.put(b"60"),
-            "setenta" | "septuagésimo" | "septuagésima" | "setentavo" => b.put(b"70"),
-            "ochenta" | "octogésimo" | "octogésima" | "ochentavo" => b.put(b"80"),
-            "noventa" | "nonagésimo" | "nonagésima" | "noventavo" => b.put(b"90"),
-            "cien" | "ciento" | "centésimo" | "centésima" | "centavo" => b.put(b"100"),
+            "treinta" | "trigésimo" | "trigésima" | "trigesimo" | "trigesima" | "treintavo" => b.put(b"30"),
+            "cuarenta" | "cuadragésimo" | "cuadragésima" | "cuadragesimo" | "cuadragesima" | "cuarentavo" => b.put(b"40"),
+            "cincuenta" | "quincuagésimo" | "quincuagésima" | "quincuagesimo" | "quincuagesima" | "cincuentavo" => b.put(b"50"),
+            "sesenta" | "sexagésimo" | "sexagésima" | "sexagesimo" | "sexagesima" | "sesentavo" => b.put(b"60"),
+            "setenta" | "septuagésimo" | "septuagésima" | "septuagesimo" | "septuagesima" | "setentavo" => b.put(b"70"),
+            "ochenta" | "octogésimo" | "octogésima" | "octogesimo" | "octogesima" | "ochentavo" => b.put(b"80"),
+            "noventa" | "nonagésimo" | "nonagésima" | "nonagesimo" | "nonagesima" | "noventavo" => b.put(b"90"),
+            "cien" | "ciento" | "centésimo" | "centésima" | "centesimo" | "centesima" | "centavo" => b.put(b"100"),
             "dosciento" | "doscienta" | "ducentésimo" | "ducentésima" => b.put(b"200"),
             "tresciento" | "trescienta" | "tricentésimo" | "tricentésima" => b.put(b"300"),
             "cuatrociento" | "cuatrocienta" | "quadringentésimo" | "quadringentésima" => {
@@ -112,9 +245,26 @@ impl LangInterpreter for Spanish {
             "millon" | "millón" | "millonésimo" | "millonésima" if b.is_range_free(6, 8) => {
                 b.shift(6)
             }
-            "y" if b.len() >= 2 => Err(Error::Incomplete),
+            "billon" | "billón" | "billonésimo" | "billonésima" => b.shift(self.scale.illion(2)),
+            "trillon" | "trillón" | "trillonésimo" | "trillonésima" => {
+                b.shift(self.scale.illion(3))
+            }
+            // only between the tens and the units: "treinta y dos", but "ciento dos"
+            "y" if matches!(b.peek(2), [b'3'..=b'9', b'0']) => {
+                b.flags = Pending::UNIT.bits();
+                Err(Error::Incomplete)
+            }
 
-            _ => Err(Error::NaN),
+            _ => match split_fused_ordinal(num_func) {
+                // "vigesimoprimero" is "vigésimo primero"
+                Some((tens, unit)) => {
+                    let mut fused = b.clone();
+                    self.apply(&tens, &mut fused)
+                        .and_then(|_| self.apply(unit, &mut fused))
+                        .map(|_| *b = fused)
+                }
+                None => Err(Error::NaN),
+            },
         };
         if status.is_ok() {
             b.marker = num_marker;
@@ -129,60 +279,181 @@ impl LangInterpreter for Spanish {
         self.apply(decimal_func, b)
     }
 
+    fn language_code(&self) -> &'static str {
+        "es"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        // the ordinals in plural: "*los primeros*", "*tres onceavos*"
+        let plurals = NUMBER_WORDS
+            .iter()
+            .filter(|word| !self.get_morph_marker(word).is_none())
+            .filter_map(|word| {
+                let plural = format!("{word}s");
+                (lemmatize(&plural) == *word && !self.get_morph_marker(&plural).is_none()).then_some(plural)
+            });
+        sorted_vocabulary(NUMBER_WORDS.iter().map(|word| String::from(*word)).chain(plurals))
+    }
+
     fn check_decimal_separator(&self, word: &str) -> Option<char> {
         match word {
             "coma" => Some(','),
             "punto" => Some('.'),
+            "con" if self.cents => Some(','),
             _ => None,
         }
     }
 
+    fn decimal_symbol(&self) -> char {
+        ','
+    }
+
+    fn decimal_width(&self, word: &str) -> Option<usize> {
+        (word == "con" && self.cents).then_some(2)
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "tres a dos"
+        &["a"]
+    }
+
     fn format_and_value(&self, b: &DigitString) -> (String, f64) {
         let val: f64 = b.parse() as f64;
         match b.marker {
             MorphologicalMarker::Fraction(_) => (format!("1/{b}"), val.recip()),
-            MorphologicalMarker::Ordinal(marker) => (format!("{b}{marker}"), val),
-            MorphologicalMarker::None => (alloc::string::ToString::to_string(&b), val),
+            marker => (self.format_marked(b, marker), val),
         }
     }
 
     fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
-        let sing = lemmatize(word).trim_start_matches("decimo");
+        if let Some((_, unit)) = split_fused_ordinal(word) {
+            return self.get_morph_marker(unit);
+        }
+        let lemma = lemmatize(word);
+        let sing = lemma
+            .strip_prefix("decimo")
+            .filter(|unit| !unit.is_empty())
+            .unwrap_or(lemma);
         let is_plur = word.ends_with('s');
         match sing {
-            "primer" => MorphologicalMarker::Ordinal(".ᵉʳ"),
+            "primer" => MorphologicalMarker::Ordinal(".ᵉʳ".into()),
             "primero" | "segundo" | "tercero" | "cuarto" | "quinto" | "sexto" | "séptimo"
             | "octavo" | "ctavo" | "noveno" => {
-                MorphologicalMarker::Ordinal(if is_plur { "ᵒˢ" } else { "º" })
+                MorphologicalMarker::Ordinal((if is_plur { ".ᵒˢ" } else { ".º" }).into())
             }
             "primera" | "segunda" | "tercera" | "cuarta" | "quinta" | "sexta" | "séptima"
             | "octava" | "ctava" | "novena" => {
-                MorphologicalMarker::Ordinal(if is_plur { "ᵃˢ" } else { "ª" })
+                MorphologicalMarker::Ordinal((if is_plur { ".ᵃˢ" } else { ".ª" }).into())
             }
             ord if ord.ends_with("imo") => {
-                MorphologicalMarker::Ordinal(if is_plur { "ᵒˢ" } else { "º" })
+                MorphologicalMarker::Ordinal((if is_plur { ".ᵒˢ" } else { ".º" }).into())
             }
             ord if ord.ends_with("ima") => {
-                MorphologicalMarker::Ordinal(if is_plur { "ᵃˢ" } else { "ª" })
+                MorphologicalMarker::Ordinal((if is_plur { ".ᵃˢ" } else { ".ª" }).into())
             }
-            ord if ord.ends_with("avo") => MorphologicalMarker::Fraction("avo"),
+            ord if ord.ends_with("avo") => MorphologicalMarker::Fraction("avo".into()),
             _ => MorphologicalMarker::None,
         }
     }
 
+    fn styled_ordinal(&self, _b: &DigitString, affix: Affix, style: OrdinalStyle) -> Option<Affix> {
+        match style {
+            OrdinalStyle::Plain | OrdinalStyle::Long => None,
+            // the plural and apocopated markers are already in superscript
+            OrdinalStyle::Superscript => Some(match affix.suffix {
+                ".º" => ".ᵒ".into(),
+                ".ª" => ".ᵃ".into(),
+                _ => affix,
+            }),
+            OrdinalStyle::PeriodOnly => Some(".".into()),
+        }
+    }
+
     fn is_linking(&self, word: &str) -> bool {
         INSIGNIFICANT.contains(word)
     }
+
+    fn is_vague_quantifier(&self, word: &str) -> bool {
+        VAGUE_QUANTIFIERS.contains(word)
+    }
+
+    fn basic_annotate<T: BasicAnnotate>(&self, tokens: &mut Vec<T>) {
+        // the article of a vague quantifier is no number, whatever the threshold: "unos cuantos cientos"
+        for i in 0..tokens.len() {
+            let article = tokens[i].text_lowercase();
+            if article != "unos" && article != "unas" {
+                continue;
+            }
+            let quantifier = tokens[i + 1..]
+                .iter()
+                .take(ANNOTATION_CONTEXT)
+                .map(BasicAnnotate::text_lowercase)
+                .find(|word| !word.chars().all(char::is_whitespace))
+                .is_some_and(|next| VAGUE_QUANTIFIERS.contains(next));
+            if quantifier {
+                tokens[i].set_nan(true);
+            }
+        }
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        // the words of the vocabulary, in context
+        is_number_word(word) && super::dry_run_start(self, word)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        (word == "y" || is_number_word(word)) && super::dry_run_continue(self, word)
+    }
+
+    fn adverbial_ordinal(&self, word: &str) -> Option<u64> {
+        // "primeramente", "segundamente"…
+        let ordinal = word.strip_suffix("mente")?;
+        self.exec_group(core::iter::once(ordinal))
+            .ok()
+            .filter(DigitString::is_ordinal)
+            .map(|ds| ds.parse())
+    }
+
+    fn format_adverbial_ordinal(&self, rank: u64) -> String {
+        format!("{rank}.º")
+    }
+
+    fn fraction_denominator(&self, word: &str) -> Option<Denominator> {
+        // "tercio" is no ordinal, and neither are the "-avos": "onceavos", "veinteavos"…
+        if matches!(word, "tercio" | "tercios") {
+            return Some(Denominator {
+                value: 3,
+                needs_context: false,
+            });
+        }
+        let ds = self.exec_group(core::iter::once(word)).ok()?;
+        let rank: u64 = ds.parse();
+        if rank < 3 || ds.marker.is_none() {
+            return None;
+        }
+        Some(Denominator {
+            value: rank,
+            needs_context: ds.is_ordinal() && !word.ends_with('s'),
+        })
+    }
+
+    fn is_fraction_context(&self, word: &str) -> bool {
+        matches!(word, "de" | "del")
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+    use crate::Options;
+    use crate::tokenizer::tokenize;
+    use crate::word_to_digit::{
+        find_numbers, replace_numbers_in_text, replace_numbers_in_text_with, text2digits,
+    };
 
     macro_rules! assert_text2digits {
         ($text:expr, $res:expr) => {
-            let f = Spanish {};
+            let f = Spanish::new();
             let res = text2digits($text, &f);
             crate::tests::dbg!(&res);
             assert!(res.is_ok());
@@ -192,21 +463,21 @@ mod tests {
 
     macro_rules! assert_replace_numbers {
         ($text:expr, $res:expr) => {
-            let f = Spanish {};
+            let f = Spanish::new();
             assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
         };
     }
 
     macro_rules! assert_replace_all_numbers {
         ($text:expr, $res:expr) => {
-            let f = Spanish {};
+            let f = Spanish::new();
             assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
         };
     }
 
     macro_rules! assert_invalid {
         ($text:expr) => {
-            let f = Spanish {};
+            let f = Spanish::new();
             let res = text2digits($text, &f);
             assert!(res.is_err());
         };
@@ -214,7 +485,7 @@ mod tests {
 
     #[test]
     fn test_apply_steps() {
-        let f = Spanish {};
+        let f = Spanish::new();
         let mut b = DigitString::new();
         assert!(f.apply("treinta", &mut b).is_ok());
         assert!(f.apply("cuatro", &mut b).is_ok());
@@ -274,22 +545,76 @@ mod tests {
     fn test_variants() {
         assert_text2digits!("un millon", "1000000");
         assert_text2digits!("un millón", "1000000");
-        assert_text2digits!("décimo primero", "11º");
-        assert_text2digits!("decimoprimero", "11º");
-        assert_text2digits!("undécimo", "11º");
-        assert_text2digits!("décimo segundo", "12º");
-        assert_text2digits!("decimosegundo", "12º");
-        assert_text2digits!("duodécimo", "12º");
+        assert_text2digits!("décimo primero", "11.º");
+        assert_text2digits!("decimoprimero", "11.º");
+        assert_text2digits!("undécimo", "11.º");
+        assert_text2digits!("décimo segundo", "12.º");
+        assert_text2digits!("decimosegundo", "12.º");
+        assert_text2digits!("duodécimo", "12.º");
     }
 
     #[test]
     fn test_ordinals() {
-        assert_text2digits!("vigésimo cuarto", "24º");
-        assert_text2digits!("vigésimo primero", "21º");
-        assert_text2digits!("centésimo primero", "101º");
-        assert_text2digits!("decimosexta", "16ª");
-        assert_text2digits!("decimosextas", "16ᵃˢ");
-        assert_text2digits!("decimosextos", "16ᵒˢ");
+        assert_text2digits!("vigésimo cuarto", "24.º");
+        assert_text2digits!("vigésimo primero", "21.º");
+        assert_text2digits!("centésimo primero", "101.º");
+        assert_text2digits!("decimosexta", "16.ª");
+        assert_text2digits!("decimosextas", "16.ᵃˢ");
+        assert_text2digits!("decimosextos", "16.ᵒˢ");
+        assert_text2digits!("segunda", "2.ª");
+        assert_text2digits!("vigésimo segundo", "22.º");
+        assert_text2digits!("vigésima segunda", "22.ª");
+        assert_text2digits!("primer", "1.ᵉʳ");
+    }
+
+    #[test]
+    fn test_scale_ordinals() {
+        // from a thousand on, the scale word makes an ordinal of the cardinal before it
+        assert_text2digits!("tres milésimo", "3000.º");
+        assert_text2digits!("doscientos milésimo", "200000.º");
+        assert_text2digits!("seis millonésimo", "6000000.º");
+        assert_text2digits!("seis millonésima", "6000000.ª");
+        assert_text2digits!("tres milésimo primero", "3001.º");
+        assert_text2digits!("ducentésimo", "200.º");
+        assert_invalid!("seis millonésimo tres");
+        assert_replace_numbers!("el seis millonésimo visitante", "el 6000000.º visitante");
+    }
+
+    #[test]
+    fn test_ordinals_11_30() {
+        let units = [
+            "primero", "segundo", "tercero", "cuarto", "quinto", "sexto", "séptimo", "octavo",
+            "noveno",
+        ];
+        for (i, unit) in units.iter().enumerate() {
+            let feminine = alloc::format!("{}a", unit.trim_end_matches('o'));
+            for (tens, fused, n) in [("décimo", "decimo", 11 + i), ("vigésimo", "vigesimo", 21 + i)] {
+                let expected = alloc::format!("{n}.º");
+                assert_text2digits!(alloc::format!("{fused}{unit}").as_str(), expected);
+                assert_text2digits!(alloc::format!("{tens} {unit}").as_str(), expected);
+                let expected = alloc::format!("{n}.ª");
+                assert_text2digits!(alloc::format!("{fused}{feminine}").as_str(), expected);
+                let tens = alloc::format!("{}a", tens.trim_end_matches('o'));
+                assert_text2digits!(alloc::format!("{tens} {feminine}").as_str(), expected);
+            }
+        }
+        assert_text2digits!("undécimo", "11.º");
+        assert_text2digits!("duodécima", "12.ª");
+        assert_text2digits!("decimoctavo", "18.º");
+        assert_text2digits!("vigésimo", "20.º");
+        assert_text2digits!("trigésima", "30.ª");
+        assert_text2digits!("vigesimoprimeros", "21.ᵒˢ");
+        assert_text2digits!("vigesimaprimera", "21.ª");
+        assert_text2digits!("nonagesimonoveno", "99.º");
+        assert_text2digits!("centésimo", "100.º");
+        // without accents
+        assert_text2digits!("vigesimo primero", "21.º");
+        assert_invalid!("vigesimodos");
+        // cardinals used as ordinals stay cardinals
+        assert_replace_all_numbers!(
+            "el capítulo veinte de la vigesimoprimera edición",
+            "el capítulo 20 de la 21.ª edición"
+        );
     }
 
     #[test]
@@ -355,6 +680,18 @@ mod tests {
     #[test]
     fn test_and() {
         assert_replace_numbers!("cincuenta sesenta treinta y once", "50 60 30 y 11");
+        assert_text2digits!("treinta y dos", "32");
+        assert_text2digits!("cuarenta y cinco mil", "45000");
+        assert_text2digits!("treinta y un mil", "31000");
+        // "y" only links the tens and the units
+        assert_invalid!("ciento y dos");
+        assert_invalid!("mil y dos");
+        assert_invalid!("veinte y dos");
+        assert_invalid!("treinta y mil");
+        assert_invalid!("treinta y cero");
+        assert_replace_all_numbers!("dos y tres gatos", "2 y 3 gatos");
+        assert_replace_all_numbers!("ciento y dos", "100 y 2");
+        assert_replace_all_numbers!("cuarenta y cinco mil y tres", "45000 y 3");
     }
 
     #[test]
@@ -370,22 +707,22 @@ mod tests {
     fn test_replace_numbers_ordinals() {
         assert_replace_numbers!(
             "Cuarto quinto segundo tercero vigésimo primero centésimo milésimo ducentésimo trigésimo.",
-            "4º 5º segundo 3º 21º 100230º."
+            "4.º 5.º segundo 3.º 21.º 100230.º."
         );
-        assert_replace_numbers!("centésimo trigésimo segundo", "132º");
-        assert_replace_numbers!("centésimo, trigésimo, segundo", "100º, 30º, segundo");
+        assert_replace_numbers!("centésimo trigésimo segundo", "132.º");
+        assert_replace_numbers!("centésimo, trigésimo, segundo", "100.º, 30.º, segundo");
         assert_replace_numbers!(
             "Un segundo por favor! Vigésimo segundo es diferente que veinte segundos.",
-            "Un segundo por favor! 22º es diferente que 20 segundos."
+            "Un segundo por favor! 22.º es diferente que 20 segundos."
         );
         assert_replace_numbers!(
             "Un segundo por favor! Vigésimos segundos es diferente que veinte segundos.",
-            "Un segundo por favor! 22ᵒˢ es diferente que 20 segundos."
+            "Un segundo por favor! 22.ᵒˢ es diferente que 20 segundos."
         );
-        assert_replace_all_numbers!("Él ha quedado tercero", "Él ha quedado 3º");
-        assert_replace_all_numbers!("Ella ha quedado tercera", "Ella ha quedado 3ª");
-        assert_replace_all_numbers!("Ellos han quedado terceros", "Ellos han quedado 3ᵒˢ");
-        assert_replace_all_numbers!("Ellas han quedado terceras", "Ellas han quedado 3ᵃˢ");
+        assert_replace_all_numbers!("Él ha quedado tercero", "Él ha quedado 3.º");
+        assert_replace_all_numbers!("Ella ha quedado tercera", "Ella ha quedado 3.ª");
+        assert_replace_all_numbers!("Ellos han quedado terceros", "Ellos han quedado 3.ᵒˢ");
+        assert_replace_all_numbers!("Ellas han quedado terceras", "Ellas han quedado 3.ᵃˢ");
     }
 
     #[test]
@@ -401,6 +738,55 @@ mod tests {
         assert_replace_numbers!("cero coma cuatrocientos uno", "0,401");
     }
 
+    #[test]
+    fn test_decimal_words() {
+        assert_replace_numbers!("tres coma cinco", "3,5");
+        assert_replace_numbers!("dos punto cinco", "2.5");
+        assert_replace_numbers!("tres con cincuenta", "3 con 50");
+        // "punto" figured as a comma too
+        let f = Spanish::new();
+        let options = Options {
+            decimal_separators: vec![("punto".into(), ',')],
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("dos punto cinco y tres coma cinco", &f, &options),
+            "2,5 y 3,5"
+        );
+    }
+
+    #[test]
+    fn test_cents() {
+        let f = Spanish::new().with_cents(true);
+        let replace = |text| replace_numbers_in_text(text, &f, 10.0);
+        assert_eq!(replace("tres con cincuenta"), "3,50");
+        assert_eq!(replace("son veinte con noventa y nueve"), "son 20,99");
+        // the cents are two digits
+        assert_eq!(replace("tres con cinco"), "3,05");
+        assert_eq!(replace("tres con cero"), "3,00");
+        assert_eq!(replace("tres con cero cinco"), "3,05");
+        assert_eq!(replace("tres con ciento veinte"), "3 con 120");
+        assert_eq!(replace("tres con cincuenta y cinco cinco"), "3,55 5");
+        // the other separators keep their decimals as spoken
+        assert_eq!(replace("tres coma cinco"), "3,5");
+        assert_eq!(replace("dos punto cinco"), "2.5");
+        let occurences = find_numbers(tokenize("tres con cinco"), &f, 10.0);
+        assert_eq!(occurences[0].value, 3.05);
+        assert_eq!(occurences[0].decimal_separator, Some(','));
+        let morphology = occurences[0].morphology.as_ref().unwrap();
+        assert_eq!(morphology.separator_word.as_deref(), Some("con"));
+    }
+
+    #[test]
+    fn test_replace_decimal_scales() {
+        assert_replace_numbers!(
+            "dos coma cinco millones de habitantes",
+            "2500000 de habitantes"
+        );
+        assert_replace_numbers!("tres coma cinco mil", "3500");
+        assert_replace_numbers!("uno coma veinticinco millones", "1250000");
+    }
+
     #[test]
     fn test_isolates() {
         assert_replace_numbers!(
@@ -417,4 +803,110 @@ mod tests {
             "Entonces 2 con 3 con 7 y 8 mas 4 menos 5 son 9 exacto"
         );
     }
+
+    #[test]
+    fn test_adverbial_ordinals() {
+        assert_replace_all_numbers!("Primeramente firmamos, segundamente salimos.", "Primeramente firmamos, segundamente salimos.");
+        let f = Spanish::default();
+        let options = Options {
+            adverbial_ordinals: true,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("Primeramente firmamos, segundamente salimos.", &f, &options),
+            "1.º firmamos, 2.º salimos."
+        );
+    }
+
+    #[test]
+    fn test_vague_quantities() {
+        assert_replace_all_numbers!("millones de personas", "millones de personas");
+        assert_replace_all_numbers!("miles de veces, cientos de coches", "miles de veces, cientos de coches");
+        assert_replace_all_numbers!("dos millones de personas", "2000000 de personas");
+    }
+
+    #[test]
+    fn test_fractions_in_text() {
+        assert_replace_all_numbers!("un quinto de los usuarios", "1/5 de los usuarios");
+        assert_replace_all_numbers!("el quinto día", "el 5.º día");
+        assert_replace_all_numbers!("dos quintos, tres cuartos, un tercio", "2/5, 3/4, 1/3");
+        assert_replace_all_numbers!("dos onceavos", "2/11");
+        assert_replace_all_numbers!("los dos primeros", "los 2 1.ᵒˢ");
+    }
+
+    #[test]
+    fn test_fraction_markers() {
+        let lang = Spanish::default();
+        let options = |keep| Options {
+            keep_ordinal_markers: keep,
+            keep_fraction_markers: keep,
+            ..Options::new(0.0)
+        };
+        let text = "el onceavo de la tarta y la tercera vez, tres quintos";
+        assert_eq!(
+            replace_numbers_in_text_with(text, &lang, &options(true)),
+            "el 1/11 de la tarta y la 3.ª vez, 3/5"
+        );
+        // the fraction keeps its slash, not to read as the number 11
+        assert_eq!(
+            replace_numbers_in_text_with(text, &lang, &options(false)),
+            "el 1/11 de la tarta y la 3 vez, 3/5"
+        );
+        // the marker a formatter writes
+        let plain = |keep| options(keep).with_formatter(Box::new(crate::formatter::Plain));
+        assert_eq!(
+            replace_numbers_in_text_with("el onceavo", &lang, &plain(true)),
+            "el 11avo"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("el onceavo", &lang, &plain(false)),
+            "el 1/11"
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("VEINTITRÉS", "23");
+        assert_replace_all_numbers!("EL VIGÉSIMO PRIMERO", "EL 21.º");
+    }
+
+    #[test]
+    fn test_vague_quantifiers() {
+        assert_replace_numbers!("unos cuantos miles de euros", "unos cuantos miles de euros");
+        assert_replace_numbers!("varios millones de personas", "varios millones de personas");
+        assert_replace_numbers!("varios cientos de kilómetros", "varios cientos de kilómetros");
+        assert_replace_numbers!("trescientas personas", "300 personas");
+        assert_replace_numbers!("tres mil euros", "3000 euros");
+        // the article of the quantifier is no number either, whatever the threshold
+        assert_replace_all_numbers!("unos cuantos cientos de euros", "unos cuantos cientos de euros");
+        assert_replace_all_numbers!("unas cuantas personas", "unas cuantas personas");
+        assert_replace_all_numbers!("unos varios miles", "unos varios miles");
+        assert_replace_all_numbers!("varios cientos de kilómetros", "varios cientos de kilómetros");
+    }
+
+    #[test]
+    fn test_scales() {
+        let long = Spanish::new();
+        let short = Spanish::with_scale(Scale::Short);
+        assert_eq!(text2digits("un billón", &long), Ok("1000000000000".into()));
+        assert_eq!(text2digits("un billón", &short), Ok("1000000000".into()));
+        assert_eq!(text2digits("dos billones", &long), Ok("2000000000000".into()));
+        assert_eq!(text2digits("un trillón", &long), Ok("1000000000000000000".into()));
+        assert_eq!(text2digits("un trillón", &short), Ok("1000000000000".into()));
+        assert_eq!(text2digits("mil millones", &long), Ok("1000000000".into()));
+        assert_eq!(
+            text2digits("un billón quinientos mil millones", &long),
+            Ok("1500000000000".into())
+        );
+        assert_eq!(
+            text2digits("tres mil doscientos millones cuatrocientos mil", &long),
+            Ok("3200400000".into())
+        );
+        assert!(text2digits("un billón dos mil billones", &long).is_err());
+        assert_eq!(
+            replace_numbers_in_text("una deuda de dos billones de euros", &long, 10.0),
+            "una deuda de 2000000000000 de euros"
+        );
+        assert_replace_all_numbers!("billones de estrellas", "billones de estrellas");
+    }
 }
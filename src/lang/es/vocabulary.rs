@@ -3,3 +3,43 @@ use phf::{Set, phf_set};
 pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
     "pues", "y", "digo", "o", "sea", "entonces", "así", "que", "bueno", "es", "eso", "en", "fin", "luego", "mas", "menos", "pero", "vale", "eh", "ah", "oye", "ya", "hum", "ok", "sí", "no", "con", "son"
 };
+
+/// Vague quantifiers, like "*unos cuantos*", "*varios*"
+pub static VAGUE_QUANTIFIERS: Set<&'static str> = phf_set! {
+    "cuantos", "cuantas", "varios", "varias"
+};
+
+/// The number words, inflected by [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "cero", "un", "uno", "una", "primer", "primero", "primera", "dos", "segunda", "tres", "tercer",
+    "tercero", "tercera", "cuatro", "cuarto", "cuarta", "cinco", "quinto", "quinta", "seis",
+    "sexto", "sexta", "siete", "séptimo", "séptima", "septimo", "septima", "ocho", "octavo",
+    "octava", "nueve", "noveno", "novena", "diez", "décimo", "décima", "decimo", "decima", "once",
+    "undécimo", "undécima", "decimoprimero", "decimoprimera", "onceavo", "doce", "duodécimo",
+    "duodécima", "decimosegundo", "decimosegunda", "doceavo", "trece", "decimotercero",
+    "decimotercera", "treceavo", "catorce", "decimocuarto", "decimocuarta", "catorceavo", "quince",
+    "decimoquinto", "decimoquinta", "quinceavo", "dieciseis", "dieciséis", "decimosexto",
+    "decimosexta", "deciseisavo", "diecisiete", "decimoséptimo", "decimoséptima", "diecisieteavo",
+    "dieciocho", "decimoctavo", "decimoctava", "dieciochoavo", "diecinueve", "decimonoveno",
+    "decimonovena", "decinueveavo", "veinte", "vigésimo", "vigésima", "vigesimo", "vigesima",
+    "veintavo", "veinteavo", "veintiuno", "veintiuna", "veintiunoavo", "veintidós", "veintidosavo",
+    "veintitrés", "veintitresavo", "veinticuatro", "veinticuatroavo", "veinticinco",
+    "veinticincoavo", "veintiseis", "veintiséis", "veintiseisavo", "veintisiete", "veintisieteavo",
+    "veintiocho", "veintiochoavo", "veintinueve", "veintinueveavo", "treinta", "trigésimo",
+    "trigésima", "trigesimo", "trigesima", "treintavo", "cuarenta", "cuadragésimo", "cuadragésima",
+    "cuadragesimo", "cuadragesima", "cuarentavo", "cincuenta", "quincuagésimo", "quincuagésima",
+    "quincuagesimo", "quincuagesima", "cincuentavo", "sesenta", "sexagésimo", "sexagésima",
+    "sexagesimo", "sexagesima", "sesentavo", "setenta", "septuagésimo", "septuagésima",
+    "septuagesimo", "septuagesima", "setentavo", "ochenta", "octogésimo", "octogésima",
+    "octogesimo", "octogesima", "ochentavo", "noventa", "nonagésimo", "nonagésima", "nonagesimo",
+    "nonagesima", "noventavo", "cien", "ciento", "centésimo", "centésima", "centesimo", "centesima",
+    "centavo", "doscientos", "doscientas", "ducentésimo", "ducentésima", "trescientos",
+    "trescientas", "tricentésimo", "tricentésima", "cuatrocientos", "cuatrocientas",
+    "quadringentésimo", "quadringentésima", "quinientos", "quinientas", "quingentésimo",
+    "quingentésima", "seiscientos", "seiscientas", "sexcentésimo", "sexcentésima", "setecientos",
+    "setecientas", "septingentésimo", "septingentésima", "ochocientos", "ochocientas",
+    "octingentésimo", "octingentésima", "novecientos", "novecientas", "noningentésimo",
+    "noningentésima", "mil", "milésimo", "milésima", "millon", "millón", "millonésimo",
+    "millonésima", "billon", "billón", "billonésimo", "billonésima", "trillon", "trillón",
+    "trillonésimo", "trillonésima", "miles", "millones", "billones", "trillones",
+];
@@ -0,0 +1,382 @@
+//! Persian number interpreter
+//!
+//! The elements of a number are joined by "و": "صد و بیست و سه", that may also be attached to the next word:
+//! "صد وبیست وسه".
+//! Ordinals end in "م" or "مین" ("بیست و سوم", "بیست و سومین") and keep that ending on the digit form ("23م").
+//! Numbers are written with ASCII digits; the [`PersianDigits`](crate::formatter::PersianDigits) formatter
+//! writes them with Persian digits.
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use bitflags::bitflags;
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::options::OrdinalStyle;
+
+mod vocabulary;
+
+use super::{Affix, LangInterpreter, MorphologicalMarker, sorted_vocabulary};
+use vocabulary::{INSIGNIFICANT, NUMBER_WORDS};
+
+bitflags! {
+    /// word chaining restrictions
+    struct Restriction: u64 {
+        const CONJUNCTION = 1;
+    }
+}
+
+/// `word` with the Arabic forms of "ی" and "ک", that Arabic keyboards produce, replaced by the Persian ones.
+fn normalize(word: &str) -> Cow<'_, str> {
+    if word.contains(['ي', 'ك']) {
+        Cow::Owned(word.replace('ي', "ی").replace('ك', "ک"))
+    } else {
+        Cow::Borrowed(word)
+    }
+}
+
+/// The ordinal endings, with their digit form: "سی‌امین", "سیام", "سومین", "سوم".
+const ORDINAL_ENDINGS: [(&str, &str); 6] = [
+    ("\u{200c}امین", "امین"),
+    ("امین", "امین"),
+    ("مین", "مین"),
+    ("\u{200c}ام", "ام"),
+    ("ام", "ام"),
+    ("م", "م"),
+];
+
+/// Return the cardinal form of `word` and the suffix of its digit form if it is an ordinal, or `word` itself.
+fn lemmatize(word: &str) -> (&str, Option<&'static str>) {
+    match word {
+        "اول" => return ("یک", Some("م")),
+        "اولین" => return ("یک", Some("مین")),
+        _ => (),
+    }
+    ORDINAL_ENDINGS
+        .iter()
+        .find_map(|(ending, suffix)| {
+            let stem = word.strip_suffix(ending)?;
+            // "سوم" is the ordinal of "سه"
+            let stem = if stem == "سو" { "سه" } else { stem };
+            is_cardinal(stem).then_some((stem, Some(*suffix)))
+        })
+        .unwrap_or((word, None))
+}
+
+fn is_cardinal(word: &str) -> bool {
+    let mut b = DigitString::new();
+    Persian::new().apply_cardinal(word, &mut b).is_ok()
+}
+
+/// Return true if `word` is one of the number words, inflected.
+fn is_number_word(word: &str) -> bool {
+    let word = normalize(word);
+    // the ordinals are made of the cardinals
+    NUMBER_WORDS.contains(&lemmatize(&word).0)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Persian {}
+
+impl Persian {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn apply_cardinal(&self, word: &str, b: &mut DigitString) -> Result<(), Error> {
+        match word {
+            "صفر" => b.put(b"0"),
+            "یک" if b.peek(2) != b"10" => b.put(b"1"),
+            "دو" if b.peek(2) != b"10" => b.put(b"2"),
+            "سه" if b.peek(2) != b"10" => b.put(b"3"),
+            "چهار" if b.peek(2) != b"10" => b.put(b"4"),
+            "پنج" if b.peek(2) != b"10" => b.put(b"5"),
+            "شش" | "شیش" if b.peek(2) != b"10" => b.put(b"6"),
+            "هفت" if b.peek(2) != b"10" => b.put(b"7"),
+            "هشت" if b.peek(2) != b"10" => b.put(b"8"),
+            "نه" if b.peek(2) != b"10" => b.put(b"9"),
+            "ده" => b.put(b"10"),
+            "یازده" => b.put(b"11"),
+            "دوازده" => b.put(b"12"),
+            "سیزده" => b.put(b"13"),
+            "چهارده" => b.put(b"14"),
+            "پانزده" => b.put(b"15"),
+            "شانزده" => b.put(b"16"),
+            "هفده" => b.put(b"17"),
+            "هجده" | "هیجده" => b.put(b"18"),
+            "نوزده" => b.put(b"19"),
+            "بیست" => b.put(b"20"),
+            "سی" => b.put(b"30"),
+            "چهل" => b.put(b"40"),
+            "پنجاه" => b.put(b"50"),
+            "شصت" => b.put(b"60"),
+            "هفتاد" => b.put(b"70"),
+            "هشتاد" => b.put(b"80"),
+            "نود" => b.put(b"90"),
+            "صد" | "یکصد" => b.put(b"100"),
+            "دویست" => b.put(b"200"),
+            "سیصد" => b.put(b"300"),
+            "چهارصد" => b.put(b"400"),
+            "پانصد" => b.put(b"500"),
+            "ششصد" => b.put(b"600"),
+            "هفتصد" => b.put(b"700"),
+            "هشتصد" => b.put(b"800"),
+            "نهصد" => b.put(b"900"),
+            "هزار" if b.is_range_free(3, 5) => b.shift(3),
+            "میلیون" if b.is_range_free(6, 8) => b.shift(6),
+            "میلیارد" if b.is_range_free(9, 11) => b.shift(9),
+            _ => Err(Error::NaN),
+        }
+    }
+
+    /// "و" may join the next element to the number, but not twice in a row, nor after an ordinal.
+    fn can_join(b: &DigitString) -> bool {
+        !b.is_empty()
+            && b.marker.is_none()
+            && !Restriction::from_bits_truncate(b.flags).contains(Restriction::CONJUNCTION)
+    }
+}
+
+impl LangInterpreter for Persian {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        let word = normalize(num_func);
+        if word == "و" {
+            return if Self::can_join(b) {
+                b.flags = Restriction::CONJUNCTION.bits();
+                Err(Error::Incomplete)
+            } else {
+                Err(Error::NaN)
+            };
+        }
+        // "وسه": the conjunction attached to the next element
+        let (joined, word) = match word.strip_prefix('و') {
+            Some(rest) if !rest.is_empty() => (true, rest),
+            _ => (false, word.as_ref()),
+        };
+        if joined && !Self::can_join(b) {
+            return Err(Error::NaN);
+        }
+        let (lemma, ordinal) = lemmatize(word);
+        let status = self.apply_cardinal(lemma, b);
+        if status.is_ok() {
+            b.flags = 0;
+            if let Some(suffix) = ordinal {
+                b.marker = MorphologicalMarker::Ordinal(suffix.into());
+                b.freeze();
+            }
+        }
+        status
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        let word = normalize(word);
+        let word = word.strip_prefix('و').unwrap_or(&word);
+        match lemmatize(word).1 {
+            Some(suffix) => MorphologicalMarker::Ordinal(suffix.into()),
+            None => MorphologicalMarker::None,
+        }
+    }
+
+    fn styled_ordinal(
+        &self,
+        _b: &DigitString,
+        _affix: Affix,
+        _style: OrdinalStyle,
+    ) -> Option<Affix> {
+        // a period doesn't mark ordinals in Persian, and the endings have no superscript form
+        None
+    }
+
+    fn language_code(&self) -> &'static str {
+        "fa"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        // the ordinals are the cardinals with an ordinal ending
+        let ordinals = NUMBER_WORDS.iter().flat_map(|word| {
+            ORDINAL_ENDINGS
+                .iter()
+                .map(move |(ending, _)| format!("{word}{ending}"))
+                .filter(|ordinal| lemmatize(ordinal).1.is_some())
+        });
+        sorted_vocabulary(NUMBER_WORDS.iter().map(|word| String::from(*word)).chain(ordinals))
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        match word {
+            "ممیز" => Some('.'),
+            _ => None,
+        }
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "سه بر دو"
+        &["بر"]
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(normalize(word).as_ref())
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        // the words of the vocabulary, in context
+        is_number_word(word) && super::dry_run_start(self, word)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        (word == "و" || is_number_word(word)) && super::dry_run_continue(self, word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Options;
+    use crate::formatter::PersianDigits;
+    use crate::word_to_digit::{
+        replace_numbers_in_text, replace_numbers_in_text_with, text2digits,
+    };
+    use alloc::boxed::Box;
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Persian::new();
+            let res = text2digits($text, &f);
+            crate::tests::dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Persian::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Persian::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Persian::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("یک", "1");
+        assert_text2digits!("نوزده", "19");
+        assert_text2digits!("بیست و سه", "23");
+        assert_text2digits!("صد", "100");
+        assert_text2digits!("صد و بیست و سه", "123");
+        assert_text2digits!("دویست و پنج", "205");
+        assert_text2digits!("نهصد و نود و نه", "999");
+        assert_text2digits!("هزار", "1000");
+        assert_text2digits!("هزار و صد", "1100");
+        assert_text2digits!("سه هزار و پانصد", "3500");
+        assert_text2digits!("بیست و پنج هزار", "25000");
+        assert_text2digits!("صد و بیست هزار و سیصد", "120300");
+        assert_text2digits!("یک میلیون", "1000000");
+        assert_text2digits!("دو میلیون و سیصد هزار", "2300000");
+        assert_text2digits!("پنج میلیارد", "5000000000");
+    }
+
+    #[test]
+    fn test_attached_conjunction() {
+        assert_text2digits!("بیست وسه", "23");
+        assert_text2digits!("صد وبیست وسه", "123");
+        assert_text2digits!("سه هزار وپانصد", "3500");
+        assert_invalid!("وسه");
+        assert_invalid!("بیست و وسه");
+    }
+
+    #[test]
+    fn test_arabic_letters() {
+        assert_text2digits!("يك", "1");
+        assert_text2digits!("بيست و سه", "23");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("اول", "1م");
+        assert_text2digits!("یکم", "1م");
+        assert_text2digits!("دوم", "2م");
+        assert_text2digits!("سوم", "3م");
+        assert_text2digits!("سومین", "3مین");
+        assert_text2digits!("نهم", "9م");
+        assert_text2digits!("بیست و سوم", "23م");
+        assert_text2digits!("بیست وسومین", "23مین");
+        assert_text2digits!("سی\u{200c}ام", "30ام");
+        assert_text2digits!("صدم", "100م");
+        assert_text2digits!("هزارم", "1000م");
+        assert_invalid!("سوم و بیست");
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("صفر", "0");
+        assert_text2digits!("صفر هشت", "08");
+        assert_invalid!("پنج صفر");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("ده پنج");
+        assert_invalid!("پنج صد");
+        assert_invalid!("هزار هزار");
+        assert_invalid!("شصت پانزده");
+        assert_invalid!("بیست و");
+        assert_invalid!("و بیست");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!("من بیست و سه سال دارم.", "من 23 سال دارم.");
+        assert_replace_numbers!(
+            "او صد و بیست و پنج کتاب و دوازده دفتر خرید.",
+            "او 125 کتاب و 12 دفتر خرید."
+        );
+        assert_replace_all_numbers!("دو و سه", "2 و 3");
+        assert_replace_all_numbers!("یک دو سه چهار بیست پانزده", "1 2 3 4 20 15");
+    }
+
+    #[test]
+    fn test_replace_decimals() {
+        assert_replace_numbers!("دوازده ممیز نود و نه", "12.99");
+        assert_replace_all_numbers!("سه ممیز پنج", "3.5");
+    }
+
+    #[test]
+    fn test_replace_ordinals() {
+        assert_replace_numbers!("او بیست و سومین نفر بود.", "او 23مین نفر بود.");
+        assert_replace_all_numbers!("روز پنجم", "روز 5م");
+    }
+
+    #[test]
+    fn test_persian_digits() {
+        let options = Options::new(0.0).with_formatter(Box::new(PersianDigits));
+        assert_eq!(
+            replace_numbers_in_text_with(
+                "صد و بیست و سه کتاب، بیست و سومین روز، سه ممیز پنج",
+                &Persian::new(),
+                &options
+            ),
+            "۱۲۳ کتاب، ۲۳مین روز، ۳٫۵"
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "و", "بعلاوه", "منهای", "ضربدر", "است", "هست", "خب", "یعنی", "بعد", "هم"
+};
+
+/// The number words, inflected by [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "صفر", "یک", "دو", "سه", "چهار", "پنج", "شش", "شیش", "هفت", "هشت", "نه", "ده", "یازده",
+    "دوازده", "سیزده", "چهارده", "پانزده", "شانزده", "هفده", "هجده", "هیجده", "نوزده", "بیست", "سی",
+    "چهل", "پنجاه", "شصت", "هفتاد", "هشتاد", "نود", "صد", "یکصد", "دویست", "سیصد", "چهارصد",
+    "پانصد", "ششصد", "هفتصد", "هشتصد", "نهصد", "هزار", "میلیون", "میلیارد", "اول", "اولین", "یکم", "دوم",
+    "چهارم", "پنجم", "ششم", "شیشم", "هفتم", "هشتم", "نهم", "دهم", "یازدهم", "دوازدهم", "سیزدهم",
+    "چهاردهم", "پانزدهم", "شانزدهم", "هفدهم", "هجدهم", "هیجدهم", "نوزدهم", "بیستم", "سی‌ام", "چهلم",
+    "پنجاهم", "شصتم", "هفتادم", "هشتادم", "نودم", "صدم", "یکصدم", "دویستم", "سیصدم", "چهارصدم",
+    "پانصدم", "ششصدم", "هفتصدم", "هشتصدم", "نهصدم", "هزارم", "میلیونم", "میلیاردم", "سوم",
+];
@@ -2,17 +2,18 @@
 //!
 //! It supports regional variants.
 
-use alloc::vec::Vec;
+use alloc::{format, string::String, vec::Vec};
 
 use bitflags::bitflags;
 
 use crate::digit_string::DigitString;
 use crate::error::Error;
+use crate::options::{Options, OrdinalStyle};
 
 mod vocabulary;
 
-use super::{BasicAnnotate, LangInterpreter, MorphologicalMarker};
-use vocabulary::INSIGNIFICANT;
+use super::{Affix, BasicAnnotate, Denominator, LangInterpreter, MorphologicalMarker, sorted_vocabulary};
+use vocabulary::{INSIGNIFICANT, MONTHS, NUMBER_WORDS, VAGUE_QUANTIFIERS};
 
 fn lemmatize(word: &str) -> &str {
     // brute, blind removal of 's' ending is enough here
@@ -23,7 +24,7 @@ fn lemmatize(word: &str) -> &str {
     }
 }
 
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct French {}
 
 impl French {
@@ -46,6 +47,9 @@ bitflags! {
     }
 }
 
+/// The decimals were read digit by digit so far (a flag of [`DigitString::flags`], next to [`Excludable`])
+const DIGIT_BY_DIGIT: u64 = 1 << 32;
+
 impl LangInterpreter for French {
     fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
         // In French, numbers can be compounded to form a group with "-"
@@ -66,6 +70,10 @@ impl LangInterpreter for French {
                 Err(err) => Err(err),
             };
         }
+        // bare plural scale words are vague quantities: "des millions de gens"
+        if b.is_empty() && matches!(num_func, "cents" | "millions" | "milliards") {
+            return Err(Error::NaN);
+        }
         let blocked = Excludable::from_bits_truncate(b.flags);
         let mut to_block = Excludable::empty();
 
@@ -161,7 +169,7 @@ impl LangInterpreter for French {
                 to_block = Excludable::UN;
                 b.put(b"70")
             }
-            "huitante" | "huitantiène" => {
+            "huitante" | "huitantième" => {
                 to_block = Excludable::UN;
                 b.put(b"80")
             }
@@ -225,35 +233,203 @@ impl LangInterpreter for French {
     }
 
     fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
-        self.apply(decimal_func, b)
+        // The decimals are read as a number, "virgule quatorze", or digit by digit, "virgule un quatre".
+        let by_digit = b.flags & DIGIT_BY_DIGIT != 0;
+        let digit = match decimal_func {
+            "zéro" => Some(b"0"),
+            "un" => Some(b"1"),
+            "deux" => Some(b"2"),
+            "trois" => Some(b"3"),
+            "quatre" => Some(b"4"),
+            "cinq" => Some(b"5"),
+            "six" => Some(b"6"),
+            "sept" => Some(b"7"),
+            "huit" => Some(b"8"),
+            "neuf" => Some(b"9"),
+            _ => None,
+        };
+        match digit {
+            Some(digit) if b.is_empty() || by_digit => {
+                b.push(digit)?;
+                b.flags = DIGIT_BY_DIGIT;
+                Ok(())
+            }
+            // "deux cent trente-six" is still a number after its first digit
+            _ if by_digit && b.len() > 1 => Err(Error::NaN),
+            _ => self.apply(decimal_func, b),
+        }
+    }
+
+    fn language_code(&self) -> &'static str {
+        "fr"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        // the ordinals in plural: "*les premiers*", "*trois cinquièmes*"
+        let plurals = NUMBER_WORDS
+            .iter()
+            .filter(|word| !self.get_morph_marker(word).is_none())
+            .filter_map(|word| {
+                let plural = format!("{word}s");
+                (lemmatize(&plural) == *word && !self.get_morph_marker(&plural).is_none()).then_some(plural)
+            });
+        sorted_vocabulary(NUMBER_WORDS.iter().map(|word| String::from(*word)).chain(plurals))
+    }
+
+    fn clitics(&self) -> &'static [&'static str] {
+        // the emphatic clitic: "vingt-là"
+        &["-là"]
     }
 
     fn check_decimal_separator(&self, word: &str) -> Option<char> {
         if word == "virgule" { Some(',') } else { None }
     }
 
+    fn decimal_symbol(&self) -> char {
+        ','
+    }
+
+    fn recommended_options(&self) -> Options {
+        // a lone "un" is mostly an article
+        Options::new(10.0)
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "trois à deux"
+        &["à"]
+    }
+
+    fn range_connectors(&self) -> &'static [&'static str] {
+        // "du premier au troisième", "de la première à la troisième"
+        &["à", "à la", "au"]
+    }
+
+    fn is_month_name(&self, word: &str) -> bool {
+        MONTHS.contains(&*crate::tokenizer::lowercase(word))
+    }
+
+    fn is_cardinal_day(&self, day: u64) -> bool {
+        // "le 3 mai", but "le 1er mai"
+        day != 1
+    }
+
+    fn fraction_tails(&self) -> &'static [(&'static str, char, &'static str)] {
+        &[
+            ("et demi", ',', "5"),
+            ("et demie", ',', "5"),
+            ("et quart", ',', "25"),
+            ("et trois quarts", ',', "75"),
+        ]
+    }
+
     fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        // "5e", "80es": the abbreviations recommended by the Imprimerie nationale
         if word.ends_with("ème") {
-            MorphologicalMarker::Ordinal("ème")
+            MorphologicalMarker::Ordinal("e".into())
         } else if word.ends_with("èmes") {
-            MorphologicalMarker::Ordinal("èmes")
+            MorphologicalMarker::Ordinal("es".into())
         } else if word.ends_with("ier") {
-            MorphologicalMarker::Ordinal("er")
+            MorphologicalMarker::Ordinal("er".into())
         } else if word.ends_with("iers") {
-            MorphologicalMarker::Ordinal("ers")
+            MorphologicalMarker::Ordinal("ers".into())
         } else if word.ends_with("ière") {
-            MorphologicalMarker::Ordinal("ère")
+            MorphologicalMarker::Ordinal("re".into())
         } else if word.ends_with("ières") {
-            MorphologicalMarker::Ordinal("ères")
+            MorphologicalMarker::Ordinal("res".into())
         } else {
             MorphologicalMarker::None
         }
     }
 
+    fn styled_ordinal(&self, _b: &DigitString, affix: Affix, style: OrdinalStyle) -> Option<Affix> {
+        match style {
+            OrdinalStyle::Plain | OrdinalStyle::Long => None,
+            OrdinalStyle::Superscript => Some(
+                match affix.suffix {
+                    "er" => "ᵉʳ",
+                    "ers" => "ᵉʳˢ",
+                    "re" => "ʳᵉ",
+                    "res" => "ʳᵉˢ",
+                    "es" => "ᵉˢ",
+                    _ => "ᵉ",
+                }
+                .into(),
+            ),
+            OrdinalStyle::PeriodOnly => Some(".".into()),
+        }
+    }
+
     fn is_linking(&self, word: &str) -> bool {
         INSIGNIFICANT.contains(word)
     }
 
+    fn is_vague_quantifier(&self, word: &str) -> bool {
+        VAGUE_QUANTIFIERS.contains(word)
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        if word.contains('-') && !word.starts_with("dix-") {
+            return super::dry_run_start(self, word);
+        }
+        // bare plural scale words are vague quantities
+        !matches!(word, "cents" | "millions" | "milliards") && NUMBER_WORDS.contains(&lemmatize(word))
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        if word.contains('-') && !word.starts_with("dix-") {
+            return super::dry_run_continue(self, word);
+        }
+        // "zéro" and "premier" never follow another number word
+        let lemma = lemmatize(word);
+        word == "et" || (!matches!(lemma, "zéro" | "premier" | "première") && NUMBER_WORDS.contains(&lemma))
+    }
+
+    fn adverbial_ordinal(&self, word: &str) -> Option<u64> {
+        // "premièrement", "deuxièmement", "vingt-et-unièmement"…
+        let ordinal = word.strip_suffix("ment")?;
+        self.exec_group(core::iter::once(ordinal))
+            .ok()
+            .filter(DigitString::is_ordinal)
+            .map(|ds| ds.parse())
+    }
+
+    fn format_adverbial_ordinal(&self, rank: u64) -> String {
+        alloc::format!("{rank}°")
+    }
+
+    fn fraction_denominator(&self, word: &str) -> Option<Denominator> {
+        // "tiers" and "quarts" are no ordinals, "quart" might be a watch on a ship.
+        let (value, needs_context) = match word {
+            "tiers" => (3, false),
+            "quarts" => (4, false),
+            "quart" => (4, true),
+            _ => {
+                let singular = word.strip_suffix('s');
+                let ordinal = singular.unwrap_or(word);
+                if !ordinal.ends_with("ième") {
+                    return None;
+                }
+                let rank: u64 = self
+                    .exec_group(core::iter::once(ordinal))
+                    .ok()
+                    .filter(DigitString::is_ordinal)?
+                    .parse();
+                if rank < 3 {
+                    return None;
+                }
+                (rank, singular.is_none())
+            }
+        };
+        Some(Denominator {
+            value,
+            needs_context,
+        })
+    }
+
+    fn is_fraction_context(&self, word: &str) -> bool {
+        matches!(word, "de" | "des" | "du") || word.starts_with("d'") || word.starts_with("d’")
+    }
+
     fn basic_annotate<T: BasicAnnotate>(&self, tokens: &mut Vec<T>) {
         let mut b = DigitString::new();
         let mut true_words: Vec<usize> = Vec::with_capacity(tokens.len());
@@ -300,7 +476,8 @@ impl LangInterpreter for French {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+    use crate::Options;
+    use crate::word_to_digit::{replace_numbers_in_text, replace_numbers_in_text_with, text2digits};
 
     macro_rules! assert_text2digits {
         ($text:expr, $res:expr) => {
@@ -403,16 +580,78 @@ mod tests {
 
     #[test]
     fn test_ordinals() {
-        assert_text2digits!("vingt-cinquième", "25ème");
-        assert_text2digits!("vingt cinquième", "25ème");
-        assert_text2digits!("vingt et unième", "21ème");
+        assert_text2digits!("vingt-cinquième", "25e");
+        assert_text2digits!("vingt cinquième", "25e");
+        assert_text2digits!("vingt et unième", "21e");
+        assert_text2digits!("vingt-et-unième", "21e");
+        assert_text2digits!("premier", "1er");
+        assert_text2digits!("première", "1re");
+        assert_text2digits!("premières", "1res");
+    }
+
+    #[test]
+    fn test_scale_ordinals() {
+        assert_text2digits!("deux centième", "200e");
+        assert_text2digits!("trois millième", "3000e");
+        assert_text2digits!("vingt et un millième", "21000e");
+        assert_text2digits!("deux cent millième", "200000e");
+        assert_text2digits!("six millionième", "6000000e");
+        assert_replace_numbers!("le six millionième visiteur", "le 6000000e visiteur");
+    }
+
+    #[test]
+    fn test_ordinals_70_99() {
+        let seventies = [
+            "soixante-dixième",
+            "soixante-et-onzième",
+            "soixante-douzième",
+            "soixante-treizième",
+            "soixante-quatorzième",
+            "soixante-quinzième",
+            "soixante-seizième",
+            "soixante-dix-septième",
+            "soixante-dix-huitième",
+            "soixante-dix-neuvième",
+        ];
+        let units = [
+            "unième", "deuxième", "troisième", "quatrième", "cinquième", "sixième", "septième",
+            "huitième", "neuvième", "dixième", "onzième", "douzième", "treizième", "quatorzième",
+            "quinzième", "seizième", "dix-septième", "dix-huitième", "dix-neuvième",
+        ];
+        let mut ordinals: Vec<String> = seventies.iter().map(|w| (*w).into()).collect();
+        ordinals.push("quatre-vingtième".into());
+        ordinals.extend(units.iter().map(|w| alloc::format!("quatre-vingt-{w}")));
+        for (i, ordinal) in ordinals.iter().enumerate() {
+            let n = 70 + i;
+            assert_text2digits!(ordinal.as_str(), alloc::format!("{n}e"));
+            // the ordinal morpheme is buried in the compound, not in a word of its own
+            let spaced = ordinal.replace('-', " ");
+            assert_text2digits!(spaced.as_str(), alloc::format!("{n}e"));
+            let plural = alloc::format!("{ordinal}s");
+            assert_text2digits!(plural.as_str(), alloc::format!("{n}es"));
+        }
+        // the Belgian and Swiss forms
+        assert_text2digits!("septantième", "70e");
+        assert_text2digits!("septante-et-unième", "71e");
+        assert_text2digits!("huitantième", "80e");
+        assert_text2digits!("octantième", "80e");
+        assert_text2digits!("nonante-neuvième", "99e");
+        // and the cardinals are still cardinals
+        assert_text2digits!("soixante-et-onze", "71");
+        assert_text2digits!("quatre-vingts", "80");
+        assert_text2digits!("quatre-vingt-trois", "83");
+        assert_text2digits!("deux cent quatre-vingts", "280");
+        assert_replace_all_numbers!(
+            "le soixante-et-onzième jour, quatre-vingts jours, la quatre-vingt-unième",
+            "le 71e jour, 80 jours, la 81e"
+        );
     }
 
     #[test]
     fn test_fractions() {
-        assert_text2digits!("vingt-cinquièmes", "25èmes");
-        assert_text2digits!("vingt cinquièmes", "25èmes");
-        assert_text2digits!("vingt et unièmes", "21èmes");
+        assert_text2digits!("vingt-cinquièmes", "25es");
+        assert_text2digits!("vingt cinquièmes", "25es");
+        assert_text2digits!("vingt et unièmes", "21es");
     }
 
     #[test]
@@ -504,28 +743,28 @@ mod tests {
     fn test_replace_numbers_ordinals() {
         assert_replace_numbers!(
             "Cinquième deuxième troisième vingt et unième centième mille deux cent trentième.",
-            "5ème 2ème 3ème 21ème 100ème 1230ème."
+            "5e 2e 3e 21e 100e 1230e."
         );
-        assert_replace_numbers!("dix-neuvième", "19ème");
-        assert_replace_numbers!("quatre-vingt dix-septième", "97ème");
+        assert_replace_numbers!("dix-neuvième", "19e");
+        assert_replace_numbers!("quatre-vingt dix-septième", "97e");
         assert_replace_numbers!("première seconde", "première seconde");
         assert_replace_numbers!("premier second", "premier second");
-        assert_replace_numbers!("cinq cent unième", "501ème");
+        assert_replace_numbers!("cinq cent unième", "501e");
         assert_replace_numbers!("cinq cent premiers", "500 premiers");
         assert_replace_numbers!("cinq cent premier", "500 premier");
         assert_replace_all_numbers!("une seconde", "une seconde");
-        assert_replace_numbers!("vingt-cinquième et trentième", "25ème et 30ème");
-        assert_replace_numbers!("un centième", "un 100ème");
-        assert_replace_numbers!("un millième", "un 1000ème");
-        assert_replace_numbers!("le millionième", "le 1000000ème");
-        assert_replace_numbers!("un millionième", "un 1000000ème");
+        assert_replace_numbers!("vingt-cinquième et trentième", "25e et 30e");
+        assert_replace_numbers!("un centième", "un 100e");
+        assert_replace_numbers!("un millième", "un 1000e");
+        assert_replace_numbers!("le millionième", "le 1000000e");
+        assert_replace_numbers!("un millionième", "un 1000000e");
     }
 
     #[test]
     fn test_replace_numbers_decimals() {
         assert_replace_numbers!(
             "Douze virgule quatre-vingt-dix-neuf, cent vingt virgule zéro cinq, un virgule deux cent trente six, un virgule deux trois six.",
-            "12,99, 120,05, 1,236, 1,2 3 6."
+            "12,99, 120,05, 1,236, 1,236."
         );
         assert_replace_numbers!("zéro virgule cent douze", "0,112");
         assert_replace_numbers!(
@@ -535,6 +774,40 @@ mod tests {
         assert_replace_numbers!("Je dis virgule cinq", "Je dis virgule cinq");
     }
 
+    #[test]
+    fn test_replace_decimal_readings() {
+        // digit by digit or grouped
+        assert_replace_numbers!("trois virgule un quatre", "3,14");
+        assert_replace_numbers!("trois virgule quatorze", "3,14");
+        assert_replace_numbers!("trois virgule zéro cinq", "3,05");
+        assert_replace_numbers!("trois virgule zéro zéro sept", "3,007");
+        // a grouped reading is over after its number
+        assert_replace_numbers!("trois virgule quatorze quinze", "3,14 15");
+        assert_replace_numbers!("trois virgule un quatorze", "3,1 14");
+        assert_replace_numbers!("trois virgule un deux cent", "3,12 100");
+    }
+
+    #[test]
+    fn test_replace_fraction_tails() {
+        assert_replace_all_numbers!("deux et demi", "2,5");
+        assert_replace_all_numbers!("une livre et demie", "une livre et demie");
+        assert_replace_all_numbers!("trois et quart", "3,25");
+        assert_replace_all_numbers!("trois et trois quarts", "3,75");
+        assert_replace_numbers!("vingt et demi", "20,5");
+        assert_replace_all_numbers!("il est deux heures et demie", "il est 2 heures et demie");
+        // not a tail
+        assert_replace_numbers!("vingt et un", "21");
+        assert_replace_all_numbers!("entre deux et trois", "entre 2 et 3");
+        assert_replace_all_numbers!("un demi", "1 demi");
+    }
+
+    #[test]
+    fn test_replace_decimal_scales() {
+        assert_replace_numbers!("deux virgule cinq millions", "2500000");
+        assert_replace_numbers!("un virgule deux milliard", "1200000000");
+        assert_replace_numbers!("trois virgule quarante-cinq mille", "3450");
+    }
+
     #[test]
     fn test_isolates() {
         assert_replace_numbers!(
@@ -557,10 +830,11 @@ mod tests {
         );
         assert_replace_all_numbers!(
             "Mon premier arrive avant mon deuxième et mon troisième",
-            "Mon 1er arrive avant mon 2ème et mon 3ème"
+            "Mon 1er arrive avant mon 2e et mon 3e"
         );
-        assert_replace_numbers!("Un douzième essai", "Un 12ème essai");
-        assert_replace_numbers!("Premier, deuxième, troisième", "1er, 2ème, 3ème");
+        assert_replace_numbers!("Un douzième essai", "Un 12e essai");
+        assert_replace_numbers!("Premier, deuxième, troisième", "1er, 2e, 3e");
+        assert_replace_numbers!("Première, deuxième, troisième", "1re, 2e, 3e");
         assert_replace_numbers!("un peu d'eau", "un peu d'eau");
         assert_replace_numbers!("un peu moins", "un peu moins");
         // assert_replace_numbers!("onze c'est un peu plus", "11 c'est un peu plus");
@@ -580,4 +854,185 @@ mod tests {
             "alors 2 et 3 plus 5 euh 6 puis 7 et encore 8 moins 4 c'est bien 3"
         );
     }
+
+    #[test]
+    fn test_stutters() {
+        let f = French {};
+        let options = Options {
+            collapse_stutters: true,
+            ..Options::new(7.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("deux deux cent cinquante euros", &f, &options),
+            "250 euros"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("vingt vingt-trois mille", &f, &options),
+            "23000"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("vingt vingt-trois", &f, &options),
+            "23"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("il a vingt vingt-trois ans", &f, &options),
+            "il a 23 ans"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("cent cent vingt", &f, &options),
+            "120"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("un un deux", &f, &options),
+            "1 1 2"
+        );
+        assert_replace_numbers!("deux deux cent cinquante euros", "2 250 euros");
+    }
+
+    #[test]
+    fn test_adverbial_ordinals() {
+        assert_replace_all_numbers!("Premièrement nous signons, deuxièmement nous partons.", "Premièrement nous signons, deuxièmement nous partons.");
+        let f = French::default();
+        let options = Options {
+            adverbial_ordinals: true,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("Premièrement nous signons, deuxièmement nous partons.", &f, &options),
+            "1° nous signons, 2° nous partons."
+        );
+    }
+
+    #[test]
+    fn test_digits_passthrough() {
+        assert_replace_all_numbers!("J'ai payé 1 234,56 euros et vingt-cinq centimes.", "J'ai payé 1 234,56 euros et 25 centimes.");
+        assert_replace_all_numbers!("vingt 1,5 trente et un", "20 1,5 31");
+        assert_replace_all_numbers!("cent 250", "100 250");
+    }
+
+    #[test]
+    fn test_digits_with_scales() {
+        assert_replace_all_numbers!("3 mille euros", "3 1000 euros");
+        let f = French::default();
+        let options = Options {
+            digits_with_scales: true,
+            ..Options::new(0.0)
+        };
+        for (text, expected) in [
+            ("3 mille euros", "3000 euros"),
+            ("20 millions d'habitants", "20000000 d'habitants"),
+            ("2 cents", "200"),
+        ] {
+            assert_eq!(replace_numbers_in_text_with(text, &f, &options), expected);
+        }
+    }
+
+    #[test]
+    fn test_vague_quantities() {
+        assert_replace_all_numbers!("des millions de gens", "des millions de gens");
+        assert_replace_all_numbers!("des milliers de fois, des milliards, des cents", "des milliers de fois, des milliards, des cents");
+        assert_replace_all_numbers!("deux millions de gens", "2000000 de gens");
+    }
+
+    #[test]
+    fn test_fractions_in_text() {
+        assert_replace_all_numbers!("un cinquième des utilisateurs", "1/5 des utilisateurs");
+        assert_replace_all_numbers!("le cinquième jour", "le 5e jour");
+        assert_replace_all_numbers!("deux cinquièmes, trois quarts et un tiers", "2/5, 3/4 et 1/3");
+        assert_replace_all_numbers!("un quart d'heure", "1/4 d'heure");
+        assert_replace_all_numbers!("le premier quart", "le 1er quart");
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("VINGT-TROIS", "23");
+        assert_replace_all_numbers!("LE VINGT ET UNIÈME", "LE 21e");
+    }
+
+    #[test]
+    fn test_hyphenated_compounds() {
+        assert_replace_all_numbers!("le trente-deux-mâts", "le trente-deux-mâts");
+        let f = French::default();
+        let options = Options {
+            convert_in_hyphenated_compounds: true,
+            ..Options::new(0.0)
+        };
+        assert_eq!(replace_numbers_in_text_with("le trente-deux-mâts", &f, &options), "le 32-mâts");
+        assert_eq!(replace_numbers_in_text_with("vingt-et-un-ans", &f, &options), "21-ans");
+        assert_eq!(replace_numbers_in_text_with("vingt-et-un ans", &f, &options), "21 ans");
+    }
+
+    #[test]
+    fn test_clitics() {
+        let f = French::default();
+        let options = Options {
+            strip_clitics: true,
+            ..Options::new(0.0)
+        };
+        assert_eq!(replace_numbers_in_text_with("ces vingt-là", &f, &options), "ces 20-là");
+        assert_eq!(replace_numbers_in_text_with("les trois cents-là", &f, &options), "les 300-là");
+        assert_eq!(replace_numbers_in_text_with("ce jour-là", &f, &options), "ce jour-là");
+        assert_replace_all_numbers!("ces vingt-là", "ces vingt-là");
+    }
+
+    #[test]
+    fn test_vague_quantifiers() {
+        assert_replace_numbers!("plusieurs millions d'euros", "plusieurs millions d'euros");
+        assert_replace_numbers!("quelques milliards de dollars", "quelques milliards de dollars");
+        assert_replace_numbers!("quelques centaines de mètres", "quelques centaines de mètres");
+        assert_replace_numbers!("trois cents personnes", "300 personnes");
+        assert_replace_numbers!("trois millions d'euros", "3000000 d'euros");
+    }
+
+    #[test]
+    fn test_ordinal_markers() {
+        let lang = French::default();
+        let text = "le premier, la première et le vingt et unième";
+        let options = |keep_ordinal_markers| Options {
+            keep_ordinal_markers,
+            ordinal_style: OrdinalStyle::Superscript,
+            ..Options::new(0.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with(text, &lang, &options(true)),
+            "le 1ᵉʳ, la 1ʳᵉ et le 21ᵉ"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with(text, &lang, &options(false)),
+            "le 1, la 1 et le 21"
+        );
+    }
+
+    #[test]
+    fn test_ordinal_ranges() {
+        assert_replace_numbers!("du premier au troisième", "du 1er au 3e");
+        assert_replace_numbers!("de la première à la troisième", "de la 1re à la 3e");
+        assert_replace_numbers!("deuxième à quatrième", "2e à 4e");
+        // no range
+        assert_replace_numbers!("le premier à partir", "le premier à partir");
+        assert_replace_numbers!("le premier à trois", "le premier à trois");
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_replace_numbers!("le premier mai", "le 1er mai");
+        assert_replace_numbers!("la première Mai", "la 1re Mai");
+        // only the first of the month is an ordinal
+        assert_replace_numbers!("le troisième mai", "le 3 mai");
+        assert_replace_numbers!("le vingt et unième juin 2021", "le 21 juin 2021");
+        assert_replace_all_numbers!("le trois mai", "le 3 mai");
+        assert_replace_all_numbers!("du premier au troisième mai", "du 1er au 3 mai");
+        // no month
+        assert_replace_all_numbers!("le troisième jour de mai", "le 3e jour de mai");
+        assert_replace_numbers!("le premier de la liste", "le premier de la liste");
+        let lang = French::default();
+        let options = Options {
+            ordinal_style: OrdinalStyle::Superscript,
+            ..Options::new(0.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("le premier et le deuxième mars", &lang, &options),
+            "le 1ᵉʳ et le 2 mars"
+        );
+    }
 }
@@ -3,3 +3,27 @@ use phf::{Set, phf_set};
 pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
     "alors", "bien", "c'est", "encore", "ensuite", "et", "euh", "heu", "ha", "ah", "hu", "hum", "moins", "ok", "oui", "plus", "puis", "voilà"
 };
+
+/// Vague quantifiers, like "*quelques*", "*plusieurs*"
+pub static VAGUE_QUANTIFIERS: Set<&'static str> = phf_set! {
+    "quelques", "plusieurs"
+};
+
+/// The month names
+pub static MONTHS: Set<&'static str> = phf_set! {
+    "janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre",
+    "novembre", "décembre"
+};
+
+/// The number words, inflected by [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "zéro", "un", "unième", "premier", "première", "deux", "deuxième", "trois", "troisième",
+    "quatre", "quatrième", "cinq", "cinquième", "six", "sixième", "sept", "septième", "huit",
+    "huitième", "neuf", "neuvième", "dix", "dixième", "onze", "onzième", "douze", "douzième",
+    "treize", "treizième", "quatorze", "quatorzième", "quinze", "quinzième", "seize", "seizième",
+    "dix-sept", "dix-septième", "dix-huit", "dix-huitième", "dix-neuf", "dix-neuvième", "vingt",
+    "vingtième", "quatre-vingt", "quatre-vingts", "quatre-vingtième", "trente", "trentième", "quarante", "quarantième", "cinquante", "cinquantième",
+    "soixante", "soixantième", "septante", "septantième", "huitante", "huitantième", "octante",
+    "octantième", "nonante", "nonantième", "cent", "centième", "mille", "mil", "millième",
+    "million", "millionième", "milliard", "milliardième", "cents", "millions", "milliards",
+];
@@ -0,0 +1,158 @@
+//! Galician number interpreter
+//!
+//! The words are read from a table generated from the CLDR spellout rules (see [`DataLanguage`]):
+//! "vinte e un", "cento dous", "dous mil trescentos", with the masculine and the feminine forms.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+#[rustfmt::skip]
+mod table;
+
+use super::{DataLanguage, LangInterpreter, MorphologicalMarker};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Galician(DataLanguage);
+
+impl Default for Galician {
+    fn default() -> Self {
+        Self(DataLanguage::new(&table::TABLE))
+    }
+}
+
+impl Galician {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl LangInterpreter for Galician {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.0.apply(num_func, b)
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.0.apply_decimal(decimal_func, b)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        self.0.get_morph_marker(word)
+    }
+
+    fn language_code(&self) -> &'static str {
+        self.0.language_code()
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        self.0.vocabulary()
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        self.0.check_decimal_separator(word)
+    }
+
+    fn decimal_symbol(&self) -> char {
+        self.0.decimal_symbol()
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "tres a dous"
+        &["a"]
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        self.0.is_linking(word)
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        self.0.can_start_number(word)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        self.0.can_continue(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Galician::new();
+            let res = text2digits($text, &f);
+            crate::tests::dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Galician::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Galician::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Galician::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_basic() {
+        assert_text2digits!("cero", "0");
+        assert_text2digits!("dezasete", "17");
+        assert_text2digits!("vinte e un", "21");
+        assert_text2digits!("noventa e nove", "99");
+        assert_text2digits!("cen", "100");
+        assert_text2digits!("cento dous", "102");
+        assert_text2digits!("cento corenta e cinco", "145");
+        assert_text2digits!("novecentos noventa e nove", "999");
+        assert_text2digits!("mil", "1000");
+        assert_text2digits!("dous mil trescentos vinte e un", "2321");
+        assert_text2digits!("un millón", "1000000");
+        assert_text2digits!("tres millóns cincocentos mil", "3500000");
+        assert_text2digits!("dous mil millóns", "2000000000");
+        assert_text2digits!("un billón", "1000000000000");
+    }
+
+    #[test]
+    fn test_feminine() {
+        assert_text2digits!("unha", "1");
+        assert_text2digits!("vinte e dúas", "22");
+        assert_text2digits!("duascentas trinta e unha", "231");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("vinte e");
+        assert_invalid!("vinte e trinta");
+        assert_invalid!("once e un");
+        assert_invalid!("mil mil");
+    }
+
+    #[test]
+    fn test_replace() {
+        assert_replace_numbers!(
+            "Compramos vinte e cinco libros e dous mil cadernos",
+            "Compramos 25 libros e 2000 cadernos"
+        );
+        assert_replace_numbers!("tres coma catorce", "3,14");
+        assert_replace_numbers!("un can e tres gatos", "un can e tres gatos");
+        assert_replace_all_numbers!("un can e tres gatos", "1 can e 3 gatos");
+    }
+}
@@ -0,0 +1,73 @@
+// Generated by text2num-gen from gen/rbnf/gl.xml, do not edit.
+// To regenerate: cargo run -p text2num-gen -- --crate crate --decimal-symbol , --output src/lang/gl/table.rs gen/rbnf/gl.xml
+
+use crate::lang::LanguageTable;
+
+pub static TABLE: LanguageTable = LanguageTable {
+    code: "gl",
+    cardinals: &[
+        ("cero", "0"),
+        ("un", "1"),
+        ("dous", "2"),
+        ("tres", "3"),
+        ("catro", "4"),
+        ("cinco", "5"),
+        ("seis", "6"),
+        ("sete", "7"),
+        ("oito", "8"),
+        ("nove", "9"),
+        ("dez", "10"),
+        ("once", "11"),
+        ("doce", "12"),
+        ("trece", "13"),
+        ("catorce", "14"),
+        ("quince", "15"),
+        ("dezaseis", "16"),
+        ("dezasete", "17"),
+        ("dezaoito", "18"),
+        ("dezanove", "19"),
+        ("vinte", "20"),
+        ("trinta", "30"),
+        ("corenta", "40"),
+        ("cincuenta", "50"),
+        ("sesenta", "60"),
+        ("setenta", "70"),
+        ("oitenta", "80"),
+        ("noventa", "90"),
+        ("cen", "100"),
+        ("cento", "100"),
+        ("douscentos", "200"),
+        ("trescentos", "300"),
+        ("catrocentos", "400"),
+        ("cincocentos", "500"),
+        ("seiscentos", "600"),
+        ("setecentos", "700"),
+        ("oitocentos", "800"),
+        ("novecentos", "900"),
+        ("unha", "1"),
+        ("dúas", "2"),
+        ("duascentas", "200"),
+        ("trescentas", "300"),
+        ("catrocentas", "400"),
+        ("cincocentas", "500"),
+        ("seiscentas", "600"),
+        ("setecentas", "700"),
+        ("oitocentas", "800"),
+        ("novecentas", "900"),
+    ],
+    scales: &[
+        ("mil", 3),
+        ("millón", 6),
+        ("millóns", 6),
+        ("billón", 12),
+        ("billóns", 12),
+        ("trillón", 18),
+        ("trillóns", 18),
+    ],
+    links: &[
+        ("e", 1),
+    ],
+    decimal_separators: &[
+        ("coma", ','),
+    ],
+};
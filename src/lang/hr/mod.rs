@@ -0,0 +1,486 @@
+//! Bosnian, Croatian and Serbian number interpreter
+//!
+//! The three standards share their number words, up to a few variants ("tisuća" or "hiljada", "dvije" or "dve")
+//! that are all accepted. Words written in the Serbian Cyrillic script are transliterated to the Latin script
+//! before being interpreted: "двадесет три" is read as "dvadeset tri".
+//! Ordinals are adjectives, like "treći", "treća", "trećeg"…, written "3.".
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use bitflags::bitflags;
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker, sorted_vocabulary};
+use vocabulary::{INSIGNIFICANT, NUMBER_WORDS};
+
+bitflags! {
+    /// word chaining restrictions
+    struct Restriction: u64 {
+        const CONJUNCTION = 1;
+    }
+}
+
+/// The Latin form of `word`, if it is written in the Serbian Cyrillic script.
+fn transliterate(word: &str) -> Cow<'_, str> {
+    if !word.chars().any(|c| ('\u{400}'..='\u{4ff}').contains(&c)) {
+        return Cow::Borrowed(word);
+    }
+    let mut latin = String::with_capacity(word.len());
+    for c in word.chars() {
+        let letters = match c {
+            'а' => "a",
+            'б' => "b",
+            'в' => "v",
+            'г' => "g",
+            'д' => "d",
+            'ђ' => "đ",
+            'е' => "e",
+            'ж' => "ž",
+            'з' => "z",
+            'и' => "i",
+            'ј' => "j",
+            'к' => "k",
+            'л' => "l",
+            'љ' => "lj",
+            'м' => "m",
+            'н' => "n",
+            'њ' => "nj",
+            'о' => "o",
+            'п' => "p",
+            'р' => "r",
+            'с' => "s",
+            'т' => "t",
+            'ћ' => "ć",
+            'у' => "u",
+            'ф' => "f",
+            'х' => "h",
+            'ц' => "c",
+            'ч' => "č",
+            'џ' => "dž",
+            'ш' => "š",
+            _ => {
+                latin.push(c);
+                continue;
+            }
+        };
+        latin.push_str(letters);
+    }
+    Cow::Owned(latin)
+}
+
+/// The stems of the ordinals.
+const ORDINAL_STEMS: [&str; 42] = [
+    "prv",
+    "drug",
+    "treć",
+    "četvrt",
+    "pet",
+    "šest",
+    "sedm",
+    "osm",
+    "devet",
+    "deset",
+    "jedanaest",
+    "dvanaest",
+    "trinaest",
+    "četrnaest",
+    "petnaest",
+    "šesnaest",
+    "sedamnaest",
+    "osamnaest",
+    "devetnaest",
+    "dvadeset",
+    "trideset",
+    "četrdeset",
+    "pedeset",
+    "šezdeset",
+    "sedamdeset",
+    "osamdeset",
+    "devedeset",
+    "stot",
+    "dvjestot",
+    "dvestot",
+    "tristot",
+    "četiristot",
+    "petstot",
+    "šeststot",
+    "sedamstot",
+    "osamstot",
+    "devetstot",
+    "tisućit",
+    "hiljadit",
+    "milijunt",
+    "miliont",
+    "milijardit",
+];
+
+/// The endings of the ordinal adjectives, in all genders, numbers and cases.
+const ORDINAL_ENDINGS: [&str; 18] = [
+    "i", "a", "o", "e", "u", "og", "oga", "om", "ome", "omu", "eg", "ega", "em", "emu", "oj", "ih",
+    "im", "ima",
+];
+
+/// Return the stem of `word` and `true` if it is an ordinal, or `word` itself and `false`.
+///
+/// "šesto" is the sixth, not "šeststo" (600).
+fn lemmatize(word: &str) -> (&str, bool) {
+    ORDINAL_STEMS
+        .iter()
+        .find(|stem| {
+            word.strip_prefix(*stem)
+                .is_some_and(|ending| ORDINAL_ENDINGS.contains(&ending))
+        })
+        .map_or((word, false), |stem| (&word[..stem.len()], true))
+}
+
+/// Return true if `word`, in Latin or Cyrillic, is one of the number words, inflected.
+fn is_number_word(word: &str) -> bool {
+    let word = transliterate(word);
+    let lemma = lemmatize(&word).0;
+    NUMBER_WORDS.iter().any(|known| lemmatize(known).0 == lemma)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SerboCroatian {}
+
+impl SerboCroatian {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl LangInterpreter for SerboCroatian {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        let word = transliterate(num_func);
+        let word = word.as_ref();
+        // bare plural scale words are vague quantities: "tisuće ljudi"
+        if b.is_empty()
+            && matches!(
+                word,
+                "stotine" | "tisuće" | "hiljade" | "milijuni" | "milioni" | "milijarde"
+            )
+        {
+            return Err(Error::NaN);
+        }
+        let (lemma, is_ordinal) = lemmatize(word);
+        let status = match lemma {
+            "nula" => b.put(b"0"),
+            "jedan" | "jedna" | "jedno" | "jednu" | "jednog" | "jednom" | "prv"
+                if b.peek(2) != b"10" =>
+            {
+                b.put(b"1")
+            }
+            "dva" | "dvije" | "dve" | "dvoje" | "dvaju" | "drug" if b.peek(2) != b"10" => {
+                b.put(b"2")
+            }
+            "tri" | "troje" | "triju" | "treć" if b.peek(2) != b"10" => b.put(b"3"),
+            "četiri" | "četvero" | "četvoro" | "četvrt" if b.peek(2) != b"10" => b.put(b"4"),
+            "pet" if b.peek(2) != b"10" => b.put(b"5"),
+            "šest" if b.peek(2) != b"10" => b.put(b"6"),
+            "sedam" | "sedm" if b.peek(2) != b"10" => b.put(b"7"),
+            "osam" | "osm" if b.peek(2) != b"10" => b.put(b"8"),
+            "devet" if b.peek(2) != b"10" => b.put(b"9"),
+            "deset" => b.put(b"10"),
+            "jedanaest" => b.put(b"11"),
+            "dvanaest" => b.put(b"12"),
+            "trinaest" => b.put(b"13"),
+            "četrnaest" => b.put(b"14"),
+            "petnaest" => b.put(b"15"),
+            "šesnaest" => b.put(b"16"),
+            "sedamnaest" => b.put(b"17"),
+            "osamnaest" => b.put(b"18"),
+            "devetnaest" => b.put(b"19"),
+            "dvadeset" => b.put(b"20"),
+            "trideset" => b.put(b"30"),
+            "četrdeset" => b.put(b"40"),
+            "pedeset" => b.put(b"50"),
+            "šezdeset" => b.put(b"60"),
+            "sedamdeset" => b.put(b"70"),
+            "osamdeset" => b.put(b"80"),
+            "devedeset" => b.put(b"90"),
+            "sto" | "stotinu" | "stot" => b.put(b"100"),
+            "stotine" | "stotina" => {
+                // "dvije stotine", "pet stotina"
+                let peek = b.peek(2);
+                if (peek.len() == 1 || peek < b"10") && peek != b"1" && peek != b"01" {
+                    b.shift(2)
+                } else {
+                    Err(Error::Overlap)
+                }
+            }
+            "dvjesto" | "dvjesta" | "dvesto" | "dvesta" | "dvjestot" | "dvestot" => b.put(b"200"),
+            "tristo" | "trista" | "tristot" => b.put(b"300"),
+            "četiristo" | "četiristot" => b.put(b"400"),
+            "petsto" | "petstot" => b.put(b"500"),
+            "šeststo" | "šeststot" => b.put(b"600"),
+            "sedamsto" | "sedamstot" => b.put(b"700"),
+            "osamsto" | "osamstot" => b.put(b"800"),
+            "devetsto" | "devetstot" => b.put(b"900"),
+            "tisuću" | "tisuća" | "tisuće" | "tisućit" | "hiljadu" | "hiljada" | "hiljade"
+            | "hiljadit"
+                if b.is_range_free(3, 5) =>
+            {
+                b.shift(3)
+            }
+            "milijun" | "milijuna" | "milijuni" | "milion" | "miliona" | "milioni" | "milijon"
+            | "milijona" | "milijunt" | "miliont"
+                if b.is_range_free(6, 8) =>
+            {
+                b.shift(6)
+            }
+            "milijarda" | "milijarde" | "milijardi" | "milijardit" if b.is_range_free(9, 11) => {
+                b.shift(9)
+            }
+            "bilijun" | "bilijuna" | "bilion" | "biliona" => b.shift(12),
+            // "sto i pet", "dvadeset i tri": only inside a number
+            "i" if b.len() >= 2
+                && b.marker.is_none()
+                && !Restriction::from_bits_truncate(b.flags).contains(Restriction::CONJUNCTION) =>
+            {
+                Err(Error::Incomplete)
+            }
+            _ => Err(Error::NaN),
+        };
+        match status {
+            Ok(()) => {
+                b.flags = 0;
+                if is_ordinal {
+                    b.marker = MorphologicalMarker::Ordinal(".".into());
+                    b.freeze();
+                }
+            }
+            Err(Error::Incomplete) => b.flags = Restriction::CONJUNCTION.bits(),
+            _ => b.flags = 0,
+        }
+        status
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        if lemmatize(&transliterate(word)).1 {
+            MorphologicalMarker::Ordinal(".".into())
+        } else {
+            MorphologicalMarker::None
+        }
+    }
+
+    fn language_code(&self) -> &'static str {
+        "hr"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        let ordinals = ORDINAL_STEMS
+            .iter()
+            .flat_map(|stem| ORDINAL_ENDINGS.iter().map(move |ending| format!("{stem}{ending}")));
+        sorted_vocabulary(NUMBER_WORDS.iter().map(|word| String::from(*word)).chain(ordinals))
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        // "tri zarez pet", "tri cijela pet" (or "tri cela pet" in ekavian)
+        match transliterate(word).as_ref() {
+            "zarez" | "cijelih" | "cijela" | "cijele" | "celih" | "cela" | "cele" => Some(','),
+            _ => None,
+        }
+    }
+
+    fn decimal_symbol(&self) -> char {
+        ','
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "tri naprema dva", in both scripts
+        &["naprema", "напрема", "prema", "према"]
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(transliterate(word).as_ref())
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        // the words of the vocabulary, in context
+        is_number_word(word) && super::dry_run_start(self, word)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        (word == "i" || is_number_word(word)) && super::dry_run_continue(self, word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = SerboCroatian::new();
+            let res = text2digits($text, &f);
+            crate::tests::dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = SerboCroatian::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = SerboCroatian::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = SerboCroatian::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("jedan", "1");
+        assert_text2digits!("dvadeset tri", "23");
+        assert_text2digits!("devetnaest", "19");
+        assert_text2digits!("sto", "100");
+        assert_text2digits!("sto dvadeset pet", "125");
+        assert_text2digits!("dvjesto četrdeset", "240");
+        assert_text2digits!("dvesta četrdeset", "240");
+        assert_text2digits!("tri stotine", "300");
+        assert_text2digits!("tisuću", "1000");
+        assert_text2digits!("tisuću sto", "1100");
+        assert_text2digits!("dvije tisuće petsto", "2500");
+        assert_text2digits!("dve hiljade petsto", "2500");
+        assert_text2digits!("pet tisuća", "5000");
+        assert_text2digits!("dvadeset pet hiljada", "25000");
+        assert_text2digits!("jedan milijun", "1000000");
+        assert_text2digits!("dva miliona tristo hiljada", "2300000");
+        assert_text2digits!("pet milijardi", "5000000000");
+    }
+
+    #[test]
+    fn test_cyrillic() {
+        assert_text2digits!("двадесет три", "23");
+        assert_text2digits!("две хиљаде", "2000");
+        assert_text2digits!("сто педесет", "150");
+        assert_text2digits!("двадесет трећи", "23.");
+        assert_replace_numbers!("Имам двадесет пет година.", "Имам 25 година.");
+        assert_replace_numbers!("три зарез четрнаест", "3,14");
+    }
+
+    #[test]
+    fn test_genders() {
+        assert_text2digits!("jedan", "1");
+        assert_text2digits!("jedna", "1");
+        assert_text2digits!("jedno", "1");
+        assert_text2digits!("dva", "2");
+        assert_text2digits!("dvije", "2");
+        assert_text2digits!("dve", "2");
+        assert_text2digits!("dvadeset jedna", "21");
+        assert_text2digits!("trideset dvije", "32");
+    }
+
+    #[test]
+    fn test_conjunction() {
+        assert_text2digits!("sto i pet", "105");
+        assert_text2digits!("dvadeset i tri", "23");
+        assert_text2digits!("tisuću i dvjesto", "1200");
+        assert_invalid!("dvadeset i i tri");
+        assert_invalid!("dvadeset i");
+        assert_replace_all_numbers!("pet i tri", "5 i 3");
+        assert_replace_all_numbers!("dvadeset i sto", "20 i 100");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("prvi", "1.");
+        assert_text2digits!("druga", "2.");
+        assert_text2digits!("treće", "3.");
+        assert_text2digits!("petog", "5.");
+        assert_text2digits!("šesto", "6.");
+        assert_text2digits!("jedanaesti", "11.");
+        assert_text2digits!("dvadeseti", "20.");
+        assert_text2digits!("dvadeset treći", "23.");
+        assert_text2digits!("sto prvi", "101.");
+        assert_text2digits!("stoti", "100.");
+        assert_text2digits!("dvjestoti", "200.");
+        assert_text2digits!("tisućiti", "1000.");
+        assert_invalid!("treći dvadeset");
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("nula", "0");
+        assert_text2digits!("nula osam", "08");
+        assert_invalid!("pet nula");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("deset pet");
+        assert_invalid!("pet sto");
+        assert_invalid!("tisuću tisuću");
+        assert_invalid!("šezdeset petnaest");
+        assert_invalid!("tisuće");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "Imamo dvadeset pet krava, dvanaest pilića i sto dvadeset pet kila krumpira.",
+            "Imamo 25 krava, 12 pilića i 125 kila krumpira."
+        );
+        assert_replace_numbers!("Tisuću dvjesto šezdeset šest eura", "1266 eura");
+        assert_replace_numbers!("jedan dva tri četiri dvadeset petnaest", "1 2 3 4 20 15");
+        assert_replace_numbers!("tisuće ljudi i milioni zvijezda", "tisuće ljudi i milioni zvijezda");
+    }
+
+    #[test]
+    fn test_replace_decimals() {
+        assert_replace_numbers!(
+            "dvanaest zarez devedeset devet, sto dvadeset cijelih nula pet",
+            "12,99, 120,05"
+        );
+        assert_replace_all_numbers!("tri cijela pet", "3,5");
+        assert_replace_all_numbers!("tri cela pet", "3,5");
+    }
+
+    #[test]
+    fn test_replace_ordinals() {
+        assert_replace_numbers!(
+            "Bio je dvadeset treći u utrci, a ona dvadeset prva.",
+            "Bio je 23. u utrci, a ona 21.."
+        );
+        assert_replace_all_numbers!("peti dan, prvi razred", "5. dan, 1. razred");
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("DVADESET TRI", "23");
+        assert_replace_all_numbers!("Двадесет Три", "23");
+    }
+
+    #[test]
+    fn test_codes() {
+        for code in ["hr", "sr", "bs"] {
+            let lang = crate::get_interpreter_for(code).unwrap();
+            assert_eq!(replace_numbers_in_text("dvadeset tri", &lang, 0.0), "23");
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "i", "a", "pa", "plus", "minus", "puta", "je", "su", "eh", "hm", "ovaj", "onda", "još"
+};
+
+/// The number words, inflected by [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "nula", "jedan", "jedna", "jedno", "jednu", "jednog", "jednom", "dva", "dvije", "dve", "dvoje",
+    "dvaju", "tri", "troje", "triju", "četiri", "četvero", "četvoro", "pet", "šest", "sedam",
+    "osam", "devet", "deset", "jedanaest", "dvanaest", "trinaest", "četrnaest", "petnaest",
+    "šesnaest", "sedamnaest", "osamnaest", "devetnaest", "dvadeset", "trideset", "četrdeset",
+    "pedeset", "šezdeset", "sedamdeset", "osamdeset", "devedeset", "sto", "stotinu", "stotina",
+    "dvjesto", "dvjesta", "dvesto", "dvesta", "tristo", "trista", "četiristo", "petsto", "šeststo",
+    "sedamsto", "osamsto", "devetsto", "tisuću", "tisuća", "hiljadu", "hiljada", "milijun",
+    "milijuna", "milion", "miliona", "milijon", "milijona", "milijarda", "milijarde", "milijardi",
+    "bilijun", "bilijuna", "bilion", "biliona", "prvi", "drugi", "treći", "četvrti", "peti",
+    "šesti", "sedmi", "osmi", "deveti", "deseti", "jedanaesti", "dvanaesti", "trinaesti",
+    "četrnaesti", "petnaesti", "šesnaesti", "sedamnaesti", "osamnaesti", "devetnaesti", "dvadeseti",
+    "trideseti", "četrdeseti", "pedeseti", "šezdeseti", "sedamdeseti", "osamdeseti", "devedeseti",
+    "stoti", "dvjestoti", "dvestoti", "tristoti", "četiristoti", "petstoti", "šeststoti",
+    "sedamstoti", "osamstoti", "devetstoti", "tisućiti", "hiljaditi", "milijunti", "milionti",
+    "milijarditi", "stotine", "tisuće", "hiljade", "milijuni", "milioni",
+];
@@ -1,17 +1,46 @@
 //! Italian number interpreter
 
+use alloc::{format, string::String, vec::Vec};
+
 use crate::digit_string::DigitString;
 use crate::error::Error;
-use crate::tokenizer::WordSplitter;
+use crate::options::OrdinalStyle;
+use crate::tokenizer::LazySplitter;
 
 mod vocabulary;
 
-use super::{LangInterpreter, MorphologicalMarker};
-use vocabulary::INSIGNIFICANT;
-
-pub struct Italian {
-    word_splitter: WordSplitter,
-}
+use super::{Affix, LangInterpreter, MorphologicalMarker, sorted_vocabulary};
+use vocabulary::{INSIGNIFICANT, NUMBER_WORDS, SECONDO_OBJECTS, VAGUE_QUANTIFIERS};
+
+/// The splitter of the compound words, built on first use.
+static WORD_SPLITTER: LazySplitter = LazySplitter::new(&[
+    "miliardesim",
+    "milionesim",
+    "bilionesim",
+    "cinquanta",
+    "centesim",
+    "millesim",
+    "miliardo",
+    "miliardi",
+    "quaranta",
+    "sessanta",
+    "settanta",
+    "milione",
+    "milioni",
+    "bilione",
+    "bilioni",
+    "ottanta",
+    "novanta",
+    "trenta",
+    "ttanta",
+    "cento",
+    "mille",
+    "venti",
+    "mila",
+]);
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Italian {}
 
 fn lemmatize(word: &str) -> &str {
     let candidate = word.trim_end_matches(['o', 'a', 'e', 'i']);
@@ -37,50 +66,56 @@ fn lemmatize(word: &str) -> &str {
     }
 }
 
-impl Default for Italian {
-    fn default() -> Self {
-        Self {
-            word_splitter: WordSplitter::new([
-                "miliardesim",
-                "milionesim",
-                "bilionesim",
-                "cinquanta",
-                "centesim",
-                "millesim",
-                "miliardo",
-                "miliardi",
-                "quaranta",
-                "sessanta",
-                "settanta",
-                "milione",
-                "milioni",
-                "bilione",
-                "bilioni",
-                "ottanta",
-                "novanta",
-                "trenta",
-                "ttanta",
-                "cento",
-                "mille",
-                "venti",
-                "mila",
-            ])
-            .unwrap(),
-        }
+/// Return true if `word` is one of the number words, inflected, compounded or made an ordinal.
+fn is_number_word(word: &str) -> bool {
+    let lemma = lemmatize(word);
+    if WORD_SPLITTER.get().is_splittable(lemma) {
+        // "cento e tre", and the elided "otto" and "ottanta" of "centotto" and "centottanta"
+        return WORD_SPLITTER
+            .get()
+            .split(lemma)
+            .all(|piece| matches!(piece, "e" | "tto" | "ttanta") || is_number_word(piece));
     }
+    NUMBER_WORDS.iter().any(|known| lemmatize(known) == lemma)
+        || lemma.strip_suffix("esim").is_some_and(|stem| {
+            // the final vowel of the cardinal is elided, as in "ventesimo"
+            ["", "o", "e", "i", "a"]
+                .iter()
+                .any(|vowel| !stem.is_empty() && is_number_word(&alloc::format!("{stem}{vowel}")))
+        })
 }
 
 impl Italian {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Apply the cardinal of an ordinal from its `stem`, the ordinal without "*-esimo*".
+    ///
+    /// The final vowel of the cardinal is elided before the suffix ("*ventesimo*", "*trentesimo*"),
+    /// but not always ("*ventitreesimo*", "*seiesimo*"): the stem is tried as it is, then with each vowel.
+    fn apply_ordinal_stem(&self, stem: &str, b: &mut DigitString) -> Result<(), Error> {
+        let mut error = Error::NaN;
+        for vowel in ["", "o", "e", "i", "a"] {
+            let mut attempt = b.clone();
+            match self.apply(&alloc::format!("{stem}{vowel}"), &mut attempt) {
+                Ok(()) => {
+                    *b = attempt;
+                    return Ok(());
+                }
+                Err(Error::NaN) => (),
+                Err(err) => error = err,
+            }
+        }
+        Err(error)
+    }
 }
 
 impl LangInterpreter for Italian {
     fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
         let lemma = lemmatize(num_func);
-        if self.word_splitter.is_splittable(lemma) {
-            return match self.exec_group(self.word_splitter.split(lemma)) {
+        if WORD_SPLITTER.get().is_splittable(lemma) {
+            return match self.exec_group(WORD_SPLITTER.get().split(lemma)) {
                 Ok(ds) => {
                     if ds.len() > 3 && ds.len() <= 6 && !b.is_range_free(3, 5) {
                         return Err(Error::Overlap);
@@ -98,58 +133,58 @@ impl LangInterpreter for Italian {
         }
         let status = match lemmatize(num_func) {
             "zero" => b.put(b"0"),
-            "un" | "uno" | "una" | "unesim" if b.is_free(2) => b.put(b"1"),
+            "un" | "uno" | "una" if b.is_free(2) => b.put(b"1"),
             "prim" if b.is_empty() => b.put(b"1"),
-            "due" | "duesim" if b.peek(2) != b"10" => b.put(b"2"),
+            "due" if b.peek(2) != b"10" => b.put(b"2"),
             "second" if b.is_empty() => b.put(b"2"),
-            "tre" | "tré" | "treesim" if b.peek(2) != b"10" => b.put(b"3"),
+            "tre" | "tré" if b.peek(2) != b"10" => b.put(b"3"),
             "terz" if b.is_empty() => b.put(b"3"),
-            "quattro" | "quattresim" if b.peek(2) != b"10" => b.put(b"4"),
+            "quattro" if b.peek(2) != b"10" => b.put(b"4"),
             "quart" if b.is_empty() => b.put(b"4"),
-            "cinque" | "cinquesim" if b.peek(2) != b"10" => b.put(b"5"),
+            "cinque" if b.peek(2) != b"10" => b.put(b"5"),
             "quint" if b.is_empty() => b.put(b"5"),
-            "sei" | "seiesim" if b.peek(2) != b"10" => b.put(b"6"),
+            "sei" if b.peek(2) != b"10" => b.put(b"6"),
             "sest" if b.is_empty() => b.put(b"6"),
-            "sette" | "settesim" if b.peek(2) != b"10" => b.put(b"7"),
+            "sette" if b.peek(2) != b"10" => b.put(b"7"),
             "settim" if b.is_empty() => b.put(b"7"),
-            "otto" | "tto" | "ottesim" | "ttesim" if b.is_free(2) => b.put(b"8"),
+            "otto" | "tto" if b.is_free(2) => b.put(b"8"),
             "ottav" if b.is_empty() => b.put(b"8"),
-            "nove" | "novesim" if b.peek(2) != b"10" => b.put(b"9"),
+            "nove" if b.peek(2) != b"10" => b.put(b"9"),
             "non" if b.is_empty() && num_func != "non" => b.put(b"9"),
             "dieci" | "decim" => b.put(b"10"),
-            "undici" | "undicesim" => b.put(b"11"),
-            "dodici" | "dodicesim" => b.put(b"12"),
-            "tredici" | "tredicesim" => b.put(b"13"),
-            "quattordici" | "quattordicesim" => b.put(b"14"),
-            "quindici" | "quindicesim" => b.put(b"15"),
-            "sedici" | "dedicesim" => b.put(b"16"),
-            "diciassette" | "diciassettesim" => b.put(b"17"),
-            "diciotto" | "diciottesim" => b.put(b"18"),
-            "diciannove" | "diciannovesim" => b.put(b"19"),
-            "venti" | "ventesim" => b.put(b"20"),
-            "ventuno" | "ventun" | "ventunesim" => b.put(b"21"),
-            "ventotto" | "ventottesim" => b.put(b"28"),
-            "trenta" | "trentesim" => b.put(b"30"),
-            "trentuno" | "trentun" | "trentunesim" => b.put(b"31"),
-            "trentotto" | "trentottesim" => b.put(b"38"),
-            "quaranta" | "quarantesim" => b.put(b"40"),
-            "quarantuno" | "quarantun" | "quarantunesim" => b.put(b"41"),
-            "quarantotto" | "quarantottesim" => b.put(b"48"),
-            "cinquanta" | "cinquantesim" => b.put(b"50"),
-            "cinquantuno" | "cinquantun" | "cinquantunesim" => b.put(b"51"),
-            "cinquantotto" | "cinquantottesim" => b.put(b"58"),
-            "sessanta" | "sessantesim" => b.put(b"60"),
-            "sessantuno" | "sessantun" | "sessantunesim" => b.put(b"61"),
-            "sessantotto" | "sessantottesim" => b.put(b"68"),
-            "settanta" | "settantesim" => b.put(b"70"),
-            "settantuno" | "settantun" | "settanunesim" => b.put(b"71"),
-            "settantotto" | "settantottesim" => b.put(b"78"),
-            "ottanta" | "ottantesim" | "ttanta" | "ttantesim" => b.put(b"80"),
-            "ottantuno" | "ottantun" | "ottantunesim" => b.put(b"81"),
-            "ottantotto" | "ottantottesim" => b.put(b"88"),
-            "novanta" | "novantesim" => b.put(b"90"),
-            "novantuno" | "novantun" | "novantunesim" => b.put(b"91"),
-            "novantotto" | "novantottesim" => b.put(b"98"),
+            "undici" => b.put(b"11"),
+            "dodici" => b.put(b"12"),
+            "tredici" => b.put(b"13"),
+            "quattordici" => b.put(b"14"),
+            "quindici" => b.put(b"15"),
+            "sedici" => b.put(b"16"),
+            "diciassette" => b.put(b"17"),
+            "diciotto" => b.put(b"18"),
+            "diciannove" => b.put(b"19"),
+            "venti" => b.put(b"20"),
+            "ventuno" | "ventun" => b.put(b"21"),
+            "ventotto" => b.put(b"28"),
+            "trenta" => b.put(b"30"),
+            "trentuno" | "trentun" => b.put(b"31"),
+            "trentotto" => b.put(b"38"),
+            "quaranta" => b.put(b"40"),
+            "quarantuno" | "quarantun" => b.put(b"41"),
+            "quarantotto" => b.put(b"48"),
+            "cinquanta" => b.put(b"50"),
+            "cinquantuno" | "cinquantun" => b.put(b"51"),
+            "cinquantotto" => b.put(b"58"),
+            "sessanta" => b.put(b"60"),
+            "sessantuno" | "sessantun" => b.put(b"61"),
+            "sessantotto" => b.put(b"68"),
+            "settanta" => b.put(b"70"),
+            "settantuno" | "settantun" => b.put(b"71"),
+            "settantotto" => b.put(b"78"),
+            "ottanta" | "ttanta" => b.put(b"80"),
+            "ottantuno" | "ottantun" => b.put(b"81"),
+            "ottantotto" => b.put(b"88"),
+            "novanta" => b.put(b"90"),
+            "novantuno" | "novantun" => b.put(b"91"),
+            "novantotto" => b.put(b"98"),
             "cento" | "centesim" => {
                 let peek = b.peek(2);
                 if (peek.len() == 1 || peek < b"10") && peek != b"1" && peek != b"01" {
@@ -158,7 +193,7 @@ impl LangInterpreter for Italian {
                     Err(Error::Overlap)
                 }
             }
-            "centuno" | "centun" | "centunesimo" => b.put(b"101"),
+            "centuno" | "centun" => b.put(b"101"),
             "mille" if b.is_range_free(3, 5) => b.put(b"1000"),
             "mila" if b.is_range_free(3, 5) => {
                 let peek = b.peek(3);
@@ -240,6 +275,9 @@ impl LangInterpreter for Italian {
                 }
             }
             "e" if b.len() >= 2 => Err(Error::Incomplete),
+            ordinal if ordinal.len() > 4 && ordinal.ends_with("esim") => {
+                self.apply_ordinal_stem(&ordinal[..ordinal.len() - 4], b)
+            }
             _ => Err(Error::NaN),
         };
         let marker = self.get_morph_marker(num_func);
@@ -258,21 +296,86 @@ impl LangInterpreter for Italian {
         if base != word {
             // word is guaranteed not to be empty
             match word.chars().last().unwrap() {
-                'o' | 'i' => MorphologicalMarker::Ordinal("º"),
-                'a' | 'e' => MorphologicalMarker::Ordinal("ª"),
+                'o' | 'i' => MorphologicalMarker::Ordinal("º".into()),
+                'a' | 'e' => MorphologicalMarker::Ordinal("ª".into()),
                 _ => MorphologicalMarker::None,
             }
         } else {
             MorphologicalMarker::None
         }
     }
+
+    fn styled_ordinal(&self, _b: &DigitString, affix: Affix, style: OrdinalStyle) -> Option<Affix> {
+        match style {
+            OrdinalStyle::Plain | OrdinalStyle::Long => None,
+            OrdinalStyle::Superscript => Some((if affix.suffix == "ª" { "ᵃ" } else { "ᵒ" }).into()),
+            OrdinalStyle::PeriodOnly => Some(".".into()),
+        }
+    }
+    fn language_code(&self) -> &'static str {
+        "it"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        let mut words: Vec<String> = NUMBER_WORDS.iter().map(|word| String::from(*word)).collect();
+        // the -esimo ordinals of the cardinals above ten, without their final vowel ("undicesimo", "ventesimo"),
+        // and of the thousands, built on "millesimo" ("duemillesimo")
+        for word in NUMBER_WORDS {
+            if let Some(units) = word.strip_suffix("mila") {
+                words.push(format!("{units}millesimo"));
+            } else if let Some(stem) = word.strip_suffix(['o', 'e', 'i', 'a']) {
+                let mut b = DigitString::new();
+                if self.apply(word, &mut b).is_ok() && !b.is_ordinal() && b.len() > 1 && b.peek(3) != b"10" {
+                    words.push(format!("{stem}esimo"));
+                }
+            }
+        }
+        // the ordinals in all genders and numbers: "primo", "prima", "primi", "prime"
+        let ordinals: Vec<String> = words
+            .iter()
+            .filter(|word| !self.get_morph_marker(word).is_none())
+            .flat_map(|word| ["o", "a", "i", "e"].map(|ending| format!("{}{ending}", lemmatize(word))))
+            .filter(|ordinal| !self.get_morph_marker(ordinal).is_none())
+            .collect();
+        sorted_vocabulary(words.into_iter().chain(ordinals))
+    }
+
     fn check_decimal_separator(&self, word: &str) -> Option<char> {
         if word == "virgola" { Some(',') } else { None }
     }
 
+    fn decimal_symbol(&self) -> char {
+        ','
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "tre a due"
+        &["a"]
+    }
+
     fn is_linking(&self, word: &str) -> bool {
         INSIGNIFICANT.contains(word)
     }
+
+    fn is_vague_quantifier(&self, word: &str) -> bool {
+        VAGUE_QUANTIFIERS.contains(word)
+    }
+
+    fn is_pronoun(&self, _previous: Option<&str>, number: &str, next: Option<&str>) -> bool {
+        // not a pronoun, but never a number either: "secondo" is also a preposition, as in "secondo me"
+        // or "secondo l'autore"
+        number == "secondo"
+            && next.is_some_and(|next| SECONDO_OBJECTS.contains(next) || next.starts_with("l'"))
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        // the words of the vocabulary, in context
+        is_number_word(word) && super::dry_run_start(self, word)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        (word == "e" || is_number_word(word)) && super::dry_run_continue(self, word)
+    }
 }
 
 #[cfg(test)]
@@ -404,6 +507,45 @@ mod tests {
         assert_text2digits!("ventunesimo", "21º");
         assert_text2digits!("venticinquesimi", "25º");
         assert_text2digits!("ventunesimi", "21º");
+        let irregular = [
+            "primo", "secondo", "terzo", "quarto", "quinto", "sesto", "settimo", "ottavo", "nono", "decimo",
+        ];
+        let teens = [
+            "undicesimo", "dodicesimo", "tredicesimo", "quattordicesimo", "quindicesimo", "sedicesimo",
+            "diciassettesimo", "diciottesimo", "diciannovesimo",
+        ];
+        let units = [
+            "unesimo", "duesimo", "treesimo", "quattresimo", "cinquesimo", "seiesimo", "settesimo", "ottesimo",
+            "novesimo",
+        ];
+        let tens = ["venti", "trenta", "quaranta", "cinquanta", "sessanta", "settanta", "ottanta", "novanta"];
+        let mut ordinals: Vec<(alloc::string::String, usize)> = Vec::new();
+        ordinals.extend(irregular.iter().enumerate().map(|(i, w)| ((*w).into(), i + 1)));
+        ordinals.extend(teens.iter().enumerate().map(|(i, w)| ((*w).into(), i + 11)));
+        for (t, ten) in tens.iter().enumerate() {
+            let elided = &ten[..ten.len() - 1];
+            ordinals.push((alloc::format!("{elided}esimo"), 20 + 10 * t));
+            for (u, unit) in units.iter().enumerate() {
+                // "ventunesimo" and "ventottesimo" elide the vowel of the tens
+                let word = match u {
+                    0 | 7 => alloc::format!("{elided}{unit}"),
+                    _ => alloc::format!("{ten}{unit}"),
+                };
+                ordinals.push((word, 21 + 10 * t + u));
+            }
+        }
+        ordinals.push(("centesimo".into(), 100));
+        ordinals.push(("centunesimo".into(), 101));
+        for (word, n) in &ordinals {
+            assert_text2digits!(word.as_str(), alloc::format!("{n}º"));
+            let feminine = alloc::format!("{}a", &word[..word.len() - 1]);
+            assert_text2digits!(feminine.as_str(), alloc::format!("{n}ª"));
+        }
+        assert_text2digits!("centounesima", "101ª");
+        assert_text2digits!("duecentoquarantottesime", "248ª");
+        assert_text2digits!("tremillesimo", "3000º");
+        assert_invalid!("medesimo");
+        assert_invalid!("battesimo");
     }
 
     #[test]
@@ -500,6 +642,14 @@ mod tests {
         assert_replace_numbers!("cinquecento primo", "500 primo");
         assert_replace_numbers!("un secondo", "un secondo");
         assert_replace_numbers!("due secondi", "due secondi");
+        // "secondo" is also a preposition
+        assert_replace_numbers!("secondo me, è il ventitreesimo", "secondo me, è il 23º");
+        assert_replace_all_numbers!("secondo me, è il ventitreesimo", "secondo me, è il 23º");
+        assert_replace_all_numbers!("Secondo il medico, è guarito", "Secondo il medico, è guarito");
+        assert_replace_all_numbers!("secondo l'autore", "secondo l'autore");
+        assert_replace_all_numbers!("il secondo piano", "il 2º piano");
+        assert_replace_all_numbers!("arrivò secondo", "arrivò 2º");
+        assert_replace_numbers!("la sedicesima e la settantunesima", "la 16ª e la 71ª");
     }
 
     #[test]
@@ -559,4 +709,19 @@ mod tests {
             "poi 2 e 3 più 5 ehm 6 poi 7 e ancora 8 meno 4 è ben 3"
         );
     }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("VENTITRÉ", "23");
+        assert_replace_all_numbers!("DUECENTO", "200");
+    }
+
+    #[test]
+    fn test_vague_quantifiers() {
+        assert_replace_numbers!("alcuni milioni di euro", "alcuni milioni di euro");
+        assert_replace_numbers!("parecchi milioni di persone", "parecchi milioni di persone");
+        assert_replace_numbers!("alcune centinaia di metri", "alcune centinaia di metri");
+        assert_replace_numbers!("tre milioni di euro", "3000000 di euro");
+    }
+
 }
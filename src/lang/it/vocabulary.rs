@@ -3,3 +3,30 @@ use phf::{Set, phf_set};
 pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
     "e", "ehm", "più", "poi", "ancora", "meno", "è", "ben"
 };
+
+/// Vague quantifiers, like "*alcuni*", "*qualche*"
+pub static VAGUE_QUANTIFIERS: Set<&'static str> = phf_set! {
+    "alcuni", "alcune", "parecchi", "parecchie", "qualche"
+};
+
+/// The words after the preposition "*secondo*" (according to), like "*me*" in "*secondo me*"
+pub static SECONDO_OBJECTS: Set<&'static str> = phf_set! {
+    "me", "te", "lui", "lei", "noi", "voi", "loro", "il", "lo", "la", "i", "gli", "le", "un", "uno",
+    "una", "quanto", "cui", "alcuni", "molti", "questo", "questa", "quello", "quella", "voci", "fonti"
+};
+
+/// The number words, inflected by [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "zero", "un", "uno", "una", "primo", "due", "secondo", "tre", "tré", "terzo", "quattro",
+    "quarto", "cinque", "quinto", "sei", "sesto", "sette", "settimo", "otto", "ottavo", "nove",
+    "nono", "dieci", "decimo", "undici", "dodici", "tredici", "quattordici", "quindici", "sedici",
+    "diciassette", "diciotto", "diciannove", "venti", "ventuno", "ventun", "ventotto", "trenta",
+    "trentuno", "trentun", "trentotto", "quaranta", "quarantuno", "quarantun", "quarantotto",
+    "cinquanta", "cinquantuno", "cinquantun", "cinquantotto", "sessanta", "sessantuno", "sessantun",
+    "sessantotto", "settanta", "settantuno", "settantun", "settantotto", "ottanta", "ottantuno",
+    "ottantun", "ottantotto", "novanta", "novantuno", "novantun", "novantotto", "cento",
+    "centesimo", "centuno", "centun", "duecento", "trecento", "quattrocento", "cinquecento", "seicento",
+    "settecento", "ottocento", "novecento", "mille", "mila", "duemila", "tremila", "quattromila",
+    "cinquemila", "seimila", "settemila", "ottomila", "novemila", "millesimo", "milione", "milionesimo",
+    "milioni", "miliardo", "miliardesimo", "miliardi", "bilione", "bilionesimo", "bilioni",
+];
@@ -0,0 +1,529 @@
+//! Lithuanian number interpreter
+//!
+//! Numbers are accepted in the nominative and accusative cases: "dvidešimt trys", "dvidešimt tris".
+//! Scale words must agree with the number they multiply:
+//! "vienas tūkstantis", "du tūkstančiai", "dvidešimt tūkstančių".
+//! Ordinals keep their ending on the digit form: "dvidešimt trečias" is "23-ias", "penktoji" is "5-oji".
+
+use alloc::{format, string::String, vec::Vec};
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker, sorted_vocabulary};
+use vocabulary::{INSIGNIFICANT, NUMBER_WORDS};
+
+/// The stems of the ordinals.
+const ORDINAL_STEMS: [&str; 31] = [
+    "pirm",
+    "antr",
+    "treč",
+    "ketvirt",
+    "penkt",
+    "šešt",
+    "septint",
+    "aštunt",
+    "devint",
+    "dešimt",
+    "vienuolikt",
+    "dvylikt",
+    "trylikt",
+    "keturiolikt",
+    "penkiolikt",
+    "šešiolikt",
+    "septyniolikt",
+    "aštuoniolikt",
+    "devyniolikt",
+    "dvidešimt",
+    "trisdešimt",
+    "keturiasdešimt",
+    "penkiasdešimt",
+    "šešiasdešimt",
+    "septyniasdešimt",
+    "aštuoniasdešimt",
+    "devyniasdešimt",
+    "šimt",
+    "tūkstant",
+    "milijon",
+    "milijard",
+];
+
+/// The nominative and accusative endings of the ordinals, with their digit form.
+const ORDINAL_ENDINGS: [(&str, &str); 11] = [
+    ("as", "-as"),
+    ("a", "-a"),
+    ("asis", "-asis"),
+    ("oji", "-oji"),
+    ("ą", "-ą"),
+    ("ąjį", "-ąjį"),
+    ("ąją", "-ąją"),
+    ("i", "-i"),
+    ("os", "-os"),
+    ("ieji", "-ieji"),
+    ("osios", "-osios"),
+];
+
+/// The endings of "trečias", with their digit form.
+const SOFT_ORDINAL_ENDINGS: [(&str, &str); 10] = [
+    ("ias", "-ias"),
+    ("ia", "-ia"),
+    ("iasis", "-iasis"),
+    ("ioji", "-ioji"),
+    ("ią", "-ią"),
+    ("iąjį", "-iąjį"),
+    ("iąją", "-iąją"),
+    ("ios", "-ios"),
+    ("ieji", "-ieji"),
+    ("iosios", "-iosios"),
+];
+
+/// Return the stem of `word` and the suffix of its digit form if it is an ordinal, or `word` itself.
+///
+/// "šimtas" and "milijonas" are cardinals: their ordinals are only recognized in the definite form,
+/// like "šimtasis".
+fn lemmatize(word: &str) -> (&str, Option<&'static str>) {
+    if matches!(
+        word,
+        "šimtas" | "šimtą" | "milijonas" | "milijoną" | "milijardas" | "milijardą"
+    ) {
+        return (word, None);
+    }
+    ORDINAL_STEMS
+        .iter()
+        .find_map(|stem| {
+            let ending = word.strip_prefix(stem)?;
+            let endings: &[(&str, &str)] = if *stem == "treč" {
+                &SOFT_ORDINAL_ENDINGS
+            } else {
+                &ORDINAL_ENDINGS
+            };
+            endings
+                .iter()
+                .find(|(e, _)| *e == ending)
+                .map(|(_, suffix)| (&word[..stem.len()], Some(*suffix)))
+        })
+        .unwrap_or((word, None))
+}
+
+/// The grammatical number that a scale word must have after the digits it multiplies.
+#[derive(Debug, PartialEq, Eq)]
+enum Agreement {
+    /// "tūkstantis", after nothing or a number ending in 1 but not 11
+    Singular,
+    /// "tūkstančiai", after a number ending in 2 to 9 but not 12 to 19
+    Plural,
+    /// "tūkstančių", after a number ending in 0 or 10 to 19
+    Genitive,
+}
+
+fn agreement(b: &DigitString, positions: usize) -> Agreement {
+    if b.is_free(positions) {
+        return Agreement::Singular;
+    }
+    let tail = b.peek(2);
+    let is_teen = tail.len() == 2 && tail[0] == b'1';
+    match tail[tail.len() - 1] {
+        _ if is_teen => Agreement::Genitive,
+        b'0' => Agreement::Genitive,
+        b'1' => Agreement::Singular,
+        _ => Agreement::Plural,
+    }
+}
+
+/// The agreement of a scale word of `positions` zeroes after `b`.
+///
+/// A scale word that multiplies a number ending with another scale word is governed by it,
+/// in the genitive plural: "*tūkstantis milijonų*" (a thousand millions).
+fn scale_agreement(b: &DigitString, positions: usize) -> Agreement {
+    if !b.is_empty() && b.is_free(3) && b.len() <= positions {
+        Agreement::Genitive
+    } else {
+        agreement(b, 3)
+    }
+}
+
+/// Return true if `word` is one of the number words, inflected.
+fn is_number_word(word: &str) -> bool {
+    let lemma = lemmatize(word).0;
+    NUMBER_WORDS.iter().any(|known| lemmatize(known).0 == lemma)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Lithuanian {}
+
+impl Lithuanian {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl LangInterpreter for Lithuanian {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        // bare plural scale words are vague quantities: "tūkstančiai žmonių"
+        if b.is_empty()
+            && matches!(
+                num_func,
+                "dešimtys"
+                    | "šimtai"
+                    | "šimtus"
+                    | "tūkstančiai"
+                    | "tūkstančius"
+                    | "milijonai"
+                    | "milijonus"
+                    | "milijardai"
+                    | "milijardus"
+            )
+        {
+            return Err(Error::NaN);
+        }
+        let (lemma, ordinal) = lemmatize(num_func);
+        let status = match lemma {
+            "nulis" => b.put(b"0"),
+            "vienas" | "viena" | "vieną" | "pirm" if b.peek(2) != b"10" => b.put(b"1"),
+            "du" | "dvi" | "antr" if b.peek(2) != b"10" => b.put(b"2"),
+            "trys" | "tris" | "treč" if b.peek(2) != b"10" => b.put(b"3"),
+            "keturi" | "keturios" | "keturis" | "keturias" | "ketvirt" if b.peek(2) != b"10" => {
+                b.put(b"4")
+            }
+            "penki" | "penkios" | "penkis" | "penkias" | "penkt" if b.peek(2) != b"10" => {
+                b.put(b"5")
+            }
+            "šeši" | "šešios" | "šešis" | "šešias" | "šešt" if b.peek(2) != b"10" => {
+                b.put(b"6")
+            }
+            "septyni" | "septynios" | "septynis" | "septynias" | "septint"
+                if b.peek(2) != b"10" =>
+            {
+                b.put(b"7")
+            }
+            "aštuoni" | "aštuonios" | "aštuonis" | "aštuonias" | "aštunt" if b.peek(2) != b"10" => {
+                b.put(b"8")
+            }
+            "devyni" | "devynios" | "devynis" | "devynias" | "devint" if b.peek(2) != b"10" => {
+                b.put(b"9")
+            }
+            "dešimt" => b.put(b"10"),
+            "vienuolika" | "vienuolikt" => b.put(b"11"),
+            "dvylika" | "dvylikt" => b.put(b"12"),
+            "trylika" | "trylikt" => b.put(b"13"),
+            "keturiolika" | "keturiolikt" => b.put(b"14"),
+            "penkiolika" | "penkiolikt" => b.put(b"15"),
+            "šešiolika" | "šešiolikt" => b.put(b"16"),
+            "septyniolika" | "septyniolikt" => b.put(b"17"),
+            "aštuoniolika" | "aštuoniolikt" => b.put(b"18"),
+            "devyniolika" | "devyniolikt" => b.put(b"19"),
+            "dvidešimt" => b.put(b"20"),
+            "trisdešimt" => b.put(b"30"),
+            "keturiasdešimt" => b.put(b"40"),
+            "penkiasdešimt" => b.put(b"50"),
+            "šešiasdešimt" => b.put(b"60"),
+            "septyniasdešimt" => b.put(b"70"),
+            "aštuoniasdešimt" => b.put(b"80"),
+            "devyniasdešimt" => b.put(b"90"),
+            // the ordinals don't agree: "du šimtasis"
+            "šimtas" | "šimtą" | "šimt"
+                if ordinal.is_some() || agreement(b, 2) == Agreement::Singular =>
+            {
+                let peek = b.peek(2);
+                if peek.len() == 1 || peek < b"10" {
+                    b.shift(2)
+                } else {
+                    Err(Error::Overlap)
+                }
+            }
+            "šimtai" | "šimtus" if agreement(b, 2) == Agreement::Plural => {
+                let peek = b.peek(2);
+                if peek.len() == 1 || peek < b"10" {
+                    b.shift(2)
+                } else {
+                    Err(Error::Overlap)
+                }
+            }
+            "tūkstantis" | "tūkstantį" | "tūkstant"
+                if b.is_range_free(3, 5)
+                    && (ordinal.is_some() || agreement(b, 3) == Agreement::Singular) =>
+            {
+                b.shift(3)
+            }
+            "tūkstančiai" | "tūkstančius"
+                if b.is_range_free(3, 5) && agreement(b, 3) == Agreement::Plural =>
+            {
+                b.shift(3)
+            }
+            "tūkstančių" if b.is_range_free(3, 5) && agreement(b, 3) == Agreement::Genitive => {
+                b.shift(3)
+            }
+            "milijonas" | "milijoną" | "milijon"
+                if b.is_range_free(6, 8)
+                    && (ordinal.is_some() || scale_agreement(b, 6) == Agreement::Singular) =>
+            {
+                b.shift(6)
+            }
+            "milijonai" | "milijonus"
+                if b.is_range_free(6, 8) && scale_agreement(b, 6) == Agreement::Plural =>
+            {
+                b.shift(6)
+            }
+            "milijonų" if b.is_range_free(6, 8) && scale_agreement(b, 6) == Agreement::Genitive => {
+                b.shift(6)
+            }
+            "milijardas" | "milijardą" | "milijard"
+                if b.is_range_free(9, 11)
+                    && (ordinal.is_some() || scale_agreement(b, 9) == Agreement::Singular) =>
+            {
+                b.shift(9)
+            }
+            "milijardai" | "milijardus"
+                if b.is_range_free(9, 11) && scale_agreement(b, 9) == Agreement::Plural =>
+            {
+                b.shift(9)
+            }
+            "milijardų" if b.is_range_free(9, 11) && scale_agreement(b, 9) == Agreement::Genitive => {
+                b.shift(9)
+            }
+            _ => Err(Error::NaN),
+        };
+        if status.is_ok()
+            && let Some(suffix) = ordinal
+        {
+            b.marker = MorphologicalMarker::Ordinal(suffix.into());
+            b.freeze();
+        }
+        status
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        match lemmatize(word).1 {
+            Some(suffix) => MorphologicalMarker::Ordinal(suffix.into()),
+            None => MorphologicalMarker::None,
+        }
+    }
+
+    fn language_code(&self) -> &'static str {
+        "lt"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        let ordinals = ORDINAL_STEMS.iter().flat_map(|stem| {
+            let endings: &[(&str, &str)] = if *stem == "treč" {
+                &SOFT_ORDINAL_ENDINGS
+            } else {
+                &ORDINAL_ENDINGS
+            };
+            endings.iter().map(move |(ending, _)| format!("{stem}{ending}"))
+        });
+        sorted_vocabulary(NUMBER_WORDS.iter().map(|word| String::from(*word)).chain(ordinals))
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        // "trys kablelis penki", "trys sveiki penki"
+        match word {
+            "kablelis" | "sveiki" | "sveikos" | "sveikų" => Some(','),
+            _ => None,
+        }
+    }
+
+    fn decimal_symbol(&self) -> char {
+        ','
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        // the words of the vocabulary, in context
+        is_number_word(word) && super::dry_run_start(self, word)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        is_number_word(word) && super::dry_run_continue(self, word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Lithuanian::new();
+            let res = text2digits($text, &f);
+            crate::tests::dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Lithuanian::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Lithuanian::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Lithuanian::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("vienas", "1");
+        assert_text2digits!("septyni", "7");
+        assert_text2digits!("dešimt", "10");
+        assert_text2digits!("vienuolika", "11");
+        assert_text2digits!("devyniolika", "19");
+        assert_text2digits!("dvidešimt", "20");
+        assert_text2digits!("dvidešimt trys", "23");
+        assert_text2digits!("keturiasdešimt du", "42");
+        assert_text2digits!("devyniasdešimt devyni", "99");
+        assert_text2digits!("šimtas", "100");
+        assert_text2digits!("šimtas dvidešimt", "120");
+        assert_text2digits!("šimtas vienas", "101");
+        assert_text2digits!("du šimtai", "200");
+        assert_text2digits!("trys šimtai keturiasdešimt penki", "345");
+        assert_text2digits!("devyni šimtai devyniasdešimt devyni", "999");
+        assert_text2digits!("tūkstantis", "1000");
+    }
+
+    #[test]
+    fn test_accusative() {
+        assert_text2digits!("dvidešimt tris", "23");
+        assert_text2digits!("vieną", "1");
+        assert_text2digits!("šimtą penkis", "105");
+        assert_text2digits!("du šimtus", "200");
+        assert_text2digits!("tūkstantį", "1000");
+        assert_text2digits!("penkis tūkstančius", "5000");
+        assert_text2digits!("tūkstantis milijonų", "1000000000");
+        assert_invalid!("tūkstantis milijonas");
+        assert_text2digits!("dvidešimt penkias", "25");
+    }
+
+    #[test]
+    fn test_scales() {
+        assert_text2digits!("vienas tūkstantis", "1000");
+        assert_text2digits!("tūkstantis šimtas", "1100");
+        assert_text2digits!("du tūkstančiai penki šimtai", "2500");
+        assert_text2digits!("dvidešimt vienas tūkstantis", "21000");
+        assert_text2digits!("dvidešimt du tūkstančiai", "22000");
+        assert_text2digits!("dvylika tūkstančių", "12000");
+        assert_text2digits!("dvidešimt tūkstančių", "20000");
+        assert_text2digits!("šimtas tūkstančių", "100000");
+        assert_text2digits!("milijonas", "1000000");
+        assert_text2digits!("trys milijonai du šimtai tūkstančių", "3200000");
+        assert_text2digits!("du milijonai tūkstantis", "2001000");
+        assert_text2digits!("dešimt milijardų", "10000000000");
+    }
+
+    #[test]
+    fn test_agreement() {
+        assert_invalid!("du tūkstantis");
+        assert_invalid!("penki tūkstančių");
+        assert_invalid!("dvidešimt tūkstančiai");
+        assert_invalid!("vienuolika tūkstantis");
+        assert_invalid!("trys šimtas");
+        assert_invalid!("vienas šimtai");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("pirmas", "1-as");
+        assert_text2digits!("antra", "2-a");
+        assert_text2digits!("trečias", "3-ias");
+        assert_text2digits!("penktoji", "5-oji");
+        assert_text2digits!("dešimtas", "10-as");
+        assert_text2digits!("dvyliktą", "12-ą");
+        assert_text2digits!("dvidešimt trečias", "23-ias");
+        assert_text2digits!("šimtas pirmas", "101-as");
+        assert_text2digits!("šimtasis", "100-asis");
+        assert_text2digits!("tūkstantasis", "1000-asis");
+        assert_invalid!("pirmas dvidešimt");
+        assert_invalid!("trečas");
+    }
+
+    #[test]
+    fn test_scale_ordinals() {
+        // the ordinal scale words don't agree with the number before them
+        assert_text2digits!("du šimtasis", "200-asis");
+        assert_text2digits!("trys tūkstantasis", "3000-asis");
+        assert_text2digits!("šeši milijonasis", "6000000-asis");
+        assert_text2digits!("du milijardasis", "2000000000-asis");
+        assert_invalid!("du tūkstantis");
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("nulis", "0");
+        assert_text2digits!("nulis aštuoni", "08");
+        assert_invalid!("penki nulis");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("dešimt penki");
+        assert_invalid!("penki šimtas");
+        assert_invalid!("tūkstantis tūkstantis");
+        assert_invalid!("šešiasdešimt penkiolika");
+        assert_invalid!("tūkstančiai");
+        assert_invalid!("dešimtys");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "Turime dvidešimt penkias karves, dvylika viščiukų ir šimtą dvidešimt penkis kilogramus bulvių.",
+            "Turime 25 karves, 12 viščiukų ir 125 kilogramus bulvių."
+        );
+        assert_replace_numbers!("Tūkstantis du šimtai šešiasdešimt šeši eurai", "1266 eurai");
+        assert_replace_numbers!(
+            "vienas du trys keturi dvidešimt penkiolika",
+            "1 2 3 4 20 15"
+        );
+        assert_replace_numbers!(
+            "dešimtys žmonių ir tūkstančiai žvaigždžių",
+            "dešimtys žmonių ir tūkstančiai žvaigždžių"
+        );
+    }
+
+    #[test]
+    fn test_replace_decimals() {
+        assert_replace_numbers!(
+            "dvylika kablelis devyniasdešimt devyni, šimtas dvidešimt kablelis nulis penki",
+            "12,99, 120,05"
+        );
+        assert_replace_all_numbers!("trys sveiki penki", "3,5");
+    }
+
+    #[test]
+    fn test_replace_ordinals() {
+        assert_replace_numbers!(
+            "Jis buvo dvidešimt trečias lenktynėse, o ji dvidešimt pirma.",
+            "Jis buvo 23-ias lenktynėse, o ji 21-a."
+        );
+        assert_replace_all_numbers!("penkta diena, pirmas kursas", "5-a diena, 1-as kursas");
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("DVIDEŠIMT TRYS", "23");
+        assert_replace_all_numbers!("Šimtas Dvidešimt", "120");
+    }
+}
@@ -0,0 +1,23 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "ir", "plius", "minus", "kart", "yra", "buvo", "na", "ee", "tai", "dar", "paskui", "taigi"
+};
+
+/// The number words, inflected by [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "nulis", "vienas", "viena", "vieną", "du", "dvi", "trys", "tris", "keturi", "keturios",
+    "keturis", "keturias", "penki", "penkios", "penkis", "penkias", "šeši", "šešios", "šešis",
+    "šešias", "septyni", "septynios", "septynis", "septynias", "aštuoni", "aštuonios", "aštuonis",
+    "aštuonias", "devyni", "devynios", "devynis", "devynias", "dešimt", "vienuolika", "dvylika",
+    "trylika", "keturiolika", "penkiolika", "šešiolika", "septyniolika", "aštuoniolika",
+    "devyniolika", "šimtas", "šimtą", "tūkstantis", "tūkstantį", "tūkstančių", "milijonas",
+    "milijoną", "milijonų", "milijardas", "milijardą", "milijardus", "milijardų", "pirmas",
+    "antras", "trečias", "ketvirtas", "penktas", "šeštas", "septintas", "aštuntas", "devintas",
+    "dešimtas", "vienuoliktas", "dvyliktas", "tryliktas", "keturioliktas", "penkioliktas",
+    "šešioliktas", "septynioliktas", "aštuonioliktas", "devynioliktas", "dvidešimtas",
+    "trisdešimtas", "keturiasdešimtas", "penkiasdešimtas", "šešiasdešimtas", "septyniasdešimtas",
+    "aštuoniasdešimtas", "devyniasdešimtas", "šimtasis", "tūkstantasis", "milijonasis",
+    "milijardasis", "šimtai", "šimtus", "tūkstančiai", "tūkstančius", "milijonai", "milijonus",
+    "milijardai",
+];
@@ -18,7 +18,8 @@ The `DigitString` is responsible for checking the validity of the constructed nu
 The intepretor part, which is specific to each language, is built by implementing the `Langinterpreter` trait, which
 translate each number word into a sequence of elementary instructions on a `DigitString`.
 
-A language is just an empty (stateless) type. Everything is provided by implementating the trait.
+A language is just a stateless type, usually empty, or holding a few settings like its [`Scale`].
+Everything is provided by implementating the trait.
 
 Look at the source of the builtin languages as examples.
 */
@@ -28,21 +29,89 @@ use alloc::{string::String, vec::Vec};
 use crate::digit_string::DigitString;
 
 use crate::error::Error;
+use crate::options::{Options, OrdinalStyle};
+
+pub mod data;
+
+pub use data::{DataLanguage, LanguageTable};
+
+/// The number of tokens, separators included, that [`LangInterpreter::basic_annotate`] may look at
+/// on each side of a token.
+pub const ANNOTATION_CONTEXT: usize = 32;
 
 pub trait BasicAnnotate {
     fn text_lowercase(&self) -> &str;
     fn set_nan(&mut self, val: bool);
+    /// Record the role of the token in the number grammar.
+    ///
+    /// Only called by [`annotate_numbers`](crate::annotate_numbers); the default ignores it.
+    fn set_kind(&mut self, _kind: TokenKind) {}
+    /// Record the index of the number occurence the token is part of, if any.
+    ///
+    /// Only called by [`annotate_numbers`](crate::annotate_numbers); the default ignores it.
+    fn set_group_id(&mut self, _id: Option<usize>) {}
+}
+
+/// The role of a token in the number grammar of a language, regardless of its context.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TokenKind {
+    /// A cardinal number word, like "*twenty*"
+    NumberWord,
+    /// A word that may link number words, like "*and*"
+    Linking,
+    /// Punctuation and spaces
+    Separator,
+    /// A decimal separator word, like "*point*"
+    DecimalSep,
+    /// An ordinal number word, like "*twentieth*"
+    OrdinalWord,
+    Other,
+}
+
+/// The decoration of a digit form: something before the digits and/or something after.
+///
+/// Most languages only use a suffix ("*20th*"), but some use a prefix (Indonesian "*ke-20*") or both.
+/// A suffix-only affix can be built from a bare string with `From`/`Into`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Affix {
+    pub prefix: &'static str,
+    pub suffix: &'static str,
+}
+
+impl Affix {
+    pub const fn suffix(suffix: &'static str) -> Self {
+        Self { prefix: "", suffix }
+    }
+
+    pub const fn prefix(prefix: &'static str) -> Self {
+        Self { prefix, suffix: "" }
+    }
+
+    pub const fn circumfix(prefix: &'static str, suffix: &'static str) -> Self {
+        Self { prefix, suffix }
+    }
+
+    /// Decorate `digits` with the affix.
+    pub fn wrap<D: core::fmt::Display>(&self, digits: D) -> String {
+        alloc::format!("{}{digits}{}", self.prefix, self.suffix)
+    }
+}
+
+impl From<&'static str> for Affix {
+    fn from(suffix: &'static str) -> Self {
+        Self::suffix(suffix)
+    }
 }
 
 /// Model the Morphological markers that differenciate ordinals or fractions from cardinals,
 /// and that must be retained on the digit form.
 ///
 /// For examples in English, "*twentieth*" becomes "*20th*", the ordinal marker "*th*"
-/// (`MorphologicalMarker::Ordinal("th")`) is kept.
-#[derive(Debug, PartialEq)]
+/// (`MorphologicalMarker::Ordinal("th".into())`) is kept.
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum MorphologicalMarker {
-    Ordinal(&'static str),
-    Fraction(&'static str),
+    Ordinal(Affix),
+    Fraction(Affix),
     None,
 }
 
@@ -58,6 +127,36 @@ impl MorphologicalMarker {
     }
 }
 
+/// A word that can be the denominator of a fraction, like "*fifths*" in "*two fifths*"
+/// (see [`LangInterpreter::fraction_denominator`]).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Denominator {
+    pub value: u64,
+    /// The word is also an ordinal, like "*fifth*": after a cardinal, it is only read as a denominator
+    /// if followed by a fraction context word (see [`LangInterpreter::is_fraction_context`]), as in "*one fifth of*".
+    pub needs_context: bool,
+}
+
+/// The meaning of the scale words beyond "*million*", for the languages where it depends on the audience.
+///
+/// On the short scale, each new word is a thousand times the previous one: a "*billion*" is 10⁹.
+/// On the long scale, it is a million times: a "*billion*" is 10¹², and 10⁹ is a "*thousand million*".
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Scale {
+    Short,
+    Long,
+}
+
+impl Scale {
+    /// The power of ten of the `rank`-th "*-illion*" word: 1 for "*million*", 2 for "*billion*"…
+    pub const fn illion(self, rank: usize) -> usize {
+        match self {
+            Scale::Short => 3 * rank + 3,
+            Scale::Long => 6 * rank,
+        }
+    }
+}
+
 /// This trait describes the linguistic services a human language interpreter must provide.
 ///
 /// All methods must be implemented except the [`exec_group`](Self::exec_group), which comes with a default implementation.
@@ -67,6 +166,9 @@ pub trait LangInterpreter {
     ///
     /// `num_func` is interpreted by calling the appropriate methods on `b`.
     /// `b` is responsible for maintaining state, you don't have to care about it.
+    ///
+    /// An ordinal scale word after a cardinal makes an ordinal of the whole number: "*six millionth*" is
+    /// the 6000000th, not 6 and the 1000000th, as in all the builtin languages.
     fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error>;
     /// Interpret the word `decimal_func` in the context of the decimal part of a number.
     ///
@@ -82,14 +184,60 @@ pub trait LangInterpreter {
     ///
     /// For example "*point*" is a decimal separator in English, figured as `'.'`
     fn check_decimal_separator(&self, word: &str) -> Option<char>;
+    /// The symbol the language writes between the integral part and the decimals of a number
+    /// that no separator word introduces, like a fraction written as a decimal number
+    /// (see [`FractionOutput::Decimal`](crate::FractionOutput::Decimal)). The default is `'.'`.
+    fn decimal_symbol(&self) -> char {
+        '.'
+    }
+    /// Return the integer part, the decimal separator symbol and the decimal digits of `word`
+    /// if it is a decimal number by itself, like "*zweieinhalb*" (2.5) in German: `Some(("zwei", ',', "5"))`.
+    ///
+    /// An empty integer part stands for the number that precedes the word, as in "*zwei einhalb*".
+    /// The decimal number is complete with that word. The default recognizes none.
+    fn split_decimal_word<'a>(&self, _word: &'a str) -> Option<(&'a str, char, &'static str)> {
+        None
+    }
+    /// Return the number of digits of the decimals after the separator `word` if they are read as a cardinal
+    /// of that many digits, zero-padded, like the cents after the "*con*" of Spanish prices: "*tres con cinco*" is 3,05.
+    ///
+    /// A word that would make the decimals longer ends the number, as "*ciento*" in "*tres con ciento*".
+    /// The default is `None`, for decimals of any length, as they are spoken.
+    fn decimal_width(&self, _word: &str) -> Option<usize> {
+        None
+    }
+    /// Render the digits of `b` decorated with `marker`.
+    ///
+    /// The marker is passed whole, so that languages whose digit form depends on gender, number
+    /// or case can pick the right rendering from it. The default wraps the digits in the affix of
+    /// an ordinal marker, and writes the digits of a fraction marker bare: a fraction is no decorated
+    /// number, so the languages that have them write them in [`format_and_value`](Self::format_and_value),
+    /// like Spanish "*onceavo*" as "*1/11*".
+    fn format_marked(&self, b: &DigitString, marker: MorphologicalMarker) -> String {
+        match marker {
+            MorphologicalMarker::Ordinal(affix) => affix.wrap(b),
+            MorphologicalMarker::Fraction(_) | MorphologicalMarker::None => {
+                alloc::string::ToString::to_string(&b)
+            }
+        }
+    }
+    /// Return the affix of the ordinal digit forms marked with `affix` in the given `style`,
+    /// or `None` if that style makes no sense in the language (see [`Options::ordinal_style`](crate::Options::ordinal_style)).
+    ///
+    /// `affix` is the one returned by [`get_morph_marker`](Self::get_morph_marker), that is the [`OrdinalStyle::Plain`] rendering,
+    /// of the digits `b`. The default only knows the [`OrdinalStyle::PeriodOnly`] rendering.
+    fn styled_ordinal(
+        &self,
+        _b: &DigitString,
+        _affix: Affix,
+        style: OrdinalStyle,
+    ) -> Option<Affix> {
+        (style == OrdinalStyle::PeriodOnly).then_some(Affix::suffix("."))
+    }
     /// Format `b` as digit string and evaluate it, according to the language's rules.
     fn format_and_value(&self, b: &DigitString) -> (String, f64) {
         let val: f64 = b.parse() as f64;
-        if let MorphologicalMarker::Ordinal(marker) = b.marker {
-            (alloc::format!("{b}{marker}"), val)
-        } else {
-            (alloc::string::ToString::to_string(&b), val)
-        }
+        (self.format_marked(b, b.marker), val)
     }
     /// Format the decimal number given as integral part `int` and decimals `dec` according the the language's rules
     /// and using the decimal separator `sep` (previously returned by [`Self::check_decimal_separator()`])
@@ -110,29 +258,389 @@ pub trait LangInterpreter {
     /// that separate unrelated numbers. So the method would return `false` for them.
     /// This function is used to find isolate numbers.
     fn is_linking(&self, word: &str) -> bool;
+    /// Return true if `word` is a vague quantifier, like "*few*" or "*several*".
+    ///
+    /// The scale words that follow a vague quantifier, like "*hundred*" in "*a few hundred*", are not numbers.
+    /// The default recognizes none.
+    fn is_vague_quantifier(&self, _word: &str) -> bool {
+        false
+    }
+    /// Decide whether the number made of the single word `number` (lowercase) converts before the word `next`
+    /// (lowercase), like "*ein*" in "*ein Uhr*" but not in "*ein bisschen*".
+    ///
+    /// This only concerns the numbers under the threshold (see [`Options::threshold`](crate::Options::threshold)):
+    /// `Some(true)` converts the number even if it is isolated, `Some(false)` never converts it, even in a sequence
+    /// of numbers, and `None` leaves it to the isolation rule. The default always returns `None`.
+    fn lone_number_context(&self, _number: &str, _next: &str) -> Option<bool> {
+        None
+    }
+    /// Return true if the number made of the single word `number` (lowercase) is a pronoun, after the word `previous`
+    /// and before the word `next` (lowercase, if any), like "*one*" in "*the one I like*" or "*which one*".
+    ///
+    /// Unlike [`lone_number_context`](Self::lone_number_context), this applies whatever the threshold:
+    /// the pronouns are never converted. The default recognizes none.
+    fn is_pronoun(&self, _previous: Option<&str>, _number: &str, _next: Option<&str>) -> bool {
+        false
+    }
     /// Process the `group` as all or nothing.
-    fn exec_group<'a, I: Iterator<Item = &'a str>>(&self, group: I) -> Result<DigitString, Error> {
+    fn exec_group<'a, I: Iterator<Item = &'a str>>(&self, group: I) -> Result<DigitString, Error>
+    where
+        Self: Sized,
+    {
+        exec_group(self, group)
+    }
+
+    /// Mark the `tokens` that are not part of a number in their context (see [`BasicAnnotate::set_nan`]).
+    ///
+    /// The replacement in texts annotates their tokens by chunks: the marks should not depend on
+    /// tokens more than [`ANNOTATION_CONTEXT`] tokens away. The default marks none.
+    fn basic_annotate<T: BasicAnnotate>(&self, _tokens: &mut Vec<T>)
+    where
+        Self: Sized,
+    {
+    }
+
+    /// Return the role of `word` (lowercase) in the number grammar, out of context.
+    fn token_kind(&self, word: &str) -> TokenKind {
         let mut b = DigitString::new();
-        let mut incomplete: bool = false;
-        for token in group {
-            incomplete = match self.apply(token, &mut b) {
-                Err(Error::Incomplete) => true,
-                Ok(()) => false,
-                Err(error) => return Err(error),
-            };
-        }
-        if incomplete {
-            Err(Error::Incomplete)
+        if self.apply(word, &mut b).is_ok() {
+            if b.is_ordinal() {
+                TokenKind::OrdinalWord
+            } else {
+                TokenKind::NumberWord
+            }
+        } else if self.check_decimal_separator(word).is_some() {
+            TokenKind::DecimalSep
+        } else if word.chars().all(|c| !c.is_alphanumeric()) {
+            TokenKind::Separator
+        } else if self.is_linking(word) {
+            TokenKind::Linking
         } else {
-            Ok(b)
+            TokenKind::Other
+        }
+    }
+
+    /// Return true if `word` (lowercase) may be the first word of a number.
+    ///
+    /// This is a query for external tokenizers, that don't run the pipeline, along with
+    /// [`can_continue`](Self::can_continue), [`check_decimal_separator`](Self::check_decimal_separator)
+    /// and [`is_linking`](Self::is_linking).
+    /// The default dry-runs [`apply`](Self::apply) on a fresh [`DigitString`].
+    ///
+    /// For example, a whitespace tokenizer can gather the words that may make numbers:
+    ///
+    /// ```rust
+    /// use text2num::{LangInterpreter, Language};
+    ///
+    /// fn number_spans<'a>(text: &'a str, lang: &impl LangInterpreter) -> Vec<Vec<&'a str>> {
+    ///     let mut spans: Vec<Vec<&str>> = Vec::new();
+    ///     let mut in_number = false;
+    ///     for word in text.split_whitespace() {
+    ///         let lower = word.to_lowercase();
+    ///         if in_number
+    ///             && (lang.can_continue(&lower) || lang.check_decimal_separator(&lower).is_some())
+    ///         {
+    ///             spans.last_mut().unwrap().push(word);
+    ///         } else if lang.can_start_number(&lower) {
+    ///             spans.push(vec![word]);
+    ///             in_number = true;
+    ///         } else {
+    ///             in_number = false;
+    ///         }
+    ///     }
+    ///     spans
+    /// }
+    ///
+    /// let en = Language::english();
+    /// assert_eq!(
+    ///     number_spans("Twenty one point five dollars and two hundred and six cents", &en),
+    ///     vec![vec!["Twenty", "one", "point", "five"], vec!["two", "hundred", "and", "six"]]
+    /// );
+    /// ```
+    fn can_start_number(&self, word: &str) -> bool {
+        dry_run_start(self, word)
+    }
+    /// Return true if `word` (lowercase) may follow a number word in the same number, like "*five*"
+    /// or "*and*" in English. Decimal separators are left to [`check_decimal_separator`](Self::check_decimal_separator).
+    ///
+    /// The answer is a best effort, out of context: the default dry-runs [`apply`](Self::apply) after
+    /// a few typical numbers, so that a word is accepted if it may continue one of them.
+    fn can_continue(&self, word: &str) -> bool {
+        dry_run_continue(self, word)
+    }
+
+    /// Return the rank expressed by `word` if it is an adverbial ordinal, like "*firstly*" in English.
+    ///
+    /// Adverbial ordinals are never interpreted by [`apply`](Self::apply), as they are discourse markers,
+    /// but they can be converted on request (see [`Options::adverbial_ordinals`](crate::Options::adverbial_ordinals)).
+    /// The default recognizes none.
+    fn adverbial_ordinal(&self, _word: &str) -> Option<u64> {
+        None
+    }
+    /// Format `rank` as an enumeration marker, like "*1.*"
+    fn format_adverbial_ordinal(&self, rank: u64) -> String {
+        alloc::format!("{rank}.")
+    }
+
+    /// Return the value of the numeral noun `noun` if it makes an informal quantity with `determiner`,
+    /// like "*a pair*" in English.
+    ///
+    /// Informal quantities are only converted on request (see [`Options::informal_quantities`](crate::Options::informal_quantities)),
+    /// and only if [`completes_informal_quantity`](Self::completes_informal_quantity) accepts what follows.
+    /// The default recognizes none.
+    fn informal_quantity(&self, _determiner: &str, _noun: &str) -> Option<u64> {
+        None
+    }
+    /// Return true if `linker` followed by `word` completes an informal quantity frame, like "*of shoes*".
+    fn completes_informal_quantity(&self, _linker: &str, _word: &str) -> bool {
+        false
+    }
+    /// Return the denominator expressed by `word` if it can be the denominator of a fraction after a cardinal,
+    /// like "*fifths*" in "*two fifths*".
+    ///
+    /// Fractions are only recognized after a complete cardinal, the numerator. The default recognizes none.
+    fn fraction_denominator(&self, _word: &str) -> Option<Denominator> {
+        None
+    }
+    /// The lowercase connectors of the fractions spoken as two cardinals, like "*over*" in "*three over four*",
+    /// with their words separated by single spaces ("*out of*").
+    ///
+    /// Spoken fractions are only recognized on request (see [`Options::spoken_fractions`](crate::Options::spoken_fractions)).
+    /// The default has none.
+    fn fraction_connectors(&self) -> &'static [&'static str] {
+        &[]
+    }
+    /// The lowercase phrases of two words or more that add a simple fraction to the cardinal before them,
+    /// like "*et demi*" in French "*deux et demi*" (2,5), with their words separated by single spaces,
+    /// the decimal separator and the decimals they add.
+    ///
+    /// The number is a complete decimal number after the phrase. The default has none.
+    fn fraction_tails(&self) -> &'static [(&'static str, char, &'static str)] {
+        &[]
+    }
+    /// The lowercase phrases like the [`fraction_tails`](Self::fraction_tails), that add a unit fraction without
+    /// a finite decimal form, like "*and a third*" in "*two and a third*", with the denominator of the fraction.
+    ///
+    /// The number is a [`NumberKind::Fraction`](crate::NumberKind::Fraction), written as a mixed number ("*2 1/3*")
+    /// or a decimal one, following [`Options::fraction_output`](crate::Options::fraction_output). The default has none.
+    fn unit_fraction_tails(&self) -> &'static [(&'static str, u64)] {
+        &[]
+    }
+    /// Return true if `word`, following a denominator that is also an ordinal, makes it a fraction,
+    /// like "*of*" in "*one fifth of the users*".
+    fn is_fraction_context(&self, _word: &str) -> bool {
+        false
+    }
+    /// The lowercase words that join the two numbers of a score, like "*to*" in "*three to two*".
+    ///
+    /// Scores are only recognized on request (see [`Options::scores`](crate::Options::scores)).
+    /// The default has none, and only recognizes the scores read without a connector ("*three two*").
+    fn score_connectors(&self) -> &'static [&'static str] {
+        &[]
+    }
+    /// The options that suit the texts of the language best (see [`Options::recommended_for`]).
+    ///
+    /// The default converts the lone numbers from ten up, as [`Options::new(10.0)`](Options::new) does.
+    fn recommended_options(&self) -> Options {
+        Options::new(10.0)
+    }
+    /// The lowercase phrases that join the two ends of a range of ordinals, like "*to the*"
+    /// in "*from the first to the third*", their words separated by a space.
+    ///
+    /// Both ends of a range are converted, even if they are lone ordinals under the threshold.
+    /// The default has none.
+    fn range_connectors(&self) -> &'static [&'static str] {
+        &[]
+    }
+    /// Return true if `word`, as written, is the name of a month, like "*June*".
+    ///
+    /// A lone ordinal under the threshold is converted next to a month name, as in "*June fifth*"
+    /// or "*the fifth of June*" (see [`is_date_connector`](Self::is_date_connector)). The word is not lowercased,
+    /// as some month names are common words too, like "*may*". The default recognizes none.
+    fn is_month_name(&self, _word: &str) -> bool {
+        false
+    }
+    /// Return true if the ordinal `day`, right before a month name, is written with plain digits,
+    /// like French "*le troisième mai*" that gives "*le 3 mai*" but "*le premier mai*" "*le 1er mai*".
+    ///
+    /// The number is then a cardinal. The default keeps the ordinals as they are.
+    fn is_cardinal_day(&self, _day: u64) -> bool {
+        false
+    }
+    /// Return true if the lowercase `word` joins an ordinal to the month name that follows it,
+    /// like "*of*" in "*the fifth of June*".
+    fn is_date_connector(&self, _word: &str) -> bool {
+        false
+    }
+    /// Return the length in bytes of the number that starts the hyphenated compound `word` (lowercase),
+    /// if the compound is not a number as a whole, like "*three*" in "*three-dimensional*".
+    ///
+    /// The default looks for the longest run of hyphen separated pieces that [`exec_group`](Self::exec_group)
+    /// accepts as one word.
+    /// It is used with [`Options::convert_in_hyphenated_compounds`](crate::Options::convert_in_hyphenated_compounds).
+    fn hyphenated_number_prefix(&self, word: &str) -> Option<usize> {
+        if exec_group(self, core::iter::once(word)).is_ok() {
+            return None;
         }
+        word.match_indices('-')
+            .map(|(pos, _)| pos)
+            .rev()
+            .find(|&pos| exec_group(self, core::iter::once(&word[..pos])).is_ok())
+    }
+    /// Return the byte offset of the number that ends the hyphenated compound `word` (lowercase),
+    /// if the compound is not a number as a whole, like "*twenty*" in "*porridge-twenty*".
+    ///
+    /// The default returns the longest suffix after a hyphen that [`apply`](Self::apply) accepts as one word.
+    /// It is used with [`Options::salvage_partial_groups`](crate::Options::salvage_partial_groups).
+    fn hyphenated_number_suffix(&self, word: &str) -> Option<usize> {
+        if exec_group(self, core::iter::once(word)).is_ok() {
+            return None;
+        }
+        word.match_indices('-')
+            .map(|(pos, _)| pos + 1)
+            .find(|&start| exec_group(self, core::iter::once(&word[start..])).is_ok())
+    }
+    /// The lowercase clitics that may be attached to the end of a number word, like "*'s*" in "*twenty's*".
+    ///
+    /// They are only stripped on request (see [`Options::strip_clitics`](crate::Options::strip_clitics)).
+    /// The default has none.
+    fn clitics(&self) -> &'static [&'static str] {
+        &[]
+    }
+    /// Return true if the runs of ideographs, like "*三百二十*", must be handed whole to [`apply`](Self::apply),
+    /// for the interpreter to cut them itself.
+    ///
+    /// It only matters with the `unicode-segmentation` feature, that cuts the words of the scripts written
+    /// without spaces on their Unicode word boundaries: one ideograph at a time, the default.
+    fn prefers_ideographic_runs(&self) -> bool {
+        false
+    }
+    /// The ISO 639-1 code of the language, like `"en"`, or `"und"` (the default) if undetermined.
+    fn language_code(&self) -> &'static str {
+        "und"
+    }
+    /// The number words of the language, for autocompletion or documentation: the words
+    /// [`apply`](Self::apply) and [`apply_decimal`](Self::apply_decimal) accept, like "*seven*" or "*hundredth*".
+    ///
+    /// The inflected forms are built from the endings the language reads, like "*zweiten*" or "*prima*", and the
+    /// linking words, like "*and*", are left out.
+    /// The languages that fuse their numbers in compounds, like German "*dreiundzwanzig*", list the pieces of the
+    /// compounds and the hundreds and thousands written as one word, like Italian "*duecento*": the other compounds
+    /// can't be enumerated.
+    /// The default has none.
+    fn vocabulary(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// The `words` of a [`LangInterpreter::vocabulary`], sorted and without duplicates.
+pub(crate) fn sorted_vocabulary(words: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut words: Vec<String> = words.into_iter().collect();
+    words.sort_unstable();
+    words.dedup();
+    words
+}
+
+/// The default [`LangInterpreter::can_start_number`], for the words a language can't look up, like compounds.
+pub(crate) fn dry_run_start<L: LangInterpreter + ?Sized>(lang: &L, word: &str) -> bool {
+    let mut b = DigitString::new();
+    matches!(lang.apply(word, &mut b), Ok(()) | Err(Error::Incomplete))
+}
+
+/// The default [`LangInterpreter::can_continue`], for the words a language can't look up, like compounds.
+pub(crate) fn dry_run_continue<L: LangInterpreter + ?Sized>(lang: &L, word: &str) -> bool {
+    [b"1".as_slice(), b"20", b"30", b"100", b"1000", b"1000000"]
+        .iter()
+        .any(|seed| {
+            let mut b = DigitString::new();
+            b.put(seed).is_ok()
+                && matches!(lang.apply(word, &mut b), Ok(()) | Err(Error::Incomplete))
+        })
+}
+
+/// The default [`LangInterpreter::exec_group`], also available to unsized interpreters.
+fn exec_group<'a, L, I>(lang: &L, group: I) -> Result<DigitString, Error>
+where
+    L: LangInterpreter + ?Sized,
+    I: Iterator<Item = &'a str>,
+{
+    let mut b = DigitString::new();
+    let mut incomplete: bool = false;
+    for token in group {
+        incomplete = match lang.apply(token, &mut b) {
+            Err(Error::Incomplete) => true,
+            Ok(()) => false,
+            Err(error) => return Err(error),
+        };
+    }
+    if incomplete {
+        Err(Error::Incomplete)
+    } else {
+        Ok(b)
+    }
+}
+
+/// A [`LangInterpreter`] that can be shared between threads as a trait object,
+/// like the languages registered at run time with the `registry` feature.
+///
+/// It is implemented for all the stateless interpreters that are `Debug`, `Send` and `Sync`.
+pub trait DynLangInterpreter: LangInterpreter + core::fmt::Debug + Send + Sync {
+    /// [`LangInterpreter::basic_annotate`], that is generic, on tokens seen as trait objects.
+    ///
+    /// Only the marks set on the tokens count: changes to the vector itself are not seen by the caller.
+    fn dyn_basic_annotate(&self, tokens: &mut Vec<&mut dyn BasicAnnotate>);
+}
+
+impl<T: LangInterpreter + core::fmt::Debug + Send + Sync> DynLangInterpreter for T {
+    fn dyn_basic_annotate(&self, tokens: &mut Vec<&mut dyn BasicAnnotate>) {
+        self.basic_annotate(tokens)
+    }
+}
+
+impl BasicAnnotate for &mut dyn BasicAnnotate {
+    fn text_lowercase(&self) -> &str {
+        (**self).text_lowercase()
+    }
+
+    fn set_nan(&mut self, val: bool) {
+        (**self).set_nan(val)
+    }
+
+    fn set_kind(&mut self, kind: TokenKind) {
+        (**self).set_kind(kind)
+    }
+
+    fn set_group_id(&mut self, id: Option<usize>) {
+        (**self).set_group_id(id)
     }
+}
 
-    fn basic_annotate<T: BasicAnnotate>(&self, _tokens: &mut Vec<T>) {}
+/// The reason why [`resolve_language`] found no interpreter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LanguageError {
+    /// No language has this code
+    UnknownCode(String),
+    /// The language of `code` is builtin, but its cargo `feature` was not enabled
+    NotCompiled { code: String, feature: &'static str },
+}
+
+impl core::fmt::Display for LanguageError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownCode(code) => write!(f, "unknown language code \"{code}\""),
+            Self::NotCompiled { code, feature } => write!(
+                f,
+                "language \"{code}\" is not compiled in, enable the \"{feature}\" feature of text2num"
+            ),
+        }
+    }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for LanguageError {}
+
 macro_rules! declare_languages {
-    ($(($feature: literal, $module: ident::$name: ident, $function: ident)),* $(,)?) => {
+    ($(($feature: literal, $module: ident::$name: ident, $function: ident $(, $alias: literal)*)),* $(,)?) => {
         $(
             #[cfg(feature = $feature)]
             mod $module;
@@ -140,14 +648,35 @@ macro_rules! declare_languages {
             pub use $module::$name;
         )*
 
+        #[cfg(not(any($(feature = $feature),*)))]
+        compile_error!(concat!(
+            "text2num needs at least one language feature, among:",
+            $(" ", $feature,)*
+        ));
+
         /// A convenience enum that encapsulates the builtin languages in a single type.
+        ///
+        /// Only the languages enabled by cargo features are available, and more may be added in the future.
+        ///
+        /// The builtin languages are stateless and their tables static: a `Language` costs nothing to build
+        /// or copy, and can be shared between threads.
+        #[non_exhaustive]
+        #[derive(Debug, Clone, Copy)]
         pub enum Language {
             $(
                 #[cfg(feature = $feature)]
                 $name($module::$name),
             )*
+            /// A language registered at run time (see [`register_language`](crate::registry::register_language))
+            #[cfg(feature = "registry")]
+            Registered(&'static dyn DynLangInterpreter),
         }
 
+        const _: () = {
+            const fn shareable<T: Send + Sync + Copy>() {}
+            shareable::<Language>();
+        };
+
         impl Language {
             $(
                 #[cfg(feature = $feature)]
@@ -157,7 +686,7 @@ macro_rules! declare_languages {
             )*
         }
 
-        #[allow(unused)]
+        #[cfg(any($(feature = $feature),*))]
         impl LangInterpreter for Language {
             fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
                 match self {
@@ -165,7 +694,8 @@ macro_rules! declare_languages {
                         #[cfg(feature = $feature)]
                         Language::$name(l) => l.apply(num_func, b),
                     )*
-                    _ => unimplemented!()
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.apply(num_func, b),
                 }
             }
 
@@ -175,7 +705,8 @@ macro_rules! declare_languages {
                         #[cfg(feature = $feature)]
                         Language::$name(l) => l.apply_decimal(decimal_func, b),
                     )*
-                    _ => unimplemented!()
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.apply_decimal(decimal_func, b),
                 }
             }
 
@@ -185,7 +716,8 @@ macro_rules! declare_languages {
                         #[cfg(feature = $feature)]
                         Language::$name(l) => l.get_morph_marker(word),
                     )*
-                    _ => unimplemented!()
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.get_morph_marker(word),
                 }
             }
             fn check_decimal_separator(&self, word: &str) -> Option<char>{
@@ -194,16 +726,369 @@ macro_rules! declare_languages {
                         #[cfg(feature = $feature)]
                         Language::$name(l) => l.check_decimal_separator(word),
                     )*
-                    _ => unimplemented!()
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.check_decimal_separator(word),
+                }
+            }
+            fn decimal_symbol(&self) -> char {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.decimal_symbol(),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.decimal_symbol(),
+                }
+            }
+            fn format_marked(&self, b: &DigitString, marker: MorphologicalMarker) -> String {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.format_marked(b, marker),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.format_marked(b, marker),
+                }
+            }
+
+            fn token_kind(&self, word: &str) -> TokenKind {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.token_kind(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.token_kind(word),
+                }
+            }
+
+            fn is_vague_quantifier(&self, word: &str) -> bool {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.is_vague_quantifier(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.is_vague_quantifier(word),
+                }
+            }
+
+            fn lone_number_context(&self, number: &str, next: &str) -> Option<bool> {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.lone_number_context(number, next),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.lone_number_context(number, next),
+                }
+            }
+
+            fn is_pronoun(&self, previous: Option<&str>, number: &str, next: Option<&str>) -> bool {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.is_pronoun(previous, number, next),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.is_pronoun(previous, number, next),
+                }
+            }
+
+            fn can_start_number(&self, word: &str) -> bool {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.can_start_number(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.can_start_number(word),
+                }
+            }
+
+            fn can_continue(&self, word: &str) -> bool {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.can_continue(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.can_continue(word),
+                }
+            }
+
+            fn adverbial_ordinal(&self, word: &str) -> Option<u64> {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.adverbial_ordinal(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.adverbial_ordinal(word),
+                }
+            }
+
+            fn decimal_width(&self, word: &str) -> Option<usize> {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.decimal_width(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.decimal_width(word),
+                }
+            }
+
+            fn split_decimal_word<'a>(&self, word: &'a str) -> Option<(&'a str, char, &'static str)> {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.split_decimal_word(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.split_decimal_word(word),
                 }
             }
+
+            fn styled_ordinal(&self, b: &DigitString, affix: Affix, style: OrdinalStyle) -> Option<Affix> {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.styled_ordinal(b, affix, style),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.styled_ordinal(b, affix, style),
+                }
+            }
+
+            fn format_adverbial_ordinal(&self, rank: u64) -> String {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.format_adverbial_ordinal(rank),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.format_adverbial_ordinal(rank),
+                }
+            }
+
+            fn informal_quantity(&self, determiner: &str, noun: &str) -> Option<u64> {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.informal_quantity(determiner, noun),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.informal_quantity(determiner, noun),
+                }
+            }
+
+            fn completes_informal_quantity(&self, linker: &str, word: &str) -> bool {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.completes_informal_quantity(linker, word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.completes_informal_quantity(linker, word),
+                }
+            }
+
+            fn fraction_denominator(&self, word: &str) -> Option<Denominator> {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.fraction_denominator(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.fraction_denominator(word),
+                }
+            }
+
+            fn is_fraction_context(&self, word: &str) -> bool {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.is_fraction_context(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.is_fraction_context(word),
+                }
+            }
+
+            fn score_connectors(&self) -> &'static [&'static str] {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.score_connectors(),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.score_connectors(),
+                }
+            }
+
+            fn recommended_options(&self) -> Options {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.recommended_options(),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.recommended_options(),
+                }
+            }
+
+            fn range_connectors(&self) -> &'static [&'static str] {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.range_connectors(),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.range_connectors(),
+                }
+            }
+
+            fn is_month_name(&self, word: &str) -> bool {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.is_month_name(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.is_month_name(word),
+                }
+            }
+
+            fn is_cardinal_day(&self, day: u64) -> bool {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.is_cardinal_day(day),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.is_cardinal_day(day),
+                }
+            }
+
+            fn is_date_connector(&self, word: &str) -> bool {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.is_date_connector(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.is_date_connector(word),
+                }
+            }
+
+            fn hyphenated_number_prefix(&self, word: &str) -> Option<usize> {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.hyphenated_number_prefix(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.hyphenated_number_prefix(word),
+                }
+            }
+
+            fn hyphenated_number_suffix(&self, word: &str) -> Option<usize> {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.hyphenated_number_suffix(word),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.hyphenated_number_suffix(word),
+                }
+            }
+
+            fn fraction_connectors(&self) -> &'static [&'static str] {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.fraction_connectors(),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.fraction_connectors(),
+                }
+            }
+
+            fn fraction_tails(&self) -> &'static [(&'static str, char, &'static str)] {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.fraction_tails(),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.fraction_tails(),
+                }
+            }
+
+            fn unit_fraction_tails(&self) -> &'static [(&'static str, u64)] {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.unit_fraction_tails(),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.unit_fraction_tails(),
+                }
+            }
+
+            fn clitics(&self) -> &'static [&'static str] {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.clitics(),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.clitics(),
+                }
+            }
+
+            fn prefers_ideographic_runs(&self) -> bool {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.prefers_ideographic_runs(),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.prefers_ideographic_runs(),
+                }
+            }
+
+            fn language_code(&self) -> &'static str {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.language_code(),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.language_code(),
+                }
+            }
+
+            fn vocabulary(&self) -> Vec<String> {
+                match self {
+                    $(
+                        #[cfg(feature = $feature)]
+                        Language::$name(l) => l.vocabulary(),
+                    )*
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.vocabulary(),
+                }
+            }
+
             fn format_and_value(&self, b: &DigitString) -> (String, f64){
                 match self{
                     $(
                         #[cfg(feature = $feature)]
                         Language::$name(l) => l.format_and_value(b),
                     )*
-                    _ => unimplemented!()
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.format_and_value(b),
                 }
             }
 
@@ -213,7 +1098,8 @@ macro_rules! declare_languages {
                         #[cfg(feature = $feature)]
                         Language::$name(l) => l.format_decimal_and_value(int, dec, sep),
                     )*
-                    _ => unimplemented!()
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.format_decimal_and_value(int, dec, sep),
                 }
             }
             fn is_linking(&self, word: &str) -> bool {
@@ -222,7 +1108,8 @@ macro_rules! declare_languages {
                         #[cfg(feature = $feature)]
                         Language::$name(l) => l.is_linking(word),
                     )*
-                    _ => unimplemented!()
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => l.is_linking(word),
                 }
             }
 
@@ -232,30 +1119,661 @@ macro_rules! declare_languages {
                         #[cfg(feature = $feature)]
                         Language::$name(l) => l.basic_annotate(tokens),
                     )*
-                    _ => unimplemented!()
+                    #[cfg(feature = "registry")]
+                    Language::Registered(l) => {
+                        let mut tokens: Vec<&mut dyn BasicAnnotate> = tokens
+                            .iter_mut()
+                            .map(|token| token as &mut dyn BasicAnnotate)
+                            .collect();
+                        l.dyn_basic_annotate(&mut tokens)
+                    }
                 }
             }
         }
 
         /// Get an interpreter for the language represented by the `language_code` ISO code.
+        ///
+        /// An interpreter may serve several close languages, like "hr", "sr" and "bs".
         pub fn get_interpreter_for(language_code: &str) -> Option<Language> {
             match language_code {
                 $(
                 #[cfg(feature = $feature)]
-                    stringify!($module) => Some(Language::$name($module::$name::default())),
+                    stringify!($module) $(| $alias)* => Some(Language::$name($module::$name::default())),
                 )*
+                #[cfg(feature = "registry")]
+                _ => crate::registry::lookup_pinned(language_code).map(Language::Registered),
+                #[cfg(not(feature = "registry"))]
                 _ => None,
             }
         }
+
+        /// Get an interpreter for the language represented by the `language_code` ISO code,
+        /// or tell why there is none.
+        ///
+        /// Unlike [`get_interpreter_for`], it tells an unknown code from the code of a builtin language
+        /// whose cargo feature was not enabled.
+        pub fn resolve_language(language_code: &str) -> Result<Language, LanguageError> {
+            if let Some(lang) = get_interpreter_for(language_code) {
+                return Ok(lang);
+            }
+            $(
+                #[cfg(not(feature = $feature))]
+                if [stringify!($module) $(, $alias)*].contains(&language_code) {
+                    return Err(LanguageError::NotCompiled {
+                        code: language_code.into(),
+                        feature: $feature,
+                    });
+                }
+            )*
+            Err(LanguageError::UnknownCode(language_code.into()))
+        }
+
+        /// The builtin languages, with all their codes.
+        #[cfg(feature = "registry")]
+        pub(crate) fn builtin_languages() -> Vec<(&'static str, Language)> {
+            let mut languages = Vec::new();
+            $(
+                #[cfg(feature = $feature)]
+                for code in [stringify!($module) $(, $alias)*] {
+                    languages.push((code, Language::$name($module::$name::default())));
+                }
+            )*
+            languages
+        }
     };
 }
 
 declare_languages![
+    ("bg", bg::Bulgarian, bulgarian),
     ("de", de::German, german),
     ("es", es::Spanish, spanish),
     ("en", en::English, english),
+    ("eo", eo::Esperanto, esperanto),
+    ("fa", fa::Persian, persian),
     ("fr", fr::French, french),
+    ("gl", gl::Galician, galician),
+    ("hr", hr::SerboCroatian, serbo_croatian, "sr", "bs"),
     ("it", it::Italian, italian),
+    ("lt", lt::Lithuanian, lithuanian),
     ("nl", nl::Dutch, dutch),
     ("pt", pt::Portuguese, portugese),
+    ("sk", sk::Slovak, slovak),
+    ("sw", sw::Swahili, swahili),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::text2digits;
+
+    /// Toy Indonesian-like language with prefixed ordinals.
+    struct Prefixed;
+
+    impl LangInterpreter for Prefixed {
+        fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+            let status = match num_func.trim_start_matches("ke") {
+                "satu" => b.put(b"1"),
+                "dua" => b.put(b"2"),
+                "puluh" => b.shift(1),
+                _ => Err(Error::NaN),
+            };
+            if status.is_ok() {
+                b.marker = self.get_morph_marker(num_func);
+            }
+            status
+        }
+
+        fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+            self.apply(decimal_func, b)
+        }
+
+        fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+            if word.starts_with("ke") {
+                MorphologicalMarker::Ordinal(Affix::prefix("ke-"))
+            } else {
+                MorphologicalMarker::None
+            }
+        }
+
+        fn check_decimal_separator(&self, _word: &str) -> Option<char> {
+            None
+        }
+
+        fn is_linking(&self, _word: &str) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn test_affix_wrap() {
+        let mut b = DigitString::new();
+        b.put(b"20").unwrap();
+        assert_eq!(Affix::prefix("ke-").wrap(&b), "ke-20");
+        assert_eq!(Affix::from("'nci").wrap(&b), "20'nci");
+        assert_eq!(Affix::circumfix("n°", "!").wrap(&b), "n°20!");
+    }
+
+    #[test]
+    fn test_prefix_marker_formatting() {
+        assert_eq!(text2digits("dua puluh", &Prefixed).unwrap(), "20");
+        assert_eq!(text2digits("dua kepuluh", &Prefixed).unwrap(), "ke-20");
+    }
+
+    #[test]
+    fn test_default_marker_formatting() {
+        let mut b = DigitString::new();
+        b.put(b"11").unwrap();
+        let marker = MorphologicalMarker::Ordinal(Affix::suffix("th"));
+        assert_eq!(Prefixed.format_marked(&b, marker), "11th");
+        // the digits of a fraction are written bare, as before format_marked
+        let marker = MorphologicalMarker::Fraction(Affix::suffix("avo"));
+        assert_eq!(Prefixed.format_marked(&b, marker), "11");
+    }
+
+    #[test]
+    fn test_stacked_scales() {
+        #[allow(unused_mut)]
+        let mut matrix: Vec<(Language, &[(&str, &str)])> = Vec::new();
+        #[cfg(feature = "en")]
+        matrix.push((
+            Language::english(),
+            &[
+                ("three hundred thousand", "300000"),
+                ("two hundred thousand five hundred", "200500"),
+                ("hundred thousand million", "100000000000"),
+                ("twelve thousand million", "12000000000"),
+                ("one thousand billion", "1000000000000"),
+            ],
+        ));
+        #[cfg(feature = "es")]
+        matrix.push((
+            Language::spanish(),
+            &[
+                ("trescientos mil", "300000"),
+                ("doscientos mil quinientos", "200500"),
+                ("mil millones", "1000000000"),
+                ("cien mil millones", "100000000000"),
+                ("dos mil millones trescientos mil", "2000300000"),
+                ("un billón", "1000000000000"),
+            ],
+        ));
+        #[cfg(feature = "fr")]
+        matrix.push((
+            Language::french(),
+            &[
+                ("trois cent mille", "300000"),
+                ("cent mille millions", "100000000000"),
+                ("deux mille trois cents millions", "2300000000"),
+                ("mille milliards", "1000000000000"),
+            ],
+        ));
+        #[cfg(feature = "de")]
+        matrix.push((
+            Language::german(),
+            &[
+                ("dreihunderttausend", "300000"),
+                ("zweihunderttausendfünfhundert", "200500"),
+                ("tausend Millionen", "1000000000"),
+                (
+                    "hunderttausend Millionen fünfhunderttausend",
+                    "100000500000",
+                ),
+                ("tausend Milliarden", "1000000000000"),
+            ],
+        ));
+        #[cfg(feature = "it")]
+        matrix.push((
+            Language::italian(),
+            &[
+                ("trecentomila", "300000"),
+                ("centomila milioni", "100000000000"),
+                ("mille miliardi", "1000000000000"),
+            ],
+        ));
+        #[cfg(feature = "pt")]
+        matrix.push((
+            Language::portugese(),
+            &[
+                ("trezentos mil", "300000"),
+                ("mil milhões", "1000000000"),
+                ("cem mil milhões e quinhentos mil", "100000500000"),
+            ],
+        ));
+        #[cfg(feature = "nl")]
+        matrix.push((
+            Language::dutch(),
+            &[
+                ("driehonderdduizend", "300000"),
+                ("honderdduizend miljoen", "100000000000"),
+                ("duizend miljard", "1000000000000"),
+            ],
+        ));
+        #[cfg(feature = "gl")]
+        matrix.push((
+            Language::galician(),
+            &[
+                ("trescentos mil", "300000"),
+                ("cen mil millóns", "100000000000"),
+                ("dous mil trescentos millóns", "2300000000"),
+                ("un billón", "1000000000000"),
+            ],
+        ));
+        #[cfg(feature = "hr")]
+        matrix.push((
+            Language::serbo_croatian(),
+            &[
+                ("dvjesto tisuća petsto", "200500"),
+                ("sto tisuća milijuna", "100000000000"),
+            ],
+        ));
+        #[cfg(feature = "sk")]
+        matrix.push((
+            Language::slovak(),
+            &[
+                ("dvesto tisíc päťsto", "200500"),
+                ("sto tisíc miliónov", "100000000000"),
+            ],
+        ));
+        #[cfg(feature = "lt")]
+        matrix.push((
+            Language::lithuanian(),
+            &[
+                ("trys šimtai tūkstančių", "300000"),
+                ("tūkstantis milijonų", "1000000000"),
+                ("šimtas tūkstančių milijonų", "100000000000"),
+                ("tūkstantis milijardų", "1000000000000"),
+            ],
+        ));
+        #[cfg(feature = "eo")]
+        matrix.push((
+            Language::esperanto(),
+            &[
+                ("ducent mil kvincent", "200500"),
+                ("cent mil milionoj", "100000000000"),
+                ("mil miliardoj", "1000000000000"),
+            ],
+        ));
+        #[cfg(feature = "bg")]
+        matrix.push((
+            Language::bulgarian(),
+            &[
+                ("двеста хиляди и петстотин", "200500"),
+                ("сто хиляди милиона", "100000000000"),
+                ("хиляда милиарда", "1000000000000"),
+            ],
+        ));
+        #[cfg(feature = "fa")]
+        matrix.push((
+            Language::persian(),
+            &[("سیصد هزار", "300000"), ("صد هزار میلیون", "100000000000")],
+        ));
+        for (lang, cases) in matrix {
+            for (text, expected) in cases {
+                assert_eq!(text2digits(text, &lang).as_deref(), Ok(*expected), "{text}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_resolve_language() {
+        assert_eq!(
+            resolve_language("xx").unwrap_err(),
+            LanguageError::UnknownCode("xx".into())
+        );
+        #[cfg(feature = "it")]
+        assert!(matches!(resolve_language("it"), Ok(Language::Italian(_))));
+        #[cfg(not(feature = "it"))]
+        assert_eq!(
+            resolve_language("it").unwrap_err(),
+            LanguageError::NotCompiled {
+                code: "it".into(),
+                feature: "it"
+            }
+        );
+        // the aliases are covered too
+        #[cfg(feature = "hr")]
+        assert!(matches!(
+            resolve_language("sr"),
+            Ok(Language::SerboCroatian(_))
+        ));
+        #[cfg(not(feature = "hr"))]
+        assert_eq!(
+            resolve_language("sr").unwrap_err(),
+            LanguageError::NotCompiled {
+                code: "sr".into(),
+                feature: "hr"
+            }
+        );
+    }
+
+    #[test]
+    fn test_undetermined_language_code() {
+        let ocs = crate::find_numbers(crate::tokenizer::tokenize("dua puluh"), &Prefixed, 0.0);
+        assert_eq!(ocs[0].lang_code, "und");
+    }
+
+    #[test]
+    fn test_ordinal_style() {
+        // the input, and its replacement in the plain, superscript and period only styles
+        let cases = [
+            (
+                "bg",
+                "за двадесет и осми път",
+                ["за 28-ми път", "за 28-ми път", "за 28. път"],
+            ),
+            (
+                "de",
+                "der erste und der einundzwanzigste",
+                [
+                    "der 1. und der 21.",
+                    "der 1. und der 21.",
+                    "der 1. und der 21.",
+                ],
+            ),
+            (
+                "en",
+                "the first, twenty-second, third and hundredth ranks",
+                [
+                    "the 1st, 22nd, 3rd and 100th ranks",
+                    "the 1ˢᵗ, 22ⁿᵈ, 3ʳᵈ and 100ᵗʰ ranks",
+                    "the 1., 22., 3. and 100. ranks",
+                ],
+            ),
+            (
+                "eo",
+                "la dek trian de majo",
+                ["la 13-an de majo", "la 13-an de majo", "la 13. de majo"],
+            ),
+            (
+                "es",
+                "el primer, el primero, la primera, los terceros y las vigésimas",
+                [
+                    "el 1.ᵉʳ, el 1.º, la 1.ª, los 3.ᵒˢ y las 20.ᵃˢ",
+                    "el 1.ᵉʳ, el 1.ᵒ, la 1.ᵃ, los 3.ᵒˢ y las 20.ᵃˢ",
+                    "el 1., el 1., la 1., los 3. y las 20.",
+                ],
+            ),
+            (
+                "fa",
+                "او هشتاد و نهمین نفر بود",
+                ["او 89مین نفر بود", "او 89مین نفر بود", "او 89مین نفر بود"],
+            ),
+            (
+                "fr",
+                "le premier, la première, les deuxièmes et le vingt et unième",
+                [
+                    "le 1er, la 1re, les 2es et le 21e",
+                    "le 1ᵉʳ, la 1ʳᵉ, les 2ᵉˢ et le 21ᵉ",
+                    "le 1., la 1., les 2. et le 21.",
+                ],
+            ),
+            (
+                "hr",
+                "živi na dvadeset trećem katu",
+                ["živi na 23. katu", "živi na 23. katu", "živi na 23. katu"],
+            ),
+            (
+                "it",
+                "il primo, la seconda e il ventunesimo",
+                [
+                    "il 1º, la 2ª e il 21º",
+                    "il 1ᵒ, la 2ᵃ e il 21ᵒ",
+                    "il 1., la 2. e il 21.",
+                ],
+            ),
+            (
+                "lt",
+                "jis buvo dvidešimt antras lenktynėse",
+                [
+                    "jis buvo 22-as lenktynėse",
+                    "jis buvo 22-as lenktynėse",
+                    "jis buvo 22. lenktynėse",
+                ],
+            ),
+            (
+                "nl",
+                "de eerste, de tweede en de eenentwintigste",
+                [
+                    "de 1e, de 2e en de 21e",
+                    "de 1ᵉ, de 2ᵉ en de 21ᵉ",
+                    "de 1., de 2. en de 21.",
+                ],
+            ),
+            (
+                "pt",
+                "o primeiro, a segunda, os terceiros e as vigésimas",
+                [
+                    "o 1.º, a 2.ª, os 3.ᵒˢ e as 20.ᵃˢ",
+                    "o 1.ᵒ, a 2.ᵃ, os 3.ᵒˢ e as 20.ᵃˢ",
+                    "o 1., a 2., os 3. e as 20.",
+                ],
+            ),
+            (
+                "sk",
+                "bol tridsiaty prvý v pretekoch",
+                [
+                    "bol 31. v pretekoch",
+                    "bol 31. v pretekoch",
+                    "bol 31. v pretekoch",
+                ],
+            ),
+        ];
+        let styles = [
+            OrdinalStyle::Plain,
+            OrdinalStyle::Superscript,
+            OrdinalStyle::PeriodOnly,
+        ];
+        for (code, input, expected) in cases {
+            let Some(lang) = get_interpreter_for(code) else {
+                continue;
+            };
+            for (ordinal_style, expected) in styles.into_iter().zip(expected) {
+                let options = crate::Options {
+                    ordinal_style,
+                    ..crate::Options::new(0.0)
+                };
+                assert_eq!(
+                    crate::replace_numbers_in_text_with(input, &lang, &options),
+                    expected,
+                    "{code} {ordinal_style:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_vocabulary() {
+        let codes = [
+            "bg", "de", "en", "eo", "es", "fa", "fr", "gl", "hr", "it", "lt", "nl", "pt", "sk",
+            "sw",
+        ];
+        for lang in codes.into_iter().filter_map(get_interpreter_for) {
+            let words = lang.vocabulary();
+            assert!(!words.is_empty(), "{}", lang.language_code());
+            for word in &words {
+                // some words need a number before them, like the scale words in plural
+                let accepted = ["", "1", "2", "3", "6", "20", "100"].iter().any(|seed| {
+                    let mut b = DigitString::new();
+                    if !seed.is_empty() {
+                        b.put(seed.as_bytes()).unwrap();
+                    }
+                    lang.apply(word, &mut b).is_ok()
+                }) || lang.apply_decimal(word, &mut DigitString::new()).is_ok();
+                assert!(accepted, "{}: {word}", lang.language_code());
+            }
+        }
+        assert!(Prefixed.vocabulary().is_empty());
+    }
+
+    /// The source of the interpreter of `code`, empty if there is none.
+    fn source(code: &str) -> &'static str {
+        match code {
+            "bg" => include_str!("bg/mod.rs"),
+            "de" => include_str!("de/mod.rs"),
+            "en" => include_str!("en/mod.rs"),
+            "eo" => include_str!("eo/mod.rs"),
+            "es" => include_str!("es/mod.rs"),
+            "fa" => include_str!("fa/mod.rs"),
+            "fr" => include_str!("fr/mod.rs"),
+            "hr" => include_str!("hr/mod.rs"),
+            "it" => include_str!("it/mod.rs"),
+            "lt" => include_str!("lt/mod.rs"),
+            "nl" => include_str!("nl/mod.rs"),
+            "pt" => include_str!("pt/mod.rs"),
+            "sk" => include_str!("sk/mod.rs"),
+            "sw" => include_str!("sw/mod.rs"),
+            _ => "",
+        }
+    }
+
+    #[test]
+    fn test_vocabulary_is_complete() {
+        let accepted = |lang: &Language, word: &str| {
+            ["", "1", "2", "3", "6", "20", "100"].iter().any(|seed| {
+                let mut b = DigitString::new();
+                if !seed.is_empty() {
+                    b.put(seed.as_bytes()).unwrap();
+                }
+                lang.apply(word, &mut b).is_ok()
+            }) || lang.apply_decimal(word, &mut DigitString::new()).is_ok()
+        };
+        let codes = [
+            "bg", "de", "en", "eo", "es", "fa", "fr", "hr", "it", "lt", "nl", "pt", "sk", "sw",
+        ];
+        for lang in codes.into_iter().filter_map(get_interpreter_for) {
+            let code = lang.language_code();
+            let words = lang.vocabulary();
+            // every word of the match arms of the interpreter, before its tests, is listed
+            let source = source(code).split("#[cfg(test)]").next().unwrap();
+            let arms = source
+                .split('"')
+                .skip(1)
+                .step_by(2)
+                .zip(source.split('"').skip(2).step_by(2));
+            for (word, after) in arms {
+                let is_arm = after.starts_with(" =>")
+                    || after.starts_with(" |")
+                    || after.starts_with(" if ");
+                if !is_arm
+                    || word.is_empty()
+                    || !word.chars().all(char::is_alphabetic)
+                    || !accepted(&lang, word)
+                {
+                    continue;
+                }
+                // or the stems of the inflected words and the elided pieces of the compounds, like Italian "prim"
+                // and "tto", a letter or two away from a listed word
+                let is_listed = words.iter().any(|listed| {
+                    listed == word
+                        || (listed.starts_with(word) || listed.ends_with(word))
+                            && listed.chars().count() - word.chars().count() <= 2
+                });
+                assert!(is_listed, "{code}: {word}");
+            }
+        }
+        // the forms apply reads with no arm of their own
+        let forms = [
+            ("de", "zweiten"),
+            ("en", "thirds"),
+            ("fr", "milliards"),
+            ("fr", "quatre-vingts"),
+            ("fr", "quatre-vingtième"),
+            ("it", "undicesimo"),
+            ("it", "ventesimo"),
+            ("it", "duecento"),
+            ("it", "tremila"),
+            ("it", "prima"),
+            ("pt", "primeiras"),
+            ("hr", "trećeg"),
+        ];
+        for (code, word) in forms {
+            if let Some(lang) = get_interpreter_for(code) {
+                assert!(accepted(&lang, word), "{code}: {word}");
+                assert!(
+                    lang.vocabulary().iter().any(|listed| listed == word),
+                    "{code}: {word}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_word_class_queries() {
+        /// A language with the default dry-run queries
+        struct DryRun<'a>(&'a Language);
+        impl LangInterpreter for DryRun<'_> {
+            fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+                self.0.apply(num_func, b)
+            }
+            fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+                self.0.apply_decimal(decimal_func, b)
+            }
+            fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+                self.0.get_morph_marker(word)
+            }
+            fn check_decimal_separator(&self, word: &str) -> Option<char> {
+                self.0.check_decimal_separator(word)
+            }
+            fn is_linking(&self, word: &str) -> bool {
+                self.0.is_linking(word)
+            }
+        }
+        let codes = [
+            "bg", "de", "en", "eo", "es", "fa", "fr", "gl", "hr", "it", "lt", "nl", "pt", "sk",
+            "sw",
+        ];
+        for lang in codes.into_iter().filter_map(get_interpreter_for) {
+            let code = lang.language_code();
+            let dry_run = DryRun(&lang);
+            // the number words, some of their inflections and compounds, and the words of the corpus
+            let vocabulary = lang.vocabulary();
+            let inflections = vocabulary.iter().flat_map(|word| {
+                ["", "s", "e", "n", "en", "a", "o", "i"]
+                    .map(|ending| alloc::format!("{word}{ending}"))
+            });
+            // a sample of the pairs is enough to cover the kinds of compounds
+            let compounds = vocabulary
+                .iter()
+                .step_by(vocabulary.len() / 50 + 1)
+                .flat_map(|first| {
+                    vocabulary
+                        .iter()
+                        .step_by(vocabulary.len() / 35 + 1)
+                        .flat_map(move |second| {
+                            [
+                                alloc::format!("{first}{second}"),
+                                alloc::format!("{first}-{second}"),
+                            ]
+                        })
+                });
+            let corpus = crate::corpus::tsv(code)
+                .lines()
+                .filter(|line| !line.starts_with('#'))
+                .flat_map(|line| crate::tokenizer::tokenize(line).strs())
+                .filter(|word| {
+                    word.chars()
+                        .all(|c| c.is_alphabetic() || c == '-' || c == '\'')
+                })
+                .map(|word| crate::tokenizer::lowercase(word).into_owned());
+            let words: Vec<String> = inflections.chain(compounds).chain(corpus).collect();
+            let start_errors: Vec<&str> = words
+                .iter()
+                .filter(|word| lang.can_start_number(word) != dry_run.can_start_number(word))
+                .map(String::as_str)
+                .collect();
+            assert!(
+                start_errors.is_empty(),
+                "{code}: can_start_number {start_errors:?}"
+            );
+            let continue_errors: Vec<&str> = words
+                .iter()
+                .filter(|word| lang.can_continue(word) != dry_run.can_continue(word))
+                .map(String::as_str)
+                .collect();
+            assert!(
+                continue_errors.is_empty(),
+                "{code}: can_continue {continue_errors:?}"
+            );
+        }
+    }
+}
@@ -3,16 +3,19 @@
 //! This interpreter is tolerant and accepts splitted words, that is "negen en zeventig" is treated like "negenenzeventig", as
 //! the main application, Speech-to-text recognition, may introduce spurious spaces.
 
+use alloc::{string::String, vec::Vec};
+
 use bitflags::bitflags;
 
 use crate::digit_string::DigitString;
 use crate::error::Error;
-use crate::tokenizer::WordSplitter;
+use crate::options::OrdinalStyle;
+use crate::tokenizer::LazySplitter;
 
 mod vocabulary;
 
-use super::{LangInterpreter, MorphologicalMarker};
-use vocabulary::INSIGNIFICANT;
+use super::{Affix, LangInterpreter, MorphologicalMarker};
+use vocabulary::{INSIGNIFICANT, NUMBER_WORDS, VAGUE_QUANTIFIERS};
 
 bitflags! {
     /// Words that can be temporarily blocked because of linguistic features.
@@ -22,72 +25,109 @@ bitflags! {
     }
 }
 
-pub struct Dutch {
-    word_splitter: WordSplitter,
-}
-
-impl Default for Dutch {
-    fn default() -> Self {
-        Self {
-            word_splitter: WordSplitter::new([
-                "honderd",
-                "honderdste",
-                "duizend",
-                "duizendste",
-                "miljoen",
-                "miljoenste",
-                "miljard",
-                "miljardste",
-                "biljoen",
-                "biljoenste",
-                // These are there because they contain "en"
-                "een",
-                // een may break  drie + en, so we add drie here
-                "drie",
-                "zeven",
-                "zevende",
-                "negen",
-                "negende",
-                "tien",
-                "tiende",
-                "dertien",
-                "dertiende",
-                "veertien",
-                "veertiende",
-                "vijftien",
-                "vijftiende",
-                "zestien",
-                "zestiende",
-                "zeventien",
-                "zeventiende",
-                "achttien",
-                "achttiende",
-                "negentien",
-                "negentiende",
-                "zeventig",
-                "zeventigste",
-                "negentig",
-                "negentigste",
-                // connector
-                "en",
-                "ën",
-            ])
-            .unwrap(),
-        }
+/// The splitter of the compound words, built on first use.
+static WORD_SPLITTER: LazySplitter = LazySplitter::new(&[
+    "honderd",
+    "honderdste",
+    "duizend",
+    "duizendste",
+    "miljoen",
+    "miljoenste",
+    "miljard",
+    "miljardste",
+    "biljoen",
+    "biljoenste",
+    // These are there because they contain "en"
+    "een",
+    // een may break  drie + en, so we add drie here
+    "drie",
+    "zeven",
+    "zevende",
+    "negen",
+    "negende",
+    "tien",
+    "tiende",
+    "dertien",
+    "dertiende",
+    "veertien",
+    "veertiende",
+    "vijftien",
+    "vijftiende",
+    "zestien",
+    "zestiende",
+    "zeventien",
+    "zeventiende",
+    "achttien",
+    "achttiende",
+    "negentien",
+    "negentiende",
+    "zeventig",
+    "zeventigste",
+    "negentig",
+    "negentigste",
+    // connector
+    "en",
+    "ën",
+]);
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Dutch {}
+
+/// Return true if `word` is one of the number words, compounded or made an ordinal.
+fn is_number_word(word: &str) -> bool {
+    if WORD_SPLITTER.get().is_splittable(word) {
+        return WORD_SPLITTER
+            .get()
+            .split(word)
+            .all(|piece| matches!(piece, "en" | "ën") || is_number_word(piece));
     }
+    NUMBER_WORDS.contains(&word)
+        || ["ste", "de"].iter().any(|suffix| {
+            word.strip_suffix(suffix)
+                .is_some_and(|stem| !stem.is_empty() && is_number_word(stem))
+        })
 }
 
 impl Dutch {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Apply the cardinal of the regular ordinal `word`, the cardinal and "*-de*" or "*-ste*",
+    /// like "*vierde*" or "*twintigste*".
+    fn apply_ordinal_stem(&self, word: &str, b: &mut DigitString) -> Result<(), Error> {
+        let Some((stem, suffix)) = ["ste", "de"].into_iter().find_map(|suffix| {
+            let stem = word.strip_suffix(suffix)?;
+            (!stem.is_empty()).then_some((stem, suffix))
+        }) else {
+            return Err(Error::NaN);
+        };
+        let mut cardinal = b.clone();
+        if self.apply(stem, &mut cardinal).is_err()
+            || cardinal.marker.is_ordinal()
+            || ordinal_suffix(&cardinal) != suffix
+        {
+            return Err(Error::NaN);
+        }
+        *b = cardinal;
+        Ok(())
+    }
+}
+
+/// The suffix of the ordinal of the cardinal `b`: "*eerste*", "*achtste*", "*twintigste*", "*honderdste*"…
+/// but "*tweede*", "*derde*", "*tiende*"…
+fn ordinal_suffix(b: &DigitString) -> &'static str {
+    match b.peek(2) {
+        b"1" | b"8" | b"01" | b"08" | b"00" | [b'2'..=b'9', _] => "ste",
+        _ => "de",
+    }
 }
 
 impl LangInterpreter for Dutch {
     fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
         // In Dutch, numbers are compounded to form a group
-        if self.word_splitter.is_splittable(num_func) {
-            return match self.exec_group(self.word_splitter.split(num_func)) {
+        if WORD_SPLITTER.get().is_splittable(num_func) {
+            return match self.exec_group(WORD_SPLITTER.get().split(num_func)) {
                 Ok(ds) => {
                     if ds.len() > 3 && ds.len() <= 6 && !b.is_range_free(3, 5) {
                         return Err(Error::Overlap);
@@ -111,7 +151,7 @@ impl LangInterpreter for Dutch {
                 to_block = Excludable::TENS;
                 b.put(b"1")
             }
-            "twee" | "tweede" if b.is_free(2) => {
+            "twee" if b.is_free(2) => {
                 to_block = Excludable::TENS;
                 b.put(b"2")
             }
@@ -119,19 +159,19 @@ impl LangInterpreter for Dutch {
                 to_block = Excludable::TENS;
                 b.put(b"3")
             }
-            "vier" | "vierde" if b.is_free(2) => {
+            "vier" if b.is_free(2) => {
                 to_block = Excludable::TENS;
                 b.put(b"4")
             }
-            "vijf" | "vijfde" if b.is_free(2) => {
+            "vijf" if b.is_free(2) => {
                 to_block = Excludable::TENS;
                 b.put(b"5")
             }
-            "zes" | "zesde" if b.is_free(2) => {
+            "zes" if b.is_free(2) => {
                 to_block = Excludable::TENS;
                 b.put(b"6")
             }
-            "zeven" | "zevende" if b.is_free(2) => {
+            "zeven" if b.is_free(2) => {
                 to_block = Excludable::TENS;
                 b.put(b"7")
             }
@@ -139,45 +179,45 @@ impl LangInterpreter for Dutch {
                 to_block = Excludable::TENS;
                 b.put(b"8")
             }
-            "negen" | "negende" if b.is_free(2) => {
+            "negen" if b.is_free(2) => {
                 to_block = Excludable::TENS;
                 b.put(b"9")
             }
-            "tien" | "tiende" => b.put(b"10"),
-            "elf" | "elfde" => b.put(b"11"),
-            "twaalf" | "twaalfde" => b.put(b"12"),
-            "dertien" | "dertiende" => b.put(b"13"),
-            "veertien" | "veertiende" => b.put(b"14"),
-            "vijftien" | "vijftiende" => b.put(b"15"),
-            "zestien" | "zestiende" => b.put(b"16"),
-            "zeventien" | "zeventiende" => b.put(b"17"),
-            "achttien" | "achttiende" => b.put(b"18"),
-            "negentien" | "negentiende" => b.put(b"19"),
-            "twintig" | "twintigste" if !blocked.contains(Excludable::TENS) => {
+            "tien" => b.put(b"10"),
+            "elf" => b.put(b"11"),
+            "twaalf" => b.put(b"12"),
+            "dertien" => b.put(b"13"),
+            "veertien" => b.put(b"14"),
+            "vijftien" => b.put(b"15"),
+            "zestien" => b.put(b"16"),
+            "zeventien" => b.put(b"17"),
+            "achttien" => b.put(b"18"),
+            "negentien" => b.put(b"19"),
+            "twintig" if !blocked.contains(Excludable::TENS) => {
                 b.put_digit_at(b'2', 1)
             }
-            "dertig" | "dertigste" if !blocked.contains(Excludable::TENS) => {
+            "dertig" if !blocked.contains(Excludable::TENS) => {
                 b.put_digit_at(b'3', 1)
             }
-            "veertig" | "veertigste" if !blocked.contains(Excludable::TENS) => {
+            "veertig" if !blocked.contains(Excludable::TENS) => {
                 b.put_digit_at(b'4', 1)
             }
-            "vijftig" | "vijftigste" if !blocked.contains(Excludable::TENS) => {
+            "vijftig" if !blocked.contains(Excludable::TENS) => {
                 b.put_digit_at(b'5', 1)
             }
-            "zestig" | "zestigste" if !blocked.contains(Excludable::TENS) => {
+            "zestig" if !blocked.contains(Excludable::TENS) => {
                 b.put_digit_at(b'6', 1)
             }
-            "zeventig" | "zeventigste" if !blocked.contains(Excludable::TENS) => {
+            "zeventig" if !blocked.contains(Excludable::TENS) => {
                 b.put_digit_at(b'7', 1)
             }
-            "tachtig" | "tachtigste" if !blocked.contains(Excludable::TENS) => {
+            "tachtig" if !blocked.contains(Excludable::TENS) => {
                 b.put_digit_at(b'8', 1)
             }
-            "negentig" | "negentigste" if !blocked.contains(Excludable::TENS) => {
+            "negentig" if !blocked.contains(Excludable::TENS) => {
                 b.put_digit_at(b'9', 1)
             }
-            "honderd" | "honderdste" => {
+            "honderd" => {
                 let peek = b.peek(2);
                 if peek.len() == 1 && peek == b"1" {
                     Err(Error::Overlap)
@@ -185,7 +225,7 @@ impl LangInterpreter for Dutch {
                     b.shift(2)
                 }
             }
-            "duizend" | "duizendste" if b.is_range_free(3, 5) => {
+            "duizend" if b.is_range_free(3, 5) => {
                 let peek = b.peek(2);
                 if peek == b"1" {
                     Err(Error::Overlap)
@@ -193,12 +233,11 @@ impl LangInterpreter for Dutch {
                     b.shift(3)
                 }
             }
-            "miljoen" | "miljoenste" if b.is_range_free(6, 8) => b.shift(6),
-            "miljard" | "miljardste" => b.shift(9),
-            "biljoen" | "biljoenste" => b.shift(12),
-            "en" | "ën" => Err(Error::Incomplete),
-
-            _ => Err(Error::NaN),
+            "miljoen" if b.is_range_free(6, 8) => b.shift(6),
+            "miljard" => b.shift(9),
+            "biljoen" => b.shift(12),
+            "en" | "ën" if !b.is_empty() => Err(Error::Incomplete),
+            _ => self.apply_ordinal_stem(num_func, b),
         };
         if status.is_ok() {
             b.flags = to_block.bits();
@@ -232,27 +271,68 @@ impl LangInterpreter for Dutch {
         self.apply(decimal_func, b)
     }
 
+    fn language_code(&self) -> &'static str {
+        "nl"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        NUMBER_WORDS.iter().map(|word| String::from(*word)).collect()
+    }
+
     fn check_decimal_separator(&self, word: &str) -> Option<char> {
         if word == "komma" { Some(',') } else { None }
     }
 
+    fn decimal_symbol(&self) -> char {
+        ','
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "drie tegen twee"
+        &["tegen"]
+    }
+
     fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
         if word.ends_with("ste") || word.ends_with("de") {
-            MorphologicalMarker::Ordinal("e")
+            MorphologicalMarker::Ordinal("e".into())
         } else {
             MorphologicalMarker::None
         }
     }
 
+    fn styled_ordinal(&self, b: &DigitString, _affix: Affix, style: OrdinalStyle) -> Option<Affix> {
+        match style {
+            OrdinalStyle::Plain => None,
+            OrdinalStyle::Long => Some(ordinal_suffix(b).into()),
+            OrdinalStyle::Superscript => Some("ᵉ".into()),
+            OrdinalStyle::PeriodOnly => Some(".".into()),
+        }
+    }
+
     fn is_linking(&self, word: &str) -> bool {
         INSIGNIFICANT.contains(word)
     }
+
+    fn is_vague_quantifier(&self, word: &str) -> bool {
+        VAGUE_QUANTIFIERS.contains(word)
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        // the words of the vocabulary, in context
+        is_number_word(word) && super::dry_run_start(self, word)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        (matches!(word, "en" | "ën") || is_number_word(word)) && super::dry_run_continue(self, word)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Dutch;
-    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+    use crate::Options;
+    use crate::options::OrdinalStyle;
+    use crate::word_to_digit::{replace_numbers_in_text, replace_numbers_in_text_with, text2digits};
 
     macro_rules! assert_text2digits {
         ($text:expr, $res:expr) => {
@@ -355,6 +435,36 @@ mod tests {
         assert_text2digits!("achtste", "8e");
         assert_text2digits!("vijfentwintigste", "25e");
         assert_text2digits!("eenentwintigste", "21e");
+        let units = [
+            "eerste", "tweede", "derde", "vierde", "vijfde", "zesde", "zevende", "achtste", "negende",
+        ];
+        let teens = [
+            "tiende", "elfde", "twaalfde", "dertiende", "veertiende", "vijftiende", "zestiende", "zeventiende",
+            "achttiende", "negentiende",
+        ];
+        let cardinals = ["een", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht", "negen"];
+        let mut ordinals: Vec<(alloc::string::String, usize)> = Vec::new();
+        ordinals.extend(units.iter().enumerate().map(|(i, w)| ((*w).into(), i + 1)));
+        ordinals.extend(teens.iter().enumerate().map(|(i, w)| ((*w).into(), i + 10)));
+        ordinals.push(("twintigste".into(), 20));
+        for (i, w) in cardinals.iter().enumerate() {
+            let link = if w.ends_with('e') { "ën" } else { "en" };
+            ordinals.push((alloc::format!("{w}{link}twintigste"), i + 21));
+        }
+        ordinals.push(("dertigste".into(), 30));
+        ordinals.push(("eenendertigste".into(), 31));
+        for (word, n) in &ordinals {
+            assert_text2digits!(word.as_str(), alloc::format!("{n}e"));
+        }
+        assert_text2digits!("honderdste", "100e");
+        assert_text2digits!("honderdtweede", "102e");
+        assert_text2digits!("tweehonderdachtste", "208e");
+        assert_text2digits!("duizendste", "1000e");
+        assert_text2digits!("drieduizendvijfhonderdzevenenveertigste", "3547e");
+        assert_text2digits!("miljoenste", "1000000e");
+        assert_invalid!("tweedede");
+        assert_invalid!("twintigde");
+        assert_invalid!("vierste");
     }
 
     #[test]
@@ -504,4 +614,44 @@ mod tests {
             "dus 2 en 3 plus 5, uh 6, dan 7 en nog eens 8 min 4, dat is 3"
         );
     }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("DRIEËNTWINTIG", "23");
+        assert_replace_all_numbers!("TWEEHONDERD", "200");
+    }
+
+    #[test]
+    fn test_vague_quantifiers() {
+        assert_replace_numbers!("een paar honderd mensen", "een paar honderd mensen");
+        assert_replace_numbers!("een paar duizend euro", "een paar duizend euro");
+        assert_replace_numbers!("enkele miljoenen mensen", "enkele miljoenen mensen");
+        assert_replace_numbers!("driehonderd mensen", "300 mensen");
+    }
+
+    #[test]
+    fn test_long_ordinal_style() {
+        let lang = Dutch::default();
+        let options = Options {
+            ordinal_style: OrdinalStyle::Long,
+            ..Options::new(0.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with(
+                "de eerste, de tweede en de eenentwintigste",
+                &lang,
+                &options,
+            ),
+            "de 1ste, de 2de en de 21ste"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with(
+                "de achtste, de twaalfde, de honderdste en de honderdderde",
+                &lang,
+                &options,
+            ),
+            "de 8ste, de 12de, de 100ste en de 103de"
+        );
+    }
+
 }
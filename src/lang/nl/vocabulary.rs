@@ -3,3 +3,21 @@ use phf::{Set, phf_set};
 pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
     "ja", "dus", "plus", "uh", "dan", "min", "dat", "is"
 };
+
+/// Vague quantifiers, like "*een paar*", "*enkele*"
+pub static VAGUE_QUANTIFIERS: Set<&'static str> = phf_set! {
+    "paar", "enkele", "meerdere", "verscheidene"
+};
+
+/// The number words, for [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "nul", "één", "een", "eerste", "twee", "drie", "derde", "vier", "vijf", "zes", "zeven", "acht",
+    "achtste", "negen", "tien", "elf", "twaalf", "dertien", "veertien", "vijftien", "zestien",
+    "zeventien", "achttien", "negentien", "twintig", "dertig", "veertig", "vijftig", "zestig",
+    "zeventig", "tachtig", "negentig", "honderd", "duizend", "miljoen", "miljard", "biljoen",
+    "tweede", "vierde", "vijfde", "zesde", "zevende", "negende", "tiende", "elfde", "twaalfde",
+    "dertiende", "veertiende", "vijftiende", "zestiende", "zeventiende", "achttiende",
+    "negentiende", "twintigste", "dertigste", "veertigste", "vijftigste", "zestigste",
+    "zeventigste", "tachtigste", "negentigste", "honderdste", "duizendste", "miljoenste",
+    "miljardste", "biljoenste",
+];
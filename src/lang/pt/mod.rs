@@ -5,23 +5,41 @@
 //! - <https://www.dicio.com.br/como-escrever-numeros-por-extenso/>
 //! - <https://exoportugais.blogspot.com/2012/12/nombres-ordinaux-en-portugais.html>
 
+use alloc::{format, string::String, vec::Vec};
+
 use bitflags::bitflags;
 
 use crate::digit_string::DigitString;
 use crate::error::Error;
+use crate::options::OrdinalStyle;
 
 mod vocabulary;
 
-use super::{LangInterpreter, MorphologicalMarker};
-use vocabulary::INSIGNIFICANT;
+use super::{Affix, LangInterpreter, MorphologicalMarker, Scale, sorted_vocabulary};
+use vocabulary::{INSIGNIFICANT, NUMBER_WORDS, VAGUE_QUANTIFIERS};
 
-#[derive(Default)]
-pub struct Portuguese {}
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Portuguese {
+    scale: Option<Scale>,
+}
 
 impl Portuguese {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Portuguese with the scale words beyond "*milhão*" on the given scale.
+    ///
+    /// By default, the scale follows the spelling: the Brazilian "*bilhão*" is on the short scale (10⁹),
+    /// the European "*bilião*" on the long scale (10¹²).
+    pub fn with_scale(scale: Scale) -> Self {
+        Portuguese { scale: Some(scale) }
+    }
+
+    /// The power of ten of the `rank`-th "*-ilhão*" word, `spelled` in the variant with that scale.
+    fn illion(&self, rank: usize, spelled: Scale) -> usize {
+        self.scale.unwrap_or(spelled).illion(rank)
+    }
 }
 
 bitflags! {
@@ -47,12 +65,36 @@ fn lemmatize(word: &str) -> &str {
     }
 }
 
+/// Return true if `word` is one of the number words, inflected.
+fn is_number_word(word: &str) -> bool {
+    let lemma = lemmatize(word);
+    NUMBER_WORDS.iter().any(|known| lemmatize(known) == lemma)
+}
+
+/// The ordinal scale words of a thousand or more, that make an ordinal of the whole cardinal before them:
+/// "*seis milionésimo*" is the 6 000 000th.
+fn is_ordinal_scale(word: &str) -> bool {
+    matches!(
+        lemmatize(word),
+        "milésim" | "milionésim" | "bilionésim" | "trilionésim"
+    )
+}
+
 impl LangInterpreter for Portuguese {
     fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
         let num_marker = self.get_morph_marker(num_func);
-        if !b.is_empty() && num_marker != b.marker {
+        // "seis milionésimo": the ordinal scale word makes an ordinal of the cardinal
+        let promoted = b.marker.is_none() && is_ordinal_scale(num_func);
+        if !b.is_empty() && num_marker != b.marker && !promoted {
             return Err(Error::Overlap);
         }
+        // bare plural scale words are vague quantities: "milhões de pessoas"
+        if b.is_empty() && matches!(
+                num_func,
+                "milhões" | "bilhões" | "biliões" | "trilhões" | "triliões"
+            ) {
+            return Err(Error::NaN);
+        }
         let restrictions = Restriction::from_bits_truncate(b.flags);
         let only_multipliers = restrictions.contains(Restriction::ONLY_MULTIPLIERS);
         let smaller_blocked = only_multipliers
@@ -124,7 +166,10 @@ impl LangInterpreter for Portuguese {
                 }
             }
             "milhã" | "milhões" | "milionésim" if b.is_range_free(6, 8) => b.shift(6),
-            "bilhã" | "biliã" | "bilhões" | "biliões" | "bilionésim" => b.shift(9),
+            "bilhã" | "bilhões" | "bilionésim" => b.shift(self.illion(2, Scale::Short)),
+            "biliã" | "biliões" => b.shift(self.illion(2, Scale::Long)),
+            "trilhã" | "trilhões" | "trilionésim" => b.shift(self.illion(3, Scale::Short)),
+            "triliã" | "triliões" => b.shift(self.illion(3, Scale::Long)),
             "e" if b.len() >= 2 && b.marker.is_none() && !only_multipliers => {
                 Err(Error::Incomplete)
             }
@@ -153,13 +198,13 @@ impl LangInterpreter for Portuguese {
     fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
         let lemma = lemmatize(word);
         let prob_marker = if word.ends_with('a') {
-            MorphologicalMarker::Ordinal("ª")
+            MorphologicalMarker::Ordinal(".ª".into())
         } else if word.ends_with("as") {
-            MorphologicalMarker::Ordinal("ᵃˢ")
+            MorphologicalMarker::Ordinal(".ᵃˢ".into())
         } else if word.ends_with('o') {
-            MorphologicalMarker::Ordinal("º")
+            MorphologicalMarker::Ordinal(".º".into())
         } else if word.ends_with("os") {
-            MorphologicalMarker::Ordinal("ᵒˢ")
+            MorphologicalMarker::Ordinal(".ᵒˢ".into())
         } else {
             return MorphologicalMarker::None;
         };
@@ -170,23 +215,89 @@ impl LangInterpreter for Portuguese {
             _ => MorphologicalMarker::None,
         }
     }
+
+    fn styled_ordinal(&self, _b: &DigitString, affix: Affix, style: OrdinalStyle) -> Option<Affix> {
+        match style {
+            OrdinalStyle::Plain | OrdinalStyle::Long => None,
+            // the plural markers are already in superscript
+            OrdinalStyle::Superscript => Some(match affix.suffix {
+                ".º" => ".ᵒ".into(),
+                ".ª" => ".ᵃ".into(),
+                _ => affix,
+            }),
+            OrdinalStyle::PeriodOnly => Some(".".into()),
+        }
+    }
+    fn language_code(&self) -> &'static str {
+        "pt"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        // the ordinals in all genders and numbers: "*primeiro*", "*primeira*", "*primeiros*", "*primeiras*"
+        let ordinals = NUMBER_WORDS
+            .iter()
+            .filter(|word| !self.get_morph_marker(word).is_none())
+            .flat_map(|word| {
+                let lemma = lemmatize(word);
+                ["o", "a", "os", "as"].map(|ending| format!("{lemma}{ending}"))
+            })
+            .filter(|ordinal| !self.get_morph_marker(ordinal).is_none());
+        sorted_vocabulary(NUMBER_WORDS.iter().map(|word| String::from(*word)).chain(ordinals))
+    }
+
     fn check_decimal_separator(&self, word: &str) -> Option<char> {
         if word == "vírgula" { Some(',') } else { None }
     }
 
+    fn decimal_symbol(&self) -> char {
+        ','
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "três a dois"
+        &["a"]
+    }
+
     fn is_linking(&self, word: &str) -> bool {
         INSIGNIFICANT.contains(word)
     }
+
+    fn is_vague_quantifier(&self, word: &str) -> bool {
+        VAGUE_QUANTIFIERS.contains(word)
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        // the words of the vocabulary, in context
+        is_number_word(word) && super::dry_run_start(self, word)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        (word == "e" || is_number_word(word)) && super::dry_run_continue(self, word)
+    }
+
+    fn adverbial_ordinal(&self, word: &str) -> Option<u64> {
+        // "primeiramente", "segundamente"…
+        let ordinal = word.strip_suffix("mente")?;
+        self.exec_group(core::iter::once(ordinal))
+            .ok()
+            .filter(DigitString::is_ordinal)
+            .map(|ds| ds.parse())
+    }
+
+    fn format_adverbial_ordinal(&self, rank: u64) -> String {
+        alloc::format!("{rank}.º")
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+    use crate::Options;
+    use crate::word_to_digit::{replace_numbers_in_text, replace_numbers_in_text_with, text2digits};
 
     macro_rules! assert_text2digits {
         ($text:expr, $res:expr) => {
-            let f = Portuguese {};
+            let f = Portuguese::new();
             let res = text2digits($text, &f);
             crate::tests::dbg!(&res);
             assert!(res.is_ok());
@@ -196,14 +307,21 @@ mod tests {
 
     macro_rules! assert_replace_numbers {
         ($text:expr, $res:expr) => {
-            let f = Portuguese {};
+            let f = Portuguese::new();
             assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
         };
     }
 
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Portuguese::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
     macro_rules! assert_invalid {
         ($text:expr) => {
-            let f = Portuguese {};
+            let f = Portuguese::new();
             let res = text2digits($text, &f);
             assert!(res.is_err());
         };
@@ -279,12 +397,21 @@ mod tests {
 
     #[test]
     fn test_ordinals() {
-        assert_text2digits!("vigésimo quarto", "24º");
-        assert_text2digits!("vigésimo primeiro", "21º");
-        assert_text2digits!("centésimo primeiro", "101º");
-        assert_text2digits!("décima sexta", "16ª");
-        assert_text2digits!("décimas sextas", "16ᵃˢ");
-        assert_text2digits!("décimos sextos", "16ᵒˢ");
+        assert_text2digits!("vigésimo quarto", "24.º");
+        assert_text2digits!("vigésimo primeiro", "21.º");
+        assert_text2digits!("centésimo primeiro", "101.º");
+        assert_text2digits!("décima sexta", "16.ª");
+        assert_text2digits!("décimas sextas", "16.ᵃˢ");
+        assert_text2digits!("décimos sextos", "16.ᵒˢ");
+    }
+
+    #[test]
+    fn test_scale_ordinals() {
+        assert_text2digits!("três milésimo", "3000.º");
+        assert_text2digits!("duzentas milésima", "200000.ª");
+        assert_text2digits!("seis milionésimo", "6000000.º");
+        assert_text2digits!("ducentésimo", "200.º");
+        assert_replace_numbers!("o seis milionésimo visitante", "o 6000000.º visitante");
     }
 
     #[test]
@@ -367,7 +494,7 @@ mod tests {
     fn test_replace_numbers_second_as_time_unit_vs_ordinal() {
         assert_replace_numbers!(
             "Um segundo por favor! Vigésimo segundo é diferente de vinte segundos.",
-            "Um segundo por favor! 22º é diferente de 20 segundos."
+            "Um segundo por favor! 22.º é diferente de 20 segundos."
         );
     }
 
@@ -375,14 +502,51 @@ mod tests {
     fn test_replace_numbers_ordinals() {
         assert_replace_numbers!(
             "Ordinais: primeiro, quinto, terceiro, vigésima, vigésimo primeiro, centésimo quadragésimo quinto",
-            "Ordinais: 1º, 5º, 3º, 20ª, 21º, 145º"
+            "Ordinais: 1.º, 5.º, 3.º, 20.ª, 21.º, 145.º"
         );
         assert_replace_numbers!(
             "A décima quarta brigada do exército português, juntamento com o nonagésimo sexto regimento britânico, bateu o centésimo vigésimo sétimo regimento de infantaria de Napoleão",
-            "A 14ª brigada do exército português, juntamento com o 96º regimento britânico, bateu o 127º regimento de infantaria de Napoleão"
+            "A 14.ª brigada do exército português, juntamento com o 96.º regimento britânico, bateu o 127.º regimento de infantaria de Napoleão"
         );
     }
 
+    #[test]
+    fn test_ordinals_1_31() {
+        let units = [
+            "primeir", "segund", "terceir", "quart", "quint", "sext", "sétim", "oitav", "non",
+        ];
+        let tens = ["décim", "vigésim", "trigésim"];
+        let f = Portuguese::new();
+        for (gender, marker) in [("o", "º"), ("a", "ª")] {
+            let mut ordinals: Vec<(alloc::string::String, usize)> = Vec::new();
+            ordinals.extend(units.iter().enumerate().map(|(i, w)| (alloc::format!("{w}{gender}"), i + 1)));
+            for (t, ten) in tens.iter().enumerate() {
+                let ten = alloc::format!("{ten}{gender}");
+                let units = if t == 2 { &units[..1] } else { &units[..] };
+                ordinals.push((ten.clone(), 10 * (t + 1)));
+                ordinals.extend(
+                    units.iter().enumerate().map(|(i, w)| (alloc::format!("{ten} {w}{gender}"), 10 * (t + 1) + i + 1)),
+                );
+            }
+            assert_eq!(ordinals.len(), 31);
+            for (text, rank) in ordinals {
+                assert_eq!(text2digits(&text, &f).unwrap(), alloc::format!("{rank}.{marker}"), "{text}");
+            }
+        }
+        assert_replace_all_numbers!("vigésimo terceira", "20.º 3.ª");
+        assert_replace_all_numbers!("o centésimo vigésimo terceiro", "o 123.º");
+        assert_replace_all_numbers!("a ducentésima trigésima primeira", "a 231.ª");
+        assert_replace_all_numbers!("os primeiros e as segundas", "os 1.ᵒˢ e as 2.ᵃˢ");
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_replace_all_numbers!("primeiro de maio", "1.º de maio");
+        assert_replace_all_numbers!("dois de maio", "2 de maio");
+        assert_replace_all_numbers!("vinte e três de maio", "23 de maio");
+        assert_replace_all_numbers!("o 23º lugar e a 1.ª vez", "o 23º lugar e a 1.ª vez");
+    }
+
     #[test]
     fn test_brazilian_variants() {
         assert_replace_numbers!("catorze", "14");
@@ -419,4 +583,66 @@ mod tests {
             "53020243724"
         );
     }
+
+    #[test]
+    fn test_adverbial_ordinals() {
+        assert_replace_all_numbers!("Primeiramente assinamos, segundamente saímos.", "Primeiramente assinamos, segundamente saímos.");
+        let f = Portuguese::default();
+        let options = Options {
+            adverbial_ordinals: true,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("Primeiramente assinamos, segundamente saímos.", &f, &options),
+            "1.º assinamos, 2.º saímos."
+        );
+    }
+
+    #[test]
+    fn test_vague_quantities() {
+        assert_replace_all_numbers!("milhões de pessoas", "milhões de pessoas");
+        assert_replace_all_numbers!("milhares de vezes, centenas", "milhares de vezes, centenas");
+        assert_replace_all_numbers!("dois milhões de pessoas", "2000000 de pessoas");
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("VINTE E TRÊS", "23");
+        assert_replace_all_numbers!("DUZENTOS", "200");
+    }
+
+    #[test]
+    fn test_scales() {
+        let pt = Portuguese::new();
+        let short = Portuguese::with_scale(Scale::Short);
+        let long = Portuguese::with_scale(Scale::Long);
+        // the scale follows the spelling by default
+        assert_eq!(text2digits("um bilhão", &pt), Ok("1000000000".into()));
+        assert_eq!(text2digits("um bilião", &pt), Ok("1000000000000".into()));
+        assert_eq!(text2digits("um trilhão", &pt), Ok("1000000000000".into()));
+        assert_eq!(text2digits("um trilião", &pt), Ok("1000000000000000000".into()));
+        assert_eq!(text2digits("um bilhão", &long), Ok("1000000000000".into()));
+        assert_eq!(text2digits("um bilião", &short), Ok("1000000000".into()));
+        assert_eq!(text2digits("dois biliões", &short), Ok("2000000000".into()));
+        assert_eq!(text2digits("mil milhões", &pt), Ok("1000000000".into()));
+        assert_eq!(
+            text2digits("um bilião e quinhentos mil milhões", &pt),
+            Ok("1500000000000".into())
+        );
+        assert_eq!(
+            replace_numbers_in_text("uma dívida de dois biliões de euros", &pt, 10.0),
+            "uma dívida de 2000000000000 de euros"
+        );
+        assert_replace_all_numbers!("biliões de estrelas", "biliões de estrelas");
+    }
+
+    #[test]
+    fn test_vague_quantifiers() {
+        assert_replace_numbers!("alguns milhões de pessoas", "alguns milhões de pessoas");
+        assert_replace_numbers!("vários milhões de reais", "vários milhões de reais");
+        assert_replace_numbers!("algumas centenas de metros", "algumas centenas de metros");
+        assert_replace_numbers!("três milhões de pessoas", "3000000 de pessoas");
+        assert_replace_numbers!("duzentas pessoas", "200 pessoas");
+    }
+
 }
@@ -4,3 +4,25 @@ pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
     "eh", "então", "bem", "isso", "outra vez", "e", "uh", "ha", "ah", "hu", "um", "menos", "ok", "sim", "mais", "aí está",
     "digo", "ou", "seja", "aquele", "é", "aquilo", "em", "fim", "mais tarde", "mas", "ei", "agora", "hum", "não", "com", "são", "novamente"
 };
+
+/// Vague quantifiers, like "*alguns*", "*vários*"
+pub static VAGUE_QUANTIFIERS: Set<&'static str> = phf_set! {
+    "alguns", "algumas", "vários", "várias"
+};
+
+/// The number words, inflected by [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "zero", "um", "primeiro", "dois", "duas", "segundo", "três", "tres", "terceiro", "quatro",
+    "quarto", "cinco", "quinto", "seis", "sexto", "sete", "sétimo", "oito", "oitavo", "nove",
+    "nono", "dez", "décimo", "onze", "doze", "treze", "catorze", "quatorze", "quinze", "dezasseis",
+    "dezesseis", "dezassete", "dezessete", "dezoito", "dezanove", "dezenove", "vinte", "vigésimo",
+    "trinta", "trigésimo", "quarenta", "quadragésimo", "cinquenta", "cinqüenta", "quinquagésimo",
+    "qüinquagésimo", "sessenta", "sexagésimo", "setenta", "septuagésimo", "setuagésimo", "oitenta",
+    "octogésimo", "noventa", "nonagésimo", "cem", "cento", "centésimo", "duzentos", "duzentas",
+    "ducentésimo", "trezentos", "trezentas", "trecentésimo", "quatrocentos", "quatrocentas",
+    "quadringentésimo", "quinhentos", "quinhentas", "quingentésimo", "qüingentésimo", "seiscentos",
+    "seiscentas", "sexcentésimo", "seiscentésimo", "setecentos", "setecentas", "septingentésimo",
+    "oitocentos", "oitocentas", "octingentésimo", "novecentos", "novecentas", "noningentésimo",
+    "nongentésimo", "mil", "milésimo", "milhão", "milionésimo", "bilhão", "bilionésimo", "bilião",
+    "trilhão", "trilionésimo", "trilião", "triliões", "milhões", "bilhões", "biliões", "trilhões",
+];
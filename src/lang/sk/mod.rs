@@ -0,0 +1,494 @@
+//! Slovak number interpreter
+//!
+//! Slovak numbers are written as one word, like "dvadsaťjeden" or "stodvadsaťtri", but the interpreter
+//! also accepts them split in several words ("sto dvadsať tri"), as speech-to-text recognition often does.
+//! Ordinals are adjectives declined in gender and case ("piaty", "piata", "piateho"…), written "5.".
+
+use alloc::{format, string::String, vec::Vec};
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+use crate::tokenizer::LazySplitter;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker, sorted_vocabulary};
+use vocabulary::{INSIGNIFICANT, NUMBER_WORDS};
+
+/// The splitter of the compound words, built on first use.
+static WORD_SPLITTER: LazySplitter = LazySplitter::new(&[
+    "dvadsať",
+    "tridsať",
+    "štyridsať",
+    "päťdesiat",
+    "šesťdesiat",
+    "sedemdesiat",
+    "osemdesiat",
+    "deväťdesiat",
+    "sto",
+    "tisíc",
+    "milión",
+    "milióny",
+    "miliónov",
+    "miliarda",
+    "miliardy",
+    "miliárd",
+]);
+
+/// The endings of the ordinal adjectives, that depend on their stem.
+#[derive(Debug, Clone, Copy)]
+enum Declension {
+    /// "druhý", "druhá", "druhého"…
+    Hard,
+    /// The endings are shortened after a long syllable: "piaty", "piata", "piateho"…
+    HardShortened,
+    /// Like [`Hard`](Self::Hard), without the forms that are also forms of the cardinal, like "stom"
+    Hundred,
+    /// "tretí", "tretia", "tretieho"…
+    Soft,
+    /// The endings are shortened after a long syllable: "tisíci", "tisícich"…
+    SoftShortened,
+}
+
+impl Declension {
+    fn endings(self) -> &'static [&'static str] {
+        match self {
+            Self::Hard => &["ý", "á", "é", "í", "ú", "ého", "ému", "ých", "ým", "ými", "om", "ej"],
+            Self::HardShortened => &["y", "a", "e", "i", "u", "eho", "emu", "ych", "ym", "ymi", "om", "ej"],
+            Self::Hundred => &["ý", "á", "é", "ú", "ého", "ému", "ých", "ým", "ými"],
+            Self::Soft => &["í", "ia", "ie", "iu", "ieho", "iemu", "ích", "ím", "ími", "ej"],
+            Self::SoftShortened => &["i", "ia", "ie", "iu", "ieho", "iemu", "ich", "im", "imi", "ej"],
+        }
+    }
+}
+
+/// The stems of the ordinals.
+const ORDINAL_STEMS: [(&str, Declension); 41] = [
+    ("prv", Declension::Hard),
+    ("druh", Declension::Hard),
+    ("tret", Declension::Soft),
+    ("štvrt", Declension::Hard),
+    ("piat", Declension::HardShortened),
+    ("šiest", Declension::HardShortened),
+    ("siedm", Declension::HardShortened),
+    ("ôsm", Declension::HardShortened),
+    ("deviat", Declension::HardShortened),
+    ("desiat", Declension::HardShortened),
+    ("jedenást", Declension::HardShortened),
+    ("dvanást", Declension::HardShortened),
+    ("trinást", Declension::HardShortened),
+    ("štrnást", Declension::HardShortened),
+    ("pätnást", Declension::HardShortened),
+    ("šestnást", Declension::HardShortened),
+    ("sedemnást", Declension::HardShortened),
+    ("osemnást", Declension::HardShortened),
+    ("devätnást", Declension::HardShortened),
+    ("dvadsiat", Declension::HardShortened),
+    ("tridsiat", Declension::HardShortened),
+    ("štyridsiat", Declension::HardShortened),
+    ("päťdesiat", Declension::HardShortened),
+    ("šesťdesiat", Declension::HardShortened),
+    ("sedemdesiat", Declension::HardShortened),
+    ("osemdesiat", Declension::HardShortened),
+    ("deväťdesiat", Declension::HardShortened),
+    ("st", Declension::Hundred),
+    ("dvojst", Declension::Hundred),
+    ("dvest", Declension::Hundred),
+    ("trojst", Declension::Hundred),
+    ("trist", Declension::Hundred),
+    ("štyrist", Declension::Hundred),
+    ("päťst", Declension::Hundred),
+    ("šesťst", Declension::Hundred),
+    ("sedemst", Declension::Hundred),
+    ("osemst", Declension::Hundred),
+    ("deväťst", Declension::Hundred),
+    ("tisíc", Declension::SoftShortened),
+    ("miliónt", Declension::HardShortened),
+    ("miliardt", Declension::Hard),
+];
+
+/// Return the stem of `word` and `true` if it is an ordinal, or `word` itself and `false`.
+fn lemmatize(word: &str) -> (&str, bool) {
+    ORDINAL_STEMS
+        .iter()
+        .find(|(stem, declension)| {
+            word.strip_prefix(stem)
+                .is_some_and(|ending| declension.endings().contains(&ending))
+        })
+        .map_or((word, false), |(stem, _)| (&word[..stem.len()], true))
+}
+
+/// The ordinal tens can be followed by an ordinal unit, as in "dvadsiaty prvý".
+fn is_ordinal_ten(stem: &str) -> bool {
+    matches!(
+        stem,
+        "dvadsiat"
+            | "tridsiat"
+            | "štyridsiat"
+            | "päťdesiat"
+            | "šesťdesiat"
+            | "sedemdesiat"
+            | "osemdesiat"
+            | "deväťdesiat"
+    )
+}
+
+/// The number words of a compound like "stodvadsaťtri".
+fn compound_parts(word: &str) -> Vec<&str> {
+    let mut parts: Vec<&str> = WORD_SPLITTER.get().split(word).collect();
+    // the ending of an ordinal is split off its stem, as in "dvetisíci"
+    if let [.., stem, ending] = parts[..] {
+        let ordinal = &word[word.len() - stem.len() - ending.len()..];
+        if lemmatize(ordinal).1 {
+            parts.pop();
+            parts.pop();
+            parts.push(ordinal);
+        }
+    }
+    parts
+}
+
+/// Return true if `word` is one of the number words, declined or compounded.
+fn is_number_word(word: &str) -> bool {
+    let (lemma, is_ordinal) = lemmatize(word);
+    if !is_ordinal && WORD_SPLITTER.get().is_splittable(word) {
+        return compound_parts(word).into_iter().all(is_number_word);
+    }
+    NUMBER_WORDS.iter().any(|known| lemmatize(known).0 == lemma)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Slovak {}
+
+impl Slovak {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Apply a word made of several number words, like "stodvadsaťtri".
+    fn apply_compound(&self, word: &str, b: &mut DigitString) -> Result<(), Error> {
+        let parts = compound_parts(word);
+        let last = parts.last().copied().unwrap_or_default();
+        let ds = self.exec_group(parts.into_iter())?;
+        if ds.len() > 3 && ds.len() <= 6 && !b.is_range_free(3, 5) {
+            return Err(Error::Overlap);
+        }
+        b.put(&ds)?;
+        if ds.marker.is_ordinal() {
+            b.marker = ds.marker;
+            if !is_ordinal_ten(lemmatize(last).0) {
+                b.freeze();
+            }
+        }
+        Ok(())
+    }
+}
+
+impl LangInterpreter for Slovak {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        // bare plural scale words are vague quantities: "tisíce ľudí"
+        if b.is_empty()
+            && matches!(
+                num_func,
+                "stovky" | "tisíce" | "tisícky" | "milióny" | "miliónov" | "miliardy" | "miliárd"
+            )
+        {
+            return Err(Error::NaN);
+        }
+        let (lemma, is_ordinal) = lemmatize(num_func);
+        if !is_ordinal && WORD_SPLITTER.get().is_splittable(num_func) {
+            return self.apply_compound(num_func, b);
+        }
+        // only an ordinal unit follows an ordinal ten
+        if b.marker.is_ordinal() && !is_ordinal {
+            return Err(Error::Overlap);
+        }
+        let status = match lemma {
+            "nula" => b.put(b"0"),
+            "jeden" | "jedna" | "jedno" | "jednu" | "jedného" | "jednej" | "prv"
+                if b.peek(2) != b"10" =>
+            {
+                b.put(b"1")
+            }
+            "dva" | "dve" | "dvaja" | "dvoch" | "dvom" | "druh" if b.peek(2) != b"10" => {
+                b.put(b"2")
+            }
+            "tri" | "traja" | "troch" | "tret" if b.peek(2) != b"10" => b.put(b"3"),
+            "štyri" | "štyria" | "štyroch" | "štvrt" if b.peek(2) != b"10" => b.put(b"4"),
+            "päť" | "piatich" | "piat" if b.peek(2) != b"10" => b.put(b"5"),
+            "šesť" | "šiestich" | "šiest" if b.peek(2) != b"10" => b.put(b"6"),
+            "sedem" | "siedmich" | "siedm" if b.peek(2) != b"10" => b.put(b"7"),
+            "osem" | "ôsmich" | "ôsm" if b.peek(2) != b"10" => b.put(b"8"),
+            "deväť" | "deviatich" | "deviat" if b.peek(2) != b"10" => b.put(b"9"),
+            "desať" | "desiat" => b.put(b"10"),
+            "jedenásť" | "jedenást" => b.put(b"11"),
+            "dvanásť" | "dvanást" => b.put(b"12"),
+            "trinásť" | "trinást" => b.put(b"13"),
+            "štrnásť" | "štrnást" => b.put(b"14"),
+            "pätnásť" | "pätnást" => b.put(b"15"),
+            "šestnásť" | "šestnást" => b.put(b"16"),
+            "sedemnásť" | "sedemnást" => b.put(b"17"),
+            "osemnásť" | "osemnást" => b.put(b"18"),
+            "devätnásť" | "devätnást" => b.put(b"19"),
+            "dvadsať" | "dvadsiat" => b.put(b"20"),
+            "tridsať" | "tridsiat" => b.put(b"30"),
+            "štyridsať" | "štyridsiat" => b.put(b"40"),
+            "päťdesiat" => b.put(b"50"),
+            "šesťdesiat" => b.put(b"60"),
+            "sedemdesiat" => b.put(b"70"),
+            "osemdesiat" => b.put(b"80"),
+            "deväťdesiat" => b.put(b"90"),
+            "sto" | "st" => {
+                // "dvesto", "päťsto"…, but not "jeden sto"
+                let peek = b.peek(2);
+                if (peek.len() == 1 || peek < b"10") && peek != b"1" && peek != b"01" {
+                    b.shift(2)
+                } else {
+                    Err(Error::Overlap)
+                }
+            }
+            "dvojst" | "dvest" => b.put(b"200"),
+            "trojst" | "trist" => b.put(b"300"),
+            "štyrist" => b.put(b"400"),
+            "päťst" => b.put(b"500"),
+            "šesťst" => b.put(b"600"),
+            "sedemst" => b.put(b"700"),
+            "osemst" => b.put(b"800"),
+            "deväťst" => b.put(b"900"),
+            "tisíc" | "tisíce" if b.is_range_free(3, 5) => b.shift(3),
+            "milión" | "milióny" | "miliónov" | "miliónt" if b.is_range_free(6, 8) => b.shift(6),
+            "miliarda" | "miliardy" | "miliárd" | "miliardt" if b.is_range_free(9, 11) => {
+                b.shift(9)
+            }
+            "bilión" | "bilióny" | "biliónov" => b.shift(12),
+            _ => Err(Error::NaN),
+        };
+        if status.is_ok() && is_ordinal {
+            b.marker = MorphologicalMarker::Ordinal(".".into());
+            if !is_ordinal_ten(lemma) {
+                b.freeze();
+            }
+        }
+        status
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        self.apply(decimal_func, b)
+    }
+
+    fn get_morph_marker(&self, word: &str) -> MorphologicalMarker {
+        if lemmatize(word).1 {
+            MorphologicalMarker::Ordinal(".".into())
+        } else {
+            MorphologicalMarker::None
+        }
+    }
+
+    fn language_code(&self) -> &'static str {
+        "sk"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        let ordinals = ORDINAL_STEMS.iter().flat_map(|(stem, declension)| {
+            declension.endings().iter().map(move |ending| format!("{stem}{ending}"))
+        });
+        sorted_vocabulary(NUMBER_WORDS.iter().map(|word| String::from(*word)).chain(ordinals))
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        // "tri celé päť", "tri čiarka päť"
+        match word {
+            "celá" | "celé" | "celých" | "čiarka" => Some(','),
+            _ => None,
+        }
+    }
+
+    fn decimal_symbol(&self) -> char {
+        ','
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        // the words of the vocabulary, in context
+        is_number_word(word) && super::dry_run_start(self, word)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        is_number_word(word) && super::dry_run_continue(self, word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Slovak::new();
+            let res = text2digits($text, &f);
+            crate::tests::dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Slovak::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Slovak::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Slovak::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("jeden", "1");
+        assert_text2digits!("dvadsaťjeden", "21");
+        assert_text2digits!("dvadsať jeden", "21");
+        assert_text2digits!("štyridsaťdva", "42");
+        assert_text2digits!("devätnásť", "19");
+        assert_text2digits!("sto", "100");
+        assert_text2digits!("stodvadsaťtri", "123");
+        assert_text2digits!("sto dvadsaťtri", "123");
+        assert_text2digits!("sto dvadsať tri", "123");
+        assert_text2digits!("dvesto", "200");
+        assert_text2digits!("päťsto šesťdesiatsedem", "567");
+        assert_text2digits!("tisíc", "1000");
+        assert_text2digits!("dvetisíc", "2000");
+        assert_text2digits!("dve tisíc päťsto", "2500");
+        assert_text2digits!("tritisícdvestopäťdesiatštyri", "3254");
+        assert_text2digits!("dvadsaťpäťtisíc", "25000");
+        assert_text2digits!("stotisíc", "100000");
+        assert_text2digits!("jeden milión", "1000000");
+        assert_text2digits!("dva milióny tristotisíc", "2300000");
+        assert_text2digits!("päť miliónov", "5000000");
+        assert_text2digits!("miliarda", "1000000000");
+        assert_text2digits!("dve miliardy", "2000000000");
+    }
+
+    #[test]
+    fn test_genders() {
+        assert_text2digits!("jeden", "1");
+        assert_text2digits!("jedna", "1");
+        assert_text2digits!("jedno", "1");
+        assert_text2digits!("dva", "2");
+        assert_text2digits!("dve", "2");
+        assert_text2digits!("dvaja", "2");
+        assert_text2digits!("traja", "3");
+        assert_text2digits!("štyria", "4");
+        assert_text2digits!("tridsaťjedna", "31");
+        assert_text2digits!("dvadsaťdve", "22");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        assert_text2digits!("prvý", "1.");
+        assert_text2digits!("druhá", "2.");
+        assert_text2digits!("tretie", "3.");
+        assert_text2digits!("piaty", "5.");
+        assert_text2digits!("piateho", "5.");
+        assert_text2digits!("siedmy", "7.");
+        assert_text2digits!("ôsma", "8.");
+        assert_text2digits!("desiaty", "10.");
+        assert_text2digits!("pätnásty", "15.");
+        assert_text2digits!("dvadsiaty", "20.");
+        assert_text2digits!("dvadsiaty prvý", "21.");
+        assert_text2digits!("dvadsiata tretia", "23.");
+        assert_text2digits!("dvadsaťprvý", "21.");
+        assert_text2digits!("stý", "100.");
+        assert_text2digits!("sto dvadsiaty piaty", "125.");
+        assert_text2digits!("stodvadsiaty piaty", "125.");
+        assert_text2digits!("dvojstý", "200.");
+        assert_text2digits!("tisíci", "1000.");
+        assert_text2digits!("dvetisíci", "2000.");
+        assert_text2digits!("miliónty", "1000000.");
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("nula", "0");
+        assert_text2digits!("nula osem", "08");
+        assert_text2digits!("nula nula sto dvadsaťpäť", "00125");
+        assert_invalid!("päť nula");
+        assert_invalid!("sto nula");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("tisíc tisíc dvesto");
+        assert_invalid!("šesťdesiat pätnásť");
+        assert_invalid!("desať päť");
+        assert_invalid!("jeden sto");
+        assert_invalid!("dvadsiaty jeden");
+        assert_invalid!("prvý druhý");
+        assert_invalid!("mesto");
+        assert_invalid!("tisíce");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "Máme dvadsaťpäť kráv, dvanásť kurčiat a stodvadsaťpäť kg zemiakov.",
+            "Máme 25 kráv, 12 kurčiat a 125 kg zemiakov."
+        );
+        assert_replace_numbers!("Tisíc dvestošesťdesiatšesť eur", "1266 eur");
+        assert_replace_numbers!("jeden dva tri štyri dvadsať pätnásť", "1 2 3 4 20 15");
+        assert_replace_numbers!("dvadsaťjeden, tridsaťjeden.", "21, 31.");
+        assert_replace_numbers!("tisíce ľudí a milióny hviezd", "tisíce ľudí a milióny hviezd");
+    }
+
+    #[test]
+    fn test_replace_decimals() {
+        assert_replace_numbers!(
+            "dvanásť celých deväťdesiatdeväť, stodvadsať celá nula päť, jedna celá dvestotridsaťšesť",
+            "12,99, 120,05, 1,236"
+        );
+        assert_replace_numbers!("tri čiarka štrnásť", "3,14");
+        assert_replace_all_numbers!("tri celé päť", "3,5");
+    }
+
+    #[test]
+    fn test_replace_ordinals() {
+        assert_replace_numbers!(
+            "Piaty prišiel, dvadsiaty prvý sa vrátil, dvadsiati druhí v rade.",
+            "Piaty prišiel, 21. sa vrátil, 22. v rade."
+        );
+        assert_replace_all_numbers!("piaty deň, prvá trieda", "5. deň, 1. trieda");
+        assert_replace_all_numbers!("dvadsiateho tretieho decembra", "23. decembra");
+        assert_replace_all_numbers!("rôzne druhy ovocia", "rôzne druhy ovocia");
+    }
+
+    #[test]
+    fn test_isolates() {
+        assert_replace_numbers!(
+            "Jeden človek prišiel, potom dva a tri.",
+            "Jeden človek prišiel, potom 2 a 3."
+        );
+        assert_replace_numbers!("mám jedno auto", "mám jedno auto");
+        assert_replace_all_numbers!("mám jedno auto", "mám 1 auto");
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("DVADSAŤJEDEN", "21");
+        assert_replace_all_numbers!("Stodvadsaťtri", "123");
+    }
+}
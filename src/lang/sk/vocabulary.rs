@@ -0,0 +1,21 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "a", "aj", "eh", "ehm", "hm", "no", "plus", "mínus", "krát", "je", "sú", "potom", "ešte", "tak", "teda", "čiže"
+};
+
+/// The number words, inflected by [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "nula", "jeden", "jedna", "jedno", "jednu", "jedného", "jednej", "dva", "dve", "dvaja", "dvoch",
+    "dvom", "tri", "traja", "troch", "štyri", "štyria", "štyroch", "päť", "piatich", "šesť",
+    "šiestich", "sedem", "siedmich", "osem", "ôsmich", "deväť", "deviatich", "desať", "jedenásť",
+    "dvanásť", "trinásť", "štrnásť", "pätnásť", "šestnásť", "sedemnásť", "osemnásť", "devätnásť",
+    "dvadsať", "tridsať", "štyridsať", "päťdesiat", "šesťdesiat", "sedemdesiat", "osemdesiat",
+    "deväťdesiat", "sto", "tisíc", "milión", "miliarda", "miliárd", "bilión", "bilióny", "biliónov",
+    "prvý", "druhý", "tretí", "štvrtý", "piaty", "šiesty", "siedmy", "ôsmy", "deviaty", "desiaty",
+    "jedenásty", "dvanásty", "trinásty", "štrnásty", "pätnásty", "šestnásty", "sedemnásty",
+    "osemnásty", "devätnásty", "dvadsiaty", "tridsiaty", "štyridsiaty", "päťdesiaty", "šesťdesiaty",
+    "sedemdesiaty", "osemdesiaty", "deväťdesiaty", "stý", "dvojstý", "dvestý", "trojstý", "tristý",
+    "štyristý", "päťstý", "šesťstý", "sedemstý", "osemstý", "deväťstý", "tisíci", "miliónty",
+    "miliardtý", "milióny", "miliónov", "miliardy",
+];
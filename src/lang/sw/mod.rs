@@ -0,0 +1,378 @@
+//! Swahili number interpreter
+//!
+//! Scale words come before their multiplier: "mia tatu" is 300, "elfu ishirini na tano" is 25000.
+//! A scale word first counts for one ("elfu" is 1000), and the multiplier that follows replaces that provisional one.
+//! The elements of a number are joined by "na": "mia moja ishirini na tatu", "elfu mbili na tano".
+//!
+//! Ordinals are not supported: they are built with the possessive "wa", "la", "cha"… before the number
+//! ("mtoto wa tatu", the third child), which can't be told from a possessive followed by a cardinal.
+//! The number is then converted as a cardinal: "mtoto wa 3".
+
+use alloc::{string::String, vec::Vec};
+
+use bitflags::bitflags;
+
+use crate::digit_string::DigitString;
+use crate::error::Error;
+
+mod vocabulary;
+
+use super::{LangInterpreter, MorphologicalMarker};
+use vocabulary::{INSIGNIFICANT, NUMBER_WORDS};
+
+bitflags! {
+    /// word chaining state
+    struct State: u64 {
+        /// after "na"
+        const CONJUNCTION = 1;
+        /// after "mia" or "laki", before its multiplier
+        const FRESH_HUNDRED = 2;
+        /// after "elfu", "milioni" or "bilioni", before its multiplier
+        const FRESH_GROUP = 4;
+        /// in the multiplier of "elfu", "milioni" or "bilioni"
+        const IN_GROUP = 8;
+        const THOUSANDS = 16;
+        const MILLIONS = 32;
+        const BILLIONS = 64;
+    }
+}
+
+impl State {
+    /// The power of ten of the multiplier being decoded.
+    fn scale(self) -> usize {
+        if self.contains(State::BILLIONS) {
+            9
+        } else if self.contains(State::MILLIONS) {
+            6
+        } else if self.contains(State::THOUSANDS) {
+            3
+        } else {
+            0
+        }
+    }
+
+    fn at_scale(scale: usize) -> Self {
+        match scale {
+            3 => State::THOUSANDS,
+            6 => State::MILLIONS,
+            9 => State::BILLIONS,
+            _ => State::empty(),
+        }
+    }
+}
+
+fn value(word: &str) -> Option<&'static [u8]> {
+    Some(match word {
+        "moja" => b"1",
+        "mbili" => b"2",
+        "tatu" => b"3",
+        "nne" => b"4",
+        "tano" => b"5",
+        "sita" => b"6",
+        "saba" => b"7",
+        "nane" => b"8",
+        "tisa" => b"9",
+        "kumi" => b"10",
+        "ishirini" => b"20",
+        "thelathini" => b"30",
+        "arobaini" => b"40",
+        "hamsini" => b"50",
+        "sitini" => b"60",
+        "sabini" => b"70",
+        "themanini" => b"80",
+        "tisini" => b"90",
+        _ => return None,
+    })
+}
+
+/// `digits` followed by `scale` zeros.
+fn scaled(digits: &[u8], scale: usize) -> Vec<u8> {
+    let mut scaled = Vec::with_capacity(digits.len() + scale);
+    scaled.extend_from_slice(digits);
+    scaled.resize(digits.len() + scale, b'0');
+    scaled
+}
+
+/// Return true if `word` is one of the number words.
+fn is_number_word(word: &str) -> bool {
+    NUMBER_WORDS.contains(&word)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Swahili {}
+
+impl Swahili {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Apply the unit, ten or "kumi" `digits` to `b`, and return the new state.
+    fn apply_value(digits: &[u8], state: State, b: &mut DigitString) -> Result<State, Error> {
+        let scale = state.scale();
+        if state.contains(State::FRESH_HUNDRED) && digits.len() == 1 {
+            // "mia tatu", "laki mbili", "elfu mia tatu"
+            b.clear_digit_at(scale + 2)?;
+            b.put_digit_at(digits[0], scale + 2)?;
+            Ok(if scale > 0 {
+                State::IN_GROUP | State::at_scale(scale)
+            } else {
+                State::empty()
+            })
+        } else if state.contains(State::FRESH_GROUP) {
+            // "elfu ishirini"
+            b.clear_digit_at(scale)?;
+            b.put(&scaled(digits, scale))?;
+            Ok(State::IN_GROUP | State::at_scale(scale))
+        } else if state.contains(State::IN_GROUP) && b.put(&scaled(digits, scale)).is_ok() {
+            // "elfu ishirini na tano"
+            Ok(State::IN_GROUP | State::at_scale(scale))
+        } else if !b.is_empty() && digits.len() == 1 && !state.contains(State::CONJUNCTION) {
+            // "ishirini tatu": a unit is joined by "na"
+            Err(Error::NaN)
+        } else {
+            b.put(digits)?;
+            Ok(State::empty())
+        }
+    }
+
+    /// Apply the scale word of power of ten `scale`, that must come before a multiplier, and return the new state.
+    fn apply_scale(scale: usize, state: State, b: &mut DigitString) -> Result<State, Error> {
+        if state.intersects(State::FRESH_HUNDRED | State::FRESH_GROUP) || !b.is_free(scale) {
+            return Err(Error::Overlap);
+        }
+        // "laki" may take the hundreds of thousands before "elfu"
+        let free = if scale == 3 {
+            b.is_range_free(3, 4)
+        } else {
+            b.is_range_free(scale, scale + 2)
+        };
+        if !free {
+            return Err(Error::Overlap);
+        }
+        b.put_digit_at(b'1', scale)?;
+        Ok(State::FRESH_GROUP | State::at_scale(scale))
+    }
+}
+
+impl LangInterpreter for Swahili {
+    fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        let state = State::from_bits_truncate(b.flags);
+        // keep `b` as is on error
+        let mut next = b.clone();
+        let status = match num_func {
+            "sifuri" => next.put(b"0").map(|()| State::empty()),
+            "na" if !b.is_empty() && b.marker.is_none() && !state.contains(State::CONJUNCTION) => {
+                // "elfu na mia tatu": "elfu" keeps its provisional one
+                let in_group = state.intersects(State::FRESH_GROUP | State::IN_GROUP);
+                b.flags = (State::CONJUNCTION | State::at_scale(state.scale())).bits();
+                if in_group {
+                    b.flags |= State::IN_GROUP.bits();
+                }
+                return Err(Error::Incomplete);
+            }
+            "mia" if !state.contains(State::FRESH_HUNDRED) => {
+                if state.contains(State::FRESH_GROUP) {
+                    // "elfu mia tatu": the hundreds of the multiplier
+                    let scale = state.scale();
+                    next.clear_digit_at(scale)
+                        .and_then(|()| next.put(&scaled(b"100", scale)))
+                        .map(|()| State::FRESH_HUNDRED | State::at_scale(scale))
+                } else {
+                    next.put(b"100").map(|()| State::FRESH_HUNDRED)
+                }
+            }
+            "laki" if !state.intersects(State::FRESH_HUNDRED | State::FRESH_GROUP) => next
+                .put(b"100000")
+                .map(|()| State::FRESH_HUNDRED | State::THOUSANDS),
+            "elfu" => Self::apply_scale(3, state, &mut next),
+            "milioni" => Self::apply_scale(6, state, &mut next),
+            "bilioni" => Self::apply_scale(9, state, &mut next),
+            word => match value(word) {
+                Some(digits) => Self::apply_value(digits, state, &mut next),
+                None => Err(Error::NaN),
+            },
+        };
+        match status {
+            Ok(state) => {
+                next.flags = state.bits();
+                *b = next;
+                Ok(())
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+        // "tatu nukta moja nne": the decimals are read digit by digit
+        match decimal_func {
+            "sifuri" => b.push(b"0"),
+            word => match value(word) {
+                Some(digit) if digit.len() == 1 => b.push(digit),
+                _ => Err(Error::NaN),
+            },
+        }
+    }
+
+    fn get_morph_marker(&self, _word: &str) -> MorphologicalMarker {
+        MorphologicalMarker::None
+    }
+
+    fn language_code(&self) -> &'static str {
+        "sw"
+    }
+
+    fn vocabulary(&self) -> Vec<String> {
+        NUMBER_WORDS.iter().map(|word| String::from(*word)).collect()
+    }
+
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        match word {
+            "nukta" => Some('.'),
+            _ => None,
+        }
+    }
+
+    fn score_connectors(&self) -> &'static [&'static str] {
+        // "tatu kwa mbili"
+        &["kwa"]
+    }
+
+    fn is_linking(&self, word: &str) -> bool {
+        INSIGNIFICANT.contains(word)
+    }
+
+    fn can_start_number(&self, word: &str) -> bool {
+        // the words of the vocabulary, in context
+        is_number_word(word) && super::dry_run_start(self, word)
+    }
+
+    fn can_continue(&self, word: &str) -> bool {
+        (word == "na" || is_number_word(word)) && super::dry_run_continue(self, word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word_to_digit::{replace_numbers_in_text, text2digits};
+
+    macro_rules! assert_text2digits {
+        ($text:expr, $res:expr) => {
+            let f = Swahili::new();
+            let res = text2digits($text, &f);
+            crate::tests::dbg!(&res);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap(), $res)
+        };
+    }
+
+    macro_rules! assert_replace_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Swahili::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 10.0), $res)
+        };
+    }
+
+    macro_rules! assert_replace_all_numbers {
+        ($text:expr, $res:expr) => {
+            let f = Swahili::new();
+            assert_eq!(replace_numbers_in_text($text, &f, 0.0), $res)
+        };
+    }
+
+    macro_rules! assert_invalid {
+        ($text:expr) => {
+            let f = Swahili::new();
+            let res = text2digits($text, &f);
+            assert!(res.is_err());
+        };
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_text2digits!("moja", "1");
+        assert_text2digits!("kumi", "10");
+        assert_text2digits!("kumi na moja", "11");
+        assert_text2digits!("ishirini na tatu", "23");
+        assert_text2digits!("tisini na tisa", "99");
+        assert_text2digits!("mia", "100");
+        assert_text2digits!("mia moja", "100");
+        assert_text2digits!("mia na tano", "105");
+        assert_text2digits!("mia moja ishirini na tatu", "123");
+        assert_text2digits!("mia moja na ishirini na tatu", "123");
+        assert_text2digits!("mia tatu", "300");
+        assert_text2digits!("mia tisa tisini na tisa", "999");
+    }
+
+    #[test]
+    fn test_scales() {
+        assert_text2digits!("elfu", "1000");
+        assert_text2digits!("elfu moja", "1000");
+        assert_text2digits!("elfu mbili", "2000");
+        assert_text2digits!("elfu mbili na tano", "2005");
+        assert_text2digits!("elfu moja mia tatu", "1300");
+        assert_text2digits!("elfu na mia tatu", "1300");
+        assert_text2digits!("elfu mbili mia tatu arobaini na tano", "2345");
+        assert_text2digits!("elfu kumi", "10000");
+        assert_text2digits!("elfu ishirini na tano", "25000");
+        assert_text2digits!("elfu ishirini na tatu mia nne hamsini na sita", "23456");
+        assert_text2digits!("elfu mia tatu", "300000");
+        assert_text2digits!("laki mbili", "200000");
+        assert_text2digits!("laki moja elfu ishirini", "120000");
+        assert_text2digits!("milioni moja", "1000000");
+        assert_text2digits!("milioni mbili elfu mia tatu", "2300000");
+        assert_text2digits!("bilioni tano", "5000000000");
+    }
+
+    #[test]
+    fn test_conjunction() {
+        assert_invalid!("ishirini na");
+        assert_invalid!("ishirini na na tatu");
+        assert_invalid!("na tatu");
+        assert_replace_all_numbers!("mbili na tatu", "2 na 3");
+        assert_replace_all_numbers!("ishirini tatu", "20 3");
+        assert_replace_all_numbers!("tatu na ishirini", "3 na 20");
+    }
+
+    #[test]
+    fn test_zeroes() {
+        assert_text2digits!("sifuri", "0");
+        assert_invalid!("tano sifuri");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_invalid!("mia mia");
+        assert_invalid!("elfu elfu");
+        assert_invalid!("elfu mbili elfu");
+        assert_invalid!("mia tatu elfu");
+        assert_invalid!("elfu mbili milioni");
+    }
+
+    #[test]
+    fn test_replace_integers() {
+        assert_replace_numbers!(
+            "Nina ng'ombe ishirini na tano, kuku kumi na mbili na viazi kilo mia moja ishirini na tano.",
+            "Nina ng'ombe 25, kuku 12 na viazi kilo 125."
+        );
+        assert_replace_numbers!("Shilingi elfu mbili mia tatu", "Shilingi 2300");
+        assert_replace_numbers!("moja mbili tatu nne ishirini kumi", "1 2 3 4 20 10");
+    }
+
+    #[test]
+    fn test_replace_decimals() {
+        assert_replace_numbers!("kumi na mbili nukta tisa tisa", "12.99");
+        assert_replace_all_numbers!("tatu nukta moja nne", "3.14");
+    }
+
+    #[test]
+    fn test_ordinals_as_cardinals() {
+        assert_replace_all_numbers!("mtoto wa tatu", "mtoto wa 3");
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_replace_all_numbers!("ISHIRINI NA TATU", "23");
+        assert_replace_all_numbers!("Mia Moja", "100");
+    }
+}
@@ -0,0 +1,12 @@
+use phf::{Set, phf_set};
+
+pub static INSIGNIFICANT: Set<&'static str> = phf_set! {
+    "na", "jumlisha", "kutoa", "mara", "ni", "ehe", "basi", "halafu", "pia"
+};
+
+/// The number words, for [`vocabulary`](crate::LangInterpreter::vocabulary)
+pub static NUMBER_WORDS: &[&str] = &[
+    "sifuri", "moja", "mbili", "tatu", "nne", "tano", "sita", "saba", "nane", "tisa", "kumi",
+    "ishirini", "thelathini", "arobaini", "hamsini", "sitini", "sabini", "themanini", "tisini",
+    "mia", "laki", "elfu", "milioni", "bilioni",
+];
@@ -18,6 +18,8 @@ used by adding `text2num` to your dependencies in your project's `Cargo.toml`.
 text2num = "1"
 ```
 
+The [`prelude`] brings the usual types and functions in scope with `use text2num::prelude::*;`.
+
 # Example: check some string is a valid number in a given language.
 
 For convenience, the builtin languages are encapsulated into the [`Language`] type so
@@ -28,7 +30,7 @@ Each builtin language support regional varieties automatically, so you don't nee
 The language interpreters are stateless so you can reuse and share them.
 
 ```rust
-use text2num::{Language, text2digits};
+use text2num::prelude::*;
 
 let en = Language::english();
 
@@ -42,7 +44,7 @@ assert!(text2digits("twenty twelve", &en).is_err());
 Of course, you can get the base 10 digit representation too:
 
 ```rust
-use text2num::{Language, text2digits};
+use text2num::prelude::*;
 
 let es = Language::spanish();
 let utterance = "ochenta y cinco";
@@ -60,7 +62,7 @@ the `Language` type:
 
 ```
 use text2num::lang::English;
-use text2num::text2digits;
+use text2num::prelude::*;
 
 let en = English::new();
 
@@ -76,7 +78,7 @@ As isolated smaller numbers may be easier to read in plain text, you can specify
 not replaced.
 
 ```rust
-use text2num::{Language, replace_numbers_in_text};
+use text2num::prelude::*;
 
 let en = Language::english();
 
@@ -107,7 +109,7 @@ The `text2num` library can process those streams as long as the token type imple
 We can show a simple example with `String` streams:
 
 ```rust
-use text2num::{replace_numbers_in_stream, Language, Token, Replace};
+use text2num::prelude::*;
 
 let en = Language::english();
 
@@ -146,7 +148,7 @@ In this more elaborate example, we show how to implement the `Token` trait on a
 how to locate numbers (and their values) in a stream of those tokens.
 
 ```rust
-use text2num::{find_numbers, Language, Token};
+use text2num::prelude::*;
 
 struct DecodedWord<'a> {
     text: &'a str,
@@ -209,22 +211,71 @@ assert!(!found.is_ordinal);
 
 extern crate alloc;
 
+pub mod alignment;
+mod bidi;
+pub mod bytes;
+#[cfg(feature = "test-utils")]
+pub mod conformance;
+#[cfg(any(test, feature = "test-utils"))]
+mod corpus;
 pub mod digit_string;
+pub mod edit;
 pub mod error;
+pub mod formatter;
 pub mod lang;
+pub mod options;
+pub mod parse;
+pub mod pipeline;
+pub mod prelude;
+pub mod prepared;
+pub mod readback;
+#[cfg(feature = "registry")]
+pub mod registry;
+#[cfg(feature = "subtitles")]
+pub mod subtitles;
 mod tokenizer;
+pub mod trace;
 pub mod word_to_digit;
 
-pub use lang::{BasicAnnotate, LangInterpreter, Language};
+pub use alignment::{Alignment, Segment};
+pub use bytes::{find_numbers_bytes, replace_numbers_bytes};
+pub use edit::{Edit, EditError, apply_edits, propose_edits};
+pub use formatter::{DigitScript, NumberFormatter};
+pub use lang::{
+    ANNOTATION_CONTEXT, Affix, BasicAnnotate, Denominator, DynLangInterpreter, LangInterpreter,
+    Language, MorphologicalMarker, Scale, TokenKind,
+};
+pub use options::{
+    AdjacentNumberPolicy, FractionOutput, Markup, MarkupFinder, MarkupPlacement, Mask, MaskError,
+    Options, OrdinalStyle,
+};
+pub use parse::{
+    CellOptions, CellResult, NumberKind, ParseError, ParsedNumber, convert_cell, convert_cells,
+    parse_first_number, parse_number,
+};
+pub use prepared::{PreparedText, find_numbers_prepared, replace_numbers_prepared};
+pub use readback::{ReadbackPolicy, verify_repeated_sequences};
+#[cfg(feature = "subtitles")]
+pub use subtitles::{Cue, convert_cues};
+pub use tokenizer::BasicToken;
+pub use trace::Trace;
 pub use word_to_digit::{
-    Occurence, Replace, Token, find_numbers, find_numbers_iter, replace_numbers_in_stream,
-    replace_numbers_in_text, text2digits,
+    ExtractedValue, FindNumbers, Morphology, Occurence, Replace, Replaced, SpliceError, Stats,
+    Token, TokenOccurence, annotate_numbers, annotate_numbers_with, extract_values, find_numbers,
+    find_numbers_in_tokens, find_numbers_iter, find_numbers_iter_with, find_numbers_with,
+    replace_numbers_in_stream, replace_numbers_in_stream_recommended,
+    replace_numbers_in_stream_with, replace_numbers_in_text, replace_numbers_in_text_aligned,
+    replace_numbers_in_text_checked, replace_numbers_in_text_cow,
+    replace_numbers_in_text_recommended, replace_numbers_in_text_stats,
+    replace_numbers_in_text_traced, replace_numbers_in_text_with, replace_numbers_in_tokens,
+    replace_numbers_to, text2digits,
 };
 
-pub use lang::get_interpreter_for;
+pub use lang::{LanguageError, get_interpreter_for, resolve_language};
 
 #[cfg(test)]
 pub(crate) mod tests {
+    #[cfg_attr(not(any(feature = "en", feature = "fr")), allow(unused_imports))]
     use super::{Language, replace_numbers_in_text};
 
     #[cfg(not(feature = "std"))]
@@ -246,7 +297,7 @@ pub(crate) mod tests {
                 &french,
                 0.0
             ),
-            "Pour la 5ème fois: 25 plus 48 égalent 73"
+            "Pour la 5e fois: 25 plus 48 égalent 73"
         );
     }
 
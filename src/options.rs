@@ -0,0 +1,504 @@
+/*!
+Options driving the number finding and replacement functions.
+
+The plain functions of the [`word_to_digit`](crate::word_to_digit) module only take a `threshold`;
+their `*_with` counterparts take an [`Options`] value instead, for finer control.
+
+```rust
+use text2num::{Language, Options, replace_numbers_in_text_with};
+
+let en = Language::english();
+let options = Options {
+    collapse_stutters: true,
+    ..Options::new(10.0)
+};
+
+assert_eq!(
+    replace_numbers_in_text_with("two two hundred and fifty dollars", &en, &options),
+    "250 dollars"
+);
+```
+*/
+
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::fmt;
+use core::ops::Range;
+
+use crate::digit_string::DEFAULT_MAX_DIGITS;
+use crate::formatter::{DigitScript, NumberFormatter};
+use crate::lang::LangInterpreter;
+
+/// Tuning of the number finding and replacement functions.
+///
+/// The [`Default`] value converts everything and doesn't apply any heuristic.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Isolated simple cardinals and ordinals strictly under `threshold` are not converted
+    /// (set to 0.0 to convert everything).
+    pub threshold: f64,
+    /// Repair ASR stutters like "*two two hundred*".
+    ///
+    /// When a word can't be added to the number being decoded because it repeats the previous number word
+    /// ("*two two*") or restates it as a compound ("*vingt vingt-trois*"), the repetition is tentatively
+    /// dropped. A repeated word is only confirmed as a stutter if the next word continues the number; otherwise
+    /// the words are understood as separate numbers, so that "*zwei zwei*" still gives two occurences.
+    /// A restating compound is a stutter by itself: "*vingt vingt-trois*" gives "*23*".
+    /// Repaired occurences are flagged with [`Occurence::repaired`](crate::Occurence::repaired).
+    pub collapse_stutters: bool,
+    /// Convert adverbial ordinals like "*firstly*" or "*erstens*" to enumeration markers like "*1.*".
+    ///
+    /// Adverbial ordinals are discourse markers, so they are left untouched by default.
+    /// The rendering is language specific (see [`LangInterpreter::format_adverbial_ordinal`]).
+    pub adverbial_ordinals: bool,
+    /// How to read numbers that follow each other without punctuation, like "*two three four*".
+    pub adjacent_number_policy: AdjacentNumberPolicy,
+    /// Convert numeral nouns in their quantity frame, like "*a pair of*" in "*a pair of shoes*" (giving "*2 shoes*").
+    ///
+    /// The frame is strict — a determiner, the numeral noun, a linker and a plural noun — so that
+    /// "*the pair*" or "*a score of 6-2*" are left untouched. Only languages that provide
+    /// [`LangInterpreter::informal_quantity`] are concerned.
+    pub informal_quantities: bool,
+    /// Convert the fractions spoken as two cardinals with a connector, like "*three over four*" (giving "*3/4*").
+    ///
+    /// The second cardinal must be a complete cardinal, so that "*three over there*" is left untouched.
+    /// Only languages that provide [`LangInterpreter::fraction_connectors`]
+    /// are concerned.
+    pub spoken_fractions: bool,
+    /// Recognize the scores and results read as two small cardinals, like "*three two*" or "*three to two*",
+    /// and flag their second number with [`Occurence::score`](crate::Occurence::score), so that a dash can be put
+    /// between them.
+    ///
+    /// The numbers of a score are never merged by the [`adjacent_number_policy`](Self::adjacent_number_policy):
+    /// "*three two*" gives "*3 2*", not "*32*". The connectors are those of
+    /// [`LangInterpreter::score_connectors`].
+    /// As ranges read alike ("*one to ten*"), scores are only recognized on request.
+    pub scores: bool,
+    /// Convert the number that starts a hyphenated compound and keep the rest of the compound:
+    /// "*three-dimensional*" gives "*3-dimensional*", "*twenty-two-year-old*" gives "*22-year-old*".
+    ///
+    /// By default, a hyphenated word is only converted if it is a number as a whole.
+    /// The number prefix is found with [`LangInterpreter::hyphenated_number_prefix`].
+    /// Only the text functions, that tokenize the text themselves, are concerned.
+    pub convert_in_hyphenated_compounds: bool,
+    /// Convert the longest number at the start or at the end of a hyphenated word that is not a number as a whole,
+    /// and keep the rest of the word: "*twenty-one-porridge*" gives "*21-porridge*", "*porridge-twenty*"
+    /// gives "*porridge-20*".
+    ///
+    /// The longer of the number prefix ([`LangInterpreter::hyphenated_number_prefix`])
+    /// and the number suffix ([`LangInterpreter::hyphenated_number_suffix`])
+    /// is converted, the prefix if they are as long, and the number is flagged with
+    /// [`Occurence::partial`](crate::Occurence::partial). Without it, such words are left as they are,
+    /// unless [`convert_in_hyphenated_compounds`](Self::convert_in_hyphenated_compounds) converts their prefix.
+    /// Only the text functions, that tokenize the text themselves, are concerned.
+    pub salvage_partial_groups: bool,
+    /// Retry the words that are not number words without their clitic, like "*twenty's*" or "*vingt-là*",
+    /// and keep the clitic after the digits: "*20's*", "*20-là*".
+    ///
+    /// The clitics are a short list per language (see [`LangInterpreter::clitics`]).
+    /// A word is only stripped if its stem is a number word, and the number ends with it, but not if
+    /// the stem is a pronoun before the clitic, like "*one*" in "*one's own*".
+    /// The clitic is recorded on the occurence (see [`Occurence::clitic`](crate::Occurence::clitic)).
+    pub strip_clitics: bool,
+    /// Extra decimal separator words, with the symbol they are figured as, like `("decimal".into(), '.')`
+    /// for aviation English.
+    ///
+    /// The words must be lowercase. They are looked up before the separators of the language
+    /// (see [`LangInterpreter::check_decimal_separator`]),
+    /// so that they can also change the symbol of a builtin separator word.
+    pub decimal_separators: Vec<(String, char)>,
+    /// Read the decimal numbers that start with their separator, like "*point five*" (giving "*0.5*").
+    ///
+    /// By default, a decimal separator is only one after the integral part, so that "*at some point five people left*"
+    /// keeps its "*point*".
+    pub leading_decimal_separator: bool,
+    /// Convert the numbers written in digits and followed by a scale word, like "*3 thousand*" (giving "*3000*").
+    ///
+    /// Only the digits without separators are concerned: the other numbers written in digits,
+    /// like "*1,234.56*", are always left as they are (see [`Token::is_digits`](crate::Token::is_digits)).
+    pub digits_with_scales: bool,
+    /// A rendering of the digit forms that replaces the one of the language (see [`Options::with_formatter`]).
+    pub formatter: Option<Arc<dyn NumberFormatter>>,
+    /// The rendering of the ordinal markers, like "*1er*" or "*1ᵉʳ*" in French.
+    pub ordinal_style: OrdinalStyle,
+    /// Write the ordinal markers: with `false`, "*twentieth*" gives "*20*", whatever the
+    /// [`ordinal_style`](Self::ordinal_style), and "*firstly*" gives "*1*" (see [`adverbial_ordinals`](Self::adverbial_ordinals)).
+    ///
+    /// Only the text of the replacement changes: the number is still an ordinal, of the same value.
+    pub keep_ordinal_markers: bool,
+    /// Write the fraction markers of the words that are fractions by themselves, like the "*avo*" of the Spanish
+    /// "*onceavo*" that the [`formatter`](Self::formatter) may write as "*11avo*": with `false`, only the slash of
+    /// "*1/11*" is written, as the digits alone would read as another number.
+    ///
+    /// The fractions read from their denominator, like "*three fifths*", have no marker: they keep their slash.
+    pub keep_fraction_markers: bool,
+    /// The rendering of fractions like "*one fifth of*" (see [`LangInterpreter::fraction_denominator`]).
+    pub fraction_output: FractionOutput,
+    /// Numbers of more digits, leading zeroes included, are left unconverted.
+    ///
+    /// Together with [`max_tokens`](Self::max_tokens), this guards against pathological inputs.
+    pub max_digits: usize,
+    /// Numbers made of more words are left unconverted.
+    pub max_tokens: usize,
+    /// Numbers made of fewer words are left unconverted, whatever their value: with 2, "*forty*"
+    /// and "*twentieth*" stay, but "*forty two*" is converted.
+    ///
+    /// The words are those of [`Occurence::words`](crate::Occurence::words): the linking words and decimal separators
+    /// count ("*five point two*" is three words, "*forty-two*" is one), and so do all the words of the numbers merged
+    /// by the [`AdjacentNumberPolicy`]. This rule comes on top of the `threshold`: a number must pass both to be converted.
+    pub min_word_count: usize,
+    /// Leave unconverted the numbers that start a sentence, as style guides want: "*Twenty people came.*"
+    /// stays as is, while "*They were twenty.*" gives "*They were 20.*".
+    ///
+    /// A sentence starts with the text and after a terminal punctuation, like "*.*", "*!*", "*?*" or "*…*",
+    /// the quotes and brackets around being ignored: the number is also sentence-initial in "*He said: "Twenty.*"
+    /// but not in "*Yes, twenty.*". That is the sentence boundary that also stops the sequences of lone numbers.
+    /// The period of an abbreviation ends a sentence too: the numbers of "*Mr. Twenty*" and "*e.g. twenty*"
+    /// are left as well.
+    ///
+    /// The numbers that follow the first one with no other word in between are left with it: "*Twenty, thirty
+    /// people*" and "*Nineteen eighty-four was…*" stay as they are.
+    pub skip_sentence_initial: bool,
+    /// Convert the ordinals anyway, with [`skip_sentence_initial`](Self::skip_sentence_initial): "*1st prize…*".
+    pub convert_sentence_initial_ordinals: bool,
+    /// Convert the years anyway, with [`skip_sentence_initial`](Self::skip_sentence_initial): "*1984 was…*".
+    pub convert_sentence_initial_years: bool,
+    /// Byte ranges of the text that must be left untouched, like code spans or URLs (see [`Options::with_mask`]).
+    ///
+    /// A number overlapping a protected range is left unconverted as a whole, even the words outside the range.
+    pub mask: Mask,
+    /// Wrap the digits spliced into a right-to-left paragraph, like a Hebrew or Arabic one, in the Unicode bidi isolates
+    /// FSI (U+2068) and PDI (U+2069), so that they are not reordered with their neighbours when displayed.
+    ///
+    /// The direction of a paragraph is the one of its first strong character. Left-to-right paragraphs are
+    /// left as they are. Off by default, as the output is then no longer the input with only the numbers replaced.
+    /// Only the text functions, that splice the digits into the text themselves, are concerned.
+    pub isolate_digits_in_rtl: bool,
+    /// The digits the replacements are written with, like the Arabic-Indic digits "*٣٫٥*" for "*3.5*".
+    ///
+    /// Only the replacements are transliterated: the digits of the text outside the numbers found are kept.
+    /// The ordinals lose the Latin letters of their affix, like "*٢١*" for "*21st*" (see [`DigitScript`]).
+    pub digit_script: DigitScript,
+    /// Check the replacements before splicing them into the text, even in release builds: their ranges must be
+    /// in order, without overlapping, and within the text, and the output no longer than
+    /// [`max_output_factor`](Self::max_output_factor) times the text. If they aren't, the text is returned unchanged
+    /// rather than corrupted, and [`replace_numbers_to`](crate::replace_numbers_to) and
+    /// [`replace_numbers_in_text_checked`](crate::replace_numbers_in_text_checked) fail with a
+    /// [`SpliceError::Internal`](crate::SpliceError::Internal).
+    ///
+    /// The checks are always on in debug builds.
+    pub paranoid: bool,
+    /// The limit of the length of the output, as a multiple of the length of the text, when the replacements are checked
+    /// (see [`paranoid`](Self::paranoid)).
+    pub max_output_factor: usize,
+    /// The markup of the text, like the HTML tags or the styling tags of the WebVTT cues, that the numbers see through:
+    /// "`twenty <i>three</i>`" gives "`<i>23</i>`".
+    ///
+    /// The markup is cut into tokens of its own (see [`Token::is_markup`](crate::Token::is_markup)), that are skipped
+    /// like spaces, even inside a number. Only the text functions, that tokenize the text themselves, are concerned.
+    pub markup: Markup,
+    /// Where the markup inside a number goes, as the words around it are replaced by a single number.
+    pub markup_placement: MarkupPlacement,
+}
+
+/// Sorted, non-overlapping byte ranges of a text, protected from the conversion (see [`Options::mask`]).
+///
+/// The offsets are those of [`Occurence::range`](crate::Occurence::range): byte offsets in the text
+/// made of all the tokens.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Mask(Vec<Range<usize>>);
+
+impl Mask {
+    /// Check that `ranges` are sorted and don't overlap. Empty ranges protect nothing.
+    pub fn new(ranges: &[Range<usize>]) -> Result<Self, MaskError> {
+        for (index, range) in ranges.iter().enumerate() {
+            if range.start > range.end {
+                return Err(MaskError::Reversed(index));
+            }
+            if let Some(prev) = index.checked_sub(1).map(|i| &ranges[i]) {
+                if range.start < prev.start {
+                    return Err(MaskError::Unsorted(index));
+                }
+                if range.start < prev.end {
+                    return Err(MaskError::Overlapping(index));
+                }
+            }
+        }
+        Ok(Self(ranges.to_vec()))
+    }
+
+    /// The protected ranges.
+    pub fn ranges(&self) -> &[Range<usize>] {
+        &self.0
+    }
+
+    /// Whether `range` overlaps a protected range.
+    pub fn overlaps(&self, range: &Range<usize>) -> bool {
+        // the ends are sorted too, as the ranges don't overlap
+        let next = self.0.partition_point(|masked| masked.end <= range.start);
+        self.0[next..]
+            .iter()
+            .take_while(|masked| masked.start < range.end)
+            .any(|masked| !masked.is_empty())
+    }
+}
+
+/// Why ranges can't make a [`Mask`]. The index is that of the faulty range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaskError {
+    /// The range ends before it starts
+    Reversed(usize),
+    /// The range starts before the previous one
+    Unsorted(usize),
+    /// The range starts before the end of the previous one
+    Overlapping(usize),
+}
+
+impl fmt::Display for MaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reversed(index) => write!(f, "mask range #{index} ends before it starts"),
+            Self::Unsorted(index) => {
+                write!(f, "mask range #{index} starts before the previous one")
+            }
+            Self::Overlapping(index) => write!(f, "mask range #{index} overlaps the previous one"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MaskError {}
+
+/// The markup of a text (see [`Options::markup`]).
+#[derive(Clone, Default)]
+pub enum Markup {
+    /// Plain text
+    #[default]
+    None,
+    /// The tags in angle brackets, as in HTML, XML or WebVTT: "`<i>`", "`</b>`", "`<c.yellow>`", "`<v Roger>`",
+    /// or the timestamps "`<00:01:02.500>`" of the WebVTT karaoke cues.
+    ///
+    /// A tag starts with a letter, a digit, "*/*" or "*!*" right after its "*<*", and doesn't span lines,
+    /// so that "*3 < four*" is no tag.
+    Tags,
+    /// The text between each pair of opening and closing delimiters, delimiters included, like
+    /// `("{".into(), "}".into())` for the override tags "*{\\i1}*" of the SubStation Alpha subtitles.
+    Delimited(Vec<(String, String)>),
+    /// The markup found by a function: called with the rest of the text at each character, it returns the length
+    /// in bytes of the markup that starts there, if any. A length that ends inside a character takes the whole
+    /// character.
+    Custom(Arc<MarkupFinder>),
+}
+
+/// The function of a [`Markup::Custom`].
+pub type MarkupFinder = dyn Fn(&str) -> Option<usize> + Send + Sync;
+
+impl Markup {
+    /// The byte range of the first markup of `text`.
+    ///
+    /// ```rust
+    /// use text2num::Markup;
+    ///
+    /// assert_eq!(Markup::Tags.find("twenty <i>three</i>"), Some(7..10));
+    /// assert_eq!(Markup::Tags.find("3 < four > 2"), None);
+    /// ```
+    pub fn find(&self, text: &str) -> Option<Range<usize>> {
+        match self {
+            Self::None => None,
+            Self::Tags => text.match_indices('<').find_map(|(start, _)| {
+                let rest = &text[start + 1..];
+                if !rest.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '/' || c == '!') {
+                    return None;
+                }
+                let len = rest.find(['<', '>', '\n'])?;
+                rest[len..]
+                    .starts_with('>')
+                    .then_some(start..start + len + 2)
+            }),
+            Self::Delimited(delimiters) => delimiters
+                .iter()
+                .filter(|(open, _)| !open.is_empty())
+                .filter_map(|(open, close)| {
+                    let start = text.find(open.as_str())?;
+                    let len = text[start + open.len()..].find(close.as_str())?;
+                    Some(start..start + open.len() + len + close.len())
+                })
+                .min_by_key(|range| range.start),
+            Self::Custom(markup) => text.char_indices().find_map(|(start, _)| {
+                markup(&text[start..]).filter(|&len| len > 0).map(|len| {
+                    // the markup ends with the character the length falls in
+                    let end = (start + len..text.len())
+                        .find(|&end| text.is_char_boundary(end))
+                        .unwrap_or(text.len());
+                    start..end
+                })
+            }),
+        }
+    }
+
+    /// The byte ranges of the successive markups of `text`.
+    pub(crate) fn find_iter(&self, text: &str) -> impl Iterator<Item = Range<usize>> {
+        let mut cursor = 0;
+        core::iter::from_fn(move || {
+            let found = self.find(&text[cursor..])?;
+            let found = cursor + found.start..cursor + found.end;
+            cursor = found.end;
+            Some(found)
+        })
+    }
+}
+
+impl fmt::Debug for Markup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => f.write_str("None"),
+            Self::Tags => f.write_str("Tags"),
+            Self::Delimited(delimiters) => f.debug_tuple("Delimited").field(delimiters).finish(),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Where the markup inside a number is written (see [`Options::markup_placement`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarkupPlacement {
+    /// Before the digits: "`twenty <i>three</i>`" gives "`<i>23</i>`"
+    #[default]
+    Before,
+    /// After the digits: "`twenty <i>three</i>`" gives "`23<i></i>`"
+    After,
+    /// Nowhere: "`twenty <i>three</i>`" gives "`23</i>`", for the texts whose tags are stripped afterwards,
+    /// as the tags left can be unbalanced
+    Drop,
+}
+
+/// The reading of successive complete numbers that are not separated by punctuation.
+///
+/// Without punctuation, a dictated list ("*sizes two four six*") and a digit-by-digit reading
+/// ("*two three four*" for 234) can't be told apart by the grammar.
+/// Only cardinals written with digits only are concerned: ordinals and decimal numbers are never merged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AdjacentNumberPolicy {
+    /// Each maximal valid number is its own occurence: "*two three four*" gives "*2 3 4*".
+    #[default]
+    Separate,
+    /// Adjacent numbers are concatenated as a digit sequence: "*two three four*" gives "*234*",
+    /// "*twenty thirty*" gives "*2030*".
+    Concatenate,
+    /// Only runs of at least three single digits are concatenated: "*two three four*" gives "*234*",
+    /// but "*two three*" gives "*2 3*".
+    Auto,
+}
+
+/// The rendering of the ordinal markers of the digit forms.
+///
+/// The languages map their markers to each style (see [`LangInterpreter::styled_ordinal`]);
+/// where a style makes no sense, the ordinals are rendered [`Plain`](Self::Plain).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OrdinalStyle {
+    /// The markers of the language: "*1er*", "*2e*", "*21st*", "*3.*"
+    #[default]
+    Plain,
+    /// The letters of the markers in superscript: "*1ᵉʳ*", "*21ˢᵗ*"
+    Superscript,
+    /// A period after the digits, whatever the language, as in German: "*1.*"
+    PeriodOnly,
+    /// The ending of the ordinal words, where the markers of the language are shorter:
+    /// "*21ste*" and "*2de*" in Dutch, instead of "*21e*" and "*2e*"
+    Long,
+}
+
+/// The rendering of fractions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FractionOutput {
+    /// "*one fifth*" gives "*1/5*"
+    #[default]
+    Slash,
+    /// "*one fifth*" gives "*0.2*", with the decimal symbol of the language (see [`LangInterpreter::decimal_symbol`])
+    /// and at most 6 decimals: "*two thirds*" gives "*0.666667*"
+    Decimal,
+}
+
+impl Options {
+    /// Default options with the given lone number `threshold`.
+    pub fn new(threshold: f64) -> Self {
+        Self {
+            threshold,
+            ..Default::default()
+        }
+    }
+
+    /// The options recommended for the texts of `lang`, as its author tuned them
+    /// (see [`LangInterpreter::recommended_options`]).
+    ///
+    /// Each field can still be overridden:
+    ///
+    /// ```rust
+    /// use text2num::{Language, Options};
+    ///
+    /// let de = Language::german();
+    /// let options = Options {
+    ///     threshold: 0.0,
+    ///     ..Options::recommended_for(&de)
+    /// };
+    /// assert!(options.adverbial_ordinals);
+    /// ```
+    pub fn recommended_for<L: LangInterpreter>(lang: &L) -> Self {
+        lang.recommended_options()
+    }
+
+    /// Render the digit forms of the numbers with `formatter` (see the [`formatter`](crate::formatter) module).
+    pub fn with_formatter(self, formatter: Box<dyn NumberFormatter>) -> Self {
+        Self {
+            formatter: Some(formatter.into()),
+            ..self
+        }
+    }
+
+    /// Leave the numbers overlapping the protected byte `ranges` unconverted (see [`Options::mask`]).
+    ///
+    /// The ranges must be sorted and must not overlap.
+    pub fn with_mask(self, ranges: &[Range<usize>]) -> Result<Self, MaskError> {
+        Ok(Self {
+            mask: Mask::new(ranges)?,
+            ..self
+        })
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            threshold: 0.0,
+            collapse_stutters: false,
+            adverbial_ordinals: false,
+            adjacent_number_policy: AdjacentNumberPolicy::Separate,
+            informal_quantities: false,
+            spoken_fractions: false,
+            scores: false,
+            convert_in_hyphenated_compounds: false,
+            salvage_partial_groups: false,
+            strip_clitics: false,
+            decimal_separators: Vec::new(),
+            leading_decimal_separator: false,
+            digits_with_scales: false,
+            formatter: None,
+            ordinal_style: OrdinalStyle::Plain,
+            keep_ordinal_markers: true,
+            keep_fraction_markers: true,
+            fraction_output: FractionOutput::Slash,
+            max_digits: DEFAULT_MAX_DIGITS,
+            max_tokens: 100,
+            min_word_count: 1,
+            skip_sentence_initial: false,
+            convert_sentence_initial_ordinals: false,
+            convert_sentence_initial_years: false,
+            mask: Mask::default(),
+            isolate_digits_in_rtl: false,
+            digit_script: DigitScript::Ascii,
+            paranoid: false,
+            max_output_factor: 8,
+            markup: Markup::None,
+            markup_placement: MarkupPlacement::Before,
+        }
+    }
+}
@@ -0,0 +1,511 @@
+/*!
+Evaluation of a text that is a number, without searching or replacing anything.
+
+[`parse_number`] is strict: the whole text must be a single number, which makes it suitable to validate a form field.
+[`parse_first_number`] is lenient and extracts the first number of a text.
+[`convert_cell`] is [`parse_number`] for the cells of a table, such as a CSV export:
+a cell is a number as a whole, once trimmed, or it is left alone.
+
+```rust
+use text2num::{Language, parse_number};
+use text2num::parse::NumberKind;
+
+let fr = Language::french();
+let number = parse_number("deux cent trente et un", &fr).unwrap();
+
+assert_eq!(number.text, "231");
+assert_eq!(number.value, 231.0);
+assert_eq!(number.kind, NumberKind::Cardinal);
+
+let error = parse_number("deux cent trente et un chats", &fr).unwrap_err();
+assert_eq!(error.word(), Some("chats"));
+```
+*/
+
+use alloc::string::String;
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use core::ops::Range;
+
+use crate::error::Error;
+use crate::lang::LangInterpreter;
+use crate::options::Options;
+use crate::tokenizer;
+use crate::word_to_digit::WordToDigitParser;
+
+/// The kind of a number, as found by the detection path that produced it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberKind {
+    Cardinal,
+    Ordinal,
+    Decimal,
+    /// A fraction, like "*three fifths*"
+    Fraction,
+    /// A year read by pairs of digits, like "*nineteen oh five*"
+    Year,
+    /// A sequence of digits, like a phone number: a number with leading zeroes,
+    /// or adjacent numbers merged by the [`AdjacentNumberPolicy`](crate::AdjacentNumberPolicy)
+    Sequence,
+    /// An amount after a currency symbol, like "*$ twenty*"
+    Currency,
+}
+
+/// A number parsed from words.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedNumber {
+    pub kind: NumberKind,
+    /// The digit representation of the number, as the language formats it (*e.g.* "21st" or "3.14")
+    pub text: String,
+    /// The value of the number. If the number is an ordinal, the value is the rank it represents.
+    pub value: f64,
+}
+
+/// The reason why a text is not a number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The index of the word that failed, among the words of the text.
+    /// If the text ended too early, this is the number of words.
+    pub index: usize,
+    /// The byte offsets of that word in the text, empty at the end of the text if it ended too early.
+    pub range: Range<usize>,
+    /// What the language said of the word: [`Error::Incomplete`] if the text ended too early.
+    pub error: Error,
+    word: Option<String>,
+}
+
+impl ParseError {
+    /// The word that failed, if the text did not end too early.
+    pub fn word(&self) -> Option<&str> {
+        self.word.as_deref()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.word, &self.error) {
+            (Some(word), Error::Frozen) => write!(f, "the number is complete before \"{word}\""),
+            (Some(word), Error::TooLong) => write!(f, "the number is too long at \"{word}\""),
+            (Some(word), _) => write!(f, "\"{word}\" does not continue the number"),
+            (None, _) if self.index == 0 => write!(f, "no number"),
+            (None, _) => write!(f, "the number is incomplete"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// The words of `text`, with their byte offsets.
+///
+/// The words are those of the tokenizer: the punctuation is cut off the words, as in "*twelve,*",
+/// and stands as a word of its own, trimmed of its white space.
+fn words(text: &str) -> impl Iterator<Item = (Range<usize>, &str)> {
+    tokenizer::tokenize(text).strs().filter_map(move |token| {
+        let word = token.trim();
+        let start = word.as_ptr() as usize - text.as_ptr() as usize;
+        (!word.is_empty()).then(|| (start..start + word.len(), word))
+    })
+}
+
+fn finish<T: LangInterpreter>(mut parser: WordToDigitParser<'_, T>) -> ParsedNumber {
+    let kind = parser.kind();
+    let (text, value) = parser.string_and_value();
+    ParsedNumber { kind, text, value }
+}
+
+/// Interpret the whole `text` as one number: cardinal, ordinal or decimal.
+///
+/// Words are separated by white space or punctuation and any case is accepted.
+/// The punctuation counts as a word, not part of the number: "*twenty,*" is not a number.
+/// Return an error naming the first word that is not part of the number, or saying the text ended too early.
+/// A linking word at the end of the text, like "*and*" in "*twenty one and*", says that it ended too early.
+pub fn parse_number<T: LangInterpreter>(text: &str, lang: &T) -> Result<ParsedNumber, ParseError> {
+    let mut parser = WordToDigitParser::new(lang, &Options::default());
+    let mut status = Err(Error::Incomplete);
+    let mut count = 0;
+    let mut words = words(text).enumerate().peekable();
+    while let Some((index, (range, word))) = words.next() {
+        count += 1;
+        let lowercase = tokenizer::lowercase(word);
+        status = parser.push(&lowercase);
+        if status.is_err()
+            && parser.has_number()
+            && words.peek().is_none()
+            && lang.is_linking(&lowercase)
+        {
+            status = Err(Error::Incomplete);
+        }
+        if let Err(error) = &status
+            && (*error != Error::Incomplete || !parser.has_number())
+        {
+            return Err(ParseError {
+                index,
+                range,
+                error: error.clone(),
+                word: Some(word.into()),
+            });
+        }
+    }
+    match status {
+        Ok(()) => Ok(finish(parser)),
+        Err(error) => Err(ParseError {
+            index: count,
+            range: text.len()..text.len(),
+            error,
+            word: None,
+        }),
+    }
+}
+
+/// Find the first number of `text` and return it with its byte offsets.
+///
+/// The number is made of the longest run of words that starts with a number word and makes a complete number:
+/// "*two hundred and cats*" gives 200, "*two hundred*".
+pub fn parse_first_number<T: LangInterpreter>(
+    text: &str,
+    lang: &T,
+) -> Option<(ParsedNumber, Range<usize>)> {
+    let mut parser = WordToDigitParser::new(lang, &Options::default());
+    // The parser and the offsets of the last complete number
+    let mut complete: Option<(WordToDigitParser<'_, T>, Range<usize>)> = None;
+    let mut start = None;
+    for (range, word) in words(text) {
+        match parser.push(&tokenizer::lowercase(word)) {
+            Ok(()) => {
+                let start = *start.get_or_insert(range.start);
+                complete = Some((parser.clone(), start..range.end));
+            }
+            Err(Error::Incomplete) if parser.has_number() => {
+                start.get_or_insert(range.start);
+            }
+            Err(_) if complete.is_some() => break,
+            Err(_) => {
+                parser.reset();
+                start = None;
+            }
+        }
+    }
+    complete.map(|(parser, range)| (finish(parser), range))
+}
+
+/// The trimming of the cells before they are parsed (see [`convert_cell`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellOptions {
+    /// Trim the white space around the cell.
+    pub trim_whitespace: bool,
+    /// The punctuation trimmed around the cell, like quotes or a final period.
+    ///
+    /// Symbols that change the meaning of the cell, like currency symbols or signs, should not be trimmed:
+    /// the cell would be converted as if they were not there.
+    pub trim_punctuation: Vec<char>,
+}
+
+impl Default for CellOptions {
+    /// Trim the white space, the quotes and the final punctuation.
+    fn default() -> Self {
+        Self {
+            trim_whitespace: true,
+            trim_punctuation: vec![
+                '"', '\'', '“', '”', '‘', '’', '«', '»', '.', ',', ';', ':', '!', '?',
+            ],
+        }
+    }
+}
+
+/// The conversion of a table cell (see [`convert_cell`]).
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellResult {
+    /// The whole cell is a number
+    Number {
+        /// The digit representation of the number, as the language formats it (*e.g.* "21st" or "3.14")
+        digits: String,
+        /// The value of the number. If the number is an ordinal, the value is the rank it represents.
+        value: f64,
+        kind: NumberKind,
+    },
+    /// The cell is not a spelled number, or not only: it must be left as it is.
+    NotANumber,
+}
+
+/// Interpret the whole `cell`, trimmed according to `options`, as one number: cardinal, ordinal or decimal.
+///
+/// The conversion is all or nothing: a cell that holds anything else than a number, like "*twenty dollars*",
+/// is [`CellResult::NotANumber`], and so are the cells already written in digits.
+///
+/// ```rust
+/// use text2num::{Language, convert_cell};
+/// use text2num::parse::{CellOptions, CellResult, NumberKind};
+///
+/// let en = Language::english();
+/// let options = CellOptions::default();
+///
+/// assert_eq!(
+///     convert_cell(" \"Forty-Two.\" ", &en, &options),
+///     CellResult::Number { digits: "42".into(), value: 42.0, kind: NumberKind::Cardinal }
+/// );
+/// assert_eq!(convert_cell("forty-two dollars", &en, &options), CellResult::NotANumber);
+/// ```
+pub fn convert_cell<T: LangInterpreter>(cell: &str, lang: &T, options: &CellOptions) -> CellResult {
+    let text = cell.trim_matches(|c: char| {
+        options.trim_whitespace && c.is_whitespace() || options.trim_punctuation.contains(&c)
+    });
+    match parse_number(text, lang) {
+        Ok(ParsedNumber { kind, text, value }) => CellResult::Number {
+            digits: text,
+            value,
+            kind,
+        },
+        Err(_) => CellResult::NotANumber,
+    }
+}
+
+/// Convert each of the `cells` with [`convert_cell`].
+pub fn convert_cells<T: LangInterpreter>(
+    cells: &[&str],
+    lang: &T,
+    options: &CellOptions,
+) -> Vec<CellResult> {
+    cells
+        .iter()
+        .map(|cell| convert_cell(cell, lang, options))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg_attr(not(any(feature = "en", feature = "fr")), allow(unused_imports))]
+    use super::*;
+    #[cfg_attr(not(any(feature = "en", feature = "fr")), allow(unused_imports))]
+    use crate::lang::Language;
+    #[cfg_attr(not(feature = "en"), allow(unused_imports))]
+    use alloc::string::ToString;
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_parse_number() {
+        let en = Language::english();
+        let number = parse_number("One Hundred and Twenty-Three", &en).unwrap();
+        assert_eq!(number.kind, NumberKind::Cardinal);
+        assert_eq!((number.text.as_str(), number.value), ("123", 123.0));
+        let number = parse_number("twenty-first", &en).unwrap();
+        assert_eq!(number.kind, NumberKind::Ordinal);
+        assert_eq!((number.text.as_str(), number.value), ("21st", 21.0));
+        let number = parse_number("three point two five", &en).unwrap();
+        assert_eq!(number.kind, NumberKind::Decimal);
+        assert_eq!((number.text.as_str(), number.value), ("3.25", 3.25));
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_parse_number_errors() {
+        let en = Language::english();
+        let error = parse_number("twenty cats", &en).unwrap_err();
+        assert_eq!(
+            (error.index, error.range.clone(), error.word()),
+            (1, 7..11, Some("cats"))
+        );
+        assert_eq!(error.to_string(), "\"cats\" does not continue the number");
+        let error = parse_number("twenty twelve", &en).unwrap_err();
+        assert_eq!((error.index, error.word()), (1, Some("twelve")));
+        let error = parse_number("cats", &en).unwrap_err();
+        assert_eq!((error.index, error.error.clone()), (0, Error::NaN));
+        let error = parse_number("first second", &en).unwrap_err();
+        assert_eq!(
+            (error.word(), error.error.clone()),
+            (Some("second"), Error::Frozen)
+        );
+        let error = parse_number("two hundred and", &en).unwrap_err();
+        assert_eq!(
+            (error.index, error.range.clone(), error.word()),
+            (3, 15..15, None)
+        );
+        assert_eq!(error.error, Error::Incomplete);
+        assert_eq!(error.to_string(), "the number is incomplete");
+        // a linking word that does not continue the number, at the end
+        let error = parse_number("twenty one and", &en).unwrap_err();
+        assert_eq!(
+            (error.index, error.range.clone(), error.word()),
+            (3, 14..14, None)
+        );
+        assert_eq!(error.error, Error::Incomplete);
+        let error = parse_number("twenty one and two", &en).unwrap_err();
+        assert_eq!(
+            (error.index, error.word(), error.error.clone()),
+            (2, Some("and"), Error::NaN)
+        );
+        let error = parse_number(" ", &en).unwrap_err();
+        assert_eq!((error.index, error.error.clone()), (0, Error::Incomplete));
+        assert_eq!(error.to_string(), "no number");
+        let error = parse_number("twenty, cats", &en).unwrap_err();
+        assert_eq!(
+            (error.index, error.range.clone(), error.word()),
+            (1, 6..7, Some(","))
+        );
+        assert!(parse_number("three point", &en).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "fr")]
+    fn test_parse_number_fr() {
+        let fr = Language::french();
+        let number = parse_number("deux cent trente et un", &fr).unwrap();
+        assert_eq!((number.text.as_str(), number.value), ("231", 231.0));
+        let number = parse_number("vingt et unième", &fr).unwrap();
+        assert_eq!(
+            (number.kind, number.text.as_str()),
+            (NumberKind::Ordinal, "21e")
+        );
+        let number = parse_number("douze virgule cinq", &fr).unwrap();
+        assert_eq!(
+            (number.kind, number.text.as_str()),
+            (NumberKind::Decimal, "12,5")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_parse_first_number() {
+        let en = Language::english();
+        let (number, range) = parse_first_number("I paid two hundred and cats", &en).unwrap();
+        assert_eq!((number.text.as_str(), range), ("200", 7..18));
+        let (number, range) = parse_first_number("the twenty-first of May, twelve", &en).unwrap();
+        assert_eq!(
+            (number.kind, number.text.as_str(), range),
+            (NumberKind::Ordinal, "21st", 4..16)
+        );
+        let (number, range) = parse_first_number("pi is three point one four.", &en).unwrap();
+        assert_eq!(
+            (number.kind, number.text.as_str(), range),
+            (NumberKind::Decimal, "3.14", 6..26)
+        );
+        let (number, range) = parse_first_number("I have twelve, you have ten", &en).unwrap();
+        assert_eq!((number.text.as_str(), range), ("12", 7..13));
+        assert_eq!(parse_first_number("no number here", &en), None);
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_convert_cell() {
+        let en = Language::english();
+        let options = CellOptions::default();
+        let number = |digits: &str, value, kind| CellResult::Number {
+            digits: digits.into(),
+            value,
+            kind,
+        };
+        assert_eq!(
+            convert_cell("forty-two", &en, &options),
+            number("42", 42.0, NumberKind::Cardinal)
+        );
+        assert_eq!(
+            convert_cell("Twenty-First", &en, &options),
+            number("21st", 21.0, NumberKind::Ordinal)
+        );
+        assert_eq!(
+            convert_cell("three point five", &en, &options),
+            number("3.5", 3.5, NumberKind::Decimal)
+        );
+        // trailing period, quotes and white space
+        assert_eq!(
+            convert_cell("one hundred.", &en, &options),
+            number("100", 100.0, NumberKind::Cardinal)
+        );
+        assert_eq!(
+            convert_cell("\t\"twelve\"\n", &en, &options),
+            number("12", 12.0, NumberKind::Cardinal)
+        );
+        assert_eq!(
+            convert_cell("‘seven’ ", &en, &options),
+            number("7", 7.0, NumberKind::Cardinal)
+        );
+        // all or nothing
+        assert_eq!(
+            convert_cell("twenty dollars", &en, &options),
+            CellResult::NotANumber
+        );
+        assert_eq!(
+            convert_cell("$twenty", &en, &options),
+            CellResult::NotANumber
+        );
+        assert_eq!(
+            convert_cell("the fifth", &en, &options),
+            CellResult::NotANumber
+        );
+        assert_eq!(
+            convert_cell("two hundred and", &en, &options),
+            CellResult::NotANumber
+        );
+        assert_eq!(
+            convert_cell("one thousand, two hundred", &en, &options),
+            CellResult::NotANumber
+        );
+        assert_eq!(convert_cell("42", &en, &options), CellResult::NotANumber);
+        assert_eq!(convert_cell("", &en, &options), CellResult::NotANumber);
+        assert_eq!(convert_cell(" . ", &en, &options), CellResult::NotANumber);
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_convert_cell_options() {
+        let en = Language::english();
+        let options = CellOptions {
+            trim_whitespace: false,
+            trim_punctuation: Vec::new(),
+        };
+        assert_eq!(
+            convert_cell("twelve.", &en, &options),
+            CellResult::NotANumber
+        );
+        assert_eq!(
+            convert_cell("\"twelve\"", &en, &options),
+            CellResult::NotANumber
+        );
+        // white space between the words is not trimming
+        assert_eq!(
+            convert_cell("twenty  one", &en, &options),
+            CellResult::Number {
+                digits: "21".into(),
+                value: 21.0,
+                kind: NumberKind::Cardinal
+            }
+        );
+        let options = CellOptions {
+            trim_punctuation: vec!['*'],
+            ..CellOptions::default()
+        };
+        assert_eq!(
+            convert_cell(" *twelve* ", &en, &options),
+            CellResult::Number {
+                digits: "12".into(),
+                value: 12.0,
+                kind: NumberKind::Cardinal
+            }
+        );
+        assert_eq!(
+            convert_cell("twelve.", &en, &options),
+            CellResult::NotANumber
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fr")]
+    fn test_convert_cells() {
+        let fr = Language::french();
+        let cells = [
+            "vingt-deux",
+            "« trois »",
+            "douze virgule cinq",
+            "vingt euros",
+            "Paris",
+        ];
+        let results = convert_cells(&cells, &fr, &CellOptions::default());
+        let digits: Vec<Option<&str>> = results
+            .iter()
+            .map(|result| match result {
+                CellResult::Number { digits, .. } => Some(digits.as_str()),
+                CellResult::NotANumber => None,
+            })
+            .collect();
+        assert_eq!(digits, [Some("22"), Some("3"), Some("12,5"), None, None]);
+    }
+}
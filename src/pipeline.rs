@@ -0,0 +1,448 @@
+/*!
+The number replacement pipeline, as composable stages.
+
+The one-call helpers of [`word_to_digit`](crate::word_to_digit) do the work of five stages, that
+are exposed here so that you can insert your own processing between them:
+
+1. [`tokenize`] splits the text into [`TokenSpan`]s, that remember their byte offsets;
+2. [`annotate`] applies the language specific disambiguation of tokens;
+3. [`group`] finds the [`NumberGroup`]s, that is the token spans that make up numbers;
+4. [`evaluate`] interprets a group into its digit form, as an [`Evaluated`] number
+   ([`evaluate_with`] takes the [`Options`] into account);
+5. [`splice`] writes the evaluated numbers back into the original text.
+
+The helpers don't call these stages: they write the numbers as [`group`] reads them, in one pass,
+where [`evaluate`] reads each group from its words again, so that the groups can be changed in between.
+
+```rust
+use text2num::{Language, Options};
+use text2num::pipeline::{annotate, evaluate, group, splice, tokenize};
+
+let en = Language::english();
+let text = "I paid twenty-five dollars for six apples";
+
+let mut tokens = tokenize(text);
+annotate(&en, &mut tokens);
+// Keep only the numbers that are followed by "dollars"
+let groups: Vec<_> = group(&en, &tokens, &Options::new(0.0))
+    .into_iter()
+    .filter(|g| tokens.get(g.tokens.end + 1).is_some_and(|t| t.lowercase == "dollars"))
+    .collect();
+let numbers: Vec<_> = groups.iter().filter_map(|g| evaluate(&en, g).ok()).collect();
+
+assert_eq!(splice(text, &numbers).as_deref(), Ok("I paid 25 dollars for six apples"));
+```
+*/
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::ops::Range;
+
+use crate::edit::SpliceGuard;
+use crate::error::Error;
+use crate::lang::{BasicAnnotate, LangInterpreter};
+use crate::options::Options;
+use crate::tokenizer;
+use crate::word_to_digit::{
+    SpliceError, Token, WordToDigitParser, starts_with_digit, starts_with_phrase, track_numbers,
+};
+
+/// A token of the source text, with its location.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenSpan {
+    pub text: String,
+    pub lowercase: String,
+    /// Byte offsets of the token in the source text
+    pub bytes: Range<usize>,
+    /// The token is known not to be part of a number (see [`BasicAnnotate::set_nan`])
+    pub nan: bool,
+}
+
+impl BasicAnnotate for TokenSpan {
+    fn text_lowercase(&self) -> &str {
+        &self.lowercase
+    }
+
+    fn set_nan(&mut self, val: bool) {
+        self.nan = val
+    }
+}
+
+impl Token for &TokenSpan {
+    fn text(&self) -> &str {
+        &self.text
+    }
+
+    fn text_lowercase(&self) -> &str {
+        &self.lowercase
+    }
+
+    fn not_a_number_part(&self) -> bool {
+        self.nan
+    }
+
+    fn is_digits(&self) -> bool {
+        starts_with_digit(&self.text)
+    }
+}
+
+/// A sequence of tokens that make up a number.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberGroup {
+    /// Indices of the tokens of the group in the token stream
+    pub tokens: Range<usize>,
+    /// Byte offsets of the group in the source text
+    pub bytes: Range<usize>,
+    /// The lowercase words to interpret, without spaces
+    pub words: Vec<String>,
+    /// The words are preceded by a minus sign, that is part of the group
+    pub negative: bool,
+}
+
+/// A number group interpreted into digits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluated {
+    /// Indices of the tokens of the number in the token stream
+    pub tokens: Range<usize>,
+    /// Byte offsets of the number in the source text
+    pub bytes: Range<usize>,
+    /// The digit representation of the number
+    pub text: String,
+    /// The value of the number, or the rank it represents for ordinals
+    pub value: f64,
+    pub is_ordinal: bool,
+}
+
+/// Split `text` into word and separator tokens, that cover the whole text.
+pub fn tokenize(text: &str) -> Vec<TokenSpan> {
+    let mut offset = 0;
+    tokenizer::tokenize(text)
+        .map(|token| {
+            let start = offset;
+            offset += token.text.len();
+            TokenSpan {
+                text: token.text,
+                lowercase: token.lowercase,
+                bytes: start..offset,
+                nan: token.nan,
+            }
+        })
+        .collect()
+}
+
+/// Apply the language specific disambiguation to `tokens` (see [`LangInterpreter::basic_annotate`]).
+pub fn annotate<L: LangInterpreter>(lang: &L, tokens: &mut Vec<TokenSpan>) {
+    lang.basic_annotate(tokens)
+}
+
+/// Find the number groups in `tokens`, according to `options`.
+///
+/// The groups are the ones [`replace_numbers_in_text_with`](crate::replace_numbers_in_text_with) would replace.
+/// The words of a group repaired from a stutter (see [`Options::collapse_stutters`]) don't include the dropped repetition.
+pub fn group<L: LangInterpreter>(
+    lang: &L,
+    tokens: &[TokenSpan],
+    options: &Options,
+) -> Vec<NumberGroup> {
+    track_numbers(tokens.iter(), lang, options)
+        .into_vec()
+        .into_iter()
+        .map(|occurence| {
+            let negative = !occurence.is_ordinal && occurence.text.starts_with('-');
+            let span = &tokens[occurence.start + negative as usize..occurence.end];
+            let mut words: Vec<String> = span
+                .iter()
+                .map(|t| t.lowercase.clone())
+                .filter(|w| w != "-" && !w.chars().all(char::is_whitespace))
+                .collect();
+            if occurence.repaired {
+                drop_stutters(&mut words);
+            }
+            NumberGroup {
+                bytes: occurence.range,
+                tokens: occurence.start..occurence.end,
+                words,
+                negative,
+            }
+        })
+        .collect()
+}
+
+/// Drop the words that are repeated or restated by the next one.
+fn drop_stutters(words: &mut Vec<String>) {
+    let mut i = 1;
+    while i < words.len() {
+        if starts_with_phrase(&words[i], &words[i - 1], '-') {
+            words.remove(i - 1);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// Interpret the words of `group` into digits.
+///
+/// Successive complete numbers in the group, as merged by [`Options::adjacent_number_policy`],
+/// are concatenated into a single digit sequence. A [`negative`](NumberGroup::negative) group gives a negative number.
+pub fn evaluate<L: LangInterpreter>(lang: &L, group: &NumberGroup) -> Result<Evaluated, Error> {
+    evaluate_with(lang, group, &Options::default())
+}
+
+/// Same as [`evaluate`], but with the [`Options`] that drive the interpretation of words,
+/// like [`Options::decimal_separators`].
+pub fn evaluate_with<L: LangInterpreter>(
+    lang: &L,
+    group: &NumberGroup,
+    options: &Options,
+) -> Result<Evaluated, Error> {
+    if let [determiner, noun, _] = &group.words[..]
+        && let Some(value) = lang.informal_quantity(determiner, noun)
+    {
+        return Ok(Evaluated {
+            tokens: group.tokens.clone(),
+            bytes: group.bytes.clone(),
+            text: value.to_string(),
+            value: value as f64,
+            is_ordinal: false,
+        });
+    }
+    if group.words.len() > options.max_tokens {
+        return Err(Error::TooLong);
+    }
+    let mut parser = WordToDigitParser::new(lang, options);
+    let mut parts: Vec<(String, f64, bool)> = Vec::with_capacity(1);
+    for word in &group.words {
+        match parser.push(word) {
+            Ok(()) | Err(Error::Incomplete) => (),
+            Err(Error::TooLong) => return Err(Error::TooLong),
+            Err(_) if parser.has_number() => {
+                let is_ordinal = parser.is_ordinal();
+                let (text, value) = parser.string_and_value();
+                parts.push((text, value, is_ordinal));
+                match parser.push(word) {
+                    Ok(()) | Err(Error::Incomplete) => (),
+                    Err(err) => return Err(err),
+                }
+            }
+            Err(err) => match (group.words.len(), lang.adverbial_ordinal(word)) {
+                (1, Some(rank)) => {
+                    parts.push((lang.format_adverbial_ordinal(rank), rank as f64, true))
+                }
+                _ => return Err(err),
+            },
+        }
+    }
+    if parser.has_number() {
+        let is_ordinal = parser.is_ordinal();
+        let (text, value) = parser.string_and_value();
+        parts.push((text, value, is_ordinal));
+    }
+    let (mut text, mut value, is_ordinal) = match parts.len() {
+        0 => return Err(Error::Incomplete),
+        1 => parts.pop().unwrap(),
+        _ => {
+            let text: String = parts.iter().map(|(text, _, _)| text.as_str()).collect();
+            let value = text.parse().unwrap_or_default();
+            (text, value, false)
+        }
+    };
+    if group.negative {
+        text.insert(0, '-');
+        value = -value;
+    }
+    Ok(Evaluated {
+        tokens: group.tokens.clone(),
+        bytes: group.bytes.clone(),
+        text,
+        value,
+        is_ordinal,
+    })
+}
+
+/// Replace the `numbers` in `text`.
+///
+/// The numbers must be sorted by position, not overlap, and their byte ranges must fall on the characters of `text`,
+/// as the edits of [`apply_edits`](crate::apply_edits), or nothing is replaced and the broken
+/// invariant is returned as a [`SpliceError::Internal`].
+pub fn splice(text: &str, numbers: &[Evaluated]) -> Result<String, SpliceError> {
+    let mut guard = SpliceGuard::new(text, None);
+    for number in numbers {
+        guard
+            .replace(&number.bytes, number.text.len())
+            .map_err(SpliceError::Internal)?;
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut cursor = 0;
+    for number in numbers {
+        out.push_str(&text[cursor..number.bytes.start]);
+        out.push_str(&number.text);
+        cursor = number.bytes.end;
+    }
+    out.push_str(&text[cursor..]);
+    guard.finish(out.len()).map_err(SpliceError::Internal)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AdjacentNumberPolicy;
+    #[cfg_attr(not(feature = "en"), allow(unused_imports))]
+    use crate::EditError;
+    use crate::word_to_digit::replace_numbers_in_text_with;
+    use alloc::vec;
+
+    /// `replace_numbers_in_text_with`, stage by stage
+    #[cfg_attr(
+        not(any(feature = "en", feature = "fr", feature = "de")),
+        allow(dead_code)
+    )]
+    fn replace_numbers<L: LangInterpreter>(text: &str, lang: &L, options: &Options) -> String {
+        let mut tokens = tokenize(text);
+        annotate(lang, &mut tokens);
+        let numbers: Vec<Evaluated> = group(lang, &tokens, options)
+            .iter()
+            .map(|g| evaluate_with(lang, g, options).unwrap())
+            .collect();
+        splice(text, &numbers).unwrap()
+    }
+
+    #[cfg_attr(
+        not(any(feature = "en", feature = "fr", feature = "de")),
+        allow(dead_code)
+    )]
+    fn assert_same<L: LangInterpreter>(lang: &L, texts: &[&str]) {
+        let options = [
+            Options::new(10.0),
+            Options::new(0.0),
+            Options {
+                collapse_stutters: true,
+                ..Options::new(10.0)
+            },
+            Options {
+                adjacent_number_policy: AdjacentNumberPolicy::Concatenate,
+                adverbial_ordinals: true,
+                informal_quantities: true,
+                decimal_separators: vec![("decimal".into(), '.'), ("komma".into(), ',')],
+                ..Options::new(10.0)
+            },
+        ];
+        for options in &options {
+            for text in texts {
+                assert_eq!(
+                    replace_numbers(text, lang, options),
+                    replace_numbers_in_text_with(text, lang, options),
+                    "{text:?} with {options:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_tokenize_offsets() {
+        let text = "Vingt-cinq vaches, été";
+        let tokens = tokenize(text);
+        for token in &tokens {
+            assert_eq!(&text[token.bytes.clone()], token.text);
+        }
+        assert_eq!(tokens.last().unwrap().bytes.end, text.len());
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_stages_en() {
+        assert_same(
+            &crate::Language::english(),
+            &[
+                "twenty-five cows, twelve chickens and one hundred twenty five kg of potatoes.",
+                "one thousand two hundred and sixty six dollars.",
+                "twelve point nine nine, one hundred twenty point o five",
+                "Fifth third second twenty-first hundredth",
+                "two two hundred and fifty, one one thousand three three hundred",
+                "Firstly two three four, then nineteen o five",
+                "I'm twenty, oh well, it's one",
+                "a pair of shoes, a trio of singers and a pair",
+                "from -twenty to −five (–three point five), minus -one",
+                "three decimal five and three komma five",
+            ],
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fr")]
+    fn test_stages_fr() {
+        assert_same(
+            &crate::Language::french(),
+            &[
+                "Vingt-cinq vaches, douze poulets et cent vingt-cinq kg de pommes de terre.",
+                "zéro neuf soixante zéro six douze vingt et un.",
+                "les uns et les autres ; une suite de chiffres : un, deux, trois !",
+                "Premièrement, mille deux cent soixante-six clous, cent cent vingt.",
+            ],
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "de")]
+    fn test_stages_de() {
+        assert_same(
+            &crate::Language::german(),
+            &[
+                "fünfundzwanzig Kühe, zwölf Hühner und einhundertfünfundzwanzig kg Kartoffeln.",
+                "Erstens zwei zwei hundert fünfzig, zwölf komma neunundneunzig.",
+            ],
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_custom_grouping() {
+        let en = crate::Language::english();
+        let text = "call nine one one";
+        let tokens = tokenize(text);
+        // read the whole phone number as one group, whatever the options
+        let phone = NumberGroup {
+            tokens: 2..7,
+            bytes: tokens[2].bytes.start..tokens[6].bytes.end,
+            words: ["nine", "one", "one"].map(String::from).into(),
+            negative: false,
+        };
+        let number = evaluate(&en, &phone).unwrap();
+        assert_eq!(number.text, "911");
+        assert_eq!(splice(text, &[number]).as_deref(), Ok("call 911"));
+        let garbage = NumberGroup {
+            words: ["nine", "potatoes"].map(String::from).into(),
+            ..phone
+        };
+        assert!(evaluate(&en, &garbage).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_rejected_splice() {
+        let en = crate::Language::english();
+        let text = "twenty one and thirty";
+        let mut tokens = tokenize(text);
+        annotate(&en, &mut tokens);
+        let numbers: Vec<_> = group(&en, &tokens, &Options::new(0.0))
+            .iter()
+            .map(|g| evaluate(&en, g).unwrap())
+            .collect();
+        assert_eq!(splice(text, &numbers).as_deref(), Ok("21 and 30"));
+        let reversed: Vec<_> = numbers.iter().rev().cloned().collect();
+        assert_eq!(
+            splice(text, &reversed),
+            Err(SpliceError::Internal(EditError::Unsorted { index: 1 }))
+        );
+        let outside = Evaluated {
+            bytes: 20..30,
+            ..numbers[1].clone()
+        };
+        assert_eq!(
+            splice(text, &[outside]),
+            Err(SpliceError::Internal(EditError::OutOfBounds { index: 0 }))
+        );
+    }
+}
@@ -0,0 +1,34 @@
+/*!
+The types and functions most applications need, to import in one go.
+
+```rust
+use text2num::prelude::*;
+
+let en = get_interpreter_for("en").unwrap();
+
+assert_eq!(text2digits("forty-two", &en), Ok("42".into()));
+assert_eq!(
+    replace_numbers_in_text_with("two two hundred", &en, &Options::new(0.0)),
+    "2 200"
+);
+```
+
+It also brings [`LangInterpreter`], [`Token`] and [`Replace`], to implement a language or a token type.
+*/
+
+pub use crate::edit::{Edit, EditError, apply_edits, propose_edits};
+pub use crate::error::Error;
+pub use crate::lang::{
+    LangInterpreter, Language, LanguageError, MorphologicalMarker, get_interpreter_for,
+    resolve_language,
+};
+pub use crate::options::{Options, OrdinalStyle};
+pub use crate::parse::{ParseError, ParsedNumber, parse_first_number, parse_number};
+pub use crate::tokenizer::BasicToken;
+pub use crate::word_to_digit::{
+    ExtractedValue, Occurence, Replace, SpliceError, Stats, Token, TokenOccurence, extract_values,
+    find_numbers, find_numbers_in_tokens, find_numbers_iter, find_numbers_iter_with,
+    find_numbers_with, replace_numbers_in_stream, replace_numbers_in_stream_with,
+    replace_numbers_in_text, replace_numbers_in_text_with, replace_numbers_in_tokens,
+    replace_numbers_to, text2digits,
+};
@@ -0,0 +1,204 @@
+/*!
+A text cut into tokens once, to find its numbers several times, with different languages or options.
+
+[`PreparedText::new`] does the tokenization and the lowercasing of the words, that the other functions
+of the crate redo on each call. [`find_numbers_prepared`] and [`replace_numbers_prepared`] then only
+add the annotations of the language and the options.
+
+```rust
+use text2num::{Language, Options, OrdinalStyle, PreparedText, find_numbers_prepared, replace_numbers_prepared};
+
+let en = Language::english();
+let text = "The twenty-first runner ran forty-two kilometers.";
+let prepared = PreparedText::new(text);
+
+let numbers = find_numbers_prepared(&prepared, &en, &Options::new(10.0));
+assert_eq!(numbers.len(), 2);
+// a second pass on the same tokens
+let options = Options {
+    ordinal_style: OrdinalStyle::Superscript,
+    ..Options::new(10.0)
+};
+assert_eq!(replace_numbers_prepared(&prepared, &en, &options), "The 21ˢᵗ runner ran 42 kilometers.");
+```
+*/
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::lang::{BasicAnnotate, LangInterpreter};
+use crate::options::{Markup, Options};
+use crate::tokenizer::TextSpan;
+use crate::word_to_digit::{
+    FindNumbers, Occurence, Token, base_tokens, replace_to_string, split_tokens,
+};
+
+/// A text with its tokens, borrowed from it.
+#[derive(Debug, Clone)]
+pub struct PreparedText<'t> {
+    text: &'t str,
+    tokens: Vec<TextSpan<'t>>,
+}
+
+impl<'t> PreparedText<'t> {
+    /// Cut `text` into tokens, with their lowercase form.
+    pub fn new(text: &'t str) -> Self {
+        Self {
+            text,
+            tokens: base_tokens(text, false, &Markup::None),
+        }
+    }
+
+    /// The prepared text.
+    pub fn text(&self) -> &'t str {
+        self.text
+    }
+
+    /// The tokens for `lang` and `options`, borrowed from the prepared ones if they need no cutting.
+    ///
+    /// The languages that keep the runs of ideographs whole (see [`LangInterpreter::prefers_ideographic_runs`])
+    /// and the markup (see [`Options::markup`]) cut the text again, and so do the hyphenated compounds
+    /// (see [`Options::convert_in_hyphenated_compounds`]).
+    fn spans<L: LangInterpreter>(&self, lang: &L, options: &Options) -> Cow<'_, [TextSpan<'t>]> {
+        let ideographic_runs =
+            cfg!(feature = "unicode-segmentation") && lang.prefers_ideographic_runs();
+        if ideographic_runs || !matches!(options.markup, Markup::None) {
+            let tokens = base_tokens(self.text, ideographic_runs, &options.markup);
+            Cow::Owned(split_tokens(&tokens, lang, options).into_owned())
+        } else {
+            split_tokens(&self.tokens, lang, options)
+        }
+    }
+}
+
+/// A prepared token with the annotations of a language, that are not written into the shared tokens.
+struct Annotated<'p, 't> {
+    span: &'p TextSpan<'t>,
+    nan: bool,
+}
+
+/// The annotations of `lang` on `spans`.
+fn annotate<'p, 't, L: LangInterpreter>(
+    spans: &'p [TextSpan<'t>],
+    lang: &L,
+) -> Vec<Annotated<'p, 't>> {
+    let mut tokens = spans
+        .iter()
+        .map(|span| Annotated {
+            span,
+            nan: span.nan,
+        })
+        .collect();
+    lang.basic_annotate(&mut tokens);
+    tokens
+}
+
+impl BasicAnnotate for Annotated<'_, '_> {
+    fn text_lowercase(&self) -> &str {
+        self.span.lowercase()
+    }
+
+    fn set_nan(&mut self, val: bool) {
+        self.nan = val
+    }
+}
+
+impl Token for &Annotated<'_, '_> {
+    fn text(&self) -> &str {
+        self.span.text
+    }
+
+    fn text_lowercase(&self) -> &str {
+        self.span.lowercase()
+    }
+
+    fn not_a_number_part(&self) -> bool {
+        self.nan
+    }
+
+    fn is_digits(&self) -> bool {
+        self.span.is_digits()
+    }
+
+    fn is_partial(&self) -> bool {
+        self.span.partial
+    }
+
+    fn is_markup(&self) -> bool {
+        self.span.markup
+    }
+}
+
+/// Same as [`find_numbers_with`](crate::find_numbers_with) on the tokens of the text, reusing them.
+///
+/// The [`Occurence::range`] are the byte offsets of the numbers in [`PreparedText::text`].
+pub fn find_numbers_prepared<L: LangInterpreter>(
+    prepared: &PreparedText<'_>,
+    lang: &L,
+    options: &Options,
+) -> Vec<Occurence> {
+    let spans = prepared.spans(lang, options);
+    let tokens = annotate(&spans, lang);
+    FindNumbers::new(tokens.iter().enumerate(), lang, options).collect()
+}
+
+/// Same as [`replace_numbers_in_text_with`](crate::replace_numbers_in_text_with), reusing the tokens of the text.
+pub fn replace_numbers_prepared<L: LangInterpreter>(
+    prepared: &PreparedText<'_>,
+    lang: &L,
+    options: &Options,
+) -> String {
+    let spans = prepared.spans(lang, options);
+    let tokens = annotate(&spans, lang);
+    replace_to_string(prepared.text, tokens.iter(), lang, options, None, None).0
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg_attr(not(all(feature = "en", feature = "fr")), allow(unused_imports))]
+    use super::*;
+    #[cfg_attr(not(all(feature = "en", feature = "fr")), allow(unused_imports))]
+    use crate::{
+        lang::Language,
+        word_to_digit::{replace_numbers_in_text_with, text_tokens},
+    };
+
+    #[test]
+    #[cfg(all(feature = "en", feature = "fr"))]
+    fn test_same_results() {
+        let text = "Twenty-one cats, the third one, three-dimensional, \
+            vingt-deux chats et le TROISIÈME, ÉLÈVES: one hundred and <i>five</i> point five";
+        let prepared = PreparedText::new(text);
+        assert_eq!(prepared.text(), text);
+        let options = [
+            Options::new(10.0),
+            Options::new(0.0),
+            Options {
+                convert_in_hyphenated_compounds: true,
+                ..Options::new(0.0)
+            },
+            Options {
+                markup: Markup::Tags,
+                ..Options::new(10.0)
+            },
+        ];
+        for lang in [Language::english(), Language::french()] {
+            for options in &options {
+                assert_eq!(
+                    replace_numbers_prepared(&prepared, &lang, options),
+                    replace_numbers_in_text_with(text, &lang, options)
+                );
+                let tokens = text_tokens(text, &lang, options);
+                let found: Vec<_> = FindNumbers::new(tokens.iter().enumerate(), &lang, options)
+                    .map(|o| (o.range, o.text))
+                    .collect();
+                let reused: Vec<_> = find_numbers_prepared(&prepared, &lang, options)
+                    .into_iter()
+                    .map(|o| (o.range, o.text))
+                    .collect();
+                assert_eq!(reused, found);
+            }
+        }
+    }
+}
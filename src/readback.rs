@@ -0,0 +1,177 @@
+/*!
+Check of the digit sequences read twice, like the numbers a call center repeats to confirm them.
+
+[`verify_repeated_sequences`] works on the output of the find functions: two adjacent
+[`NumberKind::Sequence`] occurences with the same digits are one number read back, and two with
+different digits are a readback mistake.
+
+```rust
+use text2num::{AdjacentNumberPolicy, Language, Options, PreparedText, find_numbers_prepared};
+use text2num::readback::{ReadbackPolicy, verify_repeated_sequences};
+
+let en = Language::english();
+let options = Options {
+    adjacent_number_policy: AdjacentNumberPolicy::Concatenate,
+    ..Options::new(0.0)
+};
+let text = "that's four five one two — four five one two";
+let mut numbers = find_numbers_prepared(&PreparedText::new(text), &en, &options);
+assert_eq!(numbers.len(), 2);
+
+verify_repeated_sequences(&mut numbers, ReadbackPolicy::Collapse);
+assert_eq!(numbers.len(), 1);
+assert_eq!(numbers[0].text, "4512");
+assert_eq!(numbers[0].original(text), "four five one two — four five one two");
+```
+*/
+
+use alloc::vec::Vec;
+
+use crate::parse::NumberKind;
+use crate::word_to_digit::Occurence;
+
+/// The most tokens between a sequence and its readback, like the words and spaces of "*. I repeat,*".
+const MAX_GAP: usize = 5;
+
+/// What [`verify_repeated_sequences`] does with the repeated sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadbackPolicy {
+    /// Merge a sequence and its matching readback into one occurence, which spans both and the text between them.
+    /// The sequences that do not match are left as they are.
+    Collapse,
+    /// Keep both sequences, and flag both of them with [`Occurence::readback_mismatch`] when they do not match.
+    FlagMismatch,
+}
+
+/// Verify the digit sequences read back just after themselves in `occurrences`, as the find functions return them.
+///
+/// A readback is the next occurence, if both are [`NumberKind::Sequence`] and at most five tokens apart.
+/// It matches when it has the same digits.
+pub fn verify_repeated_sequences(occurrences: &mut Vec<Occurence>, policy: ReadbackPolicy) {
+    let mut verified: Vec<Occurence> = Vec::with_capacity(occurrences.len());
+    // whether the last verified occurence is already the readback of another
+    let mut read_back = false;
+    for occurence in occurrences.drain(..) {
+        let previous = verified
+            .last_mut()
+            .filter(|previous| !read_back && is_readback(previous, &occurence));
+        read_back = previous.is_some();
+        match (previous, policy) {
+            (Some(previous), ReadbackPolicy::Collapse) if previous.text == occurence.text => {
+                previous.end = occurence.end;
+                previous.range.end = occurence.range.end;
+                previous.words += occurence.words;
+                previous.repaired |= occurence.repaired;
+                previous.partial |= occurence.partial;
+                previous.clitic = occurence.clitic;
+            }
+            (Some(previous), ReadbackPolicy::FlagMismatch) if previous.text != occurence.text => {
+                previous.readback_mismatch = true;
+                verified.push(Occurence {
+                    readback_mismatch: true,
+                    ..occurence
+                });
+            }
+            _ => verified.push(occurence),
+        }
+    }
+    *occurrences = verified;
+}
+
+fn is_readback(first: &Occurence, second: &Occurence) -> bool {
+    first.kind == NumberKind::Sequence
+        && second.kind == NumberKind::Sequence
+        && first.lang_code == second.lang_code
+        && second.start >= first.end
+        && second.start - first.end <= MAX_GAP
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg_attr(not(feature = "en"), allow(unused_imports))]
+    use super::*;
+    #[cfg_attr(not(feature = "en"), allow(unused_imports))]
+    use crate::{
+        AdjacentNumberPolicy, Options,
+        lang::Language,
+        prepared::{PreparedText, find_numbers_prepared},
+    };
+
+    #[cfg(feature = "en")]
+    fn verified(text: &str, policy: ReadbackPolicy) -> Vec<(&str, String, bool)> {
+        let options = Options {
+            adjacent_number_policy: AdjacentNumberPolicy::Concatenate,
+            ..Options::new(0.0)
+        };
+        let mut numbers =
+            find_numbers_prepared(&PreparedText::new(text), &Language::english(), &options);
+        verify_repeated_sequences(&mut numbers, policy);
+        numbers
+            .into_iter()
+            .map(|o| (o.original(text), o.text, o.readback_mismatch))
+            .collect()
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_matching_readback() {
+        let text = "that's four five one two — four five one two, thanks";
+        assert_eq!(
+            verified(text, ReadbackPolicy::Collapse),
+            [(
+                "four five one two — four five one two",
+                "4512".into(),
+                false
+            )]
+        );
+        assert_eq!(
+            verified(text, ReadbackPolicy::FlagMismatch),
+            [
+                ("four five one two", "4512".into(), false),
+                ("four five one two", "4512".into(), false)
+            ]
+        );
+        assert_eq!(
+            verified(
+                "zero four five. I repeat, zero four five",
+                ReadbackPolicy::Collapse
+            ),
+            [(
+                "zero four five. I repeat, zero four five",
+                "045".into(),
+                false
+            )]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_mismatching_readback() {
+        let text = "four five one two, four five one three";
+        assert_eq!(
+            verified(text, ReadbackPolicy::FlagMismatch),
+            [
+                ("four five one two", "4512".into(), true),
+                ("four five one three", "4513".into(), true)
+            ]
+        );
+        assert_eq!(
+            verified(text, ReadbackPolicy::Collapse),
+            [
+                ("four five one two", "4512".into(), false),
+                ("four five one three", "4513".into(), false)
+            ]
+        );
+        // too far apart, or not sequences
+        for text in [
+            "four five one two is the code of the door, four five one three",
+            "twenty-one, twenty-two",
+        ] {
+            assert!(
+                verified(text, ReadbackPolicy::FlagMismatch)
+                    .iter()
+                    .all(|(_, _, mismatch)| !mismatch)
+            );
+        }
+    }
+}
@@ -0,0 +1,185 @@
+/*!
+Registry of the interpreters, shared by all the threads, to add languages at run time.
+
+The builtin languages are registered under their codes and aliases. Other languages can be
+registered with [`register_language`], after which [`get_interpreter_for`](crate::get_interpreter_for)
+resolves their codes to a [`Language::Registered`](crate::Language::Registered) interpreter.
+
+```rust
+use text2num::registry::{lookup_language, register_language};
+use text2num::Language;
+
+assert!(lookup_language("en").is_some());
+// A builtin code is never replaced
+assert!(!register_language("en", Box::new(Language::french())));
+```
+
+This module is only available with the `registry` feature.
+*/
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+use crate::lang::{DynLangInterpreter, builtin_languages};
+
+/// A registered interpreter, with the reference handed to [`Language::Registered`](crate::Language::Registered).
+struct Entry {
+    shared: Arc<dyn DynLangInterpreter>,
+    pinned: &'static dyn DynLangInterpreter,
+}
+
+impl Entry {
+    fn new(shared: Arc<dyn DynLangInterpreter>) -> Self {
+        // One clone of the `Arc` is never dropped, so that the interpreter lives as long as the program.
+        let pinned: &'static Arc<dyn DynLangInterpreter> = Box::leak(Box::new(shared.clone()));
+        Self {
+            shared,
+            pinned: &**pinned,
+        }
+    }
+}
+
+fn registry() -> &'static RwLock<HashMap<String, Entry>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, Entry>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let builtins = builtin_languages()
+            .into_iter()
+            .map(|(code, lang)| (code.to_string(), Entry::new(Arc::new(lang))))
+            .collect();
+        RwLock::new(builtins)
+    })
+}
+
+/// Register `interpreter` for the language of code `code`, and return true.
+///
+/// If `code` is already taken, by a builtin language or a previous registration, the known interpreter is kept
+/// and false is returned.
+/// The registered interpreters are never freed.
+pub fn register_language(code: &str, interpreter: Box<dyn DynLangInterpreter>) -> bool {
+    let mut languages = registry().write().unwrap_or_else(PoisonError::into_inner);
+    if languages.contains_key(code) {
+        return false;
+    }
+    languages.insert(code.to_string(), Entry::new(Arc::from(interpreter)));
+    true
+}
+
+/// Get the interpreter registered for the language of code `code`, builtin or not.
+pub fn lookup_language(code: &str) -> Option<Arc<dyn DynLangInterpreter>> {
+    let languages = registry().read().unwrap_or_else(PoisonError::into_inner);
+    languages.get(code).map(|entry| entry.shared.clone())
+}
+
+/// Same as [`lookup_language`], as a reference that can be copied in a [`Language`](crate::Language).
+pub(crate) fn lookup_pinned(code: &str) -> Option<&'static dyn DynLangInterpreter> {
+    let languages = registry().read().unwrap_or_else(PoisonError::into_inner);
+    languages.get(code).map(|entry| entry.pinned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::digit_string::DigitString;
+    use crate::error::Error;
+    use crate::lang::{BasicAnnotate, LangInterpreter, Language, MorphologicalMarker};
+    use crate::word_to_digit::replace_numbers_in_text;
+
+    /// Toy language counting up to three.
+    #[derive(Debug)]
+    struct Toy;
+
+    impl LangInterpreter for Toy {
+        fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+            match num_func {
+                "uno" => b.put(b"1"),
+                "duo" => b.put(b"2"),
+                "tri" => b.put(b"3"),
+                _ => Err(Error::NaN),
+            }
+        }
+
+        fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+            self.apply(decimal_func, b)
+        }
+
+        fn get_morph_marker(&self, _word: &str) -> MorphologicalMarker {
+            MorphologicalMarker::None
+        }
+
+        fn check_decimal_separator(&self, _word: &str) -> Option<char> {
+            None
+        }
+
+        fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+            let repr = b.to_string();
+            let val = repr.parse().unwrap();
+            (repr, val)
+        }
+
+        fn format_decimal_and_value(
+            &self,
+            int: &DigitString,
+            dec: &DigitString,
+            sep: char,
+        ) -> (String, f64) {
+            let repr = alloc::format!("{int}{sep}{dec}");
+            let val = repr.parse().unwrap();
+            (repr, val)
+        }
+
+        fn is_linking(&self, _word: &str) -> bool {
+            false
+        }
+
+        fn basic_annotate<T: BasicAnnotate>(&self, tokens: &mut Vec<T>) {
+            // "non duo" is a name
+            for i in 2..tokens.len() {
+                if tokens[i - 2].text_lowercase() == "non" && tokens[i].text_lowercase() == "duo" {
+                    tokens[i].set_nan(true);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_builtins() {
+        for (code, _) in builtin_languages() {
+            assert!(lookup_language(code).is_some());
+            assert!(!register_language(code, Box::new(Toy)));
+            assert!(!matches!(
+                crate::get_interpreter_for(code),
+                Some(Language::Registered(_)) | None
+            ));
+        }
+        assert!(lookup_language("xx").is_none());
+    }
+
+    #[test]
+    fn test_register_from_other_thread() {
+        assert!(register_language("x-toy", Box::new(Toy)));
+        assert!(!register_language("x-toy", Box::new(Toy)));
+        let handle = std::thread::spawn(|| {
+            let toy = lookup_language("x-toy").unwrap();
+            let mut b = DigitString::new();
+            toy.apply("duo", &mut b).unwrap();
+            assert_eq!(b.to_string(), "2");
+            let lang = crate::get_interpreter_for("x-toy").unwrap();
+            assert!(matches!(lang, Language::Registered(_)));
+            replace_numbers_in_text("uno, duo, tri!", &lang, 0.0)
+        });
+        assert_eq!(handle.join().unwrap(), "1, 2, 3!");
+    }
+
+    #[test]
+    fn test_registered_annotation() {
+        assert!(register_language("x-toy-annotated", Box::new(Toy)));
+        let lang = crate::get_interpreter_for("x-toy-annotated").unwrap();
+        assert_eq!(
+            replace_numbers_in_text("uno, non duo, tri", &lang, 0.0),
+            "1, non duo, 3"
+        );
+    }
+}
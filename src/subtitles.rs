@@ -0,0 +1,166 @@
+/*!
+Replace the spelled numbers of subtitle cues, like the SRT or WebVTT cues of a speech transcription.
+
+Each cue is converted with a look-ahead into the next one, so that a number spoken across a cue
+break is converted whole: its digits go into the cue where it starts, and its words are removed
+from the next cue.
+
+```rust
+use core::time::Duration;
+use text2num::{Language, Options};
+use text2num::subtitles::{Cue, convert_cues};
+
+let en = Language::english();
+let cue = |start, end, text: &str| Cue {
+    start: Duration::from_millis(start),
+    end: Duration::from_millis(end),
+    text: text.into(),
+};
+let mut cues = vec![
+    cue(0, 1800, "we sold two hundred"),
+    cue(1800, 3500, "and five boxes in twenty-one days"),
+];
+convert_cues(&mut cues, &en, &Options::new(10.0));
+
+assert_eq!(cues[0].text, "we sold 205");
+assert_eq!(cues[1].text, "boxes in 21 days");
+```
+*/
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::time::Duration;
+
+use crate::lang::LangInterpreter;
+use crate::options::Options;
+use crate::word_to_digit::{FindNumbers, splice, text_tokens};
+
+/// A timed block of subtitle text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cue {
+    /// The time the cue shows up
+    pub start: Duration,
+    /// The time the cue goes away
+    pub end: Duration,
+    /// The text shown, that may take several lines
+    pub text: String,
+}
+
+/// Replace the spelled numbers of the `cues`, in place.
+///
+/// The numbers that run over into the next cue are written whole in the cue where they start,
+/// and the words they took from the next cue are removed, with the spaces after them.
+/// A cue left empty that way is removed. The look-ahead is one cue: a number spoken over three cues
+/// is converted in two parts.
+///
+/// The text of a cue is left unchanged if its replacement goes wrong (see [`Options::paranoid`]).
+pub fn convert_cues<L: LangInterpreter>(cues: &mut Vec<Cue>, lang: &L, options: &Options) {
+    let mut i = 0;
+    while i < cues.len() {
+        let boundary = cues[i].text.len();
+        let joined = match cues.get(i + 1) {
+            Some(next) => [cues[i].text.as_str(), next.text.as_str()].join(" "),
+            None => cues[i].text.clone(),
+        };
+        let tokens = text_tokens(&joined, lang, options);
+        // the numbers of the next cue are found again with the cue after it
+        let occurences: Vec<_> = FindNumbers::new(tokens.iter().enumerate(), lang, options)
+            .take_while(|occurence| occurence.range.start < boundary)
+            .collect();
+        let cut = occurences
+            .last()
+            .map_or(boundary, |occurence| occurence.range.end.max(boundary));
+        let mut text = String::with_capacity(cut);
+        if splice(
+            &joined[..cut],
+            occurences.into_iter(),
+            options,
+            None,
+            &mut text,
+        )
+        .is_ok()
+        {
+            cues[i].text = text;
+            if cut > boundary {
+                let rest = joined[cut..].trim_start().to_string();
+                if rest.is_empty() {
+                    cues.remove(i + 1);
+                } else {
+                    cues[i + 1].text = rest;
+                }
+            }
+        }
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg_attr(not(any(feature = "en", feature = "de")), allow(unused_imports))]
+    use crate::lang::Language;
+
+    #[cfg_attr(not(any(feature = "en", feature = "de")), allow(dead_code))]
+    fn cues(texts: &[&str]) -> Vec<Cue> {
+        texts
+            .iter()
+            .zip(0..)
+            .map(|(text, i)| Cue {
+                start: Duration::from_secs(i),
+                end: Duration::from_secs(i + 1),
+                text: text.to_string(),
+            })
+            .collect()
+    }
+
+    #[cfg_attr(not(any(feature = "en", feature = "de")), allow(dead_code))]
+    fn texts(cues: &[Cue]) -> Vec<&str> {
+        cues.iter().map(|cue| cue.text.as_str()).collect()
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_english_cues() {
+        let en = Language::english();
+        let options = Options::new(10.0);
+        let mut converted = cues(&[
+            "it costs two hundred",
+            "and five dollars, or twenty",
+            "one euros",
+        ]);
+        convert_cues(&mut converted, &en, &options);
+        assert_eq!(
+            texts(&converted),
+            ["it costs 205", "dollars, or 21", "euros"]
+        );
+        // the timing of the cues is kept
+        assert_eq!(converted[1].start, Duration::from_secs(1));
+        // a cue emptied by the number that started before it is removed
+        let mut converted = cues(&["one thousand", "two hundred", "were there"]);
+        convert_cues(&mut converted, &en, &options);
+        assert_eq!(texts(&converted), ["1200", "were there"]);
+        assert_eq!(converted[1].start, Duration::from_secs(2));
+        // the numbers that end with their cue
+        let mut converted = cues(&["twenty-one cats,", "three dogs", ""]);
+        convert_cues(&mut converted, &en, &options);
+        assert_eq!(texts(&converted), ["21 cats,", "three dogs", ""]);
+        let mut converted = Vec::new();
+        convert_cues(&mut converted, &en, &options);
+        assert!(converted.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "de")]
+    fn test_german_cues() {
+        let de = Language::german();
+        let options = Options::new(10.0);
+        let mut converted = cues(&["es kostet zweihundert", "und fünf Euro"]);
+        convert_cues(&mut converted, &de, &options);
+        assert_eq!(texts(&converted), ["es kostet 205", "Euro"]);
+        let mut converted = cues(&["im Jahr neunzehnhundert", "vierundachtzig war es", "kalt"]);
+        convert_cues(&mut converted, &de, &options);
+        assert_eq!(texts(&converted), ["im Jahr 1984", "war es", "kalt"]);
+    }
+}
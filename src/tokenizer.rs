@@ -1,12 +1,27 @@
 //! Some tokenizers
 
-use alloc::{borrow::ToOwned, string::String};
+#[cfg(feature = "unicode-segmentation")]
+use alloc::vec::Vec;
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    string::String,
+};
 
+#[cfg(any(feature = "de", feature = "it", feature = "nl", feature = "sk"))]
+use core::{
+    ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+#[cfg(any(feature = "de", feature = "it", feature = "nl", feature = "sk"))]
 use daachorse::{
     CharwiseDoubleArrayAhoCorasick, CharwiseDoubleArrayAhoCorasickBuilder, MatchKind,
-    charwise::iter::LestmostFindIterator, errors::Result,
+    charwise::iter::LeftmostFindIterator, errors::Result,
 };
 
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::{UWordBoundIndices, UnicodeSegmentation};
+
 #[derive(Debug)]
 pub struct BasicToken {
     pub text: String,
@@ -18,7 +33,7 @@ impl BasicToken {
     pub fn new(text: &str) -> Self {
         Self {
             text: text.to_owned(),
-            lowercase: text.to_lowercase(),
+            lowercase: lowercase(text).into_owned(),
             nan: false,
         }
     }
@@ -36,6 +51,92 @@ impl core::borrow::Borrow<str> for BasicToken {
     }
 }
 
+/// A token borrowed from the source text.
+///
+/// Its lowercase form is only stored if it differs from the text, and only allocated if it is not a short ASCII word.
+#[derive(Debug, Clone)]
+pub(crate) struct TextSpan<'a> {
+    pub text: &'a str,
+    lowercase: Lowercase,
+    pub nan: bool,
+    pub partial: bool,
+    /// The token is markup (see [`Options::markup`](crate::Options::markup))
+    pub markup: bool,
+}
+
+/// The longest lowercase form kept in a [`TextSpan`] itself
+const INLINE_LOWERCASE: usize = 22;
+
+/// The lowercase form of a [`TextSpan`] that differs from its text.
+#[derive(Debug, Clone)]
+enum Lowercase {
+    /// The text is its own lowercase form
+    Same,
+    /// The ASCII lowercase form, in the first bytes
+    Inline(u8, [u8; INLINE_LOWERCASE]),
+    Boxed(Box<str>),
+}
+
+impl<'a> TextSpan<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        let lowercase = if !text.bytes().any(|c| c.is_ascii_uppercase()) && text.is_ascii() {
+            Lowercase::Same
+        } else if text.len() <= INLINE_LOWERCASE && text.is_ascii() {
+            // the ASCII lowercase form is the Unicode one
+            let mut bytes = [0; INLINE_LOWERCASE];
+            bytes[..text.len()].copy_from_slice(text.as_bytes());
+            bytes.make_ascii_lowercase();
+            Lowercase::Inline(text.len() as u8, bytes)
+        } else {
+            match lowercase(text) {
+                Cow::Borrowed(_) => Lowercase::Same,
+                Cow::Owned(lowercase) => Lowercase::Boxed(lowercase.into_boxed_str()),
+            }
+        };
+        Self {
+            text,
+            lowercase,
+            nan: false,
+            partial: false,
+            markup: false,
+        }
+    }
+
+    pub fn lowercase(&self) -> &str {
+        match &self.lowercase {
+            Lowercase::Same => self.text,
+            Lowercase::Inline(len, bytes) => {
+                core::str::from_utf8(&bytes[..*len as usize]).unwrap_or_default()
+            }
+            Lowercase::Boxed(lowercase) => lowercase,
+        }
+    }
+}
+
+/// The Unicode lowercase form of `word`, with the Unicode hyphens as hyphen-minus (see [`is_hyphen`]),
+/// only allocated if it differs from the word.
+pub(crate) fn lowercase(word: &str) -> Cow<'_, str> {
+    if word
+        .chars()
+        .all(|c| is_lowercase(c) && (c == '-' || !is_hyphen(c)))
+    {
+        Cow::Borrowed(word)
+    } else {
+        Cow::Owned(
+            word.chars()
+                .map(|c| if is_hyphen(c) { '-' } else { c })
+                .collect::<String>()
+                .to_lowercase(),
+        )
+    }
+}
+
+/// `c` is its own lowercase form.
+fn is_lowercase(c: char) -> bool {
+    let mut lower = c.to_lowercase();
+    lower.next() == Some(c) && lower.next().is_none()
+}
+
 /// Plain text tokenizer on word boundaries.
 #[derive(Debug)]
 pub struct Tokenize<'a> {
@@ -55,10 +156,43 @@ impl<'a> Tokenize<'a> {
         }
     }
 
+    fn next_str(&mut self) -> Option<&'a str> {
+        let (pos, c) = self.chars.next()?;
+        let end = if c.is_ascii_digit() {
+            self.match_digits()
+        } else if c.is_alphanumeric() {
+            self.match_word()
+        } else {
+            self.match_sep()
+        };
+        Some(&self.source[pos..end])
+    }
+
+    /// Yield the tokens as slices of the source.
+    pub(crate) fn strs(mut self) -> impl Iterator<Item = &'a str> {
+        core::iter::from_fn(move || self.next_str())
+    }
+
+    /// Same as [`strs`](Self::strs), with the words in Han or kana cut on their Unicode word boundaries,
+    /// but the runs of ideographs if `ideographic_runs`.
+    #[cfg(feature = "unicode-segmentation")]
+    pub(crate) fn segmented_strs(
+        self,
+        ideographic_runs: bool,
+    ) -> Segmented<'a, impl Iterator<Item = &'a str>> {
+        Segmented {
+            tokens: self.strs(),
+            word: "",
+            bounds: None,
+            ideographic_runs,
+        }
+    }
+
     fn match_word(&mut self) -> usize {
         loop {
             if let Some((pos, c)) = self.chars.peek() {
-                if !(c.is_alphanumeric() || *c == '-' || *c == '\'') {
+                // the zero width non-joiner is part of words in Persian, as in "سی‌ام"
+                if !(c.is_alphanumeric() || is_hyphen(*c) || *c == '\'' || *c == '\u{200c}') {
                     break *pos;
                 }
                 self.chars.next();
@@ -68,15 +202,49 @@ impl<'a> Tokenize<'a> {
         }
     }
 
+    /// Match a number already written in digits, with its inner separators, as in "*1,234.56*" or "*1.234,56*".
+    fn match_digits(&mut self) -> usize {
+        loop {
+            match self.chars.peek() {
+                Some((_, c)) if c.is_ascii_digit() => {
+                    self.chars.next();
+                }
+                Some(&(pos, c)) if is_digit_separator(c) => {
+                    let mut ahead = self.chars.clone();
+                    ahead.next();
+                    if ahead.peek().is_some_and(|(_, c)| c.is_ascii_digit()) {
+                        self.chars.next();
+                    } else {
+                        break pos;
+                    }
+                }
+                Some((_, c)) if c.is_alphanumeric() || is_hyphen(*c) || *c == '\'' => {
+                    break self.match_word();
+                }
+                Some((pos, _)) => break *pos,
+                None => break self.source.len(),
+            }
+        }
+    }
+
+    /// Match a run of separators. A minus sign that sticks to the next word, as in "*(-twenty)*",
+    /// is split off the run to stand as a token of its own.
     fn match_sep(&mut self) -> usize {
         loop {
-            if let Some((pos, c)) = self.chars.peek() {
-                if c.is_alphanumeric() {
-                    break *pos;
+            match self.chars.peek() {
+                Some((pos, c)) if c.is_alphanumeric() => break *pos,
+                Some(&(pos, c)) if is_minus_sign(c) => {
+                    let mut ahead = self.chars.clone();
+                    ahead.next();
+                    if ahead.peek().is_some_and(|(_, c)| c.is_alphanumeric()) {
+                        break pos;
+                    }
+                    self.chars.next();
                 }
-                self.chars.next();
-            } else {
-                break self.source.len();
+                Some(_) => {
+                    self.chars.next();
+                }
+                None => break self.source.len(),
             }
         }
     }
@@ -86,28 +254,124 @@ impl Iterator for Tokenize<'_> {
     type Item = BasicToken;
 
     fn next(&mut self) -> Option<BasicToken> {
-        if let Some((pos, c)) = self.chars.next() {
-            let end = if c.is_alphanumeric() {
-                self.match_word()
-            } else {
-                self.match_sep()
-            };
-            Some(BasicToken::new(&self.source[pos..end]))
-        } else {
-            None
+        self.next_str().map(BasicToken::new)
+    }
+}
+
+/// The tokens of a [`Tokenize`], with the words of the scripts written without spaces cut on their
+/// Unicode (UAX #29) word boundaries.
+///
+/// The other words are left as they are, so that the languages written with spaces are tokenized the same.
+#[cfg(feature = "unicode-segmentation")]
+pub(crate) struct Segmented<'a, I> {
+    tokens: I,
+    /// The word being cut
+    word: &'a str,
+    bounds: Option<core::iter::Peekable<UWordBoundIndices<'a>>>,
+    ideographic_runs: bool,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'a, I: Iterator<Item = &'a str>> Iterator for Segmented<'a, I> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        loop {
+            if let Some(bounds) = &mut self.bounds {
+                if let Some((start, piece)) = bounds.next() {
+                    let mut end = start + piece.len();
+                    if self.ideographic_runs && piece.chars().all(is_ideograph) {
+                        while let Some((next, piece)) =
+                            bounds.next_if(|(_, piece)| piece.chars().all(is_ideograph))
+                        {
+                            end = next + piece.len();
+                        }
+                    }
+                    return Some(&self.word[start..end]);
+                }
+                self.bounds = None;
+            }
+            let token = self.tokens.next()?;
+            if !token.chars().any(is_unspaced) {
+                return Some(token);
+            }
+            self.word = token;
+            self.bounds = Some(token.split_word_bound_indices().peekable());
         }
     }
 }
 
+/// The end offsets of the pieces of `word` cut as by [`Tokenize::segmented_strs`],
+/// or `None` if it has no letter of a script written without spaces.
+#[cfg(feature = "unicode-segmentation")]
+pub(crate) fn piece_ends(word: &str, ideographic_runs: bool) -> Option<Vec<usize>> {
+    if !word.chars().any(is_unspaced) {
+        return None;
+    }
+    let pieces = Segmented {
+        tokens: core::iter::once(word),
+        word: "",
+        bounds: None,
+        ideographic_runs,
+    };
+    let mut end = 0;
+    Some(
+        pieces
+            .map(|piece| {
+                end += piece.len();
+                end
+            })
+            .collect(),
+    )
+}
+
+/// Han ideograph, including the iteration mark "*々*" and the ideographic zero "*〇*".
+#[cfg(feature = "unicode-segmentation")]
+fn is_ideograph(c: char) -> bool {
+    matches!(c,
+        '\u{3005}'
+        | '\u{3007}'
+        | '\u{3400}'..='\u{4dbf}'
+        | '\u{4e00}'..='\u{9fff}'
+        | '\u{f900}'..='\u{faff}'
+        | '\u{20000}'..='\u{3134f}'
+    )
+}
+
+/// Letter of a script written without spaces between the words: Han or kana.
+#[cfg(feature = "unicode-segmentation")]
+fn is_unspaced(c: char) -> bool {
+    is_ideograph(c)
+        || matches!(c, '\u{3040}'..='\u{30ff}' | '\u{31f0}'..='\u{31ff}' | '\u{ff66}'..='\u{ff9f}')
+}
+
+/// Decimal and thousands separators found inside digit numbers, depending on the locale.
+fn is_digit_separator(c: char) -> bool {
+    matches!(c, '.' | ',' | '\'' | '\u{a0}' | '\u{202f}')
+}
+
+/// Hyphen-minus, or a Unicode hyphen that reads the same in words: the hyphen and the non-breaking hyphen,
+/// as in "*vingt\u{2011}trois*".
+pub(crate) fn is_hyphen(c: char) -> bool {
+    matches!(c, '-' | '\u{2010}' | '\u{2011}')
+}
+
+/// Hyphen-minus, minus sign or en dash.
+pub fn is_minus_sign(c: char) -> bool {
+    matches!(c, '-' | '\u{2212}' | '\u{2013}')
+}
+
+#[cfg(any(feature = "de", feature = "it", feature = "nl", feature = "sk"))]
 pub struct WordSplitIterator<'a> {
     source: &'a str,
-    matches: LestmostFindIterator<'a, &'a str, usize>,
+    matches: LeftmostFindIterator<'a, &'a str, usize>,
     end: usize,
     cursor: usize,
 }
 
+#[cfg(any(feature = "de", feature = "it", feature = "nl", feature = "sk"))]
 impl<'a> WordSplitIterator<'a> {
-    fn new(source: &'a str, matches: LestmostFindIterator<'a, &'a str, usize>) -> Self {
+    fn new(source: &'a str, matches: LeftmostFindIterator<'a, &'a str, usize>) -> Self {
         Self {
             source,
             matches,
@@ -117,6 +381,7 @@ impl<'a> WordSplitIterator<'a> {
     }
 }
 
+#[cfg(any(feature = "de", feature = "it", feature = "nl", feature = "sk"))]
 impl<'a> Iterator for WordSplitIterator<'a> {
     type Item = &'a str;
 
@@ -146,10 +411,12 @@ impl<'a> Iterator for WordSplitIterator<'a> {
 }
 
 /// Word splitter on patterns, including the match patterns.
+#[cfg(any(feature = "de", feature = "it", feature = "nl", feature = "sk"))]
 pub struct WordSplitter {
     engine: CharwiseDoubleArrayAhoCorasick<usize>,
 }
 
+#[cfg(any(feature = "de", feature = "it", feature = "nl", feature = "sk"))]
 impl WordSplitter {
     pub fn new<I, P>(patterns: I) -> Result<Self>
     where
@@ -179,6 +446,51 @@ impl WordSplitter {
     }
 }
 
+/// A [`WordSplitter`] for static patterns, built on first use and then shared,
+/// so that the interpreters using it cost nothing to construct.
+#[cfg(any(feature = "de", feature = "it", feature = "nl", feature = "sk"))]
+pub struct LazySplitter {
+    patterns: &'static [&'static str],
+    splitter: AtomicPtr<WordSplitter>,
+}
+
+#[cfg(any(feature = "de", feature = "it", feature = "nl", feature = "sk"))]
+impl LazySplitter {
+    pub const fn new(patterns: &'static [&'static str]) -> Self {
+        Self {
+            patterns,
+            splitter: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    pub fn get(&self) -> &WordSplitter {
+        let current = self.splitter.load(Ordering::Acquire);
+        if !current.is_null() {
+            // SAFETY: a non null pointer comes from `Box::into_raw` below and is never freed.
+            return unsafe { &*current };
+        }
+        let built = Box::into_raw(Box::new(
+            WordSplitter::new(self.patterns).expect("valid splitter patterns"),
+        ));
+        match self.splitter.compare_exchange(
+            ptr::null_mut(),
+            built,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            // SAFETY: `built` is now owned by `self` for ever.
+            Ok(_) => unsafe { &*built },
+            Err(first) => {
+                // Another thread was first: ours was never shared.
+                // SAFETY: `built` comes from `Box::into_raw` and has no other owner.
+                drop(unsafe { Box::from_raw(built) });
+                // SAFETY: see above.
+                unsafe { &*first }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec::Vec;
@@ -202,6 +514,83 @@ mod tests {
     }
 
     #[test]
+    fn test_tokenizer_zero_width_non_joiner() {
+        let src = "سی\u{200c}ام ماه";
+        let texts: Vec<String> = Tokenize::new(src).map(|t| t.text).collect();
+        assert_eq!(texts, ["سی\u{200c}ام", " ", "ماه"]);
+    }
+
+    #[test]
+    fn test_tokenizer_digits() {
+        let src = "1,234.56 or 1.234,56\u{202f}€, 12'000 and 3rd, 7.";
+        let tokens: Vec<BasicToken> = Tokenize::new(src).collect();
+        crate::tests::dbg!(&tokens);
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "1,234.56",
+                " ",
+                "or",
+                " ",
+                "1.234,56",
+                "\u{202f}€, ",
+                "12'000",
+                " ",
+                "and",
+                " ",
+                "3rd",
+                ", ",
+                "7",
+                "."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenizer_signs() {
+        let src = "from -twenty to (\u{2212}5), well-known - or \u{2013}";
+        let texts: Vec<String> = Tokenize::new(src).map(|t| t.text).collect();
+        assert_eq!(
+            texts,
+            [
+                "from",
+                " ",
+                "-",
+                "twenty",
+                " ",
+                "to",
+                " (",
+                "\u{2212}",
+                "5",
+                "), ",
+                "well-known",
+                " - ",
+                "or",
+                " \u{2013}"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenizer_unicode_hyphens() {
+        let src = "vingt\u{2011}trois, twenty\u{2010}one✨ 👨\u{200d}👩\u{200d}👧";
+        let texts: Vec<String> = Tokenize::new(src).map(|t| t.text).collect();
+        assert_eq!(
+            texts,
+            [
+                "vingt\u{2011}trois",
+                ", ",
+                "twenty\u{2010}one",
+                "✨ 👨\u{200d}👩\u{200d}👧"
+            ]
+        );
+        assert_eq!(lowercase("Vingt\u{2011}Trois"), "vingt-trois");
+        assert!(matches!(lowercase("vingt-trois"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    #[cfg(any(feature = "de", feature = "it", feature = "nl", feature = "sk"))]
     fn test_word_splitter() {
         let german_splitter = WordSplitter::new([
             "billion",
@@ -223,4 +612,63 @@ mod tests {
             ["tausend", "fünf", "hundert", "zwei", "und", "dreißig"]
         );
     }
+
+    #[test]
+    #[cfg(feature = "unicode-segmentation")]
+    fn test_segmentation_of_spaced_languages() {
+        for code in crate::corpus::CODES {
+            for line in crate::corpus::tsv(code).lines() {
+                for runs in [false, true] {
+                    let segmented: Vec<&str> = Tokenize::new(line).segmented_strs(runs).collect();
+                    let plain: Vec<&str> = Tokenize::new(line).strs().collect();
+                    assert_eq!(segmented, plain, "{code}: {line}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-segmentation")]
+    fn test_segmentation_of_unspaced_scripts() {
+        let src = "はい、三百二十ページです。二〇二四年";
+        let words: Vec<&str> = Tokenize::new(src).segmented_strs(false).collect();
+        assert_eq!(
+            words,
+            [
+                "は",
+                "い",
+                "、",
+                "三",
+                "百",
+                "二",
+                "十",
+                "ページ",
+                "で",
+                "す",
+                "。",
+                "二",
+                "〇",
+                "二",
+                "四",
+                "年"
+            ]
+        );
+        let words: Vec<&str> = Tokenize::new(src).segmented_strs(true).collect();
+        assert_eq!(
+            words,
+            [
+                "は",
+                "い",
+                "、",
+                "三百二十",
+                "ページ",
+                "で",
+                "す",
+                "。",
+                "二〇二四年"
+            ]
+        );
+        // the whole text is still covered
+        assert_eq!(words.concat(), src);
+    }
 }
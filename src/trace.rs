@@ -0,0 +1,240 @@
+/*!
+Step by step record of the interpretation of a text, to find out why some words were, or were not, converted.
+
+The trace lists the calls made to the [`LangInterpreter`](crate::LangInterpreter) for each word, with the digits
+they produced, and the decisions taken on the groups of words that make the numbers.
+
+```rust
+use text2num::{Language, Options, replace_numbers_in_text_traced};
+
+let en = Language::english();
+let (text, trace) = replace_numbers_in_text_traced("two hundred and five", &en, &Options::new(0.0));
+
+assert_eq!(text, "205");
+// A readable table, one event per row
+println!("{trace}");
+```
+*/
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::error::Error;
+
+/// A [`LangInterpreter`](crate::LangInterpreter) method called on a word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Call {
+    /// [`LangInterpreter::apply`](crate::LangInterpreter::apply), on the integer part
+    Apply,
+    /// [`LangInterpreter::apply_decimal`](crate::LangInterpreter::apply_decimal), on the decimal part
+    ApplyDecimal,
+    /// [`LangInterpreter::check_decimal_separator`](crate::LangInterpreter::check_decimal_separator),
+    /// or one of the [`Options::decimal_separators`](crate::Options::decimal_separators)
+    CheckDecimalSeparator,
+}
+
+impl Call {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Apply => "apply",
+            Self::ApplyDecimal => "apply_decimal",
+            Self::CheckDecimalSeparator => "check_decimal_separator",
+        }
+    }
+}
+
+/// A decision on the group of words of a number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupAction {
+    /// A number starts with the word
+    Started,
+    /// The word is part of the current number
+    Extended,
+    /// The word may be part of the current number, depending on the words that follow
+    Pending,
+    /// The current number is dropped and left unconverted
+    Aborted,
+    /// The current number ends, with the given digit form
+    Committed(String),
+    /// The previous numbers are merged into the given digit form
+    Merged(String),
+    /// The number of the given digit form is isolated and under the threshold:
+    /// it is only kept if another number follows, or a month name for an ordinal
+    /// (see [`LangInterpreter::is_month_name`](crate::LangInterpreter::is_month_name))
+    Held(String),
+}
+
+/// An event of a [`Trace`]. `pos` is the position of the token being processed in the token stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEvent {
+    /// A language method was called on `word`: `before` are the digits before the call,
+    /// the outcome the digits after it (or the decimal separator), or the error returned.
+    Call {
+        pos: usize,
+        word: String,
+        call: Call,
+        before: String,
+        outcome: Result<String, Error>,
+    },
+    /// A decision on the current number, and why it was taken.
+    Group {
+        pos: usize,
+        action: GroupAction,
+        reason: &'static str,
+    },
+}
+
+impl TraceEvent {
+    fn pos(&self) -> usize {
+        match self {
+            Self::Call { pos, .. } | Self::Group { pos, .. } => *pos,
+        }
+    }
+}
+
+/// The events of an interpretation, in order.
+///
+/// Its [`Display`](fmt::Display) renders them as a table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub events: Vec<TraceEvent>,
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .events
+            .iter()
+            .map(|event| match event {
+                TraceEvent::Call { word, .. } => word.chars().count(),
+                TraceEvent::Group { .. } => 0,
+            })
+            .fold(4, usize::max);
+        writeln!(
+            f,
+            "{:>4}  {:<width$}  {:<23}  detail",
+            "pos", "word", "step"
+        )?;
+        for event in &self.events {
+            write!(f, "{:>4}  ", event.pos())?;
+            match event {
+                TraceEvent::Call {
+                    word,
+                    call,
+                    before,
+                    outcome,
+                    ..
+                } => {
+                    write!(f, "{word:<width$}  {:<23}  ", call.name())?;
+                    match outcome {
+                        Ok(after) => writeln!(f, "{before:?} → {after:?}")?,
+                        Err(error) => writeln!(f, "{before:?} → {error:?}")?,
+                    }
+                }
+                TraceEvent::Group { action, reason, .. } => {
+                    let (step, digits) = match action {
+                        GroupAction::Started => ("group started", None),
+                        GroupAction::Extended => ("group extended", None),
+                        GroupAction::Pending => ("group pending", None),
+                        GroupAction::Aborted => ("group aborted", None),
+                        GroupAction::Committed(text) => ("group committed", Some(text)),
+                        GroupAction::Merged(text) => ("groups merged", Some(text)),
+                        GroupAction::Held(text) => ("group held", Some(text)),
+                    };
+                    write!(f, "{:<width$}  {step:<23}  ", "")?;
+                    match digits {
+                        Some(text) => writeln!(f, "{text:?}: {reason}")?,
+                        None => writeln!(f, "{reason}")?,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg_attr(not(feature = "en"), allow(unused_imports))]
+    use super::*;
+    #[cfg_attr(not(feature = "en"), allow(unused_imports))]
+    use crate::{Options, word_to_digit::replace_numbers_in_text_traced};
+    #[cfg_attr(not(feature = "en"), allow(unused_imports))]
+    use alloc::{string::ToString, vec};
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_trace() {
+        let en = crate::Language::english();
+        let (text, trace) =
+            replace_numbers_in_text_traced("two hundred and five", &en, &Options::new(0.0));
+        assert_eq!(text, "205");
+        let call =
+            |pos, word: &str, call, before: &str, outcome: Result<&str, Error>| TraceEvent::Call {
+                pos,
+                word: word.into(),
+                call,
+                before: before.into(),
+                outcome: outcome.map(String::from),
+            };
+        let group = |pos, action, reason| TraceEvent::Group {
+            pos,
+            action,
+            reason,
+        };
+        assert_eq!(
+            trace.events,
+            vec![
+                call(0, "two", Call::Apply, "", Ok("2")),
+                group(0, GroupAction::Started, "number word"),
+                call(2, "hundred", Call::Apply, "2", Ok("200")),
+                group(2, GroupAction::Extended, "number word"),
+                // "and a half" would end the number
+                group(
+                    4,
+                    GroupAction::Pending,
+                    "fraction tail after a cardinal: a decimal number if it is complete"
+                ),
+                call(4, "and", Call::Apply, "200", Err(Error::Incomplete)),
+                call(4, "and", Call::CheckDecimalSeparator, "", Err(Error::NaN)),
+                group(4, GroupAction::Pending, "linking word or decimal separator"),
+                call(6, "five", Call::Apply, "200", Ok("205")),
+                group(6, GroupAction::Extended, "number word"),
+                group(6, GroupAction::Committed("205".into()), "end of input"),
+            ]
+        );
+        let table = trace.to_string();
+        assert!(table.starts_with(" pos  word     step"));
+        assert!(table.contains("   4  and      apply                    \"200\" → Incomplete\n"));
+        assert!(table.ends_with("group committed          \"205\": end of input\n"));
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_trace_decisions() {
+        let en = crate::Language::english();
+        let (text, trace) =
+            replace_numbers_in_text_traced("I have two apples", &en, &Options::new(10.0));
+        assert_eq!(text, "I have two apples");
+        assert!(trace.events.contains(&TraceEvent::Group {
+            pos: 6,
+            action: GroupAction::Held("2".into()),
+            reason: "under the threshold: dropped if isolated",
+        }));
+        let options = Options {
+            max_tokens: 2,
+            ..Options::new(0.0)
+        };
+        let (text, trace) = replace_numbers_in_text_traced("twenty one thousand", &en, &options);
+        assert_eq!(text, "twenty one thousand");
+        assert!(trace.events.iter().any(|event| matches!(
+            event,
+            TraceEvent::Group {
+                action: GroupAction::Aborted,
+                reason: "too long",
+                ..
+            }
+        )));
+    }
+}
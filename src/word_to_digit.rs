@@ -3,51 +3,224 @@ Top level API.
 
 For an overview with examples and use cases, see the [crate level documentation](super).
 
+The functions of this module run the whole replacement pipeline at once;
+its stages are also available separately in the [`pipeline`](crate::pipeline) module.
+
 */
 
-use alloc::{string::String, vec::Vec};
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 use alloc::collections::VecDeque;
-use core::iter::Enumerate;
+#[cfg(feature = "unicode-segmentation")]
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::ops::Range;
+#[cfg(feature = "unicode-segmentation")]
+use core::{cell::RefCell, iter::Enumerate};
 
+use crate::alignment::Alignment;
+use crate::bidi::{FSI, PDI, Paragraphs};
 use crate::digit_string::DigitString;
+use crate::edit::{EditError, SpliceGuard};
 use crate::error::Error;
-use crate::lang::{BasicAnnotate, LangInterpreter};
-use crate::tokenizer::{BasicToken, tokenize};
+use crate::formatter::{Digits, NumberFormatter};
+use crate::lang::{
+    ANNOTATION_CONTEXT, BasicAnnotate, LangInterpreter, MorphologicalMarker, TokenKind,
+};
+use crate::options::{
+    AdjacentNumberPolicy, FractionOutput, Markup, MarkupPlacement, Options, OrdinalStyle,
+};
+use crate::parse::NumberKind;
+use crate::tokenizer::{self, BasicToken, TextSpan, tokenize};
+use crate::trace::{Call, GroupAction, Trace, TraceEvent};
 
-struct WordToDigitParser<'a, T: LangInterpreter> {
+pub(crate) struct WordToDigitParser<'a, T: LangInterpreter> {
     int_part: DigitString,
     dec_part: DigitString,
     dec_separator: Option<char>,
+    /// The word read as the decimal separator, like "*point*"
+    separator_word: Option<String>,
     lang: &'a T,
+    /// Extra decimal separator words (see [`Options::decimal_separators`])
+    separators: Option<Arc<[(String, char)]>>,
+    /// A decimal separator may start the number (see [`Options::leading_decimal_separator`])
+    leading_separator: bool,
+    formatter: Option<Arc<dyn NumberFormatter>>,
+    ordinal_style: OrdinalStyle,
+    /// See [`Options::keep_ordinal_markers`]
+    keep_ordinal_markers: bool,
+    /// See [`Options::keep_fraction_markers`]
+    keep_fraction_markers: bool,
+    /// The number follows a currency symbol
+    currency: bool,
+    /// A fraction tail ended the number, that a scale word may still multiply (see [`LangInterpreter::fraction_tails`])
+    tail: bool,
+    /// The fraction added to the number by a unit fraction tail, as numerator and denominator,
+    /// that a scale word may still multiply (see [`LangInterpreter::unit_fraction_tails`])
+    unit_fraction: Option<(u64, u64)>,
+    /// The language calls of the last words pushed, if traced
+    calls: Option<Vec<TraceEvent>>,
 }
 
 impl<'a, T: LangInterpreter> WordToDigitParser<'a, T> {
-    pub fn new(lang: &'a T) -> Self {
+    pub fn new(lang: &'a T, options: &Options) -> Self {
+        let mut int_part = DigitString::new();
+        int_part.set_max_len(options.max_digits);
+        let mut dec_part = DigitString::new();
+        dec_part.set_max_len(options.max_digits);
         Self {
-            int_part: DigitString::new(),
-            dec_part: DigitString::new(),
+            int_part,
+            dec_part,
             dec_separator: None,
+            separator_word: None,
             lang,
+            separators: if options.decimal_separators.is_empty() {
+                None
+            } else {
+                Some(options.decimal_separators.as_slice().into())
+            },
+            leading_separator: options.leading_decimal_separator,
+            formatter: options.formatter.clone(),
+            ordinal_style: options.ordinal_style,
+            keep_ordinal_markers: options.keep_ordinal_markers,
+            keep_fraction_markers: options.keep_fraction_markers,
+            currency: false,
+            tail: false,
+            unit_fraction: None,
+            calls: None,
+        }
+    }
+
+    /// Record the language calls, to be collected with [`take_calls`](Self::take_calls).
+    pub fn enable_trace(&mut self) {
+        self.calls = Some(Vec::new());
+    }
+
+    /// The language calls recorded since the last time, attributed to the token at `pos`.
+    pub fn take_calls(&mut self, pos: usize) -> impl Iterator<Item = TraceEvent> + use<T> {
+        let calls = self.calls.as_mut().map(core::mem::take).unwrap_or_default();
+        calls.into_iter().map(move |mut event| {
+            if let TraceEvent::Call { pos: call_pos, .. } = &mut event {
+                *call_pos = pos;
+            }
+            event
+        })
+    }
+
+    fn record(&mut self, word: &str, call: Call, before: String, outcome: Result<String, Error>) {
+        if let Some(calls) = &mut self.calls {
+            calls.push(TraceEvent::Call {
+                pos: 0,
+                word: word.into(),
+                call,
+                before,
+                outcome,
+            });
         }
     }
 
+    fn check_decimal_separator(&self, word: &str) -> Option<char> {
+        self.separators
+            .iter()
+            .flat_map(|separators| separators.iter())
+            .find(|(separator, _)| separator == word)
+            .map(|&(_, sep)| sep)
+            .or_else(|| self.lang.check_decimal_separator(word))
+    }
+
+    /// The width of the decimals after the current separator word (see [`LangInterpreter::decimal_width`])
+    fn decimal_width(&self) -> Option<usize> {
+        self.separator_word
+            .as_deref()
+            .and_then(|word| self.lang.decimal_width(word))
+    }
+
     /// Clear all except language.
     pub fn reset(&mut self) {
         self.int_part.reset();
         self.dec_part.reset();
         self.dec_separator = None;
+        self.separator_word = None;
+        self.currency = false;
+        self.tail = false;
+        self.unit_fraction = None;
+    }
+
+    /// The fraction added to the number by a unit fraction tail, as numerator and denominator.
+    pub fn unit_fraction(&self) -> Option<(u64, u64)> {
+        self.unit_fraction
     }
 
     pub fn push(&mut self, word: &str) -> Result<(), Error> {
-        let status = if self.dec_separator.is_some() {
-            self.lang.apply_decimal(word, &mut self.dec_part)
+        if self.dec_separator.is_none()
+            && let Some((int_word, sep, decimals)) = self.lang.split_decimal_word(word)
+        {
+            return self.push_decimal_word(word, int_word, sep, decimals);
+        }
+        // a scale after the decimals multiplies the whole number, even where the decimals could take it:
+        // "*deux virgule cinq millions*", "*three and a half thousand*"
+        if self.decimal_separator().is_some()
+            && (!self.dec_part.is_frozen() || self.tail)
+            && let Some(positions) = self.scale_positions(word)
+        {
+            self.tail = false;
+            return self.push_decimal_scale(word, positions);
+        }
+        // "*two and a third thousand*"
+        if let Some(fraction) = self.unit_fraction
+            && let Some(positions) = self.scale_positions(word)
+        {
+            return self.push_unit_fraction_scale(word, positions, fraction);
+        }
+        let width = self.decimal_width();
+        let (call, part) = if self.dec_separator.is_some() {
+            (Call::ApplyDecimal, &mut self.dec_part)
         } else {
-            self.lang.apply(word, &mut self.int_part)
+            (Call::Apply, &mut self.int_part)
         };
-        if status.is_err() && self.dec_separator.is_none() && !self.int_part.is_empty() {
-            self.dec_separator = self.lang.check_decimal_separator(word);
+        let before = self.calls.as_ref().map(|_| part.to_string());
+        let saved = width
+            .filter(|_| call == Call::ApplyDecimal)
+            .map(|width| (width, part.clone()));
+        let mut status = match call {
+            Call::ApplyDecimal => self.lang.apply_decimal(word, part),
+            _ => self.lang.apply(word, part),
+        };
+        if let Some((width, saved)) = saved
+            && status.is_ok()
+            && part.len() > width
+        {
+            // "tres con ciento": the cents are two digits at most
+            *part = saved;
+            status = Err(Error::Overlap);
+        }
+        if let Some(before) = before {
+            let outcome = status.clone().map(|()| part.to_string());
+            self.record(word, call, before, outcome);
+        }
+        if status.is_err() && self.leads_with_separator() {
+            // "point taken": the separator did not start a number after all
+            self.dec_separator = None;
+            self.separator_word = None;
+            return self.push(word);
+        }
+        if status.is_err()
+            && self.dec_separator.is_none()
+            && (!self.int_part.is_empty() || self.leading_separator)
+        {
+            self.dec_separator = self.check_decimal_separator(word);
+            if self.calls.is_some() {
+                let outcome = self.dec_separator.map(String::from).ok_or(Error::NaN);
+                self.record(word, Call::CheckDecimalSeparator, String::new(), outcome);
+            }
             if self.dec_separator.is_some() {
+                self.separator_word = Some(word.into());
                 Err(Error::Incomplete)
             } else {
                 status
@@ -57,26 +230,308 @@ impl<'a, T: LangInterpreter> WordToDigitParser<'a, T> {
         }
     }
 
+    /// Multiply the decimal number by the scale word `word`, like "*million*" in "*three point five million*".
+    ///
+    /// The number is complete afterwards, and it is an integer unless the decimals outnumber the zeroes of the scale.
+    fn push_decimal_scale(&mut self, word: &str, positions: usize) -> Result<(), Error> {
+        let before = self.calls.as_ref().map(|_| self.int_part.to_string());
+        let decimals = self.dec_part.to_string();
+        let (shifted, rest) = decimals.split_at(positions.min(decimals.len()));
+        let mut digits = self.int_part.to_string();
+        digits.push_str(shifted);
+        digits.extend(core::iter::repeat_n('0', positions - shifted.len()));
+        let digits = digits.trim_start_matches('0');
+        let mut int_part = self.int_part.clone();
+        int_part.reset();
+        let mut dec_part = self.dec_part.clone();
+        dec_part.reset();
+        let status = int_part
+            .put(if digits.is_empty() {
+                b"0"
+            } else {
+                digits.as_bytes()
+            })
+            .and_then(|()| dec_part.push(rest.as_bytes()));
+        if status.is_ok() {
+            int_part.freeze();
+            dec_part.freeze();
+            self.int_part = int_part;
+            self.dec_part = dec_part;
+        }
+        if let Some(before) = before {
+            let outcome = status.clone().map(|()| match self.decimal_separator() {
+                Some(sep) => format!("{}{sep}{}", self.int_part, self.dec_part),
+                None => self.int_part.to_string(),
+            });
+            self.record(word, Call::Apply, before, outcome);
+        }
+        status
+    }
+
+    /// Multiply the number and its unit `fraction` by the scale word `word`, like "*thousand*" in
+    /// "*two and a third thousand*", that gives 2333 and 1/3.
+    fn push_unit_fraction_scale(
+        &mut self,
+        word: &str,
+        positions: usize,
+        (numerator, denominator): (u64, u64),
+    ) -> Result<(), Error> {
+        let before = self.calls.as_ref().map(|_| self.int_part.to_string());
+        // (n + num/den) * 10^p = n * 10^p + (num * 10^p) / den + ((num * 10^p) % den) / den
+        let scaled = 10u128
+            .checked_pow(positions as u32)
+            .and_then(|scale| scale.checked_mul(numerator as u128));
+        let mut int_part = self.int_part.clone();
+        int_part.reset();
+        let status = scaled.ok_or(Error::TooLong).and_then(|scaled| {
+            let digits = format!("{}{}", self.int_part, "0".repeat(positions));
+            int_part.put(digits.trim_start_matches('0').as_bytes())?;
+            let mut whole = DigitString::new();
+            whole.put(format!("{}", scaled / denominator as u128).as_bytes())?;
+            int_part.add_shifted(&whole, 0)?;
+            Ok(scaled % denominator as u128)
+        });
+        let status = status.map(|rest| {
+            let rest = rest as u64;
+            let divisor = gcd(rest, denominator);
+            int_part.freeze();
+            self.int_part = int_part;
+            self.unit_fraction = (rest > 0).then(|| (rest / divisor, denominator / divisor));
+        });
+        if let Some(before) = before {
+            let outcome = status.clone().map(|()| match self.unit_fraction {
+                Some((numerator, denominator)) => {
+                    format!("{} {numerator}/{denominator}", self.int_part)
+                }
+                None => self.int_part.to_string(),
+            });
+            self.record(word, Call::Apply, before, outcome);
+        }
+        status
+    }
+
+    /// The power of ten of `word` if it is a scale word of a thousand or more, that multiplies the number before it.
+    fn scale_positions(&self, word: &str) -> Option<usize> {
+        // some languages decline the scale words after one ("*Million*") and after more ("*Millionen*")
+        [b"1", b"2"].into_iter().find_map(|seed| {
+            let mut b = DigitString::new();
+            b.put(seed).ok()?;
+            self.lang.apply(word, &mut b).ok()?;
+            (b.marker.is_none()
+                && b.len() > 3
+                && b[0] == seed[0]
+                && b[1..].iter().all(|&d| d == b'0'))
+            .then(|| b.len() - 1)
+        })
+    }
+
+    /// Push a word that is a decimal number by itself (see [`LangInterpreter::split_decimal_word`]).
+    fn push_decimal_word(
+        &mut self,
+        word: &str,
+        int_word: &str,
+        sep: char,
+        decimals: &str,
+    ) -> Result<(), Error> {
+        let before = self.calls.as_ref().map(|_| self.int_part.to_string());
+        let mut int_part = self.int_part.clone();
+        let status = if int_word.is_empty() {
+            if self.int_part.is_empty() || self.int_part.is_ordinal() {
+                Err(Error::NaN)
+            } else {
+                Ok(())
+            }
+        } else {
+            self.lang.apply(int_word, &mut int_part).and_then(|()| {
+                if int_part.is_ordinal() {
+                    Err(Error::NaN)
+                } else {
+                    Ok(())
+                }
+            })
+        };
+        let status = status.and_then(|()| self.dec_part.push(decimals.as_bytes()));
+        if status.is_ok() {
+            self.int_part = int_part;
+            self.dec_part.freeze();
+            self.dec_separator = Some(sep);
+        }
+        if let Some(before) = before {
+            let outcome = status
+                .clone()
+                .map(|()| format!("{}{sep}{}", self.int_part, self.dec_part));
+            self.record(word, Call::Apply, before, outcome);
+        }
+        status
+    }
+
+    /// Add the `decimals` of the fraction tail `phrase` to the number (see [`LangInterpreter::fraction_tails`]).
+    fn push_fraction_tail(&mut self, phrase: &str, sep: char, decimals: &str) -> Result<(), Error> {
+        let before = self.calls.as_ref().map(|_| self.int_part.to_string());
+        let status = if self.int_part.is_empty() || self.decimal_separator().is_some() {
+            Err(Error::NaN)
+        } else {
+            self.dec_part.push(decimals.as_bytes())
+        };
+        if status.is_ok() {
+            self.int_part.freeze();
+            self.dec_part.freeze();
+            self.dec_separator = Some(sep);
+            self.tail = true;
+        }
+        if let Some(before) = before {
+            let outcome = status
+                .clone()
+                .map(|()| format!("{}{sep}{}", self.int_part, self.dec_part));
+            self.record(phrase, Call::Apply, before, outcome);
+        }
+        status
+    }
+
+    /// Complete the number before the unit fraction tail `phrase` (see [`LangInterpreter::unit_fraction_tails`]).
+    fn push_unit_fraction_tail(&mut self, phrase: &str, den: u64) -> Result<(), Error> {
+        let before = self.calls.as_ref().map(|_| self.int_part.to_string());
+        let status = if self.int_part.is_empty() || self.decimal_separator().is_some() {
+            Err(Error::NaN)
+        } else {
+            Ok(())
+        };
+        if status.is_ok() {
+            self.int_part.freeze();
+            self.unit_fraction = Some((1, den));
+        }
+        if let Some(before) = before {
+            let outcome = status
+                .clone()
+                .map(|()| format!("{} 1/{den}", self.int_part));
+            self.record(phrase, Call::Apply, before, outcome);
+        }
+        status
+    }
+
     /// Return representation and value and reset itself.
     pub fn string_and_value(&mut self) -> (String, f64) {
-        let res = if !self.dec_part.is_empty() {
-            let sep = self.dec_separator.unwrap();
-            self.lang
-                .format_decimal_and_value(&self.int_part, &self.dec_part, sep)
+        if let MorphologicalMarker::Ordinal(affix) = self.int_part.marker
+            && self.ordinal_style != OrdinalStyle::Plain
+            && let Some(styled) =
+                self.lang
+                    .styled_ordinal(&self.int_part, affix, self.ordinal_style)
+        {
+            self.int_part.marker = MorphologicalMarker::Ordinal(styled);
+        }
+        if self.int_part.is_empty() && self.decimal_separator().is_some() {
+            // "point five"
+            self.int_part.put(b"0").unwrap();
+        }
+        if let Some(width) = self.decimal_width()
+            && self.decimal_separator().is_some()
+            && self.dec_part.len() < width
+        {
+            // "con cinco" is 05
+            let mut padded = DigitString::new();
+            for _ in self.dec_part.len()..width {
+                padded.put(b"0").unwrap();
+            }
+            padded.put(self.dec_part.to_string().as_bytes()).unwrap();
+            self.dec_part = padded;
+        }
+        let decimal = self.decimal_separator().map(|sep| (&self.dec_part, sep));
+        let mut res = if let Some((dec, sep)) = decimal {
+            self.lang.format_decimal_and_value(&self.int_part, dec, sep)
         } else {
             self.lang.format_and_value(&self.int_part)
         };
+        let marker = match self.int_part.marker {
+            MorphologicalMarker::Ordinal(_) if !self.keep_ordinal_markers => {
+                MorphologicalMarker::None
+            }
+            MorphologicalMarker::Fraction(_) if !self.keep_fraction_markers => {
+                MorphologicalMarker::None
+            }
+            marker => marker,
+        };
+        if decimal.is_none() && marker.is_none() && !self.int_part.marker.is_none() {
+            // the digits alone, with the value of the marked number
+            res.0 = self.lang.format_marked(&self.int_part, marker);
+        }
+        if let Some(formatter) = &self.formatter {
+            let digits = Digits {
+                int: &self.int_part,
+                decimal,
+                marker,
+                kind: self.kind(),
+                language: self.lang.language_code(),
+            };
+            if let Some(text) = formatter.format(&digits) {
+                res.0 = text;
+            }
+        }
+        if self.int_part.marker.is_fraction() && marker.is_none() {
+            // the digits are the denominator: "onceavo" is still 1/11
+            res.0 = format!("1/{}", res.0);
+        }
         self.reset();
         res
     }
 
     pub fn has_number(&self) -> bool {
-        !self.int_part.is_empty()
+        !self.int_part.is_empty() || self.decimal_separator().is_some()
     }
 
     pub fn is_ordinal(&self) -> bool {
         self.int_part.is_ordinal()
     }
+
+    /// The kind of the current number, as far as its words tell.
+    pub fn kind(&self) -> NumberKind {
+        if self.int_part.is_ordinal() {
+            NumberKind::Ordinal
+        } else if self.int_part.marker.is_fraction() {
+            NumberKind::Fraction
+        } else if self.currency {
+            NumberKind::Currency
+        } else if self.decimal_separator().is_some() {
+            NumberKind::Decimal
+        } else if self.int_part.is_year() {
+            NumberKind::Year
+        } else if self.int_part.has_leading_zeroes() {
+            NumberKind::Sequence
+        } else {
+            NumberKind::Cardinal
+        }
+    }
+
+    /// The current number is only a decimal separator so far, like "*point*" in "*point five*".
+    pub fn leads_with_separator(&self) -> bool {
+        self.int_part.is_empty() && self.dec_part.is_empty() && self.dec_separator.is_some()
+    }
+
+    /// The decimal separator of the current number, if it has a decimal part.
+    pub fn decimal_separator(&self) -> Option<char> {
+        self.dec_separator.filter(|_| !self.dec_part.is_empty())
+    }
+}
+
+impl<T: LangInterpreter> Clone for WordToDigitParser<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            int_part: self.int_part.clone(),
+            dec_part: self.dec_part.clone(),
+            dec_separator: self.dec_separator,
+            separator_word: self.separator_word.clone(),
+            lang: self.lang,
+            separators: self.separators.clone(),
+            leading_separator: self.leading_separator,
+            formatter: self.formatter.clone(),
+            ordinal_style: self.ordinal_style,
+            keep_ordinal_markers: self.keep_ordinal_markers,
+            keep_fraction_markers: self.keep_fraction_markers,
+            currency: self.currency,
+            tail: self.tail,
+            unit_fraction: self.unit_fraction,
+            calls: self.calls.as_ref().map(|_| Vec::new()),
+        }
+    }
 }
 
 /// Interpret the `text` as a integer number or ordinal, and translate it into digits.
@@ -92,7 +547,9 @@ pub fn text2digits<T: LangInterpreter>(text: &str, lang: &T) -> Result<String, E
 pub trait Token {
     /// The text of the word or symbol (e.g. punctuation) represented by this token
     fn text(&self) -> &str;
-    /// The lowercase representation of the word represented by this token
+    /// The lowercase representation of the word represented by this token.
+    ///
+    /// Mixed case words are lowercased again by the number finder, so it may be the text itself.
     fn text_lowercase(&self) -> &str;
     /**
     In some token streams (e.g. ASR output), there is no punctuation
@@ -158,6 +615,28 @@ pub trait Token {
     fn not_a_number_part(&self) -> bool {
         false
     }
+    /// The token is a number already written in digits, like "*1,234.56*".
+    ///
+    /// Such tokens are opaque: they are never modified, nor merged into a spelled number,
+    /// but they end the spelled number that precedes them, and a scale word may multiply them
+    /// (see [`Options::digits_with_scales`]).
+    /// The default is false, so that the digit tokens go through the number finder like any other token.
+    /// The tokens of this crate are digits if they start with a digit.
+    fn is_digits(&self) -> bool {
+        false
+    }
+    /// The token is the number part of a word cut by [`Options::salvage_partial_groups`], like "*twenty-one*"
+    /// in "*twenty-one-porridge*": the number made of it is [`Occurence::partial`].
+    fn is_partial(&self) -> bool {
+        false
+    }
+    /// The token is markup, like an HTML tag: it is skipped like a space, even inside a number
+    /// (see [`Options::markup`]).
+    ///
+    /// The markup inside a number is among the tokens handed to [`Replace::replace`].
+    fn is_markup(&self) -> bool {
+        false
+    }
 }
 
 pub trait Replace {
@@ -181,6 +660,10 @@ impl Token for &BasicToken {
     fn not_a_number_part(&self) -> bool {
         self.nan
     }
+
+    fn is_digits(&self) -> bool {
+        starts_with_digit(self.text())
+    }
 }
 
 impl Replace for BasicToken {
@@ -203,6 +686,76 @@ impl BasicAnnotate for BasicToken {
     }
 }
 
+impl Token for TextSpan<'_> {
+    fn text(&self) -> &str {
+        self.text
+    }
+
+    fn text_lowercase(&self) -> &str {
+        self.lowercase()
+    }
+
+    fn nt_separated(&self, _previous: &Self) -> bool {
+        false
+    }
+
+    fn not_a_number_part(&self) -> bool {
+        self.nan
+    }
+
+    fn is_digits(&self) -> bool {
+        starts_with_digit(self.text)
+    }
+
+    fn is_partial(&self) -> bool {
+        self.partial
+    }
+
+    fn is_markup(&self) -> bool {
+        self.markup
+    }
+}
+
+impl Token for &TextSpan<'_> {
+    fn text(&self) -> &str {
+        self.text
+    }
+
+    fn text_lowercase(&self) -> &str {
+        self.lowercase()
+    }
+
+    fn nt_separated(&self, _previous: &Self) -> bool {
+        false
+    }
+
+    fn not_a_number_part(&self) -> bool {
+        self.nan
+    }
+
+    fn is_digits(&self) -> bool {
+        starts_with_digit(self.text())
+    }
+
+    fn is_partial(&self) -> bool {
+        self.partial
+    }
+
+    fn is_markup(&self) -> bool {
+        self.markup
+    }
+}
+
+impl BasicAnnotate for TextSpan<'_> {
+    fn text_lowercase(&self) -> &str {
+        self.lowercase()
+    }
+
+    fn set_nan(&mut self, val: bool) {
+        self.nan = val
+    }
+}
+
 #[derive(Debug)]
 /// This type describes a number found in a token stream.
 pub struct Occurence {
@@ -210,13 +763,69 @@ pub struct Occurence {
     pub start: usize,
     /// The offset after the last token representing the number in the stream
     pub end: usize,
+    /// The byte offsets of the number in the text made of all the tokens (see [`Occurence::original`])
+    pub range: Range<usize>,
     /// The digit representation of the number
     pub text: String,
     /// The value of the number. If the number is an ordinal, the value
     /// is the rank it represents.
+    ///
+    /// A minus sign written just before a cardinal ("*-twenty*", "*−twenty*" or "*–twenty*")
+    /// is part of the number: the value is negative and the digits start with "-".
     pub value: f64,
     /// A flag to distinguish ordinals
     pub is_ordinal: bool,
+    /// What the number is, as found by the detection path that produced it
+    pub kind: NumberKind,
+    /// The number of words of the number, linking words and decimal separators included
+    /// (see [`Options::min_word_count`])
+    pub words: usize,
+    /// The symbol of the decimal separator, for decimal numbers
+    pub decimal_separator: Option<char>,
+    /// The number was recovered from a disfluency (see [`Options::collapse_stutters`])
+    pub repaired: bool,
+    /// The clitic stripped from the last word of the number, as written, like "*'s*" in "*twenty's*"
+    /// (see [`Options::strip_clitics`]). It is kept after the digits.
+    pub clitic: Option<String>,
+    /// The number was salvaged from a word that is not a number as a whole, like "*twenty-one*"
+    /// in "*twenty-one-porridge*" (see [`Options::salvage_partial_groups`])
+    pub partial: bool,
+    /// The number is the second number of a score, whose first number is the previous occurence,
+    /// like "*two*" in "*three to two*" (see [`Options::scores`])
+    pub score: bool,
+    /// The number is a digit sequence next to another one that reads it back with other digits, like
+    /// "*four five one two — four five one three*"
+    /// (see [`verify_repeated_sequences`](crate::readback::verify_repeated_sequences))
+    pub readback_mismatch: bool,
+    /// What the words of the number told besides its digits, if anything
+    pub morphology: Option<Morphology>,
+    /// The linking and noise words inside the number, as written, with their byte offsets,
+    /// like "*and*" in "*two hundred and five*"
+    pub linking_words: Vec<(Range<usize>, String)>,
+    /// The code of the language that read the number (see [`LangInterpreter::language_code`]),
+    /// to tell the numbers apart when several languages are run on the same text
+    pub lang_code: &'static str,
+}
+
+/// The morphology of the words of a number, as the parser read them (see [`Occurence::morphology`]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Morphology {
+    /// The marker of the number, like the ordinal marker of "*third*", before any [`OrdinalStyle`]
+    pub marker: MorphologicalMarker,
+    /// The denominator of a fraction, like `3` in "*two thirds*"
+    pub denominator: Option<u64>,
+    /// The word read as the decimal separator, lowercase, like "*point*" in "*three point five*"
+    pub separator_word: Option<String>,
+}
+
+impl Occurence {
+    /// Return the original text of the number in `source`, separators included.
+    ///
+    /// `source` is the text the tokens were taken from: it must be covered by the tokens, in order and without gaps,
+    /// as the tokens of [`replace_numbers_in_text`] are.
+    pub fn original<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.range.clone()]
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -233,12 +842,14 @@ impl MatchKind {
 }
 
 #[derive(Debug)]
-struct NumTracker {
+pub(crate) struct NumTracker {
     matches: VecDeque<Occurence>,
     on_hold: Option<Occurence>,
     last_contiguous_match: MatchKind,
     match_start: usize,
     match_end: usize,
+    byte_start: usize,
+    byte_end: usize,
 }
 
 impl NumTracker {
@@ -249,31 +860,37 @@ impl NumTracker {
             last_contiguous_match: MatchKind::None,
             match_start: 0,
             match_end: 0,
+            byte_start: 0,
+            byte_end: 0,
         }
     }
 
-    fn number_advanced(&mut self, pos: usize) {
+    fn number_advanced(&mut self, pos: usize, bytes: &Range<usize>) {
         if self.match_start == self.match_end {
-            self.match_start = pos
+            self.match_start = pos;
+            self.byte_start = bytes.start;
         }
         self.match_end = pos + 1;
+        self.byte_end = bytes.end;
     }
 
-    fn number_end(
-        &mut self,
-        is_ordinal: bool,
-        digits: String,
-        value: f64,
-        forget_if_isolate: bool,
-    ) {
-        let occurence = Occurence {
-            start: self.match_start,
-            end: self.match_end,
-            text: digits,
-            is_ordinal,
-            value,
-        };
-        let kind = if is_ordinal {
+    fn in_number(&self) -> bool {
+        self.match_start != self.match_end
+    }
+
+    /// Return the token span and byte range of the current number, and start a new one.
+    fn take_span(&mut self) -> (usize, usize, Range<usize>) {
+        let span = (
+            self.match_start,
+            self.match_end,
+            self.byte_start..self.byte_end,
+        );
+        self.match_start = self.match_end;
+        span
+    }
+
+    fn number_end(&mut self, occurence: Occurence, forget_if_isolate: bool) {
+        let kind = if occurence.is_ordinal {
             MatchKind::Ordinal
         } else {
             MatchKind::Cardinal
@@ -294,7 +911,13 @@ impl NumTracker {
         }
         //
         self.last_contiguous_match = kind;
-        self.match_start = self.match_end;
+    }
+
+    /// Keep the number on hold, as if another number followed it.
+    fn release_hold(&mut self) {
+        if let Some(occurence) = self.on_hold.take() {
+            self.matches.push_back(occurence);
+        }
     }
 
     fn sequence_breaker(&mut self) {
@@ -319,11 +942,58 @@ impl NumTracker {
         }
     }
 
-    fn into_vec(self) -> Vec<Occurence> {
+    pub(crate) fn into_vec(self) -> Vec<Occurence> {
         self.matches.into()
     }
 }
 
+/// A tentatively collapsed stutter, waiting for confirmation.
+struct Stutter<'a, L: LangInterpreter> {
+    /// Parser state as if there were no repair
+    fallback: WordToDigitParser<'a, L>,
+    /// Where the number ends if there is no repair (token and byte offsets)
+    fallback_end: (usize, usize),
+    /// Position of the repeated word
+    pos: usize,
+    bytes: Range<usize>,
+    word: String,
+}
+
+/// An informal quantity frame ("*a pair of shoes*"), waiting for its plural noun.
+struct Quantity<T> {
+    value: u64,
+    /// Position of the determiner
+    start: usize,
+    byte_start: usize,
+    /// The linker word, with its position and byte range
+    linker: Option<(String, usize, Range<usize>)>,
+    /// The tokens of the frame from the numeral noun on, to replay if the frame is not completed
+    held: Vec<(usize, T, Range<usize>)>,
+}
+
+/// A spoken fraction after a complete cardinal ("*three over four*"), waiting for its denominator.
+struct SpokenFraction<'a, L: LangInterpreter, T> {
+    /// The words of the connector seen so far, separated by single spaces
+    connector: String,
+    denominator: WordToDigitParser<'a, L>,
+    /// The words of the connector and of the denominator, up to its last number word
+    words: usize,
+    /// The words read since the start of the connector
+    pending_words: usize,
+    /// The number of held tokens up to the last word of the denominator
+    end: usize,
+    /// The tokens from the connector on, to replay if there is no denominator
+    held: Vec<(usize, T, Range<usize>)>,
+}
+
+/// A phrase after a complete cardinal that may add a simple fraction to it ("*et demi*"), waiting for its last word.
+struct FractionTail<T> {
+    /// The words of the phrase seen so far, separated by single spaces
+    phrase: String,
+    /// The tokens from the start of the phrase on, to replay if it is not completed
+    held: Vec<(usize, T, Range<usize>)>,
+}
+
 /// An Iterator that yields all the number occurences found in a token stream for a given language.
 /// It lazily consumes the token stream.
 pub struct FindNumbers<'a, L, T, I>
@@ -337,7 +1007,98 @@ where
     parser: WordToDigitParser<'a, L>,
     tracker: NumTracker,
     previous: Option<T>,
-    threshold: f64,
+    options: Options,
+    /// Last word successfully applied and the parser state before it (only tracked to collapse stutters)
+    last_word: Option<(String, WordToDigitParser<'a, L>)>,
+    stutter: Option<Stutter<'a, L>>,
+    repaired: bool,
+    /// The current number has a word salvaged from a compound (see [`Options::salvage_partial_groups`])
+    partial: bool,
+    /// Adjacent numbers waiting for the [`AdjacentNumberPolicy`] to decide whether they merge
+    run: Vec<Occurence>,
+    /// A score connector follows the only number of the run (only tracked if [`Options::scores`] is set)
+    run_connector: bool,
+    /// The current run was given up, as it would merge into a number too long (see [`Options::max_digits`]):
+    /// the rest of it is dropped
+    run_too_long: bool,
+    /// Some significant token was seen since the end of the last number
+    gap: bool,
+    /// The current number immediately follows the previous one
+    starts_adjacent: bool,
+    /// The clitic stripped from the last word of the current number, as written
+    clitic: Option<String>,
+    /// An ordinal following a cardinal, that makes a fraction if the next word is a fraction context:
+    /// its position, token, byte range and denominator value
+    fraction: Option<(usize, T, Range<usize>, u64)>,
+    /// The denominator of the current number, if it is a fraction
+    denominator: Option<u64>,
+    /// Number of words in the current number (see [`Options::max_tokens`])
+    words: usize,
+    /// Number of words of the current number up to its last number word, without the pending linking words
+    number_words: usize,
+    /// The linking words of the current number, and the ones pending after its last number word
+    linking_words: Vec<(Range<usize>, String)>,
+    pending_linking_words: Vec<(Range<usize>, String)>,
+    /// The number of numbers given up (see [`Stats::aborted`])
+    aborted: usize,
+    /// The decision of [`LangInterpreter::lone_number_context`] on the current number, given the word that ends it
+    lone_context: Option<bool>,
+    /// The word before the current number, lowercase, or empty if there is none.
+    /// Its buffer is reused from word to word.
+    word_before: String,
+    /// The current number is a pronoun (see [`LangInterpreter::is_pronoun`])
+    pronoun: bool,
+    /// The last token outside of a number is a month name (see [`LangInterpreter::is_month_name`])
+    after_month: bool,
+    /// The word that ends the current number is a month name
+    before_month: bool,
+    /// The byte offset of the last ordinal day written as a cardinal (see [`LangInterpreter::is_cardinal_day`])
+    cardinal_day: Option<usize>,
+    /// The last number is an ordinal held under the threshold, that a month name would keep,
+    /// and a date connector was seen since
+    date_hold: Option<bool>,
+    /// The words of a range connector seen since the last number, if it is an ordinal
+    /// (see [`LangInterpreter::range_connectors`])
+    range_phrase: Option<String>,
+    /// The last tokens outside of a number are a complete range connector after an ordinal
+    after_range: bool,
+    /// The current number was given up as too long; its remaining words are skipped
+    too_long: bool,
+    /// Byte offset of the next token
+    offset: usize,
+    /// Byte range of the current token
+    token_bytes: Range<usize>,
+    /// Pending informal quantity frame (only tracked if [`Options::informal_quantities`] is set)
+    quantity: Option<Quantity<T>>,
+    /// Pending fraction tail (see [`LangInterpreter::fraction_tails`])
+    fraction_tail: Option<FractionTail<T>>,
+    /// Pending spoken fraction (only tracked if [`Options::spoken_fractions`] is set)
+    spoken_fraction: Option<SpokenFraction<'a, L, T>>,
+    /// Last significant word, with its position and byte range (only tracked for informal quantities)
+    last_significant: Option<(String, usize, Range<usize>)>,
+    /// The previous token is a word (so that a following "-" is a hyphen, not a sign)
+    after_word: bool,
+    /// The next word starts a sentence (see [`Options::skip_sentence_initial`])
+    sentence_start: bool,
+    /// The positions of the first word of the current sentence and of the number words that follow it,
+    /// up to the first other word (open-ended until then)
+    sentence_initial: Option<Range<usize>>,
+    /// Position and byte range of the decimal separator that starts the current number, if nothing follows it yet
+    /// (see [`Options::leading_decimal_separator`])
+    leading_separator: Option<(usize, Range<usize>)>,
+    /// Position, byte range and text of the digits of the previous token, that a scale word may multiply
+    /// (only tracked if [`Options::digits_with_scales`] is set)
+    leading_digits: Option<(usize, Range<usize>, String)>,
+    /// The previous word is a vague quantifier, or a scale word of a vague quantity, like "*hundred*" in "*a few hundred*"
+    vague: bool,
+    /// Position and byte offset of the last minus sign
+    sign: Option<(usize, usize)>,
+    /// Position and byte offset of the minus sign of the current number
+    signed: Option<(usize, usize)>,
+    /// Position of the token being processed
+    pos: usize,
+    /// The events of the interpretation, if traced
+    trace: Option<Vec<TraceEvent>>,
 }
 
 impl<'a, L, T, I> FindNumbers<'a, L, T, I>
@@ -346,193 +1107,2617 @@ where
     T: Token,
     I: Iterator<Item = (usize, T)>,
 {
-    fn new(input: I, lang: &'a L, threshold: f64) -> Self {
+    pub(crate) fn new(input: I, lang: &'a L, options: &Options) -> Self {
         Self {
             lang,
             input,
-            parser: WordToDigitParser::new(lang),
+            parser: WordToDigitParser::new(lang, options),
             tracker: NumTracker::new(),
             previous: None,
-            threshold,
+            options: options.clone(),
+            last_word: None,
+            stutter: None,
+            repaired: false,
+            partial: false,
+            run: Vec::new(),
+            run_connector: false,
+            run_too_long: false,
+            gap: true,
+            starts_adjacent: false,
+            clitic: None,
+            fraction: None,
+            denominator: None,
+            words: 0,
+            number_words: 0,
+            linking_words: Vec::new(),
+            pending_linking_words: Vec::new(),
+            aborted: 0,
+            lone_context: None,
+            word_before: String::new(),
+            pronoun: false,
+            after_month: false,
+            before_month: false,
+            cardinal_day: None,
+            date_hold: None,
+            range_phrase: None,
+            after_range: false,
+            too_long: false,
+            offset: 0,
+            token_bytes: 0..0,
+            quantity: None,
+            fraction_tail: None,
+            spoken_fraction: None,
+            last_significant: None,
+            after_word: false,
+            sentence_start: true,
+            sentence_initial: None,
+            leading_separator: None,
+            leading_digits: None,
+            vague: false,
+            sign: None,
+            signed: None,
+            pos: 0,
+            trace: None,
         }
     }
 
-    fn push(&mut self, pos: usize, token: T) {
-        if token.text() == "-" || is_whitespace(token.text()) {
+    /// Record the events of the interpretation, to be collected with [`take_trace`](Self::take_trace).
+    fn enable_trace(&mut self) {
+        self.parser.enable_trace();
+        self.trace = Some(Vec::new());
+    }
+
+    fn take_trace(&mut self) -> Trace {
+        Trace {
+            events: self.trace.take().unwrap_or_default(),
+        }
+    }
+
+    fn trace_group(&mut self, action: GroupAction, reason: &'static str) {
+        if action == GroupAction::Aborted {
+            self.aborted += 1;
+        }
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraceEvent::Group {
+                pos: self.pos,
+                action,
+                reason,
+            });
+        }
+    }
+
+    /// Push `word` to the parser, tracing the language calls.
+    fn parse(&mut self, word: &str) -> Result<(), Error> {
+        let status = self.parser.push(word);
+        if let Some(trace) = &mut self.trace {
+            trace.extend(self.parser.take_calls(self.pos));
+        }
+        status
+    }
+
+    fn feed(&mut self, pos: usize, token: T) {
+        self.pos = pos;
+        let start = self.offset;
+        self.offset += token.text().len();
+        self.token_bytes = start..self.offset;
+        if !self.after_word && is_minus_sign(token.text()) {
+            self.sign = Some((pos, start));
+        }
+        if !token.is_markup() {
+            self.after_word = token.text().chars().any(char::is_alphanumeric);
+        }
+        self.follow_sentences(pos, &token);
+        self.feed_fraction_tail(pos, token)
+    }
+
+    /// Remember the first words of the sentence of `token` that may be numbers (see [`Options::skip_sentence_initial`]).
+    fn follow_sentences(&mut self, pos: usize, token: &T) {
+        let text = token.text();
+        if is_blank(token)
+            || text
+                .chars()
+                .all(|c| c.is_whitespace() || is_quote_or_bracket(c))
+        {
             return;
         }
-        if token.not_a_number_part() {
-            if self.parser.has_number() {
-                self.number_end()
+        if self.sentence_start {
+            self.sentence_initial = Some(pos..usize::MAX);
+        }
+        if let Some(initial) = &mut self.sentence_initial
+            && initial.end == usize::MAX
+            && text.chars().any(char::is_alphanumeric)
+            && !token.is_digits()
+            && !starts_with_digit(text)
+        {
+            let word = tokenizer::lowercase(token.text_lowercase());
+            if !self.lang.can_start_number(&word) && !self.lang.can_continue(&word) {
+                // the first word is kept, as the number finder may read words the queries don't know
+                initial.end = pos.max(initial.start + 1);
             }
-            self.outside_number(&token);
-            self.previous.replace(token);
-            return;
         }
-        let lo_token = token.text_lowercase();
-        let test = if let Some(ref prev) = self.previous {
-            if self.parser.has_number() && token.nt_separated(prev) {
-                "," // force stop without loosing token (see below)
+        self.sentence_start = is_sentence_end(text);
+    }
+
+    /// Match the fraction tails before passing the tokens on.
+    fn feed_fraction_tail(&mut self, pos: usize, token: T) {
+        let bytes = self.token_bytes.clone();
+        let Some(mut tail) = self.fraction_tail.take() else {
+            let word = tokenizer::lowercase(token.text_lowercase());
+            // no pending linking word
+            if self.has_numerator()
+                && self.fraction.is_none()
+                && self.spoken_fraction.is_none()
+                && self.words == self.number_words
+                && !token.not_a_number_part()
+                && self.starts_fraction_tail(&word)
+            {
+                self.trace_group(
+                    GroupAction::Pending,
+                    "fraction tail after a cardinal: a decimal number if it is complete",
+                );
+                self.fraction_tail = Some(FractionTail {
+                    phrase: word.into_owned(),
+                    held: vec![(pos, token, bytes)],
+                });
             } else {
-                lo_token
+                self.feed_number(pos, token);
             }
-        } else {
-            lo_token
+            return;
         };
-        match self.parser.push(test) {
-            // Set match_start on first successful parse
-            Ok(()) => self.tracker.number_advanced(pos),
-            // Skip potential linking words
-            Err(Error::Incomplete) => (),
-            // First failed parse after one or more successful ones:
-            // we reached the end of a number.
-            Err(_) if self.parser.has_number() => {
-                self.number_end();
-                // The end of that match may be the start of another
-                if self.parser.push(lo_token).is_ok() {
-                    self.tracker.number_advanced(pos);
-                } else {
-                    self.outside_number(&token)
-                }
+        if is_hyphen(token.text()) || is_blank(&token) {
+            tail.held.push((pos, token, bytes));
+            self.fraction_tail = Some(tail);
+            return;
+        }
+        let word = tokenizer::lowercase(token.text_lowercase());
+        let phrase = format!("{} {word}", tail.phrase);
+        if token.not_a_number_part() || !self.starts_fraction_tail(&phrase) {
+            self.replay_fraction_tail(tail.held);
+            self.token_bytes = bytes;
+            self.feed_number(pos, token);
+            return;
+        }
+        let decimals = self
+            .lang
+            .fraction_tails()
+            .iter()
+            .find(|(known, _, _)| *known == phrase);
+        let unit = self
+            .lang
+            .unit_fraction_tails()
+            .iter()
+            .find(|(known, _)| *known == phrase);
+        let status = match (decimals, unit) {
+            (Some(&(_, sep, decimals)), _) => {
+                self.parser.push_fraction_tail(&phrase, sep, decimals)
             }
-            Err(_) => self.outside_number(&token),
+            (None, Some(&(_, den))) => self.parser.push_unit_fraction_tail(&phrase, den),
+            (None, None) => {
+                tail.phrase = phrase;
+                tail.held.push((pos, token, bytes));
+                self.fraction_tail = Some(tail);
+                return;
+            }
+        };
+        self.pos = pos;
+        if status.is_err() {
+            self.replay_fraction_tail(tail.held);
+            self.token_bytes = bytes;
+            self.feed_number(pos, token);
+            return;
         }
+        if let Some(trace) = &mut self.trace {
+            trace.extend(self.parser.take_calls(pos));
+        }
+        self.words += phrase.split(' ').count();
+        self.last_word = None;
+        self.number_advanced(pos, bytes);
         self.previous.replace(token);
     }
 
-    fn finalize(&mut self) {
-        if self.parser.has_number() {
-            self.number_end()
-        }
+    /// `phrase` is a fraction tail, or its first words.
+    fn starts_fraction_tail(&self, phrase: &str) -> bool {
+        let starts = |known: &str| starts_with_phrase(known, phrase, ' ');
+        self.lang
+            .fraction_tails()
+            .iter()
+            .any(|(known, _, _)| starts(known))
+            || self
+                .lang
+                .unit_fraction_tails()
+                .iter()
+                .any(|(known, _)| starts(known))
     }
 
-    fn number_end(&mut self) {
-        let is_ordinal = self.parser.is_ordinal();
-        let (digits, value) = self.parser.string_and_value();
-        let forget_if_isolate = (digits.len() == 1 || is_ordinal) && value < self.threshold;
-        self.tracker
-            .number_end(is_ordinal, digits, value, forget_if_isolate);
+    fn replay_fraction_tail(&mut self, held: Vec<(usize, T, Range<usize>)>) {
+        for (pos, token, bytes) in held {
+            self.token_bytes = bytes;
+            self.feed_number(pos, token);
+        }
     }
 
-    fn outside_number(&mut self, token: &T) {
-        let text = token.text();
-        if !(text.chars().all(|c| !c.is_alphabetic()) && text.trim() != "."
-            || self.lang.is_linking(text))
-        {
-            self.tracker.sequence_breaker()
-        };
+    fn feed_number(&mut self, pos: usize, token: T) {
+        if self.options.spoken_fractions {
+            self.feed_spoken_fraction(pos, token)
+        } else {
+            self.forward(pos, token)
+        }
     }
 
-    fn track_numbers(mut self) -> NumTracker {
-        while let Some((pos, token)) = self.input.next() {
-            self.push(pos, token);
+    fn forward(&mut self, pos: usize, token: T) {
+        if self.options.informal_quantities {
+            self.feed_quantity(pos, token)
+        } else {
+            self.push(pos, token)
         }
-        self.finalize();
-        self.tracker
     }
-}
-
-impl<L, T, I> Iterator for FindNumbers<'_, L, T, I>
-where
-    L: LangInterpreter,
-    T: Token,
-    I: Iterator<Item = (usize, T)>,
-{
-    type Item = Occurence;
 
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Match the spoken fractions before forwarding the tokens.
+    fn feed_spoken_fraction(&mut self, pos: usize, token: T) {
+        let bytes = self.token_bytes.clone();
+        let Some(mut fraction) = self.spoken_fraction.take() else {
+            let word = tokenizer::lowercase(token.text_lowercase());
+            // no pending linking word
+            if self.has_numerator()
+                && self.fraction.is_none()
+                && self.words == self.number_words
+                && !token.not_a_number_part()
+                && self.starts_fraction_connector(&word)
+            {
+                self.trace_group(
+                    GroupAction::Pending,
+                    "fraction connector after a cardinal: a fraction if a cardinal follows",
+                );
+                self.spoken_fraction = Some(SpokenFraction {
+                    connector: word.into_owned(),
+                    denominator: WordToDigitParser::new(self.lang, &self.options),
+                    words: 0,
+                    pending_words: 1,
+                    end: 0,
+                    held: vec![(pos, token, bytes)],
+                });
+            } else {
+                self.forward(pos, token);
+            }
+            return;
+        };
+        if is_hyphen(token.text()) || is_blank(&token) {
+            fraction.held.push((pos, token, bytes));
+            self.spoken_fraction = Some(fraction);
+            return;
+        }
+        let word = tokenizer::lowercase(token.text_lowercase());
+        let connectors = self.lang.fraction_connectors();
+        if !connectors.contains(&fraction.connector.as_str()) {
+            // the connector goes on: "out of"
+            let connector = format!("{} {word}", fraction.connector);
+            if token.not_a_number_part() || !self.starts_fraction_connector(&connector) {
+                self.replay_spoken_fraction(fraction.held);
+                self.forward(pos, token);
+                return;
+            }
+            fraction.connector = connector;
+            fraction.pending_words += 1;
+            fraction.held.push((pos, token, bytes));
+            self.spoken_fraction = Some(fraction);
+            return;
+        }
+        let status = if token.not_a_number_part() || token.is_digits() {
+            Err(Error::NaN)
+        } else {
+            fraction.denominator.push(&word)
+        };
+        match status {
+            Ok(()) | Err(Error::Incomplete) => {
+                fraction.pending_words += 1;
+                fraction.held.push((pos, token, bytes));
+                if status.is_ok() {
+                    fraction.words = fraction.pending_words;
+                    fraction.end = fraction.held.len();
+                }
+                self.spoken_fraction = Some(fraction);
+            }
+            Err(_) => {
+                self.spoken_fraction_end(fraction);
+                self.token_bytes = bytes;
+                self.forward(pos, token);
+            }
+        }
+    }
+
+    /// `connector` is a fraction connector, or its first words.
+    fn starts_fraction_connector(&self, connector: &str) -> bool {
+        self.lang
+            .fraction_connectors()
+            .iter()
+            .any(|known| starts_with_phrase(known, connector, ' '))
+    }
+
+    /// End the current number as the numerator of the spoken `fraction`, if its denominator is a cardinal,
+    /// or replay the tokens of `fraction`.
+    fn spoken_fraction_end(&mut self, mut fraction: SpokenFraction<'a, L, T>) {
+        let parser = &mut fraction.denominator;
+        let complete = parser.has_number()
+            && !parser.is_ordinal()
+            && parser.decimal_separator().is_none()
+            && !parser.int_part.marker.is_fraction();
+        let den = complete
+            .then(|| parser.string_and_value().1 as u64)
+            .filter(|&den| den > 0);
+        let Some(den) = den else {
+            self.trace_group(
+                GroupAction::Aborted,
+                "no cardinal after the fraction connector",
+            );
+            self.replay_spoken_fraction(fraction.held);
+            return;
+        };
+        let trailing = fraction.held.split_off(fraction.end);
+        let (pos, token, bytes) = fraction.held.pop().unwrap();
+        self.pos = pos;
+        self.tracker.number_advanced(pos, &bytes);
+        self.number_words += fraction.words;
+        self.words = self.number_words;
+        self.denominator = Some(den);
+        self.number_end("spoken fraction");
+        self.previous.replace(token);
+        self.replay_spoken_fraction(trailing);
+    }
+
+    fn replay_spoken_fraction(&mut self, held: Vec<(usize, T, Range<usize>)>) {
+        for (pos, token, bytes) in held {
+            self.token_bytes = bytes;
+            self.forward(pos, token);
+        }
+    }
+
+    /// Match the informal quantity frames before pushing the tokens.
+    fn feed_quantity(&mut self, pos: usize, token: T) {
+        let bytes = self.token_bytes.clone();
+        if is_hyphen(token.text()) || is_blank(&token) {
+            match &mut self.quantity {
+                Some(quantity) => quantity.held.push((pos, token, bytes)),
+                None => self.push(pos, token),
+            }
+            return;
+        }
+        let word = tokenizer::lowercase(token.text_lowercase()).into_owned();
+        if let Some(mut quantity) = self.quantity.take() {
+            match quantity.linker {
+                None => {
+                    quantity.linker = Some((word, pos, bytes.clone()));
+                    quantity.held.push((pos, token, bytes));
+                    self.quantity = Some(quantity);
+                    return;
+                }
+                Some((ref linker, end, ref linker_bytes))
+                    if self.lang.completes_informal_quantity(linker, &word) =>
+                {
+                    self.quantity_end(&quantity, end + 1, linker_bytes.end);
+                }
+                Some(_) => {
+                    for (pos, token, bytes) in quantity.held {
+                        self.token_bytes = bytes;
+                        self.push(pos, token);
+                    }
+                    self.token_bytes = bytes.clone();
+                }
+            }
+        } else if !token.not_a_number_part()
+            && let Some((determiner, start, determiner_bytes)) = &self.last_significant
+            // the determiner may have been read as a number, but only on its own
+            && (!self.parser.has_number() || self.tracker.match_start == *start)
+            && let Some(value) = self.lang.informal_quantity(determiner, &word)
+        {
+            self.quantity = Some(Quantity {
+                value,
+                start: *start,
+                byte_start: determiner_bytes.start,
+                linker: None,
+                held: vec![(pos, token, bytes.clone())],
+            });
+            self.last_significant = Some((word, pos, bytes));
+            return;
+        }
+        self.last_significant = Some((word, pos, bytes));
+        self.push(pos, token);
+    }
+
+    /// Emit the completed informal quantity, that ends before token `end` and byte `byte_end`.
+    fn quantity_end(&mut self, quantity: &Quantity<T>, end: usize, byte_end: usize) {
+        if self.parser.has_number() {
+            // forget the determiner read as a number
+            self.parser.reset();
+            self.tracker.take_span();
+            self.stutter = None;
+            self.last_word = None;
+            self.repaired = false;
+            self.partial = false;
+        }
+        self.break_run();
+        let occurence = Occurence {
+            start: quantity.start,
+            end,
+            range: quantity.byte_start..byte_end,
+            text: quantity.value.to_string(),
+            value: quantity.value as f64,
+            is_ordinal: false,
+            kind: NumberKind::Cardinal,
+            words: 2 + quantity.linker.is_some() as usize,
+            decimal_separator: None,
+            repaired: false,
+            clitic: None,
+            partial: false,
+            score: false,
+            readback_mismatch: false,
+            morphology: None,
+            linking_words: Vec::new(),
+            lang_code: self.lang.language_code(),
+        };
+        self.trace_group(
+            GroupAction::Committed(occurence.text.clone()),
+            "informal quantity",
+        );
+        self.commit(occurence, false);
+        self.gap = false;
+    }
+
+    fn push(&mut self, pos: usize, token: T) {
+        let is_sign = self.sign.is_some_and(|(sign_pos, _)| sign_pos == pos);
+        if is_hyphen(token.text()) && !is_sign || is_blank(&token) {
+            return;
+        }
+        if let Some((den_pos, den_token, den_bytes, den)) = self.fraction.take() {
+            self.pos = den_pos;
+            if !token.not_a_number_part()
+                && self
+                    .lang
+                    .is_fraction_context(&tokenizer::lowercase(token.text_lowercase()))
+            {
+                self.fraction_end(den_pos, den_bytes, den);
+            } else {
+                // the ordinal reading
+                let bytes = core::mem::replace(&mut self.token_bytes, den_bytes);
+                self.restart_number(den_pos, &den_token, false);
+                self.token_bytes = bytes;
+            }
+            self.previous.replace(den_token);
+        }
+        self.pos = pos;
+        let leading_digits = self.leading_digits.take();
+        if token.not_a_number_part() || token.is_digits() || is_sign {
+            self.vague = false;
+            if self.parser.has_number() {
+                self.check_pronoun(Some(&tokenizer::lowercase(token.text_lowercase())));
+                self.before_month = self.lang.is_month_name(token.text());
+                self.number_end("not a number word")
+            }
+            if self.options.digits_with_scales
+                && token.is_digits()
+                && !token.text().starts_with('0')
+                && token.text().bytes().all(|c| c.is_ascii_digit())
+            {
+                self.leading_digits = Some((pos, self.token_bytes.clone(), token.text().into()));
+            }
+            self.outside_number(&token);
+            self.previous.replace(token);
+            return;
+        }
+        let lowercase = tokenizer::lowercase(token.text_lowercase());
+        let lo_token = lowercase.as_ref();
+        if self.too_long {
+            if matches!(
+                self.lang.token_kind(lo_token),
+                TokenKind::Other | TokenKind::Separator
+            ) {
+                self.too_long = false;
+            } else {
+                self.previous.replace(token);
+                return;
+            }
+        }
+        let vague = core::mem::replace(&mut self.vague, self.lang.is_vague_quantifier(lo_token));
+        // the scale words of a vague quantity are no numbers: "a few hundred thousand people"
+        if vague && !self.parser.has_number() && is_scale_word(self.lang, lo_token) {
+            self.vague = true;
+            self.outside_number(&token);
+            self.previous.replace(token);
+            return;
+        }
+        if let Some((digits_pos, digits_bytes, digits)) = leading_digits
+            && !self.parser.has_number()
+            && scales_digits(self.lang, &digits, lo_token)
+            && self.parser.int_part.put(digits.as_bytes()).is_ok()
+        {
+            // "3 thousand": the digits start the number of the scale word
+            self.words += 1;
+            self.number_advanced(digits_pos, digits_bytes);
+        }
+        if self.options.adverbial_ordinals
+            && let Some(rank) = self.lang.adverbial_ordinal(lo_token)
+        {
+            if self.parser.has_number() {
+                self.number_end("adverbial ordinal")
+            }
+            self.break_run();
+            self.tracker.number_advanced(pos, &self.token_bytes);
+            let (start, end, range) = self.tracker.take_span();
+            let occurence = Occurence {
+                start,
+                end,
+                range,
+                text: if self.options.keep_ordinal_markers {
+                    self.lang.format_adverbial_ordinal(rank)
+                } else {
+                    rank.to_string()
+                },
+                value: rank as f64,
+                is_ordinal: true,
+                kind: NumberKind::Ordinal,
+                words: 1,
+                decimal_separator: None,
+                repaired: false,
+                clitic: None,
+                partial: false,
+                score: false,
+                readback_mismatch: false,
+                morphology: None,
+                linking_words: Vec::new(),
+                lang_code: self.lang.language_code(),
+            };
+            self.trace_group(
+                GroupAction::Committed(occurence.text.clone()),
+                "adverbial ordinal",
+            );
+            self.commit(occurence, false);
+            self.previous.replace(token);
+            return;
+        }
+        let test = if let Some(ref prev) = self.previous {
+            if self.parser.has_number() && token.nt_separated(prev) {
+                "," // force stop without loosing token (see below)
+            } else {
+                lo_token
+            }
+        } else {
+            lo_token
+        };
+        let before = self.options.collapse_stutters.then(|| self.parser.clone());
+        match self.parse(test) {
+            Err(Error::TooLong) => self.give_up_number(),
+            Ok(()) | Err(Error::Incomplete)
+                if self.parser.has_number() && self.words >= self.options.max_tokens =>
+            {
+                self.give_up_number()
+            }
+            // Set match_start on first successful parse
+            Ok(()) => {
+                if self.stutter.take().is_some() {
+                    self.repaired = true;
+                }
+                self.words += 1;
+                self.partial |= token.is_partial();
+                self.word_applied(test, before);
+                self.number_advanced(pos, self.token_bytes.clone())
+            }
+            // Skip potential linking words
+            Err(Error::Incomplete) => {
+                if self.parser.leads_with_separator() {
+                    self.leading_separator = Some((pos, self.token_bytes.clone()));
+                }
+                if self.parser.dec_separator.is_none() || !self.parser.dec_part.is_empty() {
+                    self.pending_linking_words
+                        .push((self.token_bytes.clone(), token.text().into()));
+                }
+                self.words += 1;
+                self.gap = true;
+                self.trace_group(GroupAction::Pending, "linking word or decimal separator");
+            }
+            Err(_)
+                if test == lo_token
+                    && self.options.strip_clitics
+                    && self.try_strip_clitic(pos, &token, lo_token) => {}
+            Err(_) if self.try_collapse_stutter(pos, test) => (),
+            Err(_) if self.stutter.is_some() => {
+                // the repeated word was not a stutter: reconsider the token
+                // against the number the repeated word starts.
+                self.unwind_stutter();
+                return self.push(pos, token);
+            }
+            // First failed parse after one or more successful ones:
+            // we reached the end of a number.
+            Err(_) if self.parser.has_number() => {
+                // A denominator ends the numerator before it. If it is also an ordinal,
+                // it depends on the next word.
+                if test == lo_token
+                    && self.has_numerator()
+                    && let Some(den) = self.lang.fraction_denominator(lo_token)
+                {
+                    if den.needs_context {
+                        self.trace_group(
+                            GroupAction::Pending,
+                            "ordinal after a cardinal: a denominator if a fraction context word follows",
+                        );
+                        self.fraction = Some((pos, token, self.token_bytes.clone(), den.value));
+                        return;
+                    }
+                    self.fraction_end(pos, self.token_bytes.clone(), den.value);
+                } else {
+                    if self.number_words == 1 {
+                        self.lone_context = self.previous.as_ref().and_then(|previous| {
+                            let number = tokenizer::lowercase(previous.text_lowercase());
+                            self.lang.lone_number_context(&number, lo_token)
+                        });
+                    }
+                    self.check_pronoun(Some(lo_token));
+                    self.before_month = self.lang.is_month_name(token.text());
+                    self.restart_number(pos, &token, test != lo_token)
+                }
+            }
+            Err(_) => self.outside_number(&token),
+        }
+        self.previous.replace(token);
+    }
+
+    /// End the current number before `token`, that may start another one.
+    fn restart_number(&mut self, pos: usize, token: &T, voice_pause: bool) {
+        self.number_end(if voice_pause {
+            "voice pause"
+        } else {
+            "the word does not continue the number"
+        });
+        if voice_pause {
+            self.break_run();
+        }
+        // The end of that match may be the start of another
+        let lowercase = tokenizer::lowercase(token.text_lowercase());
+        let lo_token = lowercase.as_ref();
+        let before = self.options.collapse_stutters.then(|| self.parser.clone());
+        if self.parse(lo_token).is_ok() {
+            self.words += 1;
+            self.partial |= token.is_partial();
+            self.word_applied(lo_token, before);
+            self.number_advanced(pos, self.token_bytes.clone());
+        } else {
+            self.outside_number(token)
+        }
+    }
+
+    /// The current number is a complete cardinal, that can be the numerator of a fraction.
+    fn has_numerator(&self) -> bool {
+        self.parser.has_number()
+            && !self.parser.is_ordinal()
+            && self.parser.decimal_separator().is_none()
+            && self.stutter.is_none()
+    }
+
+    /// End the current number as the numerator of a fraction, whose denominator `den` is the token at `pos`.
+    fn fraction_end(&mut self, pos: usize, bytes: Range<usize>, den: u64) {
+        self.tracker.number_advanced(pos, &bytes);
+        self.words += 1;
+        self.number_words = self.words;
+        self.denominator = Some(den);
+        self.number_end("fraction denominator");
+    }
+
+    /// Drop the current number, that is too long: it is left unconverted, up to its last word.
+    fn give_up_number(&mut self) {
+        self.trace_group(GroupAction::Aborted, "too long");
+        self.parser.reset();
+        self.tracker.take_span();
+        self.stutter = None;
+        self.last_word = None;
+        self.repaired = false;
+        self.partial = false;
+        self.signed = None;
+        self.linking_words.clear();
+        self.pending_linking_words.clear();
+        self.words = 0;
+        self.number_words = 0;
+        self.too_long = true;
+        self.break_run();
+    }
+
+    fn word_applied(&mut self, word: &str, before: Option<WordToDigitParser<'a, L>>) {
+        self.last_word = before.map(|state| (word.into(), state));
+    }
+
+    /// Retry `word`, the lowercase form of `token`, without its clitic, like "*twenty*" for "*twenty's*".
+    ///
+    /// The stem continues the current number or starts a new one, that ends with it.
+    /// Nothing is done if the stem is not a number word either, or if it is a pronoun before the clitic,
+    /// like "*one*" in "*one's own*" (see [`LangInterpreter::is_pronoun`]).
+    fn try_strip_clitic(&mut self, pos: usize, token: &T, word: &str) -> bool {
+        let Some((stem, clitic)) = self.lang.clitics().iter().find_map(|&clitic| {
+            let stem = word.strip_suffix(clitic)?;
+            (!stem.is_empty()).then_some((stem, clitic))
+        }) else {
+            return false;
+        };
+        let applies = |parser: &WordToDigitParser<'a, L>| parser.clone().push(stem).is_ok();
+        if !applies(&self.parser) {
+            let mut fresh = self.parser.clone();
+            fresh.reset();
+            if !self.parser.has_number() || !applies(&fresh) {
+                return false;
+            }
+            self.number_end("the word does not continue the number");
+        }
+        if !self.parser.has_number() && self.lang.is_pronoun(self.word_before(), stem, Some(clitic))
+        {
+            return false;
+        }
+        if self.parse(stem).is_err() {
+            return false;
+        }
+        let text = token.text();
+        self.clitic = Some(
+            text.get(text.len().saturating_sub(clitic.len())..)
+                .filter(|written| tokenizer::lowercase(written) == clitic)
+                .unwrap_or(clitic)
+                .into(),
+        );
+        self.words += 1;
+        self.number_advanced(pos, self.token_bytes.clone());
+        self.number_end("clitic");
+        true
+    }
+
+    /// If `word` repeats the previous number word, tentatively drop the repetition.
+    ///
+    /// A compound that restates the previous word, like "*vingt-trois*" after "*vingt*", is a stutter
+    /// by itself: the repair is confirmed at once.
+    fn try_collapse_stutter(&mut self, pos: usize, word: &str) -> bool {
+        if self.stutter.is_some() {
+            return false;
+        }
+        let Some((previous, before)) = &self.last_word else {
+            return false;
+        };
+        if !starts_with_phrase(word, previous, '-') {
+            return false;
+        }
+        let mut repaired = before.clone();
+        if repaired.push(word).is_err() {
+            return false;
+        }
+        let restated = word.len() > previous.len();
+        let before = before.clone();
+        let fallback = core::mem::replace(&mut self.parser, repaired);
+        if let Some(trace) = &mut self.trace {
+            trace.extend(self.parser.take_calls(pos));
+        }
+        if restated {
+            self.trace_group(
+                GroupAction::Extended,
+                "compound restating the previous word, collapsed as a stutter",
+            );
+            self.repaired = true;
+            self.last_word = Some((word.into(), before));
+            self.tracker.number_advanced(pos, &self.token_bytes);
+            return true;
+        }
+        self.trace_group(
+            GroupAction::Extended,
+            "repeated word, collapsed as a stutter",
+        );
+        self.stutter = Some(Stutter {
+            fallback,
+            fallback_end: (self.tracker.match_end, self.tracker.byte_end),
+            pos,
+            bytes: self.token_bytes.clone(),
+            word: word.into(),
+        });
+        self.tracker.number_advanced(pos, &self.token_bytes);
+        true
+    }
+
+    /// Cancel the pending stutter repair: the number ends before the repeated word, which starts a new one.
+    fn unwind_stutter(&mut self) {
+        if let Some(Stutter {
+            fallback,
+            fallback_end,
+            pos,
+            bytes,
+            word,
+        }) = self.stutter.take()
+        {
+            self.parser = fallback;
+            (self.tracker.match_end, self.tracker.byte_end) = fallback_end;
+            self.number_end("the repeated word was not a stutter");
+            let before = self.parser.clone();
+            if self.parse(&word).is_ok() {
+                self.last_word = Some((word, before));
+                self.words += 1;
+                self.number_advanced(pos, bytes);
+            }
+        }
+    }
+
+    fn finalize(&mut self) {
+        if let Some(tail) = self.fraction_tail.take() {
+            self.replay_fraction_tail(tail.held);
+        }
+        if let Some(fraction) = self.spoken_fraction.take() {
+            self.spoken_fraction_end(fraction);
+        }
+        if let Some(quantity) = self.quantity.take() {
+            for (pos, token, bytes) in quantity.held {
+                self.token_bytes = bytes;
+                self.push(pos, token);
+            }
+        }
+        if let Some((pos, token, bytes, _)) = self.fraction.take() {
+            self.token_bytes = bytes;
+            self.restart_number(pos, &token, false);
+        }
+        if self.parser.has_number() {
+            self.check_pronoun(None);
+            self.number_end("end of input")
+        }
+        self.flush_run();
+    }
+
+    /// Check whether the current number, before the word `next`, is a pronoun (see [`LangInterpreter::is_pronoun`]).
+    /// The word before the current number, if any.
+    fn word_before(&self) -> Option<&str> {
+        (!self.word_before.is_empty()).then_some(self.word_before.as_str())
+    }
+
+    fn check_pronoun(&mut self, next: Option<&str>) {
+        self.pronoun = self.number_words == 1
+            && self.previous.as_ref().is_some_and(|previous| {
+                let number = tokenizer::lowercase(previous.text_lowercase());
+                self.lang.is_pronoun(self.word_before(), &number, next)
+            });
+    }
+
+    fn number_advanced(&mut self, pos: usize, bytes: Range<usize>) {
+        if !self.tracker.in_number() {
+            // the separator did not start the number if it was a number word after all: "a point twenty"
+            let leading = self
+                .leading_separator
+                .take()
+                .filter(|_| self.parser.decimal_separator().is_some());
+            let start = leading.as_ref().map_or(pos, |(start, _)| *start);
+            self.starts_adjacent = !self.gap;
+            self.signed = self.sign.filter(|&(sign_pos, _)| sign_pos + 1 == start);
+            self.parser.currency = self
+                .previous
+                .as_ref()
+                .is_some_and(|token| is_currency_symbol(token.text()));
+            self.trace_group(GroupAction::Started, "number word");
+            if let Some((start, bytes)) = leading {
+                self.tracker.number_advanced(start, &bytes);
+            }
+        } else {
+            self.trace_group(GroupAction::Extended, "number word");
+        }
+        self.number_words = self.words;
+        if self.tracker.in_number() {
+            self.linking_words.append(&mut self.pending_linking_words);
+        } else {
+            // the noise before the first number word
+            self.pending_linking_words.clear();
+        }
+        self.tracker.number_advanced(pos, &bytes)
+    }
+
+    /// End the current number, for the given `reason` (only traced).
+    fn number_end(&mut self, reason: &'static str) {
+        self.unwind_stutter();
+        self.date_hold = None;
+        let is_ordinal = self.parser.is_ordinal();
+        let decimal_separator = self.parser.decimal_separator();
+        let mut kind = self.parser.kind();
+        let mut marker = self.parser.int_part.marker;
+        let separator_word = self
+            .parser
+            .separator_word
+            .clone()
+            .filter(|_| decimal_separator.is_some());
+        let unit_fraction = self.parser.unit_fraction();
+        let (mut text, mut value) = self.parser.string_and_value();
+        let mut denominator = self.denominator.take();
+        if let Some(den) = denominator {
+            kind = NumberKind::Fraction;
+            value /= den as f64;
+            text = match self.options.fraction_output {
+                FractionOutput::Slash => format!("{text}/{den}"),
+                FractionOutput::Decimal => fraction_decimal(self.lang, value),
+            };
+        } else if let Some((num, den)) = unit_fraction {
+            // "two and a third"
+            kind = NumberKind::Fraction;
+            value += num as f64 / den as f64;
+            text = match self.options.fraction_output {
+                FractionOutput::Slash => format!("{text} {num}/{den}"),
+                FractionOutput::Decimal => fraction_decimal(self.lang, value),
+            };
+            denominator = Some(den);
+        }
+        // "le troisième mai" gives "le 3 mai"
+        let cardinal_day = core::mem::take(&mut self.before_month)
+            && is_ordinal
+            && value == value as u64 as f64
+            && self.lang.is_cardinal_day(value as u64);
+        if cardinal_day {
+            text.truncate(text.trim_end_matches(|c: char| !c.is_ascii_digit()).len());
+            kind = NumberKind::Cardinal;
+            marker = MorphologicalMarker::None;
+        }
+        let (mut start, end, mut range) = self.tracker.take_span();
+        self.cardinal_day = cardinal_day.then_some(range.start);
+        let signed = self.signed.take().filter(|_| !is_ordinal);
+        if let Some((sign_pos, sign_byte)) = signed {
+            start = sign_pos;
+            range.start = sign_byte;
+            text.insert(0, '-');
+            value = -value;
+        }
+        let clitic = self.clitic.take();
+        if let Some(clitic) = &clitic {
+            text.push_str(clitic);
+        }
+        let occurence = Occurence {
+            start,
+            end,
+            range,
+            text,
+            value,
+            is_ordinal: is_ordinal && !cardinal_day,
+            kind,
+            words: self.number_words,
+            decimal_separator,
+            repaired: core::mem::take(&mut self.repaired),
+            clitic,
+            partial: core::mem::take(&mut self.partial),
+            score: false,
+            readback_mismatch: false,
+            morphology: (!marker.is_none() || denominator.is_some() || separator_word.is_some())
+                .then_some(Morphology {
+                    marker,
+                    denominator,
+                    separator_word,
+                }),
+            linking_words: core::mem::take(&mut self.linking_words),
+            lang_code: self.lang.language_code(),
+        };
+        self.trace_group(GroupAction::Committed(occurence.text.clone()), reason);
+        let mergeable = self.options.adjacent_number_policy != AdjacentNumberPolicy::Separate
+            && !is_ordinal
+            && signed.is_none()
+            && occurence.text.bytes().all(|c| c.is_ascii_digit())
+            || self.options.scores && is_score_number(&occurence);
+        let lone = occurence.words == 1 && occurence.value < self.options.threshold;
+        let pronoun = core::mem::take(&mut self.pronoun);
+        // the last word of the number is the word before the next one, like "*third*" in "*the third one*"
+        self.word_before.clear();
+        if let Some(previous) = &self.previous {
+            self.word_before
+                .push_str(&tokenizer::lowercase(previous.text_lowercase()));
+        }
+        match self.lone_context.take().filter(|_| lone) {
+            _ if pronoun => {
+                self.flush_run();
+                self.trace_group(GroupAction::Aborted, "a pronoun");
+                self.tracker.sequence_breaker();
+            }
+            Some(licensed) => {
+                self.flush_run();
+                if licensed {
+                    self.commit(occurence, false);
+                } else {
+                    self.trace_group(GroupAction::Aborted, "not a number before the next word");
+                    self.tracker.sequence_breaker();
+                }
+            }
+            None => {
+                if !mergeable || !self.starts_adjacent {
+                    self.flush_run();
+                }
+                if mergeable {
+                    self.push_run(occurence);
+                } else {
+                    self.emit(occurence);
+                }
+            }
+        }
+        self.pending_linking_words.clear();
+        self.gap = false;
+        self.after_month = false;
+        self.after_range = false;
+        self.range_phrase = is_ordinal.then(String::new);
+        self.last_word = None;
+        self.words = 0;
+        self.number_words = 0;
+    }
+
+    fn emit(&mut self, occurence: Occurence) {
+        let mut forget_if_isolate = (occurence.text.len() == 1 || occurence.is_ordinal)
+            && occurence.value < self.options.threshold;
+        if self.after_range && occurence.is_ordinal {
+            // "first to third": both ends of the range are kept
+            self.tracker.release_hold();
+            forget_if_isolate = false;
+        } else if forget_if_isolate
+            && (occurence.is_ordinal || self.cardinal_day == Some(occurence.range.start))
+        {
+            if self.after_month {
+                // "June fifth"
+                forget_if_isolate = false;
+            } else {
+                // "the fifth of June": kept if a month name follows
+                self.date_hold = Some(false);
+            }
+        }
+        if forget_if_isolate {
+            self.trace_group(
+                GroupAction::Held(occurence.text.clone()),
+                "under the threshold: dropped if isolated",
+            );
+        }
+        self.commit(occurence, forget_if_isolate);
+    }
+
+    /// Hand `occurence` to the tracker, in the [`Options::digit_script`], unless it overlaps the [`Options::mask`],
+    /// is shorter than [`Options::min_word_count`] or starts a sentence to skip (see [`Options::skip_sentence_initial`]).
+    fn commit(&mut self, mut occurence: Occurence, forget_if_isolate: bool) {
+        if self.options.mask.overlaps(&occurence.range) {
+            self.trace_group(GroupAction::Aborted, "masked");
+            self.tracker.sequence_breaker();
+        } else if occurence.words < self.options.min_word_count {
+            self.trace_group(GroupAction::Aborted, "fewer words than min_word_count");
+            self.tracker.sequence_breaker();
+        } else if self.options.skip_sentence_initial
+            && self
+                .sentence_initial
+                .as_ref()
+                .is_some_and(|initial| initial.contains(&occurence.start))
+            && !(occurence.is_ordinal && self.options.convert_sentence_initial_ordinals)
+            && !(occurence.kind == NumberKind::Year && self.options.convert_sentence_initial_years)
+        {
+            self.trace_group(GroupAction::Aborted, "at the start of a sentence");
+            self.tracker.sequence_breaker();
+        } else {
+            if let Some(text) = self.options.digit_script.transliterate(
+                &occurence.text,
+                occurence.decimal_separator,
+                occurence.is_ordinal,
+            ) {
+                occurence.text = text;
+            }
+            self.tracker.number_end(occurence, forget_if_isolate);
+        }
+    }
+
+    /// Apply the [`AdjacentNumberPolicy`] to the pending run of adjacent numbers.
+    /// Add `occurence` to the run of adjacent numbers, or give the run up if it would merge into a number
+    /// of more than [`Options::max_digits`] digits or [`Options::max_tokens`] words.
+    fn push_run(&mut self, occurence: Occurence) {
+        if self.run_too_long {
+            return;
+        }
+        self.run.push(occurence);
+        let merges = match self.options.adjacent_number_policy {
+            AdjacentNumberPolicy::Separate => false,
+            AdjacentNumberPolicy::Concatenate => true,
+            AdjacentNumberPolicy::Auto => self.run.iter().all(|o| o.text.len() == 1),
+        };
+        let digits: usize = self.run.iter().map(|o| o.text.len()).sum();
+        let words: usize = self.run.iter().map(|o| o.words).sum();
+        if merges && (digits > self.options.max_digits || words > self.options.max_tokens) {
+            self.trace_group(GroupAction::Aborted, "adjacent numbers too long");
+            self.run.clear();
+            self.run_connector = false;
+            self.run_too_long = true;
+            self.tracker.sequence_breaker();
+        }
+    }
+
+    fn flush_run(&mut self) {
+        self.run_too_long = false;
+        let mut run = core::mem::take(&mut self.run);
+        let connector = core::mem::take(&mut self.run_connector);
+        if self.options.scores && run.len() == 2 && run.iter().all(is_score_number) {
+            // the numbers of a score are not isolated, even if they are small
+            run[1].score = true;
+            for occurence in run {
+                self.commit(occurence, false);
+            }
+            return;
+        }
+        let merge = !connector
+            && match self.options.adjacent_number_policy {
+                AdjacentNumberPolicy::Separate => false,
+                AdjacentNumberPolicy::Concatenate => run.len() > 1,
+                AdjacentNumberPolicy::Auto => {
+                    run.len() >= 3 && run.iter().all(|o| o.text.len() == 1)
+                }
+            };
+        if merge {
+            let text: String = run.iter().map(|o| o.text.as_str()).collect();
+            let occurence = Occurence {
+                start: run[0].start,
+                end: run[run.len() - 1].end,
+                range: run[0].range.start..run[run.len() - 1].range.end,
+                value: text.parse().unwrap_or_default(),
+                text,
+                is_ordinal: false,
+                kind: NumberKind::Sequence,
+                words: run.iter().map(|o| o.words).sum(),
+                decimal_separator: None,
+                repaired: run.iter().any(|o| o.repaired),
+                clitic: None,
+                partial: run.iter().any(|o| o.partial),
+                score: false,
+                readback_mismatch: false,
+                morphology: None,
+                linking_words: run
+                    .iter_mut()
+                    .flat_map(|o| core::mem::take(&mut o.linking_words))
+                    .collect(),
+                lang_code: self.lang.language_code(),
+            };
+            self.trace_group(
+                GroupAction::Merged(occurence.text.clone()),
+                "adjacent number policy",
+            );
+            self.emit(occurence);
+        } else {
+            for occurence in run {
+                self.emit(occurence);
+            }
+        }
+    }
+
+    /// Adjacency is broken by a token between numbers.
+    fn break_run(&mut self) {
+        self.gap = true;
+        self.flush_run();
+    }
+
+    fn outside_number(&mut self, token: &T) {
+        self.too_long = false;
+        // a leading decimal separator that does not start a number after all
+        self.leading_separator = None;
+        self.words = 0;
+        // the ordinals in digits of the German style, like "*3.*" in "*the 3. one*"
+        if token.text().trim_end() == "."
+            && !self.word_before.is_empty()
+            && self.word_before.bytes().all(|c| c.is_ascii_digit())
+        {
+            self.word_before.push('.');
+        } else {
+            self.word_before.clear();
+            if token.text().chars().any(char::is_alphanumeric) {
+                self.word_before
+                    .push_str(&tokenizer::lowercase(token.text_lowercase()));
+            }
+        }
+        let month = self.lang.is_month_name(token.text());
+        if let Some(connector) = self.date_hold.take() {
+            if month {
+                self.tracker.release_hold();
+            } else if !connector
+                && self
+                    .lang
+                    .is_date_connector(&tokenizer::lowercase(token.text_lowercase()))
+            {
+                self.date_hold = Some(true);
+            }
+        }
+        self.after_month = month;
+        self.after_range = false;
+        if let Some(phrase) = self.range_phrase.take() {
+            let word = tokenizer::lowercase(token.text_lowercase());
+            let phrase = if phrase.is_empty() {
+                word.into_owned()
+            } else {
+                format!("{phrase} {word}")
+            };
+            let connectors = self.lang.range_connectors();
+            if connectors
+                .iter()
+                .any(|known| starts_with_phrase(known, &phrase, ' '))
+            {
+                self.after_range = connectors.contains(&phrase.as_str());
+                self.range_phrase = Some(phrase);
+            }
+        }
+        if self.options.scores
+            && !self.run_connector
+            && self.run.len() == 1
+            && is_score_number(&self.run[0])
+            && self
+                .lang
+                .score_connectors()
+                .contains(&tokenizer::lowercase(token.text_lowercase()).as_ref())
+        {
+            // "three to two": the run goes on
+            self.run_connector = true;
+            return;
+        }
+        if self.options.scores && self.run.len() == 2 && token.not_a_number_part() {
+            // "three two-bedroom flats": the second number starts a compound (see
+            // `split_hyphenated_compounds`), it is no score
+            let second = self.run.pop();
+            self.flush_run();
+            self.run.extend(second);
+        }
+        self.break_run();
+        let text = token.text();
+        if !(text.chars().all(|c| !c.is_alphabetic()) && !is_sentence_end(text)
+            || self.lang.is_linking(text))
+        {
+            self.tracker.sequence_breaker()
+        };
+    }
+
+    fn track_numbers(mut self) -> NumTracker {
+        while let Some((pos, token)) = self.input.next() {
+            self.feed(pos, token);
+        }
+        self.finalize();
+        self.tracker
+    }
+}
+
+impl<L, T, I> Iterator for FindNumbers<'_, L, T, I>
+where
+    L: LangInterpreter,
+    T: Token,
+    I: Iterator<Item = (usize, T)>,
+{
+    type Item = Occurence;
+
+    fn next(&mut self) -> Option<Self::Item> {
         if self.tracker.has_matches() {
             return self.tracker.pop();
         }
-        while let Some((pos, token)) = self.input.next() {
-            self.push(pos, token);
-            if self.tracker.has_matches() {
-                return self.tracker.pop();
+        while let Some((pos, token)) = self.input.next() {
+            self.feed(pos, token);
+            if self.tracker.has_matches() {
+                return self.tracker.pop();
+            }
+        }
+        self.finalize();
+        self.tracker.pop()
+    }
+}
+
+/// A token of the caller, or a piece of it cut on the word boundaries of the scripts written without spaces,
+/// so that the token streams are read as the texts are (see [`track_numbers`]).
+#[cfg(feature = "unicode-segmentation")]
+enum Piece<T> {
+    Whole(T),
+    /// The `bytes` of the text of a token shared by its pieces, the first piece if `first`
+    Cut {
+        token: Rc<T>,
+        bytes: Range<usize>,
+        first: bool,
+    },
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<T> Piece<T> {
+    fn token(&self) -> &T {
+        match self {
+            Piece::Whole(token) => token,
+            Piece::Cut { token, .. } => token,
+        }
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<T: Token> Token for Piece<T> {
+    fn text(&self) -> &str {
+        match self {
+            Piece::Whole(token) => token.text(),
+            Piece::Cut { token, bytes, .. } => &token.text()[bytes.clone()],
+        }
+    }
+
+    fn text_lowercase(&self) -> &str {
+        match self {
+            Piece::Whole(token) => token.text_lowercase(),
+            Piece::Cut { token, bytes, .. } => &token.text_lowercase()[bytes.clone()],
+        }
+    }
+
+    fn nt_separated(&self, previous: &Self) -> bool {
+        !matches!(self, Piece::Cut { first: false, .. })
+            && self.token().nt_separated(previous.token())
+    }
+
+    fn not_a_number_part(&self) -> bool {
+        self.token().not_a_number_part()
+    }
+
+    fn is_digits(&self) -> bool {
+        match self {
+            Piece::Whole(token) => token.is_digits(),
+            Piece::Cut { token, .. } => token.is_digits() && starts_with_digit(self.text()),
+        }
+    }
+
+    fn is_partial(&self) -> bool {
+        self.token().is_partial()
+    }
+
+    fn is_markup(&self) -> bool {
+        self.token().is_markup()
+    }
+}
+
+/// The tokens that were cut into pieces, with their position and byte offset.
+#[cfg(feature = "unicode-segmentation")]
+type Cuts<T> = Rc<RefCell<Vec<(usize, usize, Rc<T>)>>>;
+
+/// The [`Piece`]s of a token stream, that log the tokens they cut.
+#[cfg(feature = "unicode-segmentation")]
+struct CutTokens<T, I> {
+    input: I,
+    pieces: VecDeque<(usize, Piece<T>)>,
+    /// Byte offset of the next token
+    offset: usize,
+    ideographic_runs: bool,
+    cuts: Cuts<T>,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<T: Token, I: Iterator<Item = (usize, T)>> Iterator for CutTokens<T, I> {
+    type Item = (usize, Piece<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(piece) = self.pieces.pop_front() {
+            return Some(piece);
+        }
+        let (pos, token) = self.input.next()?;
+        let start = self.offset;
+        self.offset += token.text().len();
+        // the pieces of the lowercase form are only taken if they are pieces of the text too
+        let ends =
+            tokenizer::piece_ends(token.text_lowercase(), self.ideographic_runs).filter(|ends| {
+                ends.len() > 1
+                    && token.text().len() == token.text_lowercase().len()
+                    && ends.iter().all(|&end| token.text().is_char_boundary(end))
+            });
+        let Some(ends) = ends else {
+            return Some((pos, Piece::Whole(token)));
+        };
+        let token = Rc::new(token);
+        self.cuts.borrow_mut().push((pos, start, token.clone()));
+        let mut piece_start = 0;
+        for end in ends {
+            self.pieces.push_back((
+                pos,
+                Piece::Cut {
+                    token: token.clone(),
+                    bytes: piece_start..end,
+                    first: piece_start == 0,
+                },
+            ));
+            piece_start = end;
+        }
+        self.pieces.pop_front()
+    }
+}
+
+/// The [`Piece`]s of the tokens of `input`, that log the tokens they cut in `cuts`.
+#[cfg(feature = "unicode-segmentation")]
+fn cut_tokens<T: Token, I: Iterator<Item = T>>(
+    input: I,
+    ideographic_runs: bool,
+    cuts: &Cuts<T>,
+) -> CutTokens<T, Enumerate<I>> {
+    CutTokens {
+        input: input.enumerate(),
+        pieces: VecDeque::new(),
+        offset: 0,
+        ideographic_runs,
+        cuts: cuts.clone(),
+    }
+}
+
+/// Extend `occurence` to the whole tokens it starts or ends in, if they were cut.
+///
+/// Return false if it starts before `end`, the end of the previous occurence, as a token is part of one number at most.
+#[cfg(feature = "unicode-segmentation")]
+fn cover_cut_tokens<T: Token>(
+    occurence: &mut Occurence,
+    cuts: &[(usize, usize, Rc<T>)],
+    end: &mut usize,
+) -> bool {
+    if occurence.start < *end {
+        return false;
+    }
+    *end = occurence.end;
+    let cut = |pos: usize| cuts.iter().find(|(cut, ..)| *cut == pos);
+    if let Some((_, start, token)) = cut(occurence.end - 1) {
+        occurence
+            .text
+            .push_str(&token.text()[occurence.range.end - start..]);
+        occurence.range.end = start + token.text().len();
+    }
+    if let Some((_, start, token)) = cut(occurence.start) {
+        occurence
+            .text
+            .insert_str(0, &token.text()[..occurence.range.start - start]);
+        occurence.range.start = *start;
+    }
+    true
+}
+
+/// Find the numbers of the token stream `input`, with the words of the scripts written without spaces cut
+/// as in the text functions, like [`replace_numbers_in_text`].
+///
+/// The numbers still cover whole tokens: the rest of a token cut by a number is kept in its text,
+/// and a token is part of one number at most, as in [`find_numbers_in_tokens`].
+#[cfg(feature = "unicode-segmentation")]
+fn find_in_pieces<'a, L, T, I>(
+    input: I,
+    lang: &'a L,
+    options: &Options,
+) -> impl Iterator<Item = Occurence> + use<'a, L, T, I>
+where
+    L: LangInterpreter,
+    T: Token,
+    I: Iterator<Item = T>,
+{
+    let cuts = Cuts::default();
+    let pieces = cut_tokens(input, lang.prefers_ideographic_runs(), &cuts);
+    let mut end = 0;
+    FindNumbers::new(pieces, lang, options).filter_map(move |mut occurence| {
+        let mut cuts = cuts.borrow_mut();
+        let kept = cover_cut_tokens(&mut occurence, &cuts, &mut end);
+        cuts.retain(|(pos, ..)| *pos >= end);
+        kept.then_some(occurence)
+    })
+}
+
+/// Find spelled numbers (including decimal numbers) in the input token stream.
+///
+/// The words of the scripts written without spaces are cut as in the text functions (see [`find_in_pieces`]).
+#[cfg(feature = "unicode-segmentation")]
+pub(crate) fn track_numbers<L: LangInterpreter, T: Token, I: Iterator<Item = T>>(
+    input: I,
+    lang: &L,
+    options: &Options,
+) -> NumTracker {
+    let cuts = Cuts::default();
+    let pieces = cut_tokens(input, lang.prefers_ideographic_runs(), &cuts);
+    let mut tracker = FindNumbers::new(pieces, lang, options).track_numbers();
+    let cuts = cuts.borrow();
+    let mut end = 0;
+    tracker
+        .matches
+        .retain_mut(|occurence| cover_cut_tokens(occurence, &cuts, &mut end));
+    tracker
+}
+
+/// Find spelled numbers (including decimal numbers) in the input token stream.
+#[cfg(not(feature = "unicode-segmentation"))]
+pub(crate) fn track_numbers<L: LangInterpreter, T: Token, I: Iterator<Item = T>>(
+    input: I,
+    lang: &L,
+    options: &Options,
+) -> NumTracker {
+    let scanner = FindNumbers::new(input.enumerate(), lang, options);
+    scanner.track_numbers()
+}
+
+/**
+Find the spelled numbers (including decimal numbers) in a token stream.
+
+Return a list of the successive [`Occurence`]s of numbers in the stream.
+The `threshold` drives the *lone number* policy: if a number is isolated — that is,
+surrounded by significant non-number words — and lower than `threshold`, then it
+is ignored.
+
+With the `unicode-segmentation` feature, the tokens in the scripts written without spaces, like Chinese,
+are cut on their word boundaries, as the text of [`replace_numbers_in_text`] is. A number still covers whole tokens:
+its [`text`](Occurence::text) keeps the rest of the tokens it starts or ends in, and a token is part of one number at most.
+*/
+pub fn find_numbers<L: LangInterpreter, T: Token, I: Iterator<Item = T>>(
+    input: I,
+    lang: &L,
+    threshold: f64,
+) -> Vec<Occurence> {
+    find_numbers_with(input, lang, &Options::new(threshold))
+}
+
+/// Same as [`find_numbers`], but driven by the given [`Options`].
+pub fn find_numbers_with<L: LangInterpreter, T: Token, I: Iterator<Item = T>>(
+    input: I,
+    lang: &L,
+    options: &Options,
+) -> Vec<Occurence> {
+    track_numbers(input, lang, options).into_vec()
+}
+
+/**
+Return an iterator over all the number occurences (including decimal numbers) found in a speech token stream.
+
+Return an iterator of the successive [`Occurence`]s of numbers in the stream.
+The `threshold` drives the *lone number* policy: if a number is isolated — that is,
+surrounded by significant non-number words — and lower than `threshold`, then it
+is ignored.
+*/
+pub fn find_numbers_iter<L, T, I>(
+    input: I,
+    lang: &L,
+    threshold: f64,
+) -> impl Iterator<Item = Occurence> + use<'_, L, T, I>
+where
+    L: LangInterpreter,
+    T: Token,
+    I: Iterator<Item = T>,
+{
+    find_numbers_iter_with(input, lang, &Options::new(threshold))
+}
+
+/// Same as [`find_numbers_iter`], but driven by the given [`Options`].
+#[cfg(feature = "unicode-segmentation")]
+pub fn find_numbers_iter_with<'a, L, T, I>(
+    input: I,
+    lang: &'a L,
+    options: &Options,
+) -> impl Iterator<Item = Occurence> + use<'a, L, T, I>
+where
+    L: LangInterpreter,
+    T: Token,
+    I: Iterator<Item = T>,
+{
+    find_in_pieces(input, lang, options)
+}
+
+/// Same as [`find_numbers_iter`], but driven by the given [`Options`].
+#[cfg(not(feature = "unicode-segmentation"))]
+pub fn find_numbers_iter_with<'a, L, T, I>(
+    input: I,
+    lang: &'a L,
+    options: &Options,
+) -> impl Iterator<Item = Occurence> + use<'a, L, T, I>
+where
+    L: LangInterpreter,
+    T: Token,
+    I: Iterator<Item = T>,
+{
+    FindNumbers::new(input.enumerate(), lang, options)
+}
+
+/// Find spelled numbers (including decimal) in the token stream and replace them by their digit representation.
+/// Isolated digits strictly under `threshold` are not converted (set to 0.0 to convert everything).
+pub fn replace_numbers_in_stream<'a, L, T>(input: Vec<T>, lang: &L, threshold: f64) -> Vec<T>
+where
+    L: LangInterpreter,
+    T: Replace + 'a,
+    for<'b> &'b T: Token,
+{
+    replace_numbers_in_stream_with(input, lang, &Options::new(threshold))
+}
+
+/// Same as [`replace_numbers_in_stream`], but driven by the given [`Options`].
+pub fn replace_numbers_in_stream_with<'a, L, T>(
+    mut input: Vec<T>,
+    lang: &L,
+    options: &Options,
+) -> Vec<T>
+where
+    L: LangInterpreter,
+    T: Replace + 'a,
+    for<'b> &'b T: Token,
+{
+    let tracker = track_numbers(input.iter(), lang, options);
+    tracker.replace(&mut input);
+    input
+}
+
+/// Same as [`replace_numbers_in_stream`], but driven by the options recommended for `lang`
+/// (see [`Options::recommended_for`]).
+pub fn replace_numbers_in_stream_recommended<'a, L, T>(input: Vec<T>, lang: &L) -> Vec<T>
+where
+    L: LangInterpreter,
+    T: Replace + 'a,
+    for<'b> &'b T: Token,
+{
+    replace_numbers_in_stream_with(input, lang, &Options::recommended_for(lang))
+}
+
+/// Annotate the token stream with the analysis of the number finder, without replacing anything.
+///
+/// After the language specific annotation ([`LangInterpreter::basic_annotate`]), each token gets its
+/// [`TokenKind`] and the index of the number occurence it belongs to, if any, through the
+/// [`BasicAnnotate`] setters. The occurence indices are the ones [`find_numbers`] would yield
+/// with the same `threshold`.
+pub fn annotate_numbers<L, T>(tokens: &mut Vec<T>, lang: &L, threshold: f64)
+where
+    L: LangInterpreter,
+    T: BasicAnnotate,
+    for<'b> &'b T: Token,
+{
+    annotate_numbers_with(tokens, lang, &Options::new(threshold))
+}
+
+/// Same as [`annotate_numbers`], but driven by the given [`Options`].
+pub fn annotate_numbers_with<L, T>(tokens: &mut Vec<T>, lang: &L, options: &Options)
+where
+    L: LangInterpreter,
+    T: BasicAnnotate,
+    for<'b> &'b T: Token,
+{
+    lang.basic_annotate(tokens);
+    let occurences = track_numbers(tokens.iter(), lang, options).into_vec();
+    for token in tokens.iter_mut() {
+        let lowercase = tokenizer::lowercase(token.text_lowercase());
+        let kind = if (&*token).not_a_number_part() {
+            TokenKind::Other
+        } else if options
+            .decimal_separators
+            .iter()
+            .any(|(separator, _)| *separator == lowercase)
+        {
+            TokenKind::DecimalSep
+        } else {
+            lang.token_kind(&lowercase)
+        };
+        token.set_kind(kind);
+        token.set_group_id(None);
+    }
+    for (id, occurence) in occurences.iter().enumerate() {
+        for token in &mut tokens[occurence.start..occurence.end] {
+            token.set_group_id(Some(id));
+        }
+    }
+}
+
+/// Find spelled numbers (including decimal) in the `text` and replace them by their digit representation.
+/// Isolated digits strictly under `threshold` are not converted (set to 0.0 to convert everything).
+///
+/// The replacement is idempotent: replacing the output again, with the same options, gives it back as is.
+pub fn replace_numbers_in_text<L: LangInterpreter>(text: &str, lang: &L, threshold: f64) -> String {
+    replace_numbers_in_text_with(text, lang, &Options::new(threshold))
+}
+
+/// Same as [`replace_numbers_in_text`], but driven by the given [`Options`].
+pub fn replace_numbers_in_text_with<L: LangInterpreter>(
+    text: &str,
+    lang: &L,
+    options: &Options,
+) -> String {
+    replace_to_string(
+        text,
+        token_stream(text, lang, options),
+        lang,
+        options,
+        None,
+        None,
+    )
+    .0
+}
+
+/// Same as [`replace_numbers_in_text`], but driven by the options recommended for `lang`
+/// (see [`Options::recommended_for`]).
+pub fn replace_numbers_in_text_recommended<L: LangInterpreter>(text: &str, lang: &L) -> String {
+    replace_numbers_in_text_with(text, lang, &Options::recommended_for(lang))
+}
+
+/// A number found by [`extract_values`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractedValue {
+    /// The exact digits of the number, whatever the rendering options: without ordinal marker, with a "*-*" sign
+    /// and a "*.*" decimal separator, like "*21*" for "*twenty-first*" or "*-2.5*" for "*minus two point five*"
+    pub digits: String,
+    /// The value of the number, or the rank it represents for ordinals
+    pub value: f64,
+    pub kind: NumberKind,
+    /// The byte offsets of the number in the text
+    pub range: Range<usize>,
+}
+
+/// The digits of the numbers, for [`extract_values`].
+struct BareDigits;
+
+impl NumberFormatter for BareDigits {
+    fn format(&self, number: &Digits<'_>) -> Option<String> {
+        Some(match number.decimal {
+            Some((dec, _)) => format!("{}.{dec}", number.int),
+            None => number.int.to_string(),
+        })
+    }
+}
+
+/**
+Find the spelled numbers of `text` and return their values, in order, without building the replaced text.
+
+The numbers are found as with [`replace_numbers_in_text_with`], but the rendering options, like
+[`Options::formatter`] or [`Options::ordinal_style`], are ignored: [`ExtractedValue::digits`]
+always carries the bare digits.
+
+```rust
+use text2num::{Language, NumberKind, Options, extract_values};
+
+let en = Language::english();
+let values = extract_values("the twenty-first of forty-two point five", &en, &Options::new(10.0));
+
+assert_eq!(values[0].digits, "21");
+assert_eq!(values[0].kind, NumberKind::Ordinal);
+assert_eq!(values[1].digits, "42.5");
+assert_eq!(values[1].value, 42.5);
+assert_eq!(values[1].range, 20..40);
+```
+*/
+pub fn extract_values<L: LangInterpreter>(
+    text: &str,
+    lang: &L,
+    options: &Options,
+) -> Vec<ExtractedValue> {
+    let options = Options {
+        formatter: Some(Arc::new(BareDigits)),
+        ordinal_style: OrdinalStyle::Plain,
+        ..options.clone()
+    };
+    let tokens = text_tokens(text, lang, &options);
+    FindNumbers::new(tokens.iter().enumerate(), lang, &options)
+        .map(|occurence| {
+            let mut digits = occurence.text;
+            if let Some(clitic) = &occurence.clitic {
+                digits.truncate(digits.len() - clitic.len());
+            }
+            if occurence.kind == NumberKind::Ordinal {
+                // the enumeration markers of the adverbial ordinals, like "*2.*"
+                digits.truncate(digits.trim_end_matches(|c: char| !c.is_ascii_digit()).len());
+            }
+            ExtractedValue {
+                digits,
+                value: occurence.value,
+                kind: occurence.kind,
+                range: occurence.range,
+            }
+        })
+        .collect()
+}
+
+/// Statistics of a replacement (see [`replace_numbers_to`] and [`replace_numbers_in_text_stats`]).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of occurences replaced
+    pub numbers: usize,
+    /// The number of bytes written
+    pub bytes_written: usize,
+    /// The number of linking and noise words inside the occurences replaced (see [`Occurence::linking_words`])
+    pub linking_words: usize,
+    /// The number of numbers given up and left unconverted, like the masked ones or the too long ones
+    /// (the [`GroupAction::Aborted`] events of a [`Trace`])
+    pub aborted: usize,
+}
+
+/// Same as [`replace_numbers_in_text_with`], but also return the [`Stats`] of the replacement.
+///
+/// ```rust
+/// use text2num::{Language, Options, replace_numbers_in_text_stats};
+///
+/// let en = Language::english();
+/// let (text, stats) = replace_numbers_in_text_stats("two hundred and five cats", &en, &Options::new(10.0));
+///
+/// assert_eq!(text, "205 cats");
+/// assert_eq!((stats.numbers, stats.linking_words), (1, 1));
+/// ```
+pub fn replace_numbers_in_text_stats<L: LangInterpreter>(
+    text: &str,
+    lang: &L,
+    options: &Options,
+) -> (String, Stats) {
+    replace_to_string(
+        text,
+        token_stream(text, lang, options),
+        lang,
+        options,
+        None,
+        None,
+    )
+}
+
+/// Same as [`replace_numbers_in_text_with`], but write the output to `writer` as it goes,
+/// instead of building it in memory.
+///
+/// The error of the writer is returned as soon as it occurs, as [`SpliceError::Write`]: the output is then incomplete.
+/// So is it if an occurence breaks the invariants of the splice (see [`Options::paranoid`]), with a
+/// [`SpliceError::Internal`], but nothing of that occurence is written.
+///
+/// ```rust
+/// use text2num::{Language, Options, replace_numbers_to};
+///
+/// let en = Language::english();
+/// let mut out = String::new();
+/// let stats = replace_numbers_to("twenty-one cats and forty dogs", &en, &Options::new(10.0), &mut out).unwrap();
+///
+/// assert_eq!(out, "21 cats and 40 dogs");
+/// assert_eq!((stats.numbers, stats.bytes_written), (2, out.len()));
+/// ```
+pub fn replace_numbers_to<L: LangInterpreter, W: core::fmt::Write>(
+    text: &str,
+    lang: &L,
+    options: &Options,
+    writer: &mut W,
+) -> Result<Stats, SpliceError> {
+    replace_in_text(
+        text,
+        token_stream(text, lang, options),
+        lang,
+        options,
+        None,
+        None,
+        writer,
+    )
+}
+
+/// Same as [`replace_numbers_in_text_with`], but fail with a [`SpliceError::Internal`] if an occurence breaks
+/// the invariants of the splice (see [`Options::paranoid`]), instead of returning the text unchanged.
+///
+/// ```rust
+/// use text2num::{EditError, Language, Options, SpliceError, replace_numbers_in_text_checked};
+///
+/// let en = Language::english();
+/// let options = Options {
+///     paranoid: true,
+///     max_output_factor: 0,
+///     ..Options::new(10.0)
+/// };
+/// assert_eq!(
+///     replace_numbers_in_text_checked("twenty-one cats", &en, &options),
+///     Err(SpliceError::Internal(EditError::TooLong { index: 0 }))
+/// );
+/// ```
+pub fn replace_numbers_in_text_checked<L: LangInterpreter>(
+    text: &str,
+    lang: &L,
+    options: &Options,
+) -> Result<String, SpliceError> {
+    let mut out = String::with_capacity(text.len());
+    replace_numbers_to(text, lang, options, &mut out)?;
+    Ok(out)
+}
+
+/// Same as [`replace_numbers_in_text_with`], but also return the [`Trace`] of the interpretation,
+/// to find out why some words were, or were not, converted.
+pub fn replace_numbers_in_text_traced<L: LangInterpreter>(
+    text: &str,
+    lang: &L,
+    options: &Options,
+) -> (String, Trace) {
+    let mut trace = Trace::default();
+    let (out, _) = replace_to_string(
+        text,
+        token_stream(text, lang, options),
+        lang,
+        options,
+        Some(&mut trace),
+        None,
+    );
+    (out, trace)
+}
+
+/// The text returned by [`replace_numbers_in_text_cow`], with the number of replacements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replaced<'t> {
+    /// The replaced text, borrowed from the input if no number was replaced
+    pub text: Cow<'t, str>,
+    /// The number of occurences replaced
+    pub count: usize,
+}
+
+/// Same as [`replace_numbers_in_text_with`], but return the input itself if it has no number to replace,
+/// without allocating a copy of it. Whatever the length of such a text, only the window of the tokens being
+/// annotated is then allocated, with the languages that annotate without allocating, like English.
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use text2num::{Language, Options, replace_numbers_in_text_cow};
+///
+/// let en = Language::english();
+/// let replaced = replace_numbers_in_text_cow("no number here", &en, &Options::new(10.0));
+/// assert!(matches!(replaced.text, Cow::Borrowed("no number here")));
+/// assert_eq!(replaced.count, 0);
+/// ```
+pub fn replace_numbers_in_text_cow<'t, L: LangInterpreter>(
+    text: &'t str,
+    lang: &L,
+    options: &Options,
+) -> Replaced<'t> {
+    let mut out = Deferred {
+        text,
+        copied: 0,
+        out: None,
+    };
+    // writing to a String never fails, and the text is left unchanged if the splice goes wrong
+    match replace_in_text(
+        text,
+        token_stream(text, lang, options),
+        lang,
+        options,
+        None,
+        None,
+        &mut out,
+    ) {
+        Ok(stats) => Replaced {
+            text: out.out.map_or(Cow::Borrowed(text), Cow::Owned),
+            count: stats.numbers,
+        },
+        Err(_) => Replaced {
+            text: Cow::Borrowed(text),
+            count: 0,
+        },
+    }
+}
+
+/// An output that is only allocated at the first replacement: until then, it is the prefix of `text` copied so far.
+struct Deferred<'t> {
+    text: &'t str,
+    copied: usize,
+    out: Option<String>,
+}
+
+impl core::fmt::Write for Deferred<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        match &mut self.out {
+            Some(out) => out.push_str(s),
+            None if s.is_empty() => (),
+            // the copied segments are slices of the text itself
+            None if core::ptr::eq(s.as_ptr(), self.text[self.copied..].as_ptr()) => {
+                self.copied += s.len();
+            }
+            None => {
+                let mut out = String::with_capacity(self.text.len());
+                out.push_str(&self.text[..self.copied]);
+                out.push_str(s);
+                self.out = Some(out);
             }
         }
-        self.finalize();
-        self.tracker.pop()
+        Ok(())
     }
 }
 
-/// Find spelled numbers (including decimal numbers) in the input token stream.
-/// Isolated digits strictly under `threshold` are not converted (set to 0.0 to convert everything).
-fn track_numbers<L: LangInterpreter, T: Token, I: Iterator<Item = T>>(
-    input: I,
+/// Same as [`replace_numbers_in_text_with`], but also return the [`Alignment`] of the input and the output,
+/// to map offsets from one to the other.
+pub fn replace_numbers_in_text_aligned<L: LangInterpreter>(
+    text: &str,
     lang: &L,
-    threshold: f64,
-) -> NumTracker {
-    let scanner = FindNumbers::new(input.enumerate(), lang, threshold);
-    scanner.track_numbers()
+    options: &Options,
+) -> (String, Alignment) {
+    let mut alignment = Alignment::default();
+    let (out, _) = replace_to_string(
+        text,
+        token_stream(text, lang, options),
+        lang,
+        options,
+        None,
+        Some(&mut alignment),
+    );
+    (out, alignment)
+}
+
+/// A number found in a slice of tokens (see [`find_numbers_in_tokens`]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenOccurence {
+    /// The indices of the tokens of the number in the slice
+    pub tokens: Range<usize>,
+    /// The replacement of those tokens: the digit form of the number,
+    /// with what the first and last tokens hold besides the number, like punctuation
+    pub text: String,
+    /// The value of the number, or the rank it represents for ordinals
+    pub value: f64,
+    pub kind: NumberKind,
+    /// The code of the language that read the number (see [`Occurence::lang_code`])
+    pub lang_code: &'static str,
 }
 
 /**
-Find the spelled numbers (including decimal numbers) in a token stream.
+Find the spelled numbers in tokens that are already split by the caller, like the word-level output of an ASR engine.
 
-Return a list of the successive [`Occurence`]s of numbers in the stream.
-The `threshold` drives the *lone number* policy: if a number is isolated — that is,
-surrounded by significant non-number words — and lower than `threshold`, then it
-is ignored.
+The tokens are read as if they were joined by single spaces (this also defines the byte offsets of [`Options::mask`]).
+They may bear punctuation, like "*twenty,*" or "*(five*": the number then stops at the punctuation.
+A number always covers whole tokens: [`TokenOccurence::tokens`] is the range of the tokens it touches,
+and [`TokenOccurence::text`] is their replacement, punctuation included ("*21,*").
+A token is part of one number at most: a number that would start in the last token of the previous one,
+like "*two*" in "*one/two*", is left unconverted.
+
+```rust
+use text2num::{Language, Options, find_numbers_in_tokens};
+
+let en = Language::english();
+let tokens = ["we", "sold", "twenty", "two,", "then", "three", "hundred"];
+let numbers = find_numbers_in_tokens(&tokens, &en, &Options::new(10.0));
+
+assert_eq!(numbers[0].tokens, 2..4);
+assert_eq!(numbers[0].text, "22,");
+assert_eq!(numbers[1].tokens, 5..7);
+assert_eq!(numbers[1].text, "300");
+```
 */
-pub fn find_numbers<L: LangInterpreter, T: Token, I: Iterator<Item = T>>(
-    input: I,
+pub fn find_numbers_in_tokens<L: LangInterpreter, S: AsRef<str>>(
+    tokens: &[S],
     lang: &L,
-    threshold: f64,
-) -> Vec<Occurence> {
-    track_numbers(input, lang, threshold).into_vec()
+    options: &Options,
+) -> Vec<TokenOccurence> {
+    let mut text = String::new();
+    let mut starts = Vec::with_capacity(tokens.len());
+    for (i, token) in tokens.iter().enumerate() {
+        if i > 0 {
+            text.push(' ');
+        }
+        starts.push(text.len());
+        text.push_str(token.as_ref());
+    }
+    // the index of the token holding the byte at `offset`
+    let token_at = |offset: usize| starts.partition_point(|&start| start <= offset) - 1;
+    let spans = text_tokens(&text, lang, options);
+    let mut found: Vec<TokenOccurence> = Vec::new();
+    for occurence in FindNumbers::new(spans.iter().enumerate(), lang, options) {
+        let first = token_at(occurence.range.start);
+        let last = token_at(occurence.range.end - 1);
+        if found.last().is_some_and(|prev| prev.tokens.end > first) {
+            continue;
+        }
+        let end = starts[last] + tokens[last].as_ref().len();
+        found.push(TokenOccurence {
+            tokens: first..last + 1,
+            text: format!(
+                "{}{}{}",
+                &text[starts[first]..occurence.range.start],
+                occurence.text,
+                &text[occurence.range.end..end]
+            ),
+            value: occurence.value,
+            kind: occurence.kind,
+            lang_code: occurence.lang_code,
+        });
+    }
+    found
+}
+
+/// Same as [`find_numbers_in_tokens`], but replace the tokens of each number by a single token, its replacement.
+///
+/// The tokens that follow a number are shifted down accordingly.
+pub fn replace_numbers_in_tokens<L: LangInterpreter>(
+    tokens: &mut Vec<String>,
+    lang: &L,
+    options: &Options,
+) {
+    let found = find_numbers_in_tokens(tokens, lang, options);
+    for occurence in found.into_iter().rev() {
+        tokens.splice(occurence.tokens, core::iter::once(occurence.text));
+    }
+}
+
+/// The tokens of `text`, cut on the Unicode word boundaries in the scripts written without spaces.
+/// The runs of ideographs are kept whole if `ideographic_runs` (see [`LangInterpreter::prefers_ideographic_runs`]).
+#[cfg(feature = "unicode-segmentation")]
+fn text_strs(text: &str, ideographic_runs: bool) -> impl Iterator<Item = &str> {
+    tokenize(text).segmented_strs(ideographic_runs)
+}
+
+/// The tokens of `text`.
+#[cfg(not(feature = "unicode-segmentation"))]
+fn text_strs(text: &str, _ideographic_runs: bool) -> impl Iterator<Item = &str> {
+    tokenize(text).strs()
+}
+
+/// The annotated tokens of `text`, borrowed from it.
+pub(crate) fn text_tokens<'t, L: LangInterpreter>(
+    text: &'t str,
+    lang: &L,
+    options: &Options,
+) -> Vec<TextSpan<'t>> {
+    annotate_tokens(
+        base_tokens(text, lang.prefers_ideographic_runs(), &options.markup),
+        lang,
+        options,
+    )
+}
+
+/// The tokens of `text` with their lowercase form, before the annotations of the language.
+/// Each `markup` is a token of its own.
+pub(crate) fn base_tokens<'t>(
+    text: &'t str,
+    ideographic_runs: bool,
+    markup: &Markup,
+) -> Vec<TextSpan<'t>> {
+    base_token_stream(text, ideographic_runs, markup).collect()
+}
+
+/// Same as [`base_tokens`], but produced lazily.
+fn base_token_stream<'t>(
+    text: &'t str,
+    ideographic_runs: bool,
+    markup: &Markup,
+) -> impl Iterator<Item = TextSpan<'t>> {
+    let mut cursor = 0;
+    markup
+        .find_iter(text)
+        .map(Some)
+        .chain(core::iter::once(None))
+        .flat_map(move |tag| {
+            let end = tag.as_ref().map_or(text.len(), |tag| tag.start);
+            let words = text_strs(&text[cursor..end], ideographic_runs).map(TextSpan::new);
+            let tag = tag.map(|tag| {
+                cursor = tag.end;
+                let mut token = TextSpan::new(&text[tag]);
+                token.markup = true;
+                token
+            });
+            words.chain(tag)
+        })
+}
+
+/// The number of tokens annotated at once by [`token_stream`]
+const ANNOTATION_CHUNK: usize = 256;
+
+/// Same as [`text_tokens`], but produced lazily, chunk by chunk, so that the tokens of a large text
+/// are never all in memory.
+///
+/// Each chunk is annotated with the [`ANNOTATION_CONTEXT`] tokens around it, that are not part of it.
+pub(crate) fn token_stream<'t, L: LangInterpreter>(
+    text: &'t str,
+    lang: &L,
+    options: &Options,
+) -> impl Iterator<Item = TextSpan<'t>> {
+    let split = options.convert_in_hyphenated_compounds || options.salvage_partial_groups;
+    let mut base = base_token_stream(text, lang.prefers_ideographic_runs(), &options.markup)
+        .fuse()
+        .peekable();
+    // The last tokens of the previous chunk, the tokens of the chunk from `start` to `end`,
+    // and the tokens read after it, that are annotated again with the next chunk
+    // about one token in two bytes, for the short texts
+    let mut window: Vec<TextSpan<'t>> =
+        Vec::with_capacity((text.len() / 2 + 1).min(ANNOTATION_CHUNK + 2 * ANNOTATION_CONTEXT));
+    let (mut start, mut end): (usize, usize) = (0, 0);
+    let mut after_nan: Vec<bool> = Vec::new();
+    core::iter::from_fn(move || {
+        if start == end {
+            window.drain(..end.saturating_sub(ANNOTATION_CONTEXT));
+            let before = end.min(ANNOTATION_CONTEXT);
+            for (token, nan) in window[before..].iter_mut().zip(&after_nan) {
+                token.nan = *nan;
+            }
+            let wanted = (ANNOTATION_CHUNK + ANNOTATION_CONTEXT + before) - window.len();
+            if split {
+                let fresh = base.by_ref().take(wanted).collect();
+                window.extend(split_hyphenated_compounds(
+                    fresh,
+                    lang,
+                    options.salvage_partial_groups,
+                ));
+            } else {
+                window.extend(base.by_ref().take(wanted));
+            }
+            if window.len() == before {
+                window.clear();
+                (start, end) = (0, 0);
+                return None;
+            }
+            start = before;
+            end = if base.peek().is_none() {
+                window.len()
+            } else {
+                window.len() - ANNOTATION_CONTEXT
+            };
+            after_nan.clear();
+            after_nan.extend(window[end..].iter().map(|token| token.nan));
+            lang.basic_annotate(&mut window);
+        }
+        // the last tokens of the chunk are kept as the context of the next one
+        let token = if end - start > ANNOTATION_CONTEXT {
+            core::mem::replace(&mut window[start], TextSpan::new(""))
+        } else {
+            window[start].clone()
+        };
+        start += 1;
+        Some(token)
+    })
+}
+
+/// Split and annotate the [`base_tokens`] for `lang` and `options`.
+pub(crate) fn annotate_tokens<'t, L: LangInterpreter>(
+    mut tokens: Vec<TextSpan<'t>>,
+    lang: &L,
+    options: &Options,
+) -> Vec<TextSpan<'t>> {
+    if splits_compounds(options) {
+        tokens = split_hyphenated_compounds(tokens, lang, options.salvage_partial_groups);
+    }
+    lang.basic_annotate(&mut tokens);
+    tokens
+}
+
+/// The [`base_tokens`] cut at the hyphens of the compounds that `options` converts,
+/// still borrowed if `options` converts none.
+pub(crate) fn split_tokens<'p, 't, L: LangInterpreter>(
+    tokens: &'p [TextSpan<'t>],
+    lang: &L,
+    options: &Options,
+) -> Cow<'p, [TextSpan<'t>]> {
+    if splits_compounds(options) {
+        Cow::Owned(split_hyphenated_compounds(
+            tokens.to_vec(),
+            lang,
+            options.salvage_partial_groups,
+        ))
+    } else {
+        Cow::Borrowed(tokens)
+    }
+}
+
+fn splits_compounds(options: &Options) -> bool {
+    options.convert_in_hyphenated_compounds || options.salvage_partial_groups
+}
+
+/// Why a replacement stopped before the end of the text (see [`replace_numbers_to`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpliceError {
+    /// The writer failed
+    Write,
+    /// An occurence broke an invariant of the splice (see [`Options::paranoid`]): nothing of it was written.
+    /// This is a bug of the crate, or of the language, or of the numbers given to [`splice`](crate::pipeline::splice).
+    Internal(EditError),
+}
+
+impl From<core::fmt::Error> for SpliceError {
+    fn from(_: core::fmt::Error) -> Self {
+        Self::Write
+    }
+}
+
+impl core::fmt::Display for SpliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Write => f.write_str("the writer failed"),
+            Self::Internal(error) => write!(f, "internal error: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SpliceError {}
+
+/// Write `text` to `out` with the numbers found in its `tokens` replaced.
+///
+/// The output is written in one forward pass, splicing the occurences in by their byte range.
+pub(crate) fn replace_in_text<L: LangInterpreter, T: Token, W: core::fmt::Write>(
+    text: &str,
+    tokens: impl Iterator<Item = T>,
+    lang: &L,
+    options: &Options,
+    trace: Option<&mut Trace>,
+    alignment: Option<&mut Alignment>,
+    out: &mut W,
+) -> Result<Stats, SpliceError> {
+    let mut finder = FindNumbers::new(tokens.enumerate(), lang, options);
+    if trace.is_some() {
+        finder.enable_trace();
+    }
+    let stats = splice(text, finder.by_ref(), options, alignment, out);
+    if let Some(trace) = trace {
+        *trace = finder.take_trace();
+    }
+    let mut stats = stats?;
+    stats.aborted = finder.aborted;
+    Ok(stats)
+}
+
+/// Write `text` to `out` with the `occurences` spliced in.
+///
+/// In debug builds, or with [`Options::paranoid`], each occurence is checked before anything of it is written.
+pub(crate) fn splice<W: core::fmt::Write>(
+    text: &str,
+    occurences: impl Iterator<Item = Occurence>,
+    options: &Options,
+    mut alignment: Option<&mut Alignment>,
+    out: &mut W,
+) -> Result<Stats, SpliceError> {
+    let mut stats = Stats::default();
+    let mut write = |input: Range<usize>, s: &str, replaced: bool| {
+        if let Some(alignment) = alignment.as_deref_mut() {
+            alignment.push(input, s.len(), replaced);
+        }
+        stats.bytes_written += s.len();
+        out.write_str(s)
+    };
+    let mut guard = (cfg!(debug_assertions) || options.paranoid).then(|| {
+        SpliceGuard::new(
+            text,
+            Some(text.len().saturating_mul(options.max_output_factor)),
+        )
+    });
+    let mut cursor = 0;
+    let mut paragraphs = options.isolate_digits_in_rtl.then(|| Paragraphs::new(text));
+    let (mut numbers, mut linking_words) = (0, 0);
+    for occurence in occurences {
+        let mut replacement = if let Some(paragraphs) = paragraphs.as_mut()
+            && paragraphs.is_rtl_at(occurence.range.start)
+        {
+            Cow::Owned(format!("{FSI}{}{PDI}", occurence.text))
+        } else {
+            Cow::Borrowed(occurence.text.as_str())
+        };
+        if options.markup_placement != MarkupPlacement::Drop
+            && !matches!(options.markup, Markup::None)
+            && let Some(number) = text.get(occurence.range.clone())
+        {
+            let tags: String = options
+                .markup
+                .find_iter(number)
+                .map(|tag| &number[tag])
+                .collect();
+            if !tags.is_empty() {
+                replacement = Cow::Owned(match options.markup_placement {
+                    MarkupPlacement::After => format!("{replacement}{tags}"),
+                    _ => format!("{tags}{replacement}"),
+                });
+            }
+        }
+        if let Some(guard) = guard.as_mut() {
+            guard
+                .replace(&occurence.range, replacement.len())
+                .map_err(SpliceError::Internal)?;
+        }
+        write(
+            cursor..occurence.range.start,
+            &text[cursor..occurence.range.start],
+            false,
+        )?;
+        write(occurence.range.clone(), &replacement, true)?;
+        cursor = occurence.range.end;
+        numbers += 1;
+        linking_words += occurence.linking_words.len();
+    }
+    write(cursor..text.len(), &text[cursor..], false)?;
+    if let Some(guard) = guard {
+        guard
+            .finish(stats.bytes_written)
+            .map_err(SpliceError::Internal)?;
+    }
+    stats.numbers = numbers;
+    stats.linking_words = linking_words;
+    Ok(stats)
+}
+
+/// Replace the numbers found in the `tokens` of `text` in a new string, or return `text` unchanged
+/// if an invariant of the splice was broken.
+pub(crate) fn replace_to_string<L: LangInterpreter, T: Token>(
+    text: &str,
+    tokens: impl Iterator<Item = T>,
+    lang: &L,
+    options: &Options,
+    trace: Option<&mut Trace>,
+    mut alignment: Option<&mut Alignment>,
+) -> (String, Stats) {
+    let mut out = String::with_capacity(text.len());
+    // writing to a String never fails
+    match replace_in_text(
+        text,
+        tokens,
+        lang,
+        options,
+        trace,
+        alignment.as_deref_mut(),
+        &mut out,
+    ) {
+        Ok(stats) => (out, stats),
+        Err(_) => {
+            if let Some(alignment) = alignment {
+                *alignment = Alignment::default();
+                alignment.push(0..text.len(), text.len(), false);
+            }
+            (text.to_string(), Stats::default())
+        }
+    }
+}
+
+/// Split the hyphenated compounds that start with a number into the number, the hyphen and the rest of the compound,
+/// which is not a number part (see [`Options::convert_in_hyphenated_compounds`]).
+///
+/// If `salvage`, the compounds that end with a longer number are split too, and the numbers are marked as partial
+/// (see [`Options::salvage_partial_groups`]).
+fn split_hyphenated_compounds<'a, L: LangInterpreter>(
+    tokens: Vec<TextSpan<'a>>,
+    lang: &L,
+    salvage: bool,
+) -> Vec<TextSpan<'a>> {
+    let mut split = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        // The hyphen that ends the number prefix, or starts the number suffix, in the lowercase form
+        let cut = (!token.markup && token.text.contains(tokenizer::is_hyphen))
+            .then(|| {
+                let lowercase = token.lowercase();
+                let prefix = lang.hyphenated_number_prefix(lowercase);
+                let suffix = salvage
+                    .then(|| lang.hyphenated_number_suffix(lowercase))
+                    .flatten();
+                match (prefix, suffix) {
+                    (Some(len), Some(start)) if lowercase.len() - start > len => {
+                        Some((start - 1, false))
+                    }
+                    (Some(len), _) => Some((len, true)),
+                    (None, Some(start)) => Some((start - 1, false)),
+                    (None, None) => None,
+                }
+            })
+            .flatten();
+        // Found again in the text as the lowercase form may not have the same length,
+        // nor the same hyphens
+        let hyphen = cut.and_then(|(pos, is_prefix)| {
+            let hyphens = token.lowercase()[..pos].matches('-').count();
+            token
+                .text
+                .match_indices(tokenizer::is_hyphen)
+                .nth(hyphens)
+                .map(|(pos, hyphen)| (pos..pos + hyphen.len(), is_prefix))
+        });
+        if let Some((hyphen, is_prefix)) = hyphen {
+            let text = token.text;
+            let (head, tail) = (
+                TextSpan::new(&text[..hyphen.start]),
+                TextSpan::new(&text[hyphen.end..]),
+            );
+            let (mut number, mut rest) = if is_prefix {
+                (head, tail)
+            } else {
+                (tail, head)
+            };
+            number.partial = salvage;
+            rest.nan = true;
+            let hyphen = TextSpan::new(&text[hyphen]);
+            if is_prefix {
+                split.extend([number, hyphen, rest]);
+            } else {
+                split.extend([rest, hyphen, number]);
+            }
+        } else {
+            split.push(token);
+        }
+    }
+    split
+}
+
+/// The token `text` is a number already written in digits, like "*1,234.56*" or "*3rd*" (see [`Token::is_digits`]).
+pub(crate) fn starts_with_digit(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// The greatest common divisor of `a` and `b`.
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// `text` starts with the whole words of `phrase`: it is `phrase`, or `phrase` followed by `separator`.
+pub(crate) fn starts_with_phrase(text: &str, phrase: &str, separator: char) -> bool {
+    text.strip_prefix(phrase)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(separator))
+}
+
+/// `word` is a scale word on its own, like "*hundred*" or "*thousand*".
+fn is_scale_word<L: LangInterpreter>(lang: &L, word: &str) -> bool {
+    lang.exec_group(core::iter::once(word)).is_ok_and(|ds| {
+        let digits = ds.to_string();
+        !ds.is_ordinal()
+            && digits.len() >= 3
+            && digits.starts_with('1')
+            && digits[1..].bytes().all(|c| c == b'0')
+    })
+}
+
+/// The number of decimals of the fractions written as decimal numbers (see [`FractionOutput::Decimal`])
+const FRACTION_DECIMALS: usize = 6;
+
+/// The positive `value` of a fraction as a decimal number of `lang`, rounded to [`FRACTION_DECIMALS`] decimals.
+fn fraction_decimal<L: LangInterpreter>(lang: &L, value: f64) -> String {
+    let scale = 10u64.pow(FRACTION_DECIMALS as u32);
+    // without `f64::round`, that needs std
+    let scaled = (value * scale as f64 + 0.5) as u64;
+    let mut int = DigitString::new();
+    let _ = int.push((scaled / scale).to_string().as_bytes());
+    let decimals = format!("{:0width$}", scaled % scale, width = FRACTION_DECIMALS);
+    let decimals = decimals.trim_end_matches('0');
+    if decimals.is_empty() {
+        return int.to_string();
+    }
+    let mut dec = DigitString::new();
+    let _ = dec.push(decimals.as_bytes());
+    lang.format_decimal_and_value(&int, &dec, lang.decimal_symbol())
+        .0
+}
+
+/// `word` multiplies the number written in `digits` by a power of ten, like "*thousand*" after "*3*"
+/// or "*millions*" after "*20*".
+fn scales_digits<L: LangInterpreter>(lang: &L, digits: &str, word: &str) -> bool {
+    let mut b = DigitString::new();
+    if b.put(digits.as_bytes()).is_err() || lang.apply(word, &mut b).is_err() || b.is_ordinal() {
+        return false;
+    }
+    let scaled = b.to_string();
+    scaled.len() > digits.len()
+        && scaled.starts_with(digits)
+        && scaled[digits.len()..].bytes().all(|c| c == b'0')
+}
+
+fn is_whitespace(token: &str) -> bool {
+    token.chars().all(char::is_whitespace)
 }
 
-/**
-Return an iterator over all the number occurences (including decimal numbers) found in a speech token stream.
+/// The token ends a sentence: its last punctuation, before the closing quotes and brackets, is terminal,
+/// like "*. *" or "*?» *".
+fn is_sentence_end(token: &str) -> bool {
+    token
+        .trim_end_matches(|c: char| c.is_whitespace() || is_quote_or_bracket(c))
+        .ends_with(['.', '!', '?', '…', '。', '！', '？'])
+}
 
-Return an iterator of the successive [`Occurence`]s of numbers in the stream.
-The `threshold` drives the *lone number* policy: if a number is isolated — that is,
-surrounded by significant non-number words — and lower than `threshold`, then it
-is ignored.
-*/
-pub fn find_numbers_iter<L, T, I>(
-    input: I,
-    lang: &L,
-    threshold: f64,
-) -> FindNumbers<'_, L, T, Enumerate<I>>
-where
-    L: LangInterpreter,
-    T: Token,
-    I: Iterator<Item = T>,
-{
-    FindNumbers::new(input.enumerate(), lang, threshold)
+fn is_quote_or_bracket(c: char) -> bool {
+    matches!(
+        c,
+        '"' | '\''
+            | '«'
+            | '»'
+            | '“'
+            | '”'
+            | '„'
+            | '‘'
+            | '’'
+            | '‹'
+            | '›'
+            | '('
+            | ')'
+            | '['
+            | ']'
+            | '{'
+            | '}'
+    )
 }
 
-/// Find spelled numbers (including decimal) in the token stream and replace them by their digit representation.
-/// Isolated digits strictly under `threshold` are not converted (set to 0.0 to convert everything).
-pub fn replace_numbers_in_stream<'a, L, T>(mut input: Vec<T>, lang: &L, threshold: f64) -> Vec<T>
-where
-    L: LangInterpreter,
-    T: Replace + 'a,
-    for<'b> &'b T: Token,
-{
-    let tracker = track_numbers(input.iter(), lang, threshold);
-    tracker.replace(&mut input);
-    input
+/// The token is skipped like a space: a space or markup.
+fn is_blank<T: Token>(token: &T) -> bool {
+    token.is_markup() || is_whitespace(token.text())
 }
 
-/// Find spelled numbers (including decimal) in the `text` and replace them by their digit representation.
-/// Isolated digits strictly under `threshold` are not converted (set to 0.0 to convert everything).
-pub fn replace_numbers_in_text<L: LangInterpreter>(text: &str, lang: &L, threshold: f64) -> String {
-    let mut tokens = tokenize(text).collect();
-    lang.basic_annotate(&mut tokens);
-    let out = replace_numbers_in_stream(tokens, lang, threshold);
-    out.join("")
+/// A token ending with a currency symbol, like "*$*" or "*, €*"
+fn is_currency_symbol(token: &str) -> bool {
+    token
+        .trim_end()
+        .chars()
+        .next_back()
+        .is_some_and(|c| "$€£¥₩₹₽₺₴₦₱₪₫฿¢".contains(c))
 }
 
-fn is_whitespace(token: &str) -> bool {
-    token.chars().all(char::is_whitespace)
+/// `occurence` can be one of the numbers of a score (see [`Options::scores`]).
+fn is_score_number(occurence: &Occurence) -> bool {
+    occurence.kind == NumberKind::Cardinal
+        && occurence.value < 100.0
+        && occurence.text.bytes().all(|c| c.is_ascii_digit())
+}
+
+/// A token made of a single minus sign
+fn is_minus_sign(token: &str) -> bool {
+    let mut chars = token.chars();
+    chars.next().is_some_and(tokenizer::is_minus_sign) && chars.next().is_none()
+}
+
+/// A hyphen alone, like "*-*" or "*\u{2011}*" (see [`tokenizer::is_hyphen`])
+fn is_hyphen(token: &str) -> bool {
+    let mut chars = token.chars();
+    chars.next().is_some_and(tokenizer::is_hyphen) && chars.next().is_none()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg_attr(not(any(feature = "en", feature = "fr")), allow(unused_imports))]
     use crate::lang::Language;
+    #[cfg_attr(not(any(feature = "en", feature = "fr")), allow(unused_imports))]
     use crate::tokenizer::tokenize;
 
     impl Token for BasicToken {
@@ -554,9 +3739,60 @@ mod tests {
     }
 
     #[test]
+    #[cfg(all(feature = "en", feature = "fr", feature = "de"))]
+    fn test_recommended_options() {
+        let (en, fr, de) = (Language::english(), Language::french(), Language::german());
+        assert_eq!(
+            replace_numbers_in_text_recommended("one of the twenty's", &en),
+            "one of the 20's"
+        );
+        assert_eq!(
+            replace_numbers_in_text_recommended("un chat et vingt et un chiens", &fr),
+            "un chat et 21 chiens"
+        );
+        assert_eq!(
+            replace_numbers_in_text_recommended(
+                "erstens kam ein Mann am dritten, zweitens ein Kilo",
+                &de
+            ),
+            "1. kam ein Mann am 3., 2. 1 Kilo"
+        );
+        // the profiles differ
+        assert!(Options::recommended_for(&en).strip_clitics);
+        assert!(!Options::recommended_for(&fr).strip_clitics);
+        assert!(Options::recommended_for(&de).threshold < Options::recommended_for(&fr).threshold);
+        assert!(Options::recommended_for(&de).adverbial_ordinals);
+        assert!(!Options::recommended_for(&en).adverbial_ordinals);
+        // the overrides win
+        let options = Options {
+            threshold: 0.0,
+            ..Options::recommended_for(&fr)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("un chat", &fr, &options),
+            "1 chat"
+        );
+        let options = Options {
+            adverbial_ordinals: false,
+            ..Options::recommended_for(&de)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("erstens", &de, &options),
+            "erstens"
+        );
+        let tokens = tokenize("one of twenty two").collect();
+        let replaced: Vec<String> = replace_numbers_in_stream_recommended(tokens, &en)
+            .into_iter()
+            .map(|token| token.text().to_owned())
+            .collect();
+        assert_eq!(replaced.concat(), "one of 22");
+    }
+
+    #[test]
+    #[cfg(feature = "fr")]
     fn test_word_to_digits_parser_zero() {
         let fr = Language::french();
-        let mut parser = WordToDigitParser::new(&fr);
+        let mut parser = WordToDigitParser::new(&fr, &Options::default());
         parser.push("zéro").unwrap();
         assert!(parser.has_number());
         let (repr, val) = parser.string_and_value();
@@ -565,6 +3801,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fr")]
     fn test_grouping() {
         let fr = Language::french();
         let wyget = replace_numbers_in_text("zéro zéro trente quatre-vingt-dix-sept", &fr, 10.0);
@@ -572,6 +3809,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fr")]
     fn test_find_isolated_single() {
         let fr = Language::french();
         let ocs = find_numbers(tokenize("c'est un logement neuf"), &fr, 10.0);
@@ -580,6 +3818,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fr")]
     fn test_find_all_isolated_single() {
         let fr = Language::french();
         let ocs = find_numbers(tokenize("c'est zéro"), &fr, 0.0);
@@ -590,6 +3829,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fr")]
     fn test_find_isolated_long() {
         let fr = Language::french();
         let ocs = find_numbers(tokenize("trente-sept rue du docteur leroy"), &fr, 10.0);
@@ -600,6 +3840,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "fr")]
     fn test_find_isolated_with_leading_zero() {
         let fr = Language::french();
         let ocs = find_numbers(tokenize("quatre-vingt-douze slash zéro deux"), &fr, 10.0);
@@ -609,6 +3850,1202 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "en")]
+    fn test_find_stutter() {
+        let en = Language::english();
+        let options = Options {
+            collapse_stutters: true,
+            ..Options::new(10.0)
+        };
+        let ocs = find_numbers_with(tokenize("two two hundred and fifty"), &en, &options);
+        crate::tests::dbg!(&ocs);
+        assert_eq!(ocs.len(), 1);
+        assert_eq!(ocs[0].text, "250");
+        assert_eq!(ocs[0].start, 0);
+        assert_eq!(ocs[0].end, 9);
+        assert!(ocs[0].repaired);
+        let ocs = find_numbers_with(tokenize("two two three"), &en, &options);
+        crate::tests::dbg!(&ocs);
+        assert_eq!(ocs.len(), 3);
+        assert!(ocs.iter().all(|o| !o.repaired));
+        let ocs = find_numbers(tokenize("two two hundred and fifty"), &en, 10.0);
+        assert_eq!(ocs.len(), 2);
+        assert!(!ocs[1].repaired);
+    }
+
+    #[test]
+    fn test_token_stream_chunks() {
+        let marks = |spans: &mut dyn Iterator<Item = TextSpan<'_>>| -> Vec<(String, bool, bool)> {
+            spans
+                .map(|t| (t.text.to_owned(), t.nan, t.partial))
+                .collect()
+        };
+        let samples = [
+            (
+                "en",
+                "Oh well, o two o, eleven-o'clock. Oh seven, an o or two o. ",
+            ),
+            (
+                "fr",
+                "Un logement neuf, le neuf vaches. Du neuf neuf, vingt-neuf. ",
+            ),
+        ];
+        let options = Options {
+            salvage_partial_groups: true,
+            ..Options::new(10.0)
+        };
+        for (code, sample) in samples {
+            let Some(lang) = crate::get_interpreter_for(code) else {
+                continue;
+            };
+            // the chunks end at many positions of the sample
+            for shift in 0..16 {
+                let text = "x ".repeat(shift) + &sample.repeat(ANNOTATION_CHUNK / 16);
+                assert_eq!(
+                    marks(&mut token_stream(&text, &lang, &options)),
+                    marks(&mut text_tokens(&text, &lang, &options).into_iter()),
+                    "{code}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_find_partial() {
+        let en = Language::english();
+        let options = Options {
+            salvage_partial_groups: true,
+            ..Options::new(0.0)
+        };
+        let text = "twenty-one-porridge, porridge-three and four";
+        let tokens = text_tokens(text, &en, &options);
+        let ocs: Vec<_> = FindNumbers::new(tokens.iter().enumerate(), &en, &options)
+            .map(|o| (o.original(text).to_owned(), o.partial))
+            .collect();
+        assert_eq!(
+            ocs,
+            [
+                ("twenty-one".to_owned(), true),
+                ("three".to_owned(), true),
+                ("four".to_owned(), false)
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_replace_cow() {
+        let en = Language::english();
+        let options = Options::new(10.0);
+        for text in [
+            "",
+            "no number here",
+            "one apple, 21 pears",
+            "it's the first",
+        ] {
+            let replaced = replace_numbers_in_text_cow(text, &en, &options);
+            assert!(matches!(replaced.text, Cow::Borrowed(t) if t == text));
+            assert_eq!(replaced.count, 0);
+        }
+        for (text, expected, count) in [
+            ("twenty-one", "21", 1),
+            (
+                "one apple and twenty-five pears",
+                "one apple and 25 pears",
+                1,
+            ),
+            ("fifteen, then sixteen.", "15, then 16.", 2),
+        ] {
+            let replaced = replace_numbers_in_text_cow(text, &en, &options);
+            assert!(matches!(replaced.text, Cow::Owned(_)));
+            assert_eq!(replaced.text, expected);
+            assert_eq!(replaced.count, count);
+            assert_eq!(
+                replaced.text,
+                replace_numbers_in_text_with(text, &en, &options)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "en", feature = "fr", feature = "es"))]
+    fn test_unicode_neighbours() {
+        let options = Options {
+            convert_in_hyphenated_compounds: true,
+            ..Options::new(0.0)
+        };
+        for (lang, text, expected) in [
+            (
+                Language::english(),
+                "twenty\u{2011}three 👍, twenty✨three, 👨\u{200d}👩\u{200d}👧forty-two👨\u{200d}👩\u{200d}👧, \
+                three\u{2011}dimensional, five\u{301}",
+                "23 👍, 20✨3, 👨\u{200d}👩\u{200d}👧42👨\u{200d}👩\u{200d}👧, 3\u{2011}dimensional, 5\u{301}",
+            ),
+            (
+                Language::french(),
+                "quatre\u{2011}vingt\u{2010}dix 🎉vingt-et-un🎉",
+                "90 🎉21🎉",
+            ),
+            (
+                Language::spanish(),
+                "treinta y dos👍 e\u{301}l",
+                "32👍 e\u{301}l",
+            ),
+        ] {
+            assert_eq!(
+                replace_numbers_in_text_with(text, &lang, &options),
+                expected
+            );
+            let tokens = text_tokens(text, &lang, &options);
+            for occurence in FindNumbers::new(tokens.iter().enumerate(), &lang, &options) {
+                assert!(text.is_char_boundary(occurence.range.start));
+                assert!(text.is_char_boundary(occurence.range.end));
+            }
+            let (output, alignment) = replace_numbers_in_text_aligned(text, &lang, &options);
+            for segment in alignment.segments() {
+                assert!(text.is_char_boundary(segment.input.end));
+                assert!(output.is_char_boundary(segment.output.end));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_find_morphology() {
+        let en = Language::english();
+        let options = Options {
+            decimal_separators: vec![("dot".into(), '.')],
+            ordinal_style: OrdinalStyle::Superscript,
+            ..Options::new(0.0)
+        };
+        let text = "the third, two thirds, three point five, four dot two and seven";
+        let tokens = text_tokens(text, &en, &options);
+        let ocs: Vec<_> = FindNumbers::new(tokens.iter().enumerate(), &en, &options)
+            .map(|o| o.morphology)
+            .collect();
+        let morphology = |marker, denominator, separator_word: Option<&str>| {
+            Some(Morphology {
+                marker,
+                denominator,
+                separator_word: separator_word.map(String::from),
+            })
+        };
+        assert_eq!(
+            ocs,
+            [
+                morphology(MorphologicalMarker::Ordinal("rd".into()), None, None),
+                morphology(MorphologicalMarker::None, Some(3), None),
+                morphology(MorphologicalMarker::None, None, Some("point")),
+                morphology(MorphologicalMarker::None, None, Some("dot")),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "en", feature = "fa"))]
+    fn test_isolate_digits_in_rtl() {
+        let en = Language::english();
+        let fa = Language::persian();
+        let isolate = Options {
+            isolate_digits_in_rtl: true,
+            ..Options::new(0.0)
+        };
+        let text = "המחיר הוא twenty one שקלים\nthe price is twenty one";
+        assert_eq!(
+            replace_numbers_in_text_with(text, &en, &isolate),
+            "המחיר הוא \u{2068}21\u{2069} שקלים\nthe price is 21"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with(text, &en, &Options::new(0.0)),
+            "המחיר הוא 21 שקלים\nthe price is 21"
+        );
+        // an English paragraph quoting Hebrew
+        let text = "he said שלום twenty one times";
+        assert_eq!(
+            replace_numbers_in_text_with(text, &en, &isolate),
+            "he said שלום 21 times"
+        );
+        let text = "من بیست و یک سال دارم";
+        assert_eq!(
+            replace_numbers_in_text_with(text, &fa, &isolate),
+            "من \u{2068}21\u{2069} سال دارم"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with(text, &fa, &Options::new(0.0)),
+            "من 21 سال دارم"
+        );
+        let (aligned, alignment) = replace_numbers_in_text_aligned(text, &fa, &isolate);
+        assert_eq!(aligned, "من \u{2068}21\u{2069} سال دارم");
+        assert_eq!(
+            alignment.map_input_to_output(text.find("سال").unwrap()),
+            aligned.find("سال").unwrap()
+        );
+        let edits = crate::propose_edits(text, &fa, &isolate);
+        assert_eq!(crate::apply_edits(text, &edits).unwrap(), aligned);
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_find_linking_words() {
+        let en = Language::english();
+        let options = Options::new(10.0);
+        let text = "one thousand and one nights and two hundred and five cats";
+        let tokens = text_tokens(text, &en, &options);
+        let words: Vec<_> = FindNumbers::new(tokens.iter().enumerate(), &en, &options)
+            .map(|o| o.linking_words)
+            .collect();
+        assert_eq!(
+            words,
+            [
+                vec![(13..16, String::from("and"))],
+                vec![(44..47, String::from("and"))]
+            ]
+        );
+        // not the one between the numbers
+        assert_eq!(&text[28..31], "and");
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_splice_guard() {
+        let en = Language::english();
+        let options = Options {
+            paranoid: true,
+            ..Options::new(10.0)
+        };
+        let text = "twenty-one cats and forty dogs";
+        let tokens = text_tokens(text, &en, &options);
+        // the occurences found, one by one
+        let nth = |i: usize| {
+            FindNumbers::new(tokens.iter().enumerate(), &en, &options)
+                .nth(i)
+                .unwrap()
+        };
+        let spliced = |occurences: Vec<Occurence>| {
+            let mut out = String::new();
+            let result = splice(text, occurences.into_iter(), &options, None, &mut out);
+            (out, result.map(|stats| stats.numbers))
+        };
+        assert_eq!(
+            spliced(vec![nth(0), nth(1)]),
+            ("21 cats and 40 dogs".to_string(), Ok(2))
+        );
+        // nothing of the faulty occurence is written
+        let (out, result) = spliced(vec![nth(0), nth(1), nth(1)]);
+        assert_eq!(out, "21 cats and 40");
+        assert_eq!(
+            result,
+            Err(SpliceError::Internal(EditError::Overlap { index: 2 }))
+        );
+        assert_eq!(
+            spliced(vec![nth(1), nth(0)]).1,
+            Err(SpliceError::Internal(EditError::Unsorted { index: 1 }))
+        );
+        let mut outside = nth(1);
+        outside.range = 20..40;
+        assert_eq!(
+            spliced(vec![outside]).1,
+            Err(SpliceError::Internal(EditError::OutOfBounds { index: 0 }))
+        );
+        let mut reversed = nth(1);
+        reversed.range.end = 3;
+        assert_eq!(
+            spliced(vec![reversed]).1,
+            Err(SpliceError::Internal(EditError::InvalidRange { index: 0 }))
+        );
+        let mut duplicated = nth(0);
+        duplicated.text = text.repeat(8);
+        assert_eq!(
+            spliced(vec![duplicated]).1,
+            Err(SpliceError::Internal(EditError::TooLong { index: 0 }))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_find_scores() {
+        let en = Language::english();
+        let scores = |text: &str, options: &Options| -> Vec<(String, bool)> {
+            let tokens = text_tokens(text, &en, options);
+            FindNumbers::new(tokens.iter().enumerate(), &en, options)
+                .map(|o| (o.text, o.score))
+                .collect()
+        };
+        let pairs = |expected: &[(&str, bool)]| -> Vec<(String, bool)> {
+            expected.iter().map(|&(t, s)| (t.into(), s)).collect()
+        };
+        let mut options = Options {
+            scores: true,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            scores("the vote was three two", &options),
+            pairs(&[("3", false), ("2", true)])
+        );
+        assert_eq!(
+            scores("twenty one to nineteen", &options),
+            pairs(&[("21", false), ("19", true)])
+        );
+        assert_eq!(
+            scores("three to two hundred", &options),
+            pairs(&[("3", false), ("200", false)])
+        );
+        assert_eq!(scores("three to go", &options), pairs(&[]));
+        options.adjacent_number_policy = AdjacentNumberPolicy::Concatenate;
+        assert_eq!(
+            scores("three two", &options),
+            pairs(&[("3", false), ("2", true)])
+        );
+        assert_eq!(scores("one two three", &options), pairs(&[("123", false)]));
+        options.convert_in_hyphenated_compounds = true;
+        assert_eq!(
+            scores("three two-bedroom flats", &options),
+            pairs(&[("3", false), ("2", false)])
+        );
+        options.scores = false;
+        assert_eq!(scores("three two", &options), pairs(&[("32", false)]));
+    }
+
+    #[test]
+    fn test_scores() {
+        // a score with the connector of the language, and a score without connector;
+        // "lt" and "sk" have no connector, as their scores are read without one ("trys du"),
+        // or with a connector that governs a case the numbers are not read in ("tri ku dvom")
+        let cases = [
+            (
+                "bg",
+                Some(("спечелиха три на две", "спечелиха 3 на 2")),
+                "три две",
+            ),
+            (
+                "de",
+                Some(("sie gewannen drei zu zwei", "sie gewannen 3 zu 2")),
+                "drei zwei",
+            ),
+            (
+                "en",
+                Some(("the vote was three to two", "the vote was 3 to 2")),
+                "three two",
+            ),
+            (
+                "eo",
+                Some(("ili venkis tri kontraŭ du", "ili venkis 3 kontraŭ 2")),
+                "tri du",
+            ),
+            (
+                "es",
+                Some(("ganaron tres a dos", "ganaron 3 a 2")),
+                "tres dos",
+            ),
+            ("fa", Some(("سه بر دو بردند", "3 بر 2 بردند")), "سه دو"),
+            (
+                "fr",
+                Some(("ils ont gagné trois à deux", "ils ont gagné 3 à 2")),
+                "trois deux",
+            ),
+            (
+                "gl",
+                Some(("gañaron tres a dous", "gañaron 3 a 2")),
+                "tres dous",
+            ),
+            (
+                "hr",
+                Some(("pobijedili su tri naprema dva", "pobijedili su 3 naprema 2")),
+                "tri dva",
+            ),
+            (
+                "it",
+                Some(("hanno vinto tre a due", "hanno vinto 3 a 2")),
+                "tre due",
+            ),
+            ("lt", None, "trys du"),
+            (
+                "nl",
+                Some(("ze wonnen drie tegen twee", "ze wonnen 3 tegen 2")),
+                "drie twee",
+            ),
+            (
+                "pt",
+                Some(("ganharam três a dois", "ganharam 3 a 2")),
+                "três dois",
+            ),
+            ("sk", None, "tri dva"),
+            (
+                "sw",
+                Some(("walishinda tatu kwa mbili", "walishinda 3 kwa 2")),
+                "tatu mbili",
+            ),
+        ];
+        let options = Options {
+            scores: true,
+            adjacent_number_policy: AdjacentNumberPolicy::Concatenate,
+            ..Options::new(10.0)
+        };
+        for (code, score, plain) in cases {
+            let Some(lang) = crate::get_interpreter_for(code) else {
+                continue;
+            };
+            if let Some((text, expected)) = score {
+                assert_eq!(
+                    replace_numbers_in_text_with(text, &lang, &options),
+                    expected,
+                    "{code}"
+                );
+                // not on request
+                assert_eq!(replace_numbers_in_text(text, &lang, 10.0), text, "{code}");
+            }
+            assert_eq!(
+                replace_numbers_in_text_with(plain, &lang, &options),
+                "3 2",
+                "{code}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_linking_stats() {
+        // (numbers, linking words, aborted): the numbers of a single word are given up
+        let cases = [
+            (
+                "de",
+                "Es gibt hundert und fünf Katzen, zweiundzwanzig Hunde und einen Vogel, dann eins zwei drei.",
+                "Es gibt 105 Katzen, zweiundzwanzig Hunde und einen Vogel, dann eins zwei drei.",
+                (1, 1, 4),
+            ),
+            (
+                "en",
+                "We sold two hundred and five cats, one hundred and twenty dogs and one bird, then one two three.",
+                "We sold 205 cats, 120 dogs and one bird, then one two three.",
+                (2, 2, 4),
+            ),
+            (
+                "es",
+                "Hay treinta y dos gatos, cuarenta y tres perros y un pájaro, luego uno dos tres.",
+                "Hay 32 gatos, 43 perros y un pájaro, luego uno dos tres.",
+                (2, 2, 4),
+            ),
+            (
+                "fr",
+                "Il y a vingt et un chats, cent trois chiens et un oiseau, puis un deux trois.",
+                "Il y a 21 chats, 103 chiens et un oiseau, puis un deux trois.",
+                (2, 1, 4),
+            ),
+        ];
+        let options = Options {
+            min_word_count: 2,
+            ..Options::new(10.0)
+        };
+        for (code, text, expected, counts) in cases {
+            let Some(lang) = crate::get_interpreter_for(code) else {
+                continue;
+            };
+            let (replaced, stats) = replace_numbers_in_text_stats(text, &lang, &options);
+            assert_eq!(replaced, expected, "{code}");
+            assert_eq!(
+                (stats.numbers, stats.linking_words, stats.aborted),
+                counts,
+                "{code}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_original_text() {
+        let en = Language::english();
+        let text = "Total: two hundred  and five, twenty-one\tpoint five; then (fifty-six)!";
+        let ocs = find_numbers(tokenize(text), &en, 10.0);
+        crate::tests::dbg!(&ocs);
+        let originals: Vec<&str> = ocs.iter().map(|o| o.original(text)).collect();
+        assert_eq!(
+            originals,
+            [
+                "two hundred  and five",
+                "twenty-one\tpoint five",
+                "fifty-six"
+            ]
+        );
+        for o in &ocs {
+            assert_eq!(&text[o.range.clone()], o.original(text));
+        }
+        let options = Options {
+            collapse_stutters: true,
+            ..Options::new(10.0)
+        };
+        let text = "two  two hundred, three three";
+        let ocs = find_numbers_with(tokenize(text), &en, &options);
+        let originals: Vec<&str> = ocs.iter().map(|o| o.original(text)).collect();
+        assert_eq!(originals, ["two  two hundred", "three", "three"]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "en", feature = "fr"))]
+    fn test_lang_code() {
+        let text = "twenty-one euros, soit vingt-deux dollars";
+        let en = Language::english();
+        let ocs = find_numbers(tokenize(text), &en, 10.0);
+        assert_eq!(ocs.len(), 1);
+        assert_eq!((ocs[0].value, ocs[0].lang_code), (21.0, "en"));
+        // several languages on the same text
+        let fr = Language::french();
+        let mut ocs: Vec<_> = [en, fr]
+            .iter()
+            .flat_map(|lang| find_numbers(tokenize(text), lang, 10.0))
+            .collect();
+        ocs.sort_by_key(|o| o.start);
+        let found: Vec<_> = ocs.iter().map(|o| (o.value, o.lang_code)).collect();
+        assert_eq!(found, [(21.0, "en"), (22.0, "fr")]);
+        let found = find_numbers_in_tokens(&["vingt", "et", "un"], &fr, &Options::new(10.0));
+        assert_eq!(found[0].lang_code, "fr");
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_numbers_in_tokens() {
+        let en = Language::english();
+        let options = Options::new(10.0);
+        // whisper style word-level output
+        let tokens = [
+            "so", "i", "paid", "twenty", "two", "dollars", "and", "three", "cents",
+        ];
+        let found = find_numbers_in_tokens(&tokens, &en, &options);
+        assert_eq!(
+            found,
+            [TokenOccurence {
+                tokens: 3..5,
+                text: "22".into(),
+                value: 22.0,
+                kind: NumberKind::Cardinal,
+                lang_code: "en",
+            }]
+        );
+        let mut tokens: Vec<String> = [
+            "twenty-one,",
+            "forty",
+            "two.",
+            "the",
+            "twentieth",
+            "one",
+            "point",
+            "five",
+        ]
+        .map(String::from)
+        .into();
+        let found = find_numbers_in_tokens(&tokens, &en, &options);
+        let ranges: Vec<_> = found.iter().map(|o| o.tokens.clone()).collect();
+        assert_eq!(ranges, [0..1, 1..3, 4..5, 5..8]);
+        let kinds: Vec<_> = found.iter().map(|o| o.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                NumberKind::Cardinal,
+                NumberKind::Cardinal,
+                NumberKind::Ordinal,
+                NumberKind::Decimal
+            ]
+        );
+        replace_numbers_in_tokens(&mut tokens, &en, &options);
+        assert_eq!(tokens, ["21,", "42.", "the", "20th", "1.5"]);
+        // punctuation inside a number splits it, and a token is part of one number at most
+        let mut tokens: Vec<String> = ["(twenty", "two)", "one/two", "or", "six"]
+            .map(String::from)
+            .into();
+        replace_numbers_in_tokens(&mut tokens, &en, &Options::new(0.0));
+        assert_eq!(tokens, ["(22)", "1/two", "or", "6"]);
+        let mut tokens = Vec::new();
+        replace_numbers_in_tokens(&mut tokens, &en, &options);
+        assert!(tokens.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_mask() {
+        use crate::options::{Mask, MaskError};
+        use alloc::string::ToString;
+        let en = Language::english();
+        let text = "call one hundred twenty three or `forty two` then five hundred";
+        let at = |word: &str| {
+            let start = text.find(word).unwrap();
+            start..start + word.len()
+        };
+        let replace = |ranges: &[Range<usize>]| {
+            let options = Options::new(10.0).with_mask(ranges).unwrap();
+            replace_numbers_in_text_with(text, &en, &options)
+        };
+        assert_eq!(replace(&[]), "call 123 or `42` then 500");
+        // a number straddling a protected range is left whole
+        assert_eq!(
+            replace(&[at("twenty")]),
+            "call one hundred twenty three or `42` then 500"
+        );
+        assert_eq!(
+            replace(&[at("hundred twenty"), at("`forty two`")]),
+            "call one hundred twenty three or `forty two` then 500"
+        );
+        assert_eq!(
+            replace(&[at("then five")]),
+            "call 123 or `42` then five hundred"
+        );
+        // touching is not overlapping
+        assert_eq!(
+            replace(&[at("call "), at(" or "), 200..200]),
+            "call 123 or `42` then 500"
+        );
+        let options = Options::new(10.0).with_mask(&[at("three")]).unwrap();
+        let ocs = find_numbers_with(tokenize(text), &en, &options);
+        let originals: Vec<&str> = ocs.iter().map(|o| o.original(text)).collect();
+        assert_eq!(originals, ["forty two", "five hundred"]);
+
+        assert_eq!(
+            Mask::new(&[0..2, Range { start: 4, end: 2 }]),
+            Err(MaskError::Reversed(1))
+        );
+        assert_eq!(Mask::new(&[0..2, 6..8, 4..5]), Err(MaskError::Unsorted(2)));
+        assert_eq!(Mask::new(&[0..5, 4..8]), Err(MaskError::Overlapping(1)));
+        assert_eq!(
+            Options::new(10.0)
+                .with_mask(&[0..5, 4..8])
+                .unwrap_err()
+                .to_string(),
+            "mask range #1 overlaps the previous one"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_markup() {
+        use crate::options::{Markup, MarkupPlacement};
+        let en = Language::english();
+        let replace = |text: &str, markup: Markup, markup_placement| {
+            let options = Options {
+                markup,
+                markup_placement,
+                ..Options::new(10.0)
+            };
+            replace_numbers_in_text_with(text, &en, &options)
+        };
+        let vtt = "WEBVTT\n\n\
+            00:00:01.000 --> 00:00:04.000\n\
+            <v Anna>I paid twenty <i>three</i> dollars</v>\n\n\
+            00:00:04.500 --> 00:00:06.000\n\
+            <c.yellow>one hundred</c> <00:00:05.000>and five <b>cats</b>\n";
+        assert_eq!(
+            replace(vtt, Markup::Tags, MarkupPlacement::Before),
+            "WEBVTT\n\n\
+            00:00:01.000 --> 00:00:04.000\n\
+            <v Anna>I paid <i>23</i> dollars</v>\n\n\
+            00:00:04.500 --> 00:00:06.000\n\
+            <c.yellow></c><00:00:05.000>105 <b>cats</b>\n"
+        );
+        assert_eq!(
+            replace(vtt, Markup::Tags, MarkupPlacement::After),
+            "WEBVTT\n\n\
+            00:00:01.000 --> 00:00:04.000\n\
+            <v Anna>I paid 23<i></i> dollars</v>\n\n\
+            00:00:04.500 --> 00:00:06.000\n\
+            <c.yellow>105</c><00:00:05.000> <b>cats</b>\n"
+        );
+        assert_eq!(
+            replace(vtt, Markup::Tags, MarkupPlacement::Drop),
+            "WEBVTT\n\n\
+            00:00:01.000 --> 00:00:04.000\n\
+            <v Anna>I paid 23</i> dollars</v>\n\n\
+            00:00:04.500 --> 00:00:06.000\n\
+            <c.yellow>105 <b>cats</b>\n"
+        );
+        // without markup, the tags end the numbers
+        assert_eq!(
+            replace(vtt, Markup::None, MarkupPlacement::Before),
+            "WEBVTT\n\n\
+            00:00:01.000 --> 00:00:04.000\n\
+            <v Anna>I paid 20 <i>three</i> dollars</v>\n\n\
+            00:00:04.500 --> 00:00:06.000\n\
+            <c.yellow>100</c> <00:00:05.000>and five <b>cats</b>\n"
+        );
+        let html = "<p>Chapter <em>twenty-one</em>: <b>forty</b>\
+            <br/>two</p><!-- seven -->3 < four > two";
+        assert_eq!(
+            replace(html, Markup::Tags, MarkupPlacement::Before),
+            "<p>Chapter <em>21</em>: <b></b><br/>42</p><!-- seven -->3 < 4 > 2"
+        );
+        // the tags are skipped like spaces, but the lone numbers are still lone
+        assert_eq!(
+            replace(
+                "<i>three</i> <b>cats</b>",
+                Markup::Tags,
+                MarkupPlacement::Before
+            ),
+            "<i>three</i> <b>cats</b>"
+        );
+        let ass = r"Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,twenty {\i1}three{\i0} cats";
+        assert_eq!(
+            replace(
+                ass,
+                Markup::Delimited(vec![("{".into(), "}".into())]),
+                MarkupPlacement::Before
+            ),
+            r"Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,{\i1}23{\i0} cats"
+        );
+        let custom = Markup::Custom(Arc::new(|text: &str| {
+            text.starts_with("[pause]").then_some("[pause]".len())
+        }));
+        assert_eq!(
+            replace("twenty [pause] three", custom, MarkupPlacement::Drop),
+            "23"
+        );
+        // a length inside a character takes the whole character
+        let split = Markup::Custom(Arc::new(|text: &str| text.starts_with('¶').then_some(1)));
+        assert_eq!(split.find("twenty ¶ three"), Some(7..9));
+        assert_eq!(
+            replace("twenty ¶ three", split, MarkupPlacement::Drop),
+            "23"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_number_kind() {
+        let en = Language::english();
+        let kinds = |text: &str, options: &Options| -> Vec<(String, NumberKind)> {
+            find_numbers_with(tokenize(text), &en, options)
+                .into_iter()
+                .map(|o| (o.text, o.kind))
+                .collect()
+        };
+        let options = Options {
+            informal_quantities: true,
+            adverbial_ordinals: true,
+            ..Options::new(0.0)
+        };
+        assert_eq!(
+            kinds(
+                "twenty-one, the twentieth, two point five, three fifths of them, in nineteen oh five",
+                &options
+            ),
+            [
+                ("21".into(), NumberKind::Cardinal),
+                ("20th".into(), NumberKind::Ordinal),
+                ("2.5".into(), NumberKind::Decimal),
+                ("3/5".into(), NumberKind::Fraction),
+                ("1905".into(), NumberKind::Year),
+            ]
+        );
+        assert_eq!(
+            kinds(
+                "$ twenty, €forty two, a gross of pencils, secondly",
+                &options
+            ),
+            [
+                ("20".into(), NumberKind::Currency),
+                ("42".into(), NumberKind::Currency),
+                ("144".into(), NumberKind::Cardinal),
+                ("2.".into(), NumberKind::Ordinal),
+            ]
+        );
+        assert_eq!(
+            kinds("code zero seven", &options),
+            [("07".into(), NumberKind::Sequence)]
+        );
+        let options = Options {
+            adjacent_number_policy: AdjacentNumberPolicy::Concatenate,
+            ..Options::new(0.0)
+        };
+        assert_eq!(
+            kinds("call five five five twelve", &options),
+            [("55512".into(), NumberKind::Sequence)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_extract_values() {
+        let en = Language::english();
+        let text =
+            "the twenty-first, -seven, two point zero five, three fifths, secondly, eighty's";
+        let options = Options {
+            adverbial_ordinals: true,
+            strip_clitics: true,
+            ordinal_style: OrdinalStyle::Superscript,
+            ..Options::new(0.0)
+        }
+        .with_formatter(alloc::boxed::Box::new(crate::formatter::PersianDigits));
+        let values = extract_values(text, &en, &options);
+        let digits: Vec<(&str, NumberKind)> =
+            values.iter().map(|v| (v.digits.as_str(), v.kind)).collect();
+        assert_eq!(
+            digits,
+            [
+                ("21", NumberKind::Ordinal),
+                ("-7", NumberKind::Cardinal),
+                ("2.05", NumberKind::Decimal),
+                ("3/5", NumberKind::Fraction),
+                ("2", NumberKind::Ordinal),
+                ("80", NumberKind::Cardinal),
+            ]
+        );
+        assert_eq!(values[1].value, -7.0);
+        assert_eq!(values[2].value, 2.05);
+        assert_eq!(values[3].value, 0.6);
+        assert_eq!(&text[values[1].range.clone()], "-seven");
+        assert_eq!(&text[values[5].range.clone()], "eighty's");
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_min_word_count() {
+        let en = Language::english();
+        let replace = |text: &str, threshold: f64, min_word_count: usize| {
+            let options = Options {
+                min_word_count,
+                ..Options::new(threshold)
+            };
+            replace_numbers_in_text_with(text, &en, &options)
+        };
+        let text = "forty, forty two and the twentieth, five point two, forty-two and three fifths of them";
+        assert_eq!(
+            replace(text, 0.0, 1),
+            "40, 42 and the 20th, 5.2, 42 and 3/5 of them"
+        );
+        assert_eq!(
+            replace(text, 0.0, 0),
+            "40, 42 and the 20th, 5.2, 42 and 3/5 of them"
+        );
+        assert_eq!(
+            replace(text, 0.0, 2),
+            "forty, 42 and the twentieth, 5.2, forty-two and 3/5 of them"
+        );
+        assert_eq!(
+            replace(text, 0.0, 3),
+            "forty, forty two and the twentieth, 5.2, forty-two and three fifths of them"
+        );
+        // a linking word only counts if the number goes on after it
+        assert_eq!(replace("forty and more", 0.0, 2), "forty and more");
+        assert_eq!(replace("one hundred and five", 0.0, 4), "105");
+        // both filters must pass
+        let text = "six cats, eleven dogs, twenty one birds and one two";
+        assert_eq!(
+            replace(text, 10.0, 1),
+            "six cats, 11 dogs, 21 birds and 1 2"
+        );
+        assert_eq!(
+            replace(text, 0.0, 2),
+            "six cats, eleven dogs, 21 birds and one two"
+        );
+        assert_eq!(
+            replace(text, 10.0, 2),
+            "six cats, eleven dogs, 21 birds and one two"
+        );
+        assert_eq!(replace("six seven", 10.0, 2), "six seven");
+        // the words of merged numbers add up
+        let options = Options {
+            adjacent_number_policy: AdjacentNumberPolicy::Concatenate,
+            min_word_count: 3,
+            ..Options::new(0.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text_with("call five five twelve, or five five", &en, &options),
+            "call 5512, or five five"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_skip_sentence_initial() {
+        let en = Language::english();
+        let skipping = Options {
+            skip_sentence_initial: true,
+            ..Options::new(0.0)
+        };
+        let replace =
+            |text: &str, options: &Options| replace_numbers_in_text_with(text, &en, options);
+        assert_eq!(
+            replace("Twenty people came. They stayed twenty days!", &skipping),
+            "Twenty people came. They stayed 20 days!"
+        );
+        assert_eq!(
+            replace("Who came? Twenty-one people, and then forty.", &skipping),
+            "Who came? Twenty-one people, and then 40."
+        );
+        // behind quotes and brackets
+        assert_eq!(
+            replace(
+                "He said: \"Twenty.\" (Thirty more?) \"Yes, forty.\"",
+                &skipping
+            ),
+            "He said: \"20.\" (Thirty more?) \"Yes, 40.\""
+        );
+        assert_eq!(
+            replace(
+                "\u{201c}Twelve apostles,\u{201d} he said. \u{ab} Twelve \u{bb}",
+                &skipping
+            ),
+            "\u{201c}Twelve apostles,\u{201d} he said. \u{ab} Twelve \u{bb}"
+        );
+        // mid-sentence
+        assert_eq!(replace("There were twenty.", &skipping), "There were 20.");
+        // with the numbers that follow the first one
+        assert_eq!(replace("one, two, three", &skipping), "one, two, three");
+        assert_eq!(
+            replace("Nineteen eighty-four was a year.", &skipping),
+            "Nineteen eighty-four was a year."
+        );
+        assert_eq!(
+            replace("Twenty, thirty people came, then forty.", &skipping),
+            "Twenty, thirty people came, then 40."
+        );
+        // an abbreviation ends a sentence
+        assert_eq!(
+            replace("Ask Mr. Twenty, or e.g. twenty people.", &skipping),
+            "Ask Mr. Twenty, or e.g. twenty people."
+        );
+        // the ordinals and the years on request
+        let text = "First prize. Nineteen oh five was a good year.";
+        assert_eq!(replace(text, &skipping), text);
+        let options = Options {
+            convert_sentence_initial_ordinals: true,
+            ..skipping.clone()
+        };
+        assert_eq!(
+            replace(text, &options),
+            "1st prize. Nineteen oh five was a good year."
+        );
+        let options = Options {
+            convert_sentence_initial_years: true,
+            ..skipping.clone()
+        };
+        assert_eq!(
+            replace(text, &options),
+            "First prize. 1905 was a good year."
+        );
+        // not on request
+        assert_eq!(
+            replace("Twenty people came.", &Options::new(0.0)),
+            "20 people came."
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_adjacent_number_policy() {
+        let en = Language::english();
+        let with = |policy| Options {
+            adjacent_number_policy: policy,
+            ..Options::new(10.0)
+        };
+        let separate = with(AdjacentNumberPolicy::Separate);
+        let concatenate = with(AdjacentNumberPolicy::Concatenate);
+        let auto = with(AdjacentNumberPolicy::Auto);
+        let cases = [
+            ("two three four", ["2 3 4", "234", "234"]),
+            ("two three", ["2 3", "23", "2 3"]),
+            ("twenty thirty", ["20 30", "2030", "20 30"]),
+            ("zero two three", ["02 3", "023", "02 3"]),
+            ("twenty three", ["23", "23", "23"]),
+            ("sizes two, four and six", ["sizes 2, 4 and 6"; 3]),
+            (
+                "one two three, four five six",
+                ["1 2 3, 4 5 6", "123, 456", "123, 456"],
+            ),
+            ("first second third", ["1st 2nd 3rd"; 3]),
+            ("one point five two", ["1.52"; 3]),
+            ("one point five twenty three", ["1.5 23"; 3]),
+        ];
+        for (text, [s, c, a]) in cases {
+            assert_eq!(replace_numbers_in_text_with(text, &en, &separate), s);
+            assert_eq!(replace_numbers_in_text_with(text, &en, &concatenate), c);
+            assert_eq!(replace_numbers_in_text_with(text, &en, &auto), a);
+        }
+        let ocs = find_numbers_with(tokenize("call two three four now"), &en, &auto);
+        assert_eq!(ocs.len(), 1);
+        assert_eq!(ocs[0].start, 2);
+        assert_eq!(ocs[0].end, 7);
+        assert_eq!(ocs[0].value, 234.0);
+    }
+
+    #[test]
+    #[cfg(feature = "fr")]
+    fn test_adjacent_number_policy_fr() {
+        let fr = Language::french();
+        let concatenate = Options {
+            adjacent_number_policy: AdjacentNumberPolicy::Concatenate,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_in_text("deux trois quatre", &fr, 10.0),
+            "2 3 4"
+        );
+        assert_eq!(
+            replace_numbers_in_text_with("zéro six vingt trente", &fr, &concatenate),
+            "062030"
+        );
+    }
+
+    #[test]
+    fn test_adjacent_number_policy_languages() {
+        let cases = [
+            (
+                "de",
+                ["zwei drei vier", "zwanzig dreißig", "null zwei drei"],
+            ),
+            ("es", ["dos tres cuatro", "veinte treinta", "cero dos tres"]),
+            (
+                "fr",
+                ["deux trois quatre", "vingt trente", "zéro deux trois"],
+            ),
+            ("gl", ["dous tres catro", "vinte trinta", "cero dous tres"]),
+            (
+                "hr",
+                ["dva tri četiri", "dvadeset trideset", "nula dva tri"],
+            ),
+            ("it", ["due tre quattro", "venti trenta", "zero due tre"]),
+            ("nl", ["twee drie vier", "twintig dertig", "nul twee drie"]),
+            ("pt", ["dois três quatro", "vinte trinta", "zero dois três"]),
+        ];
+        // the same for every language: [separate, concatenate, auto]
+        let expected = [
+            ["2 3 4", "234", "234"],
+            ["20 30", "2030", "20 30"],
+            ["02 3", "023", "02 3"],
+        ];
+        let policies = [
+            AdjacentNumberPolicy::Separate,
+            AdjacentNumberPolicy::Concatenate,
+            AdjacentNumberPolicy::Auto,
+        ];
+        for (code, texts) in cases {
+            let Some(lang) = crate::get_interpreter_for(code) else {
+                continue;
+            };
+            for (text, results) in texts.into_iter().zip(expected) {
+                for (policy, result) in policies.into_iter().zip(results) {
+                    let options = Options {
+                        adjacent_number_policy: policy,
+                        ..Options::new(10.0)
+                    };
+                    assert_eq!(
+                        replace_numbers_in_text_with(text, &lang, &options),
+                        result,
+                        "{code}: {text} with {policy:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    /// A token for a UI that only highlights numbers
+    #[derive(Debug)]
+    struct Highlighted {
+        text: String,
+        lowercase: String,
+        nan: bool,
+        kind: Option<TokenKind>,
+        group: Option<usize>,
+    }
+
+    impl BasicAnnotate for Highlighted {
+        fn text_lowercase(&self) -> &str {
+            &self.lowercase
+        }
+
+        fn set_nan(&mut self, val: bool) {
+            self.nan = val
+        }
+
+        fn set_kind(&mut self, kind: TokenKind) {
+            self.kind = Some(kind)
+        }
+
+        fn set_group_id(&mut self, id: Option<usize>) {
+            self.group = id
+        }
+    }
+
+    impl Token for &Highlighted {
+        fn text(&self) -> &str {
+            &self.text
+        }
+
+        fn text_lowercase(&self) -> &str {
+            &self.lowercase
+        }
+
+        fn not_a_number_part(&self) -> bool {
+            self.nan
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_annotate_numbers() {
+        let en = Language::english();
+        let mut tokens: Vec<Highlighted> =
+            tokenize("Pay twenty-one point five, then forty and two. Oh")
+                .map(|t| Highlighted {
+                    text: t.text,
+                    lowercase: t.lowercase,
+                    nan: t.nan,
+                    kind: None,
+                    group: None,
+                })
+                .collect();
+        annotate_numbers(&mut tokens, &en, 10.0);
+        crate::tests::dbg!(&tokens);
+        let annotations: Vec<(&str, TokenKind, Option<usize>)> = tokens
+            .iter()
+            .filter(|t| t.text != " ")
+            .map(|t| (t.text.as_str(), t.kind.unwrap(), t.group))
+            .collect();
+        assert_eq!(
+            annotations,
+            [
+                ("Pay", TokenKind::Other, None),
+                ("twenty-one", TokenKind::NumberWord, Some(0)),
+                ("point", TokenKind::DecimalSep, Some(0)),
+                ("five", TokenKind::NumberWord, Some(0)),
+                (", ", TokenKind::Separator, None),
+                ("then", TokenKind::Linking, None),
+                ("forty", TokenKind::NumberWord, Some(1)),
+                ("and", TokenKind::Linking, Some(1)),
+                ("two", TokenKind::NumberWord, Some(1)),
+                (". ", TokenKind::Separator, None),
+                ("Oh", TokenKind::Other, None),
+            ]
+        );
+        // nothing was replaced
+        let text: String = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(text, "Pay twenty-one point five, then forty and two. Oh");
+    }
+
+    /// A token that does not lowercase its text
+    #[cfg(feature = "en")]
+    struct Verbatim<'a>(&'a str);
+
+    #[cfg(feature = "en")]
+    impl Token for Verbatim<'_> {
+        fn text(&self) -> &str {
+            self.0
+        }
+
+        fn text_lowercase(&self) -> &str {
+            self.0
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_find_mixed_case_tokens() {
+        let en = Language::english();
+        let stream = ["I", " ", "have", " ", "TWENTY-One", " ", "Cats"].map(Verbatim);
+        let ocs = find_numbers(stream.into_iter(), &en, 10.0);
+        assert_eq!(ocs.len(), 1);
+        assert_eq!(ocs[0].text, "21");
+        assert_eq!((ocs[0].start, ocs[0].end), (4, 5));
+    }
+
+    #[test]
+    #[cfg(feature = "fr")]
     fn bench() {
         let fr = Language::french();
         // increase to bench
@@ -628,4 +5065,180 @@ mod tests {
             );
         }
     }
+
+    /// A writer that fails beyond `cap` bytes
+    #[cfg(feature = "en")]
+    struct Capped {
+        out: String,
+        cap: usize,
+    }
+
+    #[cfg(feature = "en")]
+    impl core::fmt::Write for Capped {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            if self.out.len() + s.len() > self.cap {
+                return Err(core::fmt::Error);
+            }
+            self.out.push_str(s);
+            Ok(())
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "en")]
+    fn test_replace_numbers_to() {
+        let en = Language::english();
+        let text = "I have twenty-one cats, three hundred fish and one dog.";
+        let options = Options::new(10.0);
+        let mut out = Capped {
+            out: String::new(),
+            cap: 100,
+        };
+        let stats = replace_numbers_to(text, &en, &options, &mut out).unwrap();
+        assert_eq!(out.out, replace_numbers_in_text_with(text, &en, &options));
+        assert_eq!(out.out, "I have 21 cats, 300 fish and one dog.");
+        assert_eq!(
+            stats,
+            Stats {
+                numbers: 2,
+                bytes_written: out.out.len(),
+                linking_words: 0,
+                aborted: 0,
+            }
+        );
+        // the error stops the writing mid-stream
+        let mut out = Capped {
+            out: String::new(),
+            cap: 20,
+        };
+        assert_eq!(
+            replace_numbers_to(text, &en, &options, &mut out),
+            Err(SpliceError::Write)
+        );
+        assert_eq!(out.out, "I have 21 cats, 300");
+        let mut out = Capped {
+            out: String::new(),
+            cap: 0,
+        };
+        assert_eq!(
+            replace_numbers_to("", &en, &options, &mut out),
+            Ok(Stats::default())
+        );
+        // a broken invariant is not a writer error
+        let options = Options {
+            paranoid: true,
+            max_output_factor: 0,
+            ..Options::new(10.0)
+        };
+        assert_eq!(
+            replace_numbers_to(text, &en, &options, &mut String::new()),
+            Err(SpliceError::Internal(EditError::TooLong { index: 0 }))
+        );
+        assert_eq!(
+            replace_numbers_in_text_checked(text, &en, &options),
+            Err(SpliceError::Internal(EditError::TooLong { index: 0 }))
+        );
+        assert_eq!(replace_numbers_in_text_with(text, &en, &options), text);
+    }
+
+    /// Toy Chinese-like language, written without spaces.
+    #[cfg(feature = "unicode-segmentation")]
+    struct Han;
+
+    #[cfg(feature = "unicode-segmentation")]
+    impl LangInterpreter for Han {
+        fn apply(&self, num_func: &str, b: &mut DigitString) -> Result<(), Error> {
+            let units = ["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+            match num_func {
+                "十" if b.is_empty() => b.put(b"10"),
+                "十" => b.shift(1),
+                "百" => b.shift(2),
+                word => match units.iter().position(|unit| *unit == word) {
+                    Some(digit) => b.put(&[b'0' + digit as u8]),
+                    None => Err(Error::NaN),
+                },
+            }
+        }
+
+        fn apply_decimal(&self, decimal_func: &str, b: &mut DigitString) -> Result<(), Error> {
+            self.apply(decimal_func, b)
+        }
+
+        fn get_morph_marker(&self, _word: &str) -> MorphologicalMarker {
+            MorphologicalMarker::None
+        }
+
+        fn check_decimal_separator(&self, _word: &str) -> Option<char> {
+            None
+        }
+
+        fn format_and_value(&self, b: &DigitString) -> (String, f64) {
+            let repr = b.to_string();
+            let val = repr.parse().unwrap();
+            (repr, val)
+        }
+
+        fn format_decimal_and_value(
+            &self,
+            int: &DigitString,
+            dec: &DigitString,
+            sep: char,
+        ) -> (String, f64) {
+            let repr = alloc::format!("{int}{sep}{dec}");
+            let val = repr.parse().unwrap();
+            (repr, val)
+        }
+
+        fn is_linking(&self, _word: &str) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-segmentation")]
+    fn test_unspaced_script() {
+        assert_eq!(
+            replace_numbers_in_text("我有三百本书，他有二十本。", &Han, 0.0),
+            "我有300本书，他有20本。"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-segmentation")]
+    fn test_unspaced_script_in_stream() {
+        let text = "我有三百本书，他有二十本。";
+        let tokens: Vec<BasicToken> = ["我有三百本书", "，", "他有二十本", "。"]
+            .into_iter()
+            .map(BasicToken::new)
+            .collect();
+        let found: Vec<_> = find_numbers(tokens.iter(), &Han, 0.0)
+            .into_iter()
+            .map(|occurence| {
+                (
+                    occurence.start..occurence.end,
+                    occurence.original(text).to_string(),
+                    occurence.text,
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (0..1, "我有三百本书".to_string(), "我有300本书".to_string()),
+                (2..3, "他有二十本".to_string(), "他有20本".to_string())
+            ]
+        );
+        let replaced: String = replace_numbers_in_stream(tokens, &Han, 0.0)
+            .into_iter()
+            .map(|token| token.text)
+            .collect();
+        assert_eq!(replaced, replace_numbers_in_text(text, &Han, 0.0));
+        // a token is part of one number at most
+        let tokens = [BasicToken::new(text)];
+        let found: Vec<_> = find_numbers(tokens.iter(), &Han, 0.0)
+            .into_iter()
+            .map(|occurence| occurence.text)
+            .collect();
+        assert_eq!(found, ["我有300本书，他有二十本。"]);
+    }
 }
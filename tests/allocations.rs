@@ -0,0 +1,53 @@
+//! Peak memory of the text replacement, measured with a counting allocator.
+//!
+//! This is an integration test of its own, as the allocator is global to the test binary.
+
+#![cfg(feature = "en")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use text2num::{Language, replace_numbers_in_text};
+
+struct Counting;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[test]
+fn test_peak_allocation() {
+    let sentence = "Twenty-five cows, twelve chickens and one hundred twenty five kg of potatoes. ";
+    // about 10 MB
+    let text = sentence.repeat(1 << 17);
+    let tokens = text2num::pipeline::tokenize(sentence).len() << 17;
+    let en = Language::english();
+
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    let out = replace_numbers_in_text(&text, &en, 10.0);
+    let peak = PEAK.load(Ordering::Relaxed) - base;
+
+    assert!(out.starts_with("25 cows, 12 chickens and 125 kg of potatoes. 25 cows"));
+    // The output and 16 bytes per token, that are annotated by chunks
+    let bound = text.len() + tokens * 16;
+    assert!(
+        peak < bound,
+        "peak allocation of {peak} bytes, expected less than {bound}"
+    );
+}
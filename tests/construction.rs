@@ -0,0 +1,66 @@
+//! Cost of building the builtin interpreters, measured with a counting allocator.
+//!
+//! This is an integration test of its own, as the allocator is global to the test binary.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use text2num::{Language, get_interpreter_for, replace_numbers_in_text};
+
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+const CODES: [&str; 17] = [
+    "bg", "bs", "de", "en", "eo", "es", "fa", "fr", "gl", "hr", "it", "lt", "nl", "pt", "sk", "sr",
+    "sw",
+];
+
+fn build_all() -> Vec<Option<Language>> {
+    let mut languages = Vec::with_capacity(CODES.len());
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for code in CODES {
+        languages.push(get_interpreter_for(code));
+    }
+    assert_eq!(
+        ALLOCATIONS.load(Ordering::Relaxed),
+        before,
+        "construction allocates"
+    );
+    languages
+}
+
+#[test]
+fn test_construction_is_free() {
+    // Before and after the first use of the languages, that builds their shared tables
+    let languages = build_all();
+    for language in languages.iter().flatten() {
+        replace_numbers_in_text("zwanzig twenty vingt", language, 0.0);
+    }
+    let languages = build_all();
+
+    // A language is copied into the threads that share it
+    let language = languages.into_iter().flatten().next().unwrap();
+    let results: Vec<String> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..4)
+            .map(|_| scope.spawn(move || replace_numbers_in_text("zwanzig", &language, 0.0)))
+            .collect();
+        workers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+    let expected = replace_numbers_in_text("zwanzig", &language, 0.0);
+    assert!(results.iter().all(|result| *result == expected));
+}
@@ -0,0 +1,173 @@
+//! Conformance of the builtin languages on the corpora of `tests/corpus`.
+//!
+//! Each `<code>.tsv` file holds utterances in the style of ASR output (lowercase, no punctuation, disfluencies)
+//! for the language of that code, one per line, with three tab separated columns:
+//!
+//! 1. the input utterance;
+//! 2. the expected output of [`replace_numbers_in_text_with`];
+//! 3. optional comma separated flags:
+//!    - `threshold=<value>`: the lone number threshold (10 by default);
+//!    - `stutters`: set [`Options::collapse_stutters`];
+//!    - `concatenate` or `auto`: the [`AdjacentNumberPolicy`];
+//!    - `xfail`: a known gap, expected to fail until it is fixed.
+//!
+//! Empty lines and lines starting with `#` are ignored.
+//! All the lines are run and all the failures reported at once, including the `xfail` lines that pass.
+
+use std::fs;
+use std::path::Path;
+
+use text2num::{
+    AdjacentNumberPolicy, Options, OrdinalStyle, get_interpreter_for, replace_numbers_in_text_with,
+};
+
+struct Case {
+    location: String,
+    input: String,
+    expected: String,
+    options: Options,
+    xfail: bool,
+}
+
+fn parse_flags(flags: &str, location: &str) -> (Options, bool) {
+    let mut options = Options::new(10.0);
+    let mut xfail = false;
+    for flag in flags
+        .split(',')
+        .map(str::trim)
+        .filter(|flag| !flag.is_empty())
+    {
+        match flag.split_once('=') {
+            Some(("threshold", value)) => {
+                options.threshold = value
+                    .parse()
+                    .unwrap_or_else(|_| panic!("{location}: bad threshold {value:?}"))
+            }
+            None if flag == "stutters" => options.collapse_stutters = true,
+            None if flag == "concatenate" => {
+                options.adjacent_number_policy = AdjacentNumberPolicy::Concatenate
+            }
+            None if flag == "auto" => options.adjacent_number_policy = AdjacentNumberPolicy::Auto,
+            None if flag == "xfail" => xfail = true,
+            _ => panic!("{location}: unknown flag {flag:?}"),
+        }
+    }
+    (options, xfail)
+}
+
+fn load(path: &Path) -> Vec<Case> {
+    let name = path.file_name().unwrap().to_string_lossy();
+    let content = fs::read_to_string(path).unwrap();
+    let mut cases = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let location = format!("{name}:{}", index + 1);
+        let mut columns = line.split('\t');
+        let (Some(input), Some(expected)) = (columns.next(), columns.next()) else {
+            panic!("{location}: expected at least two tab separated columns");
+        };
+        let (options, xfail) = parse_flags(columns.next().unwrap_or(""), &location);
+        cases.push(Case {
+            location,
+            input: input.into(),
+            expected: expected.into(),
+            options,
+            xfail,
+        });
+    }
+    cases
+}
+
+/// The corpus files, in order.
+fn corpora() -> Vec<std::path::PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+    let mut paths: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "tsv"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[test]
+fn test_corpora() {
+    let paths = corpora();
+    let mut failures = Vec::new();
+    let (mut run, mut known) = (0, 0);
+    for path in paths {
+        let code = path.file_stem().unwrap().to_string_lossy();
+        // the language may be disabled
+        let Some(lang) = get_interpreter_for(&code) else {
+            continue;
+        };
+        for case in load(&path) {
+            run += 1;
+            let got = replace_numbers_in_text_with(&case.input, &lang, &case.options);
+            match (got == case.expected, case.xfail) {
+                (true, false) => (),
+                (false, true) => known += 1,
+                (passes, _) => failures.push((case, got, passes)),
+            }
+        }
+    }
+    for (case, got, passes) in &failures {
+        if *passes {
+            eprintln!("{}: passes, remove the xfail flag", case.location);
+        } else {
+            eprintln!("{}", case.location);
+        }
+        eprintln!("    input    | {}", case.input);
+        eprintln!("    expected | {}", case.expected);
+        eprintln!("    got      | {got}");
+    }
+    eprintln!(
+        "{run} corpus lines, {} failures, {known} known gaps",
+        failures.len()
+    );
+    assert!(failures.is_empty(), "{} corpus failures", failures.len());
+}
+
+/// The replacement of a replaced text changes nothing: the input lines and their expected outputs,
+/// replaced once in each ordinal style, are replaced again as they are.
+#[test]
+fn test_idempotency() {
+    let mut failures = Vec::new();
+    for path in corpora() {
+        let code = path.file_stem().unwrap().to_string_lossy();
+        let Some(lang) = get_interpreter_for(&code) else {
+            continue;
+        };
+        for case in load(&path) {
+            for ordinal_style in [
+                OrdinalStyle::Plain,
+                OrdinalStyle::Superscript,
+                OrdinalStyle::PeriodOnly,
+            ] {
+                let options = Options {
+                    ordinal_style,
+                    ..case.options.clone()
+                };
+                for text in [&case.input, &case.expected] {
+                    let once = replace_numbers_in_text_with(text, &lang, &options);
+                    let twice = replace_numbers_in_text_with(&once, &lang, &options);
+                    if twice != once {
+                        failures.push((case.location.clone(), once, twice));
+                    }
+                }
+            }
+        }
+    }
+    for (location, once, twice) in &failures {
+        eprintln!("{location}");
+        eprintln!("    once  | {once}");
+        eprintln!("    twice | {twice}");
+    }
+    assert!(
+        failures.is_empty(),
+        "{} idempotency failures",
+        failures.len()
+    );
+}
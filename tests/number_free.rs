@@ -0,0 +1,67 @@
+//! Allocations of the text replacement on a text without numbers, measured with a counting allocator.
+//!
+//! This is an integration test of its own, as the allocator is global to the test binary.
+
+#![cfg(feature = "en")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use text2num::{Language, Options, replace_numbers_in_text_cow};
+
+struct Counting;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// The bytes and the number of allocations of [`replace_numbers_in_text_cow`] on `text`.
+fn allocated(text: &str, en: &Language, options: &Options) -> (usize, usize) {
+    let (bytes, count) = (
+        ALLOCATED.load(Ordering::Relaxed),
+        ALLOCATIONS.load(Ordering::Relaxed),
+    );
+    let replaced = replace_numbers_in_text_cow(text, en, options);
+    assert!(matches!(replaced.text, Cow::Borrowed(_)));
+    assert_eq!(replaced.count, 0);
+    (
+        ALLOCATED.load(Ordering::Relaxed) - bytes,
+        ALLOCATIONS.load(Ordering::Relaxed) - count,
+    )
+}
+
+#[test]
+fn test_number_free_allocation() {
+    let sentence = "The quick brown fox jumps over the lazy dog, and Nobody counts anything here. ";
+    let en = Language::english();
+    let options = Options::new(10.0);
+    let small = sentence.repeat(100);
+    let large = sentence.repeat(10_000);
+
+    let (small_bytes, small_count) = allocated(&small, &en, &options);
+    let (large_bytes, large_count) = allocated(&large, &en, &options);
+
+    // Only the window of tokens being annotated and the state of the finder, whatever the length of the text:
+    // nothing per word, nor per chunk of tokens.
+    assert!(
+        large_bytes < 20_000 && large_count < 10,
+        "{large_bytes} bytes in {large_count} allocations for {} bytes of text",
+        large.len()
+    );
+    assert_eq!((large_bytes, large_count), (small_bytes, small_count));
+}